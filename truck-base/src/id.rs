@@ -3,12 +3,69 @@ use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 
 /// ID structure with `Copy`, `Hash` and `Eq` using raw pointers
+///
+/// With the feature `deterministic-ids`, the elements which hold their IDs are given the numbers
+/// of the thread-local counter instead of the addresses. Hence, the IDs are reproducible if the
+/// elements are created in the same order, which is useful for the snapshot tests.
+/// cf. [`ID::register`], [`reset_id_counter`]
 pub struct ID<T>(usize, PhantomData<T>);
 
 impl<T> ID<T> {
     /// Creates the ID by a raw pointer.
     #[inline(always)]
     pub fn new(ptr: *const T) -> ID<T> { ID(ptr as usize, PhantomData) }
+
+    /// Creates the ID of a new element by the next number of the thread-local counter.
+    ///
+    /// The ID does not depend on the address, so the element has to hold the returned ID by itself.
+    /// # Examples
+    /// ```
+    /// use truck_base::id::*;
+    /// reset_id_counter();
+    /// let id0 = ID::<f64>::register();
+    /// let id1 = ID::<f64>::register();
+    /// assert_ne!(id0, id1);
+    /// assert_eq!(format!("{id0:?}"), "0x0");
+    /// ```
+    #[cfg(feature = "deterministic-ids")]
+    #[inline(always)]
+    pub fn register() -> ID<T> { ID(next_id(), PhantomData) }
+}
+
+/// Resets the thread-local counter giving the IDs with the feature `deterministic-ids`.
+///
+/// The elements created after the reset in the same thread are numbered from zero again. The elements
+/// created before the reset keep their IDs, so the IDs given after the reset may be the same as theirs.
+/// The counter is not shared with the other threads, so build each fixture in one thread.
+/// # Examples
+/// ```
+/// use truck_base::id::*;
+/// reset_id_counter();
+/// let id0 = ID::<f64>::register();
+/// reset_id_counter();
+/// let id1 = ID::<f64>::register();
+/// assert_eq!(id0, id1);
+/// ```
+#[cfg(feature = "deterministic-ids")]
+#[inline(always)]
+pub fn reset_id_counter() { COUNTER.with(|counter| counter.set(0)) }
+
+/// Returns the next number of the thread-local counter with the feature `deterministic-ids`.
+///
+/// This is for the IDs which are not associated with pointers, e.g. the ones of rendered objects.
+#[cfg(feature = "deterministic-ids")]
+#[inline(always)]
+pub fn next_id() -> usize {
+    COUNTER.with(|counter| {
+        let id = counter.get();
+        counter.set(id + 1);
+        id
+    })
+}
+
+#[cfg(feature = "deterministic-ids")]
+thread_local! {
+    static COUNTER: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
 }
 
 impl<T> Clone for ID<T> {
@@ -673,6 +673,8 @@ pub fn derive_parametric_surface3d(input: TokenStream) -> TokenStream {
                 variants,
                 trait_name1,
                 fn normal(&self, u: f64, v: f64) -> Vector3,
+                fn gaussian_curvature(&self, u: f64, v: f64) -> f64,
+                fn mean_curvature(&self, u: f64, v: f64) -> f64,
             );
             quote! {
                 #[automatically_derived]
@@ -726,6 +728,10 @@ pub fn derive_parametric_surface3d(input: TokenStream) -> TokenStream {
                     #(#where_predicates,)*
                     #field_type: #trait_name0, {
                     fn normal(&self, u: f64, v: f64) -> Vector3 { self.0.normal(u, v) }
+                    fn gaussian_curvature(&self, u: f64, v: f64) -> f64 {
+                        self.0.gaussian_curvature(u, v)
+                    }
+                    fn mean_curvature(&self, u: f64, v: f64) -> f64 { self.0.mean_curvature(u, v) }
                 }
             }
         }
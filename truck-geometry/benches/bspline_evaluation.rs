@@ -0,0 +1,78 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use truck_geometry::prelude::*;
+
+fn bspcurve() -> BSplineCurve<Point3> {
+    let knot_vec = KnotVec::uniform_knot(3, 20);
+    let ctrl_pts = (0..23)
+        .map(|i| {
+            let t = i as f64;
+            Point3::new(t, f64::sin(t), f64::cos(2.0 * t))
+        })
+        .collect();
+    BSplineCurve::new(knot_vec, ctrl_pts)
+}
+
+fn bspsurface() -> BSplineSurface<Point3> {
+    let knot_vecs = (KnotVec::uniform_knot(3, 10), KnotVec::uniform_knot(3, 10));
+    let ctrl_pts = (0..13)
+        .map(|i| {
+            (0..13)
+                .map(|j| {
+                    let (u, v) = (i as f64, j as f64);
+                    Point3::new(u, v, f64::sin(u) * f64::cos(v))
+                })
+                .collect()
+        })
+        .collect();
+    BSplineSurface::new(knot_vecs, ctrl_pts)
+}
+
+fn curve_evaluation(c: &mut Criterion) {
+    let curve = bspcurve();
+    let ts = (0..=1000).map(|i| i as f64 / 1000.0).collect::<Vec<_>>();
+    let mut group = c.benchmark_group("curve evaluation");
+    group.bench_function("subs", |b| {
+        b.iter(|| ts.iter().map(|t| curve.subs(*t)).collect::<Vec<_>>())
+    });
+    group.bench_function("subs_many", |b| b.iter(|| curve.subs_many(black_box(&ts))));
+    group.finish();
+
+    let mut group = c.benchmark_group("curve division");
+    group.bench_function("by subs", |b| {
+        b.iter(|| algo::curve::parameter_division(&curve, (0.0, 1.0), black_box(0.001)))
+    });
+    group.bench_function("by subs_many", |b| {
+        b.iter(|| curve.parameter_division((0.0, 1.0), black_box(0.001)))
+    });
+    group.finish();
+}
+
+fn surface_evaluation(c: &mut Criterion) {
+    let surface = bspsurface();
+    let us = (0..=100).map(|i| i as f64 / 100.0).collect::<Vec<_>>();
+    let mut group = c.benchmark_group("surface evaluation");
+    group.bench_function("subs", |b| {
+        b.iter(|| {
+            us.iter()
+                .map(|u| us.iter().map(|v| surface.subs(*u, *v)).collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("subs_grid", |b| {
+        b.iter(|| surface.subs_grid(black_box(&us), black_box(&us)))
+    });
+    group.finish();
+
+    let range = ((0.0, 1.0), (0.0, 1.0));
+    let mut group = c.benchmark_group("surface division");
+    group.bench_function("by subs", |b| {
+        b.iter(|| algo::surface::parameter_division(&surface, range, black_box(0.001)))
+    });
+    group.bench_function("by subs_grid", |b| {
+        b.iter(|| surface.parameter_division(range, black_box(0.001)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, curve_evaluation, surface_evaluation);
+criterion_main!(benches);
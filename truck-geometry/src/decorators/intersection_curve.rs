@@ -8,7 +8,7 @@ fn subs_tuple<S: ParametricSurface>(
     (surface.subs(u, v), surface.uder(u, v), surface.vder(u, v))
 }
 
-fn double_projection<S0, S1>(
+pub(crate) fn double_projection<S0, S1>(
     surface0: &S0,
     hint0: Option<(f64, f64)>,
     surface1: &S1,
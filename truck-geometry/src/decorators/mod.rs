@@ -323,3 +323,5 @@ mod pcurve;
 mod processor;
 mod revolved_curve;
 mod trimmied_curve;
+
+pub(crate) use intersection_curve::double_projection;
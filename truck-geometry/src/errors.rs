@@ -191,6 +191,56 @@ the number of control points: {1}"
     /// ```
     #[error("Gaussian elimination is failed.")]
     GaussianEliminationFailure,
+    /// The number of points is too few to fit a B-spline curve of the degree.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    ///
+    /// let points = [Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0)];
+    /// assert_eq!(
+    ///     BSplineCurve::interpolate(&points, 2),
+    ///     Err(Error::TooFewPoints(2, 2)),
+    /// );
+    /// ```
+    #[error(
+        "The number of points is too few for the degree.
+the number of points: {0}
+the degree: {1}"
+    )]
+    TooFewPoints(usize, usize),
+    /// The weight of a control point must be positive.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    ///
+    /// let mut curve = NurbsCurve::new(BSplineCurve::new(
+    ///     KnotVec::bezier_knot(1),
+    ///     vec![Vector3::new(0.0, 0.0, 1.0), Vector3::new(1.0, 0.0, 1.0)],
+    /// ));
+    /// assert!(matches!(
+    ///     curve.try_set_weight(1, -1.0),
+    ///     Err(Error::NonPositiveWeight(w)) if w == -1.0,
+    /// ));
+    /// ```
+    #[error("The weight must be positive, but the given weight is {0}.")]
+    NonPositiveWeight(f64),
+    /// The points are collinear, and the plane through them is not determined.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// use truck_geometry::errors::Error;
+    ///
+    /// let points = [
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(2.0, 0.0, 0.0),
+    /// ];
+    /// assert_eq!(best_fit_plane(&points), Err(Error::CollinearPoints));
+    /// ```
+    #[error("The points are collinear, and the plane is not determined.")]
+    CollinearPoints,
 }
 
 #[test]
@@ -208,5 +258,8 @@ fn print_messages() {
     writeln!(stderr, "{}\n", Error::EmptyControlPoints).unwrap();
     writeln!(stderr, "{}\n", Error::TooShortKnotVector(1, 2)).unwrap();
     writeln!(stderr, "{}\n", Error::IrregularControlPoints).unwrap();
+    writeln!(stderr, "{}\n", Error::TooFewPoints(2, 3)).unwrap();
+    writeln!(stderr, "{}\n", Error::NonPositiveWeight(-1.0)).unwrap();
+    writeln!(stderr, "{}\n", Error::CollinearPoints).unwrap();
     writeln!(stderr, "*******************************************************").unwrap();
 }
@@ -0,0 +1,667 @@
+use crate::{decorators::double_projection, prelude::*};
+use std::f64::consts::PI;
+
+const TRIALS: usize = 100;
+const SEED_DIVISION: usize = 16;
+const MAX_MARCHING_STEPS: usize = 10_000;
+
+/// Computes the intersection curves of two surfaces, sampled to the tolerance `tol`.
+///
+/// Each branch of the intersection is returned as one polyline, i.e. the sequence of its vertices.
+/// All vertices of the polylines lie on both surfaces,
+/// and the distance between the polylines and the exact intersection is less than `tol`.
+/// Closed branches, e.g. the section of a sphere by a plane, are returned as closed polylines.
+///
+/// All surfaces are traced by the numerical marching. The intersections of a plane with a plane,
+/// a sphere and a cylinder are computed in closed form by [`plane_plane_intersection`],
+/// [`plane_sphere_intersection`] and [`plane_cylinder_intersection`] respectively.
+///
+/// The points where the surfaces touch tangentially are returned as polylines with only one vertex.
+/// Overlapping surfaces are not supported.
+///
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// let plane = Plane::new(
+///     Point3::new(-2.0, -2.0, 0.5),
+///     Point3::new(2.0, -2.0, 0.5),
+///     Point3::new(-2.0, 2.0, 0.5),
+/// );
+/// let sphere = Sphere::new(Point3::origin(), 1.0);
+/// let polylines = surface_surface_intersection(&plane, &sphere, 0.001);
+/// assert_eq!(polylines.len(), 1);
+///
+/// // closed polyline
+/// let points = &polylines[0];
+/// assert_near!(points[0], points[points.len() - 1]);
+/// // the radius of the section is `sqrt(1 - 0.5^2)`.
+/// let center = Point3::new(0.0, 0.0, 0.5);
+/// points.iter().for_each(|p| assert_near!(p.distance(center), f64::sqrt(0.75)));
+/// ```
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+pub fn surface_surface_intersection<S0, S1>(
+    surface0: &S0,
+    surface1: &S1,
+    tol: f64,
+) -> Vec<Vec<Point3>>
+where
+    S0: ParametricSurface3D
+        + BoundedSurface
+        + ParameterDivision2D
+        + SearchNearestParameter<D2, Point = Point3>,
+    S1: ParametricSurface3D
+        + BoundedSurface
+        + ParameterDivision2D
+        + SearchNearestParameter<D2, Point = Point3>,
+{
+    nonpositive_tolerance!(tol);
+    let seeds0 = search_seeds(surface0, surface1, tol);
+    let seeds1 = search_seeds(surface1, surface0, tol);
+    let bdd = seeds0.bounding_box + seeds1.bounding_box;
+    let marcher = Marcher {
+        surface0,
+        surface1,
+        domain0: Domain::new(surface0),
+        domain1: Domain::new(surface1),
+        tol,
+        max_step: f64::max(bdd.diameter(), tol),
+    };
+
+    let swap = |(p, uv1, uv0): Triple| (p, uv0, uv1);
+    let crossings = seeds0
+        .crossings
+        .into_iter()
+        .chain(seeds1.crossings.into_iter().map(swap));
+    let contacts = seeds0
+        .contacts
+        .into_iter()
+        .chain(seeds1.contacts.into_iter().map(swap));
+
+    let mut polylines = Vec::<Vec<Point3>>::new();
+    crossings.for_each(|seed| {
+        if !is_traced(&polylines, seed.0, tol) {
+            polylines.push(marcher.trace(seed));
+        }
+    });
+    contacts.for_each(|(p, _, _)| {
+        if !is_traced(&polylines, p, tol) {
+            polylines.push(vec![p]);
+        }
+    });
+    polylines
+}
+
+/// Computes the intersection of two planes in closed form.
+///
+/// The planes are the parallelograms of the parameters in `[0, 1] x [0, 1]`, so the intersection
+/// is a segment of the line. Returns the segment as a polyline with two vertices, or with only
+/// one vertex if the planes touch at a point. Parallel planes are regarded as disjoint.
+///
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// let plane0 = Plane::new(
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+/// );
+/// let plane1 = Plane::new(
+///     Point3::new(0.5, -1.0, -1.0),
+///     Point3::new(0.5, 2.0, -1.0),
+///     Point3::new(0.5, -1.0, 1.0),
+/// );
+/// let polylines = plane_plane_intersection(&plane0, &plane1);
+/// assert_eq!(polylines.len(), 1);
+/// let (p, q) = (polylines[0][0], polylines[0][1]);
+/// let (p, q) = if p.y < q.y { (p, q) } else { (q, p) };
+/// assert_near!(p, Point3::new(0.5, 0.0, 0.0));
+/// assert_near!(q, Point3::new(0.5, 1.0, 0.0));
+/// ```
+pub fn plane_plane_intersection(plane0: &Plane, plane1: &Plane) -> Vec<Vec<Point3>> {
+    let (n0, n1) = (plane0.normal(), plane1.normal());
+    let dir = n0.cross(n1);
+    if dir.magnitude().so_small() {
+        return Vec::new();
+    }
+    let (d0, d1) = (
+        n0.dot(plane0.origin().to_vec()),
+        n1.dot(plane1.origin().to_vec()),
+    );
+    let origin = Point3::from_vec((n1.cross(dir) * d0 + dir.cross(n0) * d1) / dir.magnitude2());
+    let [a, b] = Slab::plane(plane0);
+    let [c, d] = Slab::plane(plane1);
+    clip_line(origin, dir, &[a, b, c, d])
+}
+
+/// Computes the intersection of a plane and a sphere in closed form, sampled to the tolerance `tol`.
+///
+/// The plane is the parallelogram of the parameters in `[0, 1] x [0, 1]`, so the section circle is
+/// divided into the arcs in the parallelogram. All vertices of the polylines are on the exact circle.
+/// If the plane is tangent to the sphere, returns the contact point as a polyline with only one vertex.
+///
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// let plane = Plane::new(
+///     Point3::new(-2.0, -2.0, 0.5),
+///     Point3::new(2.0, -2.0, 0.5),
+///     Point3::new(-2.0, 2.0, 0.5),
+/// );
+/// let sphere = Sphere::new(Point3::origin(), 1.0);
+/// let polylines = plane_sphere_intersection(&plane, &sphere, 0.001);
+/// assert_eq!(polylines.len(), 1);
+///
+/// let points = &polylines[0];
+/// assert_near!(points[0], points[points.len() - 1]);
+/// let center = Point3::new(0.0, 0.0, 0.5);
+/// points.iter().for_each(|p| assert_near!(p.distance(center), f64::sqrt(0.75)));
+/// ```
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+pub fn plane_sphere_intersection(plane: &Plane, sphere: &Sphere, tol: f64) -> Vec<Vec<Point3>> {
+    nonpositive_tolerance!(tol);
+    let normal = plane.normal();
+    let distance = normal.dot(sphere.center() - plane.origin());
+    let center = sphere.center() - normal * distance;
+    let slabs = Slab::plane(plane);
+    if f64::abs(distance).near(&sphere.radius()) {
+        return match slabs.iter().all(|slab| slab.contains(center, TOLERANCE)) {
+            true => vec![vec![center]],
+            false => Vec::new(),
+        };
+    } else if f64::abs(distance) > sphere.radius() {
+        return Vec::new();
+    }
+    let radius = f64::sqrt(sphere.radius() * sphere.radius() - distance * distance);
+    let x = plane.u_axis().normalize();
+    let y = normal.cross(x);
+    let circle = |t: f64| center + (x * f64::cos(t) + y * f64::sin(t)) * radius;
+    clip_closed_curve(circle, circle_division(radius, tol), &slabs)
+}
+
+/// Computes the intersection of a plane and a cylinder in closed form, sampled to the tolerance `tol`.
+///
+/// The cylinder is the revolution of the line parallel to the axis. The plane is the parallelogram
+/// of the parameters in `[0, 1] x [0, 1]`, and the cylinder is bounded by the end points of the line,
+/// so the section, an ellipse or a pair of lines, is divided into the parts in both of them.
+/// All vertices of the polylines are on the exact section. If the plane is tangent to the cylinder,
+/// returns the contact line. If the line is not parallel to the axis, the surface is not
+/// a cylinder, and the intersection is traced by [`surface_surface_intersection`].
+///
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// let line = Line(Point3::new(1.0, 0.0, -2.0), Point3::new(1.0, 0.0, 2.0));
+/// let cylinder = RevolutedCurve::by_revolution(line, Point3::origin(), Vector3::unit_z());
+/// let plane = Plane::new(
+///     Point3::new(-2.0, -2.0, -1.0),
+///     Point3::new(2.0, -2.0, 1.0),
+///     Point3::new(-2.0, 2.0, -1.0),
+/// );
+/// let polylines = plane_cylinder_intersection(&plane, &cylinder, 0.001);
+/// assert_eq!(polylines.len(), 1);
+///
+/// // the ellipse `x^2 + y^2 = 1, z = x / 2`
+/// let points = &polylines[0];
+/// assert_near!(points[0], points[points.len() - 1]);
+/// points.iter().for_each(|p| {
+///     assert_near!(p.x * p.x + p.y * p.y, 1.0);
+///     assert_near!(p.z, p.x / 2.0);
+/// });
+/// ```
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+pub fn plane_cylinder_intersection(
+    plane: &Plane,
+    cylinder: &RevolutedCurve<Line<Point3>>,
+    tol: f64,
+) -> Vec<Vec<Point3>> {
+    nonpositive_tolerance!(tol);
+    let (origin, axis) = (cylinder.origin(), cylinder.axis());
+    let Line(p0, p1) = *cylinder.entity_curve();
+    let foot = origin + axis * axis.dot(p0 - origin);
+    let radius = p0.distance(foot);
+    if !(p1 - p0).normalize().cross(axis).so_small() || radius.so_small() {
+        return surface_surface_intersection(plane, cylinder, tol);
+    }
+    let height = Slab::new(axis / axis.dot(p1 - p0), p0);
+    let [a, b] = Slab::plane(plane);
+    let slabs = [a, b, height];
+
+    let normal = plane.normal();
+    let cos = normal.dot(axis);
+    if cos.so_small() {
+        let distance = normal.dot(foot - plane.origin());
+        let center = foot - normal * distance;
+        if f64::abs(distance).near(&radius) {
+            return clip_line(center, axis, &slabs);
+        } else if f64::abs(distance) > radius {
+            return Vec::new();
+        }
+        let offset = axis.cross(normal) * f64::sqrt(radius * radius - distance * distance);
+        let mut polylines = clip_line(center + offset, axis, &slabs);
+        polylines.extend(clip_line(center - offset, axis, &slabs));
+        return polylines;
+    }
+    let x = (p0 - foot) / radius;
+    let y = axis.cross(x);
+    let ellipse = |t: f64| {
+        let p = foot + (x * f64::cos(t) + y * f64::sin(t)) * radius;
+        p - axis * (normal.dot(p - plane.origin()) / cos)
+    };
+    // the maximum of the curvature radius is `radius / |cos|`
+    let division = circle_division(radius / f64::abs(cos), tol);
+    clip_closed_curve(ellipse, division, &slabs)
+}
+
+/// the affine function `f` of points defining the domain `0 <= f(p) <= 1`
+#[derive(Clone, Copy, Debug)]
+struct Slab {
+    gradient: Vector3,
+    offset: f64,
+}
+
+impl Slab {
+    /// the slab whose function vanishes at `origin`
+    fn new(gradient: Vector3, origin: Point3) -> Self {
+        Self {
+            gradient,
+            offset: -gradient.dot(origin.to_vec()),
+        }
+    }
+    /// the slabs of the parameters of the plane
+    fn plane(plane: &Plane) -> [Self; 2] {
+        let mat = Matrix3::from_cols(plane.u_axis(), plane.v_axis(), plane.normal())
+            .invert()
+            .unwrap();
+        [0, 1].map(|i| Self::new(mat.row(i), plane.origin()))
+    }
+    fn value(self, point: Point3) -> f64 { self.gradient.dot(point.to_vec()) + self.offset }
+    fn contains(self, point: Point3, margin: f64) -> bool {
+        let value = self.value(point);
+        -margin <= value && value <= 1.0 + margin
+    }
+}
+
+/// Returns the number of the segments of the circle with radius `radius` for the tolerance `tol`.
+fn circle_division(radius: f64, tol: f64) -> usize {
+    let acos = f64::acos(f64::max(1.0 - tol / radius, 0.0));
+    usize::max(1 + (2.0 * PI / acos).floor() as usize, 4)
+}
+
+/// Clips the line `origin + s * dir` by `slabs`.
+fn clip_line(origin: Point3, dir: Vector3, slabs: &[Slab]) -> Vec<Vec<Point3>> {
+    let (mut s0, mut s1) = (f64::NEG_INFINITY, f64::INFINITY);
+    for slab in slabs {
+        let (a, b) = (slab.value(origin), slab.gradient.dot(dir));
+        if b.so_small() {
+            if !slab.contains(origin, TOLERANCE) {
+                return Vec::new();
+            }
+            continue;
+        }
+        let (t0, t1) = (-a / b, (1.0 - a) / b);
+        s0 = f64::max(s0, f64::min(t0, t1));
+        s1 = f64::min(s1, f64::max(t0, t1));
+    }
+    let (p, q) = (origin + dir * s0, origin + dir * s1);
+    if p.near(&q) {
+        vec![vec![p.midpoint(q)]]
+    } else if s0 < s1 {
+        vec![vec![p, q]]
+    } else {
+        Vec::new()
+    }
+}
+
+/// Samples the closed curve on `[0, 2π]` by `division` segments,
+/// and divides it into the branches in `slabs`.
+fn clip_closed_curve(
+    curve: impl Fn(f64) -> Point3,
+    division: usize,
+    slabs: &[Slab],
+) -> Vec<Vec<Point3>> {
+    let inside = |t: f64, margin: f64| slabs.iter().all(|slab| slab.contains(curve(t), margin));
+    let parameter = |i: usize| 2.0 * PI * i as f64 / division as f64;
+    let flags = (0..division)
+        .map(|i| inside(parameter(i), TOLERANCE))
+        .collect::<Vec<_>>();
+    let Some(start) = flags.iter().position(|flag| !flag) else {
+        let mut points = (0..division)
+            .map(|i| curve(parameter(i)))
+            .collect::<Vec<_>>();
+        points.push(points[0]);
+        return vec![points];
+    };
+    // the boundary of the domain between `t0` inside and `t1` outside
+    let boundary = |mut t0: f64, mut t1: f64| {
+        while !f64::abs(t1 - t0).so_small2() {
+            let t = (t0 + t1) / 2.0;
+            match inside(t, 0.0) {
+                true => t0 = t,
+                false => t1 = t,
+            }
+        }
+        curve(t0)
+    };
+
+    let mut polylines = Vec::new();
+    let mut current = Vec::new();
+    (start..start + division).for_each(|i| {
+        let (t0, t1) = (parameter(i), parameter(i + 1));
+        match (flags[i % division], flags[(i + 1) % division]) {
+            (false, true) => current = vec![boundary(t1, t0), curve(t1)],
+            (true, true) => current.push(curve(t1)),
+            (true, false) => {
+                current.push(boundary(t0, t1));
+                polylines.push(std::mem::take(&mut current));
+            }
+            (false, false) => {}
+        }
+    });
+    polylines
+}
+
+/// the point in the space, the parameter on the first surface, the parameter on the second surface
+type Triple = (Point3, Point2, Point2);
+
+#[derive(Clone, Copy, Debug)]
+struct Domain {
+    range: ((f64, f64), (f64, f64)),
+    period: (Option<f64>, Option<f64>),
+}
+
+impl Domain {
+    fn new<S: BoundedSurface>(surface: &S) -> Self {
+        Self {
+            range: surface.range_tuple(),
+            period: (surface.u_period(), surface.v_period()),
+        }
+    }
+    /// Returns the parameter moved into the domain by the periods,
+    /// or `None` if the parameter is out of the domain.
+    fn normalize(&self, uv: Point2) -> Option<Point2> {
+        let normalize = |x: f64, (a, b): (f64, f64), period: Option<f64>| {
+            let x = match period {
+                Some(period) => a + (x - a).rem_euclid(period),
+                None => x,
+            };
+            (a - TOLERANCE <= x && x <= b + TOLERANCE).then_some(x)
+        };
+        Some(Point2::new(
+            normalize(uv.x, self.range.0, self.period.0)?,
+            normalize(uv.y, self.range.1, self.period.1)?,
+        ))
+    }
+}
+
+/// the seeds of marching found by the grid on a surface
+struct Seeds {
+    crossings: Vec<Triple>,
+    contacts: Vec<Triple>,
+    bounding_box: BoundingBox<Point3>,
+}
+
+/// Divides `division` so that the number of the intervals is at least `SEED_DIVISION`.
+fn seed_division(division: Vec<f64>) -> Vec<f64> {
+    let n = usize::max(division.len(), 2) - 1;
+    let k = SEED_DIVISION.div_ceil(n);
+    let mut res: Vec<f64> = division
+        .windows(2)
+        .flat_map(|w| (0..k).map(move |i| w[0] + (w[1] - w[0]) * i as f64 / k as f64))
+        .collect();
+    res.extend(division.last());
+    res
+}
+
+/// the sample of the surface `a` with the signed distance from the surface `b`
+#[derive(Clone, Copy, Debug)]
+struct Sample {
+    point: Point3,
+    uv_a: Point2,
+    uv_b: Point2,
+    distance: f64,
+}
+
+/// Searches the seeds of marching by the sign changes of the signed distance
+/// from `surface_b` on the grid of `surface_a`.
+fn search_seeds<Sa, Sb>(surface_a: &Sa, surface_b: &Sb, tol: f64) -> Seeds
+where
+    Sa: ParametricSurface3D
+        + BoundedSurface
+        + ParameterDivision2D
+        + SearchNearestParameter<D2, Point = Point3>,
+    Sb: ParametricSurface3D
+        + BoundedSurface
+        + ParameterDivision2D
+        + SearchNearestParameter<D2, Point = Point3>, {
+    let range = surface_a.range_tuple();
+    let (udiv, vdiv) = surface_a.parameter_division(range, tol);
+    let (udiv, vdiv) = (seed_division(udiv), seed_division(vdiv));
+    let samples: Vec<Vec<Option<Sample>>> = udiv
+        .iter()
+        .map(|&u| {
+            vdiv.iter()
+                .map(|&v| {
+                    let point = surface_a.subs(u, v);
+                    let (s, t) = surface_b.search_nearest_parameter(point, None, TRIALS)?;
+                    let normal = surface_b.normal(s, t);
+                    let distance = (point - surface_b.subs(s, t)).dot(normal);
+                    distance.is_finite().then_some(Sample {
+                        point,
+                        uv_a: Point2::new(u, v),
+                        uv_b: Point2::new(s, t),
+                        distance,
+                    })
+                })
+                .collect()
+        })
+        .collect();
+    let bounding_box = samples
+        .iter()
+        .flatten()
+        .flatten()
+        .map(|x| x.point)
+        .collect();
+
+    let (domain_a, domain_b) = (Domain::new(surface_a), Domain::new(surface_b));
+    let refine = |point: Point3, uv_a: Point2, uv_b: Point2, direction: Vector3| {
+        let normal = match tangent(surface_a, uv_a, surface_b, uv_b) {
+            Some(tangent) => tangent,
+            None => direction.normalize(),
+        };
+        let (p, uv_a, uv_b) = double_projection(
+            surface_a,
+            Some(uv_a.into()),
+            surface_b,
+            Some(uv_b.into()),
+            point,
+            normal,
+            TRIALS,
+        )?;
+        Some((p, domain_a.normalize(uv_a)?, domain_b.normalize(uv_b)?))
+    };
+
+    let mut crossings = Vec::new();
+    let mut contacts = Vec::new();
+    let edges = samples.iter().enumerate().flat_map(|(i, vec)| {
+        let samples = &samples;
+        vec.iter().enumerate().flat_map(move |(j, x)| {
+            let next_u = samples.get(i + 1).map(|vec| vec[j]);
+            let next_v = vec.get(j + 1).copied();
+            [next_u, next_v].into_iter().flatten().map(move |y| (*x, y))
+        })
+    });
+    edges.for_each(|edge| {
+        if let (Some(x), Some(y)) = edge {
+            if x.distance * y.distance < 0.0 {
+                let t = x.distance / (x.distance - y.distance);
+                let point = x.point + (y.point - x.point) * t;
+                let uv_a = x.uv_a + (y.uv_a - x.uv_a) * t;
+                let uv_b = if t < 0.5 { x.uv_b } else { y.uv_b };
+                crossings.extend(refine(point, uv_a, uv_b, y.point - x.point));
+            }
+        }
+    });
+    samples.iter().flatten().flatten().for_each(|x| {
+        if f64::abs(x.distance) >= tol {
+            return;
+        }
+        match tangent(surface_a, x.uv_a, surface_b, x.uv_b) {
+            Some(_) => crossings.extend(refine(x.point, x.uv_a, x.uv_b, Vector3::zero())),
+            None => {
+                let point = x.point.midpoint(surface_b.subs(x.uv_b.x, x.uv_b.y));
+                if let Some(uv_b) = domain_b.normalize(x.uv_b) {
+                    contacts.push((point, x.uv_a, uv_b));
+                }
+            }
+        }
+    });
+
+    Seeds {
+        crossings,
+        contacts,
+        bounding_box,
+    }
+}
+
+/// Returns the unit tangent vector of the intersection curve,
+/// or `None` if the surfaces are tangent to each other.
+fn tangent<S0, S1>(surface0: &S0, uv0: Point2, surface1: &S1, uv1: Point2) -> Option<Vector3>
+where
+    S0: ParametricSurface3D,
+    S1: ParametricSurface3D, {
+    let n0 = surface0.normal(uv0.x, uv0.y);
+    let n1 = surface1.normal(uv1.x, uv1.y);
+    let tangent = n0.cross(n1);
+    match tangent.magnitude().so_small() {
+        true => None,
+        false => Some(tangent.normalize()),
+    }
+}
+
+/// Returns whether `point` is on one of `polylines` up to the tolerance.
+fn is_traced(polylines: &[Vec<Point3>], point: Point3, tol: f64) -> bool {
+    let distance2 = |p: Point3, q: Point3| {
+        let (a, b) = (q - p, point - p);
+        let t = match a.magnitude2().so_small2() {
+            true => 0.0,
+            false => f64::clamp(a.dot(b) / a.magnitude2(), 0.0, 1.0),
+        };
+        (b - a * t).magnitude2()
+    };
+    polylines.iter().any(|polyline| match polyline.len() {
+        1 => polyline[0].distance2(point) < 16.0 * tol * tol,
+        _ => polyline
+            .windows(2)
+            .any(|w| distance2(w[0], w[1]) < 16.0 * tol * tol),
+    })
+}
+
+struct Marcher<'a, S0, S1> {
+    surface0: &'a S0,
+    surface1: &'a S1,
+    domain0: Domain,
+    domain1: Domain,
+    tol: f64,
+    max_step: f64,
+}
+
+impl<S0, S1> Marcher<'_, S0, S1>
+where
+    S0: ParametricSurface3D
+        + BoundedSurface
+        + ParameterDivision2D
+        + SearchNearestParameter<D2, Point = Point3>,
+    S1: ParametricSurface3D
+        + BoundedSurface
+        + ParameterDivision2D
+        + SearchNearestParameter<D2, Point = Point3>,
+{
+    /// Traces the branch of the intersection through `seed`.
+    fn trace(&self, seed: Triple) -> Vec<Point3> {
+        let (forward, closed) = self.march(seed, 1.0);
+        if closed {
+            return forward;
+        }
+        let (backward, _) = self.march(seed, -1.0);
+        let mut points: Vec<Point3> = backward.into_iter().rev().collect();
+        points.extend(forward.into_iter().skip(1));
+        points
+    }
+
+    /// Marches from `seed` in the direction `sign` until the end of the branch.
+    /// Returns the points and whether the branch is closed.
+    fn march(&self, seed: Triple, sign: f64) -> (Vec<Point3>, bool) {
+        let (mut p, mut uv0, mut uv1) = seed;
+        let mut points = vec![p];
+        let Some(mut tangent) = tangent(self.surface0, uv0, self.surface1, uv1) else {
+            return (points, false);
+        };
+        tangent *= sign;
+        let mut step = self.max_step / SEED_DIVISION as f64;
+        for _ in 0..MAX_MARCHING_STEPS {
+            let Some((q, w0, w1, t)) = self.step(p, uv0, uv1, tangent, &mut step) else {
+                break;
+            };
+            let to_start = points[0] - p;
+            let closing = to_start.magnitude() <= q.distance(p) && to_start.dot(tangent) > 0.0;
+            if points.len() > 2 && closing {
+                points.push(points[0]);
+                return (points, true);
+            }
+            points.push(q);
+            (p, uv0, uv1, tangent) = (q, w0, w1, t);
+        }
+        (points, false)
+    }
+
+    /// One step of marching.
+    /// The length of the step is adjusted so that the chord error is less than the tolerance.
+    fn step(
+        &self,
+        p: Point3,
+        uv0: Point2,
+        uv1: Point2,
+        tangent: Vector3,
+        step: &mut f64,
+    ) -> Option<(Point3, Point2, Point2, Vector3)> {
+        while *step >= self.tol / 2.0 {
+            let next = double_projection(
+                self.surface0,
+                Some(uv0.into()),
+                self.surface1,
+                Some(uv1.into()),
+                p + *step * tangent,
+                tangent,
+                TRIALS,
+            )
+            .and_then(|(q, w0, w1)| {
+                let (w0, w1) = (self.domain0.normalize(w0)?, self.domain1.normalize(w1)?);
+                let t = self::tangent(self.surface0, w0, self.surface1, w1)?;
+                let t = if t.dot(tangent) < 0.0 { -t } else { t };
+                Some((q, w0, w1, t))
+            });
+            if let Some((q, w0, w1, t)) = next {
+                let distance = q.distance(p);
+                let angle = f64::acos(f64::clamp(tangent.dot(t), -1.0, 1.0));
+                let error = distance * angle / 8.0;
+                if 0.5 * *step < distance && distance < 2.0 * *step && error < self.tol {
+                    if error < self.tol / 4.0 {
+                        *step = f64::min(*step * 2.0, self.max_step);
+                    }
+                    return Some((q, w0, w1, t));
+                }
+            }
+            *step /= 2.0;
+        }
+        None
+    }
+}
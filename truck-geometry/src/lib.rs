@@ -41,12 +41,19 @@ pub mod specifieds;
 /// Declares some decorators
 pub mod decorators;
 
+/// Intersection of geometric items
+pub mod intersection;
+
 /// re-export all modules.
 pub mod prelude {
     use crate::*;
     pub use base::*;
     pub use decorators::*;
     pub use errors::*;
+    pub use intersection::{
+        plane_cylinder_intersection, plane_plane_intersection, plane_sphere_intersection,
+        surface_surface_intersection,
+    };
     pub use nurbs::*;
     pub use specifieds::*;
 }
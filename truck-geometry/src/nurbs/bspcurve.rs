@@ -180,6 +180,46 @@ impl<P: ControlPoint<f64>> BSplineCurve<P> {
     /// ```
     #[inline(always)]
     pub fn get_closure(&self) -> impl Fn(f64) -> P + '_ { move |t| self.subs(t) }
+
+    /// Substitutes the parameters `ts` to the B-spline curve.
+    ///
+    /// The result coincides with `self.subs(t)` for each `t`, while only the nonzero basis
+    /// functions are calculated and the knot spans are searched incrementally. The parameters need
+    /// not be sorted, but the increasing parameters are evaluated fastest.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.25, 0.5, 0.75, 1.0, 1.0, 1.0]);
+    /// let ctrl_pts = (0..6)
+    ///     .map(|i| Point2::new(i as f64, (i * i % 5) as f64))
+    ///     .collect::<Vec<_>>();
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    ///
+    /// const N: usize = 100; // sample size
+    /// let ts = (0..=N).map(|i| i as f64 / N as f64).collect::<Vec<_>>();
+    /// let pts = bspcurve.subs_many(&ts);
+    /// ts.iter().zip(&pts).for_each(|(t, pt)| assert_near2!(bspcurve.subs(*t), *pt));
+    ///
+    /// // unsorted parameters
+    /// let ts = [0.7, 0.1, 1.0, 0.0, 0.3];
+    /// let pts = bspcurve.subs_many(&ts);
+    /// ts.iter().zip(&pts).for_each(|(t, pt)| assert_near2!(bspcurve.subs(*t), *pt));
+    /// ```
+    pub fn subs_many(&self, ts: &[f64]) -> Vec<P> {
+        let degree = self.degree();
+        let mut span = 0;
+        ts.iter()
+            .map(|t| {
+                let (i, basis) = self
+                    .knot_vec
+                    .nonzero_bspline_basis_functions(degree, *t, &mut span);
+                self.control_points[i..]
+                    .iter()
+                    .zip(basis)
+                    .fold(P::origin(), |sum, (pt, b)| sum + pt.to_vec() * b)
+            })
+            .collect()
+    }
     #[inline(always)]
     fn delta_control_points(&self, i: usize) -> P::Diff {
         if i == 0 {
@@ -325,6 +365,178 @@ impl<P: ControlPoint<f64>> BSplineCurve<P> {
     }
 }
 
+impl<P> BSplineCurve<P>
+where P: ControlPoint<f64> + MetricSpace<Metric = f64> + Tolerance
+{
+    /// Returns the parameters of `points` assigned by the centripetal method, normalized to `[0, 1]`.
+    /// Consecutive duplicated points are assigned the same parameter.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let points = [
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(1.0, 0.0),
+    ///     Point2::new(1.0, 4.0),
+    /// ];
+    /// let params = BSplineCurve::centripetal_parameters(&points);
+    /// assert_eq!(params, vec![0.0, 1.0 / 3.0, 1.0 / 3.0, 1.0]);
+    /// ```
+    pub fn centripetal_parameters(points: &[P]) -> Vec<f64> {
+        let mut sum = 0.0;
+        let mut params = points
+            .iter()
+            .enumerate()
+            .map(|(i, p)| {
+                if i > 0 {
+                    sum += f64::sqrt(points[i - 1].distance(*p));
+                }
+                sum
+            })
+            .collect::<Vec<_>>();
+        if sum > 0.0 {
+            params.iter_mut().for_each(|t| *t /= sum);
+        }
+        params
+    }
+
+    /// Interpolates `points` by the B-spline curve of degree `degree`.
+    ///
+    /// The parameters are assigned by the centripetal method, and the knot vector is
+    /// constructed by averaging the parameters. Consecutive duplicated points are merged.
+    /// # Failures
+    /// - If `points` is empty, returns [`Error::EmptyControlPoints`].
+    /// - If the number of points, except for duplicated ones, is not more than `degree`,
+    ///   returns [`Error::TooFewPoints`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let points = [
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 2.0, 0.0),
+    ///     Point3::new(3.0, 2.0, 1.0),
+    ///     Point3::new(4.0, 0.0, 1.0),
+    ///     Point3::new(6.0, 1.0, 0.0),
+    /// ];
+    /// let curve = BSplineCurve::interpolate(&points, 3).unwrap();
+    /// let params = BSplineCurve::centripetal_parameters(&points);
+    /// params.into_iter().zip(points).for_each(|(t, p)| {
+    ///     assert_near!(curve.subs(t), p);
+    /// });
+    /// ```
+    pub fn interpolate(points: &[P], degree: usize) -> Result<Self> {
+        let mut points = points.to_vec();
+        points.dedup_by(|p, q| (*p).near(q));
+        if points.is_empty() {
+            return Err(Error::EmptyControlPoints);
+        } else if points.len() <= degree {
+            return Err(Error::TooFewPoints(points.len(), degree));
+        }
+        let params = Self::centripetal_parameters(&points);
+        let inner_knots = match degree {
+            0 => (1..params.len())
+                .map(|j| (params[j - 1] + params[j]) / 2.0)
+                .collect::<Vec<_>>(),
+            _ => (1..params.len() - degree)
+                .map(|j| params[j..j + degree].iter().sum::<f64>() / degree as f64)
+                .collect::<Vec<_>>(),
+        };
+        let knot_vec = clamped_knot_vec(inner_knots, degree);
+        let parameter_points = params.into_iter().zip(points).collect::<Vec<_>>();
+        Self::try_interpole(knot_vec, parameter_points)
+    }
+
+    /// Approximates `points` by the B-spline curve of degree `degree` with `num_ctrl` control points
+    /// in the sense of least squares.
+    ///
+    /// The parameters are assigned by the centripetal method. The returned curve passes through
+    /// the first and the last points. Consecutive duplicated points are merged. If the number of
+    /// points is not more than `num_ctrl`, returns the interpolation curve.
+    /// # Failures
+    /// - If `points` is empty, returns [`Error::EmptyControlPoints`].
+    /// - If `num_ctrl` or the number of points is not more than `degree`, returns [`Error::TooFewPoints`].
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let points = (0..=50)
+    ///     .map(|i| {
+    ///         let t = i as f64 / 50.0;
+    ///         Point2::new(t, f64::sin(std::f64::consts::PI * t))
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// let curve = BSplineCurve::approximate(&points, 3, 10).unwrap();
+    /// assert_eq!(curve.control_points().len(), 10);
+    /// assert_near!(curve.front(), points[0]);
+    /// assert_near!(curve.back(), points[50]);
+    /// let params = BSplineCurve::centripetal_parameters(&points);
+    /// params.into_iter().zip(&points).for_each(|(t, p)| {
+    ///     assert!(curve.subs(t).distance(*p) < 1.0e-2);
+    /// });
+    /// ```
+    pub fn approximate(points: &[P], degree: usize, num_ctrl: usize) -> Result<Self> {
+        let mut points = points.to_vec();
+        points.dedup_by(|p, q| (*p).near(q));
+        if points.is_empty() {
+            return Err(Error::EmptyControlPoints);
+        } else if num_ctrl <= degree {
+            return Err(Error::TooFewPoints(num_ctrl, degree));
+        } else if points.len() <= num_ctrl {
+            return Self::interpolate(&points, degree);
+        }
+        let params = Self::centripetal_parameters(&points);
+        let (n, h) = (points.len() - 1, num_ctrl - 1);
+        let d = (n + 1) as f64 / (h - degree + 1) as f64;
+        let inner_knots = (1..=h - degree)
+            .map(|j| {
+                let i = (j as f64 * d) as usize;
+                let a = j as f64 * d - i as f64;
+                (1.0 - a) * params[i - 1] + a * params[i]
+            })
+            .collect::<Vec<_>>();
+        let knot_vec = clamped_knot_vec(inner_knots, degree);
+
+        let (front, back) = (points[0], points[n]);
+        let mut control_points = vec![front; num_ctrl];
+        control_points[h] = back;
+        if h > 1 {
+            let basis = params[1..n]
+                .iter()
+                .map(|t| knot_vec.try_bspline_basis_functions(degree, *t))
+                .collect::<Result<Vec<_>>>()?;
+            let matrix = (1..h)
+                .map(|i| {
+                    (1..h)
+                        .map(|j| basis.iter().map(|row| row[i] * row[j]).sum::<f64>())
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            for c in 0..P::DIM {
+                let mut rows = matrix.clone();
+                rows.iter_mut().enumerate().for_each(|(i, row)| {
+                    let b = basis.iter().zip(&points[1..n]).map(|(r, p)| {
+                        let residual = p[c] - r[0] * front[c] - r[h] * back[c];
+                        r[i + 1] * residual
+                    });
+                    row.push(b.sum());
+                });
+                gaussian_elimination::gaussian_elimination(&mut rows)
+                    .ok_or(Error::GaussianEliminationFailure)?
+                    .into_iter()
+                    .zip(&mut control_points[1..h])
+                    .for_each(|(res, p)| p[c] = res);
+            }
+        }
+        Self::try_new(knot_vec, control_points)
+    }
+}
+
+fn clamped_knot_vec(inner_knots: Vec<f64>, degree: usize) -> KnotVec {
+    let mut vec = vec![0.0; degree + 1];
+    vec.extend(inner_knots);
+    vec.resize(vec.len() + degree + 1, 1.0);
+    KnotVec::from(vec)
+}
+
 impl<P> BSplineCurve<P>
 where P: ControlPoint<f64> + MetricSpace<Metric = f64> + HashGen<f64>
 {
@@ -600,6 +812,39 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
         self
     }
 
+    /// Inserts all knots in `knots` by Boehm's algorithm, and do not change `self` as a curve.
+    /// # Remarks
+    /// Unlike [`BSplineCurve::add_knot`], the range of the knot vector is preserved:
+    /// - knots out of the range of the knot vector are ignored, and
+    /// - the multiplicity of each knot is raised up to the degree, i.e. the insertion is skipped
+    ///   if the multiplicity has already reached the degree, e.g. at the clamped ends.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::bezier_knot(2);
+    /// let ctrl_pts = vec![Vector2::new(-1.0, 1.0), Vector2::new(0.0, -1.0), Vector2::new(1.0, 1.0)];
+    /// let mut bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let org_curve = bspcurve.clone();
+    ///
+    /// // 0.5 is inserted twice, and the others are ignored.
+    /// bspcurve.refine(&[0.0, 0.5, 0.5, 0.5, 1.0, 2.0]);
+    /// assert_eq!(
+    ///     bspcurve.knot_vec(),
+    ///     &KnotVec::from(vec![0.0, 0.0, 0.0, 0.5, 0.5, 1.0, 1.0, 1.0]),
+    /// );
+    /// assert!(bspcurve.near2_as_curve(&org_curve));
+    /// assert_near!(bspcurve.front(), org_curve.front());
+    /// assert_near!(bspcurve.back(), org_curve.back());
+    /// ```
+    pub fn refine(&mut self, knots: &[f64]) -> &mut Self {
+        knots.iter().for_each(|x| {
+            if let Some(x) = self.knot_vec.refinement_knot(self.degree(), *x) {
+                self.add_knot(x);
+            }
+        });
+        self
+    }
+
     /// Removes a knot corresponding to the indices `idx`, and do not change `self` as a curve.
     /// If cannot remove the knot, do not change `self` and return `self`.
     /// # Examples
@@ -637,12 +882,22 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
     /// assert_eq!(bspcurve.try_remove_knot(2), Err(Error::CannotRemoveKnot(2)));
     /// ```
     pub fn try_remove_knot(&mut self, idx: usize) -> Result<&mut BSplineCurve<P>> {
+        let new_points = self
+            .knot_removal_points(idx)
+            .ok_or(Error::CannotRemoveKnot(idx))?;
+        self.apply_knot_removal(idx, new_points);
+        Ok(self)
+    }
+
+    /// Returns the control points replaced by removing the knot `idx`,
+    /// or `None` if the knot cannot be removed.
+    fn knot_removal_points(&self, idx: usize) -> Option<Vec<P>> {
         let k = self.degree();
         let n = self.control_points.len();
         let knot_vec = &self.knot_vec;
 
         if idx < k + 1 || idx >= n {
-            return Err(Error::CannotRemoveKnot(idx));
+            return None;
         }
 
         let mut new_points = Vec::with_capacity(k + 1);
@@ -659,17 +914,21 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
             }
         }
 
-        if !new_points.last().unwrap().near(self.control_point(idx)) {
-            return Err(Error::CannotRemoveKnot(idx));
+        match new_points.last().unwrap().near(self.control_point(idx)) {
+            true => Some(new_points),
+            false => None,
         }
+    }
 
+    /// Removes the knot `idx` by the control points given by `knot_removal_points`.
+    fn apply_knot_removal(&mut self, idx: usize, new_points: Vec<P>) {
+        let k = self.degree();
         for (i, vec) in new_points.into_iter().skip(1).enumerate() {
             self.control_points[idx - k + i] = vec;
         }
 
         self.control_points.remove(idx);
         self.knot_vec.remove(idx);
-        Ok(self)
     }
 
     /// elevate 1 degree for bezier curve.
@@ -700,7 +959,36 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
     /// assert_eq!(bspcurve.knot_vec(), &KnotVec::bezier_knot(2));
     /// assert_eq!(bspcurve.control_point(1), &Vector2::new(0.5, 0.5));
     /// ```
+    /// The multiplicity of each knot is increased by one.
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0]);
+    /// let ctrl_pts = vec![
+    ///     Vector2::new(0.0, 0.0),
+    ///     Vector2::new(1.0, 2.0),
+    ///     Vector2::new(2.0, -1.0),
+    ///     Vector2::new(3.0, 0.0),
+    /// ];
+    /// let mut bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let org_curve = bspcurve.clone();
+    /// bspcurve.elevate_degree();
+    /// assert_eq!(
+    ///     bspcurve.knot_vec(),
+    ///     &KnotVec::from(vec![0.0, 0.0, 0.0, 0.0, 0.5, 0.5, 1.0, 1.0, 1.0, 1.0]),
+    /// );
+    /// assert_eq!(bspcurve.control_points().len(), 6);
+    /// assert!(bspcurve.near2_as_curve(&org_curve));
+    /// ```
     pub fn elevate_degree(&mut self) -> &mut Self {
+        let knot_vec = self.knot_vec.clone();
+        self.elevate_degree_decomposed();
+        Self::remove_elevated_knots(std::slice::from_mut(self), &knot_vec);
+        self
+    }
+
+    /// Elevates 1 degree by the Bezier decomposition.
+    /// The multiplicity of each inner knot becomes the degree plus one.
+    pub(super) fn elevate_degree_decomposed(&mut self) -> &mut Self {
         let mut result = CurveCollector::Singleton;
         for mut bezier in self.bezier_decomposition() {
             result.concat(bezier.elevate_degree_bezier());
@@ -709,6 +997,45 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
         self
     }
 
+    /// Removes the redundant knots of `curves` elevated by `elevate_degree_decomposed`
+    /// so that the multiplicity of each knot is the one in `knot_vec` plus one.
+    /// All curves in `curves` must have the same knot vector, and the knots are removed simultaneously
+    /// only if they can be removed from all curves.
+    pub(super) fn remove_elevated_knots(curves: &mut [Self], knot_vec: &KnotVec) {
+        if curves.is_empty() {
+            return;
+        }
+        let (knots, mults) = knot_vec.to_single_multi();
+        let len = knots.len();
+        knots
+            .into_iter()
+            .zip(mults)
+            .take(len.saturating_sub(1))
+            .skip(1)
+            .for_each(|(knot, mult)| {
+                let Some(idx) = curves[0].knot_vec.iter().position(|t| t.near(&knot)) else {
+                    return;
+                };
+                let elevated_mult = curves[0].knot_vec[idx..]
+                    .iter()
+                    .take_while(|t| (*t).near(&knot))
+                    .count();
+                for _ in mult + 1..elevated_mult {
+                    let Some(new_points) = curves
+                        .iter()
+                        .map(|curve| curve.knot_removal_points(idx))
+                        .collect::<Option<Vec<_>>>()
+                    else {
+                        return;
+                    };
+                    curves
+                        .iter_mut()
+                        .zip(new_points)
+                        .for_each(|(curve, new_points)| curve.apply_knot_removal(idx, new_points));
+                }
+            });
+    }
+
     /// Makes the B-spline curve clamped
     /// # Examples
     /// ```
@@ -892,6 +1219,48 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
         result
     }
 
+    /// Subdivides the curve at `t` into two clamped curves, without changing `self`.
+    ///
+    /// The knot `t` is inserted up to the full multiplicity, and the control points are sliced.
+    /// `t` is clamped to the parameter range. If `t` is the front (resp. back) end,
+    /// the first (resp. second) curve is an empty-range curve which has no control points,
+    /// and the other one is the same as `self` as a curve.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.4, 1.0, 1.0, 1.0]);
+    /// let ctrl_pts = vec![
+    ///     Point2::new(0.0, 0.0),
+    ///     Point2::new(1.0, 2.0),
+    ///     Point2::new(2.0, -1.0),
+    ///     Point2::new(3.0, 0.0),
+    /// ];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let (curve0, curve1) = bspcurve.subdivide(0.7);
+    /// assert_eq!(curve0.range_tuple(), (0.0, 0.7));
+    /// assert_eq!(curve1.range_tuple(), (0.7, 1.0));
+    ///
+    /// const N: usize = 100;
+    /// for i in 0..=N {
+    ///     let t = 0.7 * i as f64 / N as f64;
+    ///     assert_near!(curve0.subs(t), bspcurve.subs(t));
+    ///     let t = 0.7 + 0.3 * i as f64 / N as f64;
+    ///     assert_near!(curve1.subs(t), bspcurve.subs(t));
+    /// }
+    ///
+    /// // subdividing at the end
+    /// let (curve0, curve1) = bspcurve.subdivide(1.0);
+    /// assert_eq!(curve0, bspcurve);
+    /// assert!(curve1.control_points().is_empty());
+    /// ```
+    pub fn subdivide(&self, t: f64) -> (BSplineCurve<P>, BSplineCurve<P>) {
+        let mut curve0 = self.clone();
+        curve0.clamp();
+        let (t0, t1) = curve0.range_tuple();
+        let curve1 = curve0.cut(f64::clamp(t, t0, t1));
+        (curve0, curve1)
+    }
+
     /// Makes the curve locally injective.
     /// # Example
     /// ```
@@ -1114,7 +1483,7 @@ where P: ControlPoint<f64>
 {
     type Point = P;
     fn parameter_division(&self, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<P>) {
-        algo::curve::parameter_division(self, range, tol)
+        algo::curve::parameter_division_by_batch(range, tol, |ts| self.subs_many(ts))
     }
 }
 
@@ -1282,6 +1651,38 @@ impl<P: Bounded> BSplineCurve<P> {
     pub fn roughly_bounding_box(&self) -> BoundingBox<P> { self.control_points.iter().collect() }
 }
 
+impl<P> BSplineCurve<P>
+where P: ControlPoint<f64> + Bounded<Scalar = f64> + Tolerance
+{
+    /// Returns the bounding box of the curve by the convex hull property.
+    ///
+    /// The box is the one of the control points of the clamped curve, which contains the whole
+    /// curve. Hence, it is larger than the exact bounding box in general, but is cheap and useful
+    /// for the broad phase of culling and collision detection. If the knot vector is not clamped,
+    /// the control points are recomputed by knot insertion, since the curve is not in the convex
+    /// hull of the original control points.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vec = KnotVec::from(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+    /// let ctrl_pts = vec![Point2::new(0.0, 0.0), Point2::new(1.0, 2.0), Point2::new(2.0, 0.0)];
+    /// let bspcurve = BSplineCurve::new(knot_vec, ctrl_pts);
+    /// let bdb = bspcurve.bounding_box();
+    ///
+    /// const N: usize = 100;
+    /// for i in 0..=N {
+    ///     let pt = bspcurve.subs(5.0 * i as f64 / N as f64);
+    ///     assert!(bdb.contains(pt));
+    /// }
+    /// ```
+    pub fn bounding_box(&self) -> BoundingBox<P> {
+        match self.is_clamped() {
+            true => self.roughly_bounding_box(),
+            false => self.clone().clamp().roughly_bounding_box(),
+        }
+    }
+}
+
 impl<P: Clone> Invertible for BSplineCurve<P> {
     #[inline(always)]
     fn invert(&mut self) { self.invert(); }
@@ -1436,6 +1837,171 @@ where
     }
 }
 
+impl BSplineCurve<Point2> {
+    /// Returns the approximation of the offset curve of `self` by the signed distance `distance`.
+    ///
+    /// The offset is taken along the normal obtained by rotating the tangent by -90 degrees,
+    /// i.e. a positive `distance` offsets to the right of the direction of `self`.
+    /// For counter-clockwise closed curves, a positive `distance` offsets to the outside.
+    ///
+    /// The returned curve is a C^1 cubic B-spline whose distance from the exact offset curve,
+    /// hence the error of the perpendicular distance from `self`, is less than `tol`.
+    /// Each Bezier segment of `self` is bisected until the cubic Hermite interpolation of the
+    /// offset curve is within the tolerance, so the knots are refined where the curvature is high.
+    /// Since the offset direction is computed without dividing by the curvature, the regions with
+    /// near-zero curvature are handled without any special treatment.
+    ///
+    /// # Remarks
+    /// - The parameter of the returned curve corresponds to the parameter of `self`.
+    ///   However, if `self` has corners, i.e. is not G^1-continuous at some knots, the offset pieces
+    ///   are connected by circular arcs, and the parameter range becomes longer by their lengths.
+    /// - Self-intersections, typically occurring on the concave side when `distance` is larger than
+    ///   the radius of curvature, are not removed. Trimming them is the responsibility of the caller.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let bspcurve = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![Point2::new(0.0, 0.0), Point2::new(1.0, 1.0), Point2::new(2.0, 0.0)],
+    /// );
+    /// let offset = bspcurve.offset(0.1, 1.0e-4);
+    ///
+    /// const N: usize = 100;
+    /// for i in 0..=N {
+    ///     let t = i as f64 / N as f64;
+    ///     let der = bspcurve.der(t);
+    ///     let normal = Vector2::new(der.y, -der.x).normalize();
+    ///     let exact = bspcurve.subs(t) + normal * 0.1;
+    ///     assert!(offset.subs(t).distance(exact) < 1.0e-4);
+    /// }
+    /// ```
+    pub fn offset(&self, distance: f64, tol: f64) -> BSplineCurve<Point2> {
+        let mut pieces = Vec::<BSplineCurve<Point2>>::new();
+        let mut shift = 0.0;
+        let mut last_end: Option<Point2> = None;
+        for bezier in self.bezier_decomposition() {
+            let (t0, t1) = bezier.range_tuple();
+            let offset = move |t: f64| offset_point_derivation(&bezier, t, distance);
+            let (start, end) = (offset(t0), offset(t1));
+            let mut a0 = t0 + shift;
+            if let Some(pt) = last_end.filter(|pt| !pt.near(&start.0)) {
+                let corner = self.subs(t0);
+                let arc = corner_arc(corner, pt - corner, start.0 - corner);
+                let length = f64::max(distance.abs() * arc.angle, TOLERANCE);
+                let arc_offset = move |u: f64| {
+                    let (pt, der) = arc.point_derivation((u - a0) / length);
+                    (pt, der / length)
+                };
+                let ends = ((pt, arc_offset(a0).1), (start.0, arc_offset(a0 + length).1));
+                pieces.push(offset_hermite_approximation(
+                    &arc_offset,
+                    (a0, a0 + length),
+                    ends,
+                    tol,
+                    OFFSET_TRIALS,
+                ));
+                a0 += length;
+                shift = a0 - t0;
+            }
+            let shifted_offset = move |u: f64| offset(u - shift);
+            pieces.push(offset_hermite_approximation(
+                &shifted_offset,
+                (a0, t1 + shift),
+                (start, end),
+                tol,
+                OFFSET_TRIALS,
+            ));
+            last_end = Some(end.0);
+        }
+        let mut iter = pieces.into_iter();
+        let first = iter.next().expect("The B-spline curve has no span.");
+        let mut curve = iter.fold(first, |curve, piece| {
+            curve
+                .try_concat(&piece)
+                .expect("The offset pieces must be connected.")
+        });
+        curve.optimize();
+        curve
+    }
+}
+
+const OFFSET_TRIALS: usize = 24;
+
+fn offset_point_derivation(
+    curve: &BSplineCurve<Point2>,
+    t: f64,
+    distance: f64,
+) -> (Point2, Vector2) {
+    let pt = curve.subs(t);
+    let der = curve.der(t);
+    let rotate = |v: Vector2| Vector2::new(v.y, -v.x);
+    if der.so_small() {
+        let (t0, t1) = curve.range_tuple();
+        let h = (t1 - t0) * 1.0e-6;
+        let dir = curve.subs(f64::min(t + h, t1)) - curve.subs(f64::max(t - h, t0));
+        return (pt + rotate(dir.normalize()) * distance, Vector2::zero());
+    }
+    let der2 = curve.der2(t);
+    let mag = der.magnitude();
+    let normal = rotate(der) / mag;
+    let normal_der = rotate(der2) / mag - rotate(der) * der.dot(der2) / (mag * mag * mag);
+    (pt + normal * distance, der + normal_der * distance)
+}
+
+#[derive(Clone, Copy, Debug)]
+struct CornerArc {
+    center: Point2,
+    start: Vector2,
+    angle: f64,
+    sign: f64,
+}
+
+impl CornerArc {
+    fn point_derivation(&self, s: f64) -> (Point2, Vector2) {
+        let theta = self.sign * self.angle * s;
+        let rot = Matrix2::from_angle(Rad(theta));
+        let vec = rot * self.start;
+        let der = Vector2::new(-vec.y, vec.x) * self.sign * self.angle;
+        (self.center + vec, der)
+    }
+}
+
+fn corner_arc(center: Point2, start: Vector2, end: Vector2) -> CornerArc {
+    let cross = start.x * end.y - start.y * end.x;
+    let angle = f64::atan2(cross.abs(), start.dot(end));
+    CornerArc {
+        center,
+        start,
+        angle,
+        sign: f64::signum(cross),
+    }
+}
+
+fn offset_hermite_approximation(
+    offset: &impl Fn(f64) -> (Point2, Vector2),
+    range: (f64, f64),
+    ends: ((Point2, Vector2), (Point2, Vector2)),
+    tol: f64,
+    trials: usize,
+) -> BSplineCurve<Point2> {
+    let ((pt0, der0), (pt1, der1)) = ends;
+    let bezier = BSplineCurve::cubic_bezier_interpolation(pt0, pt1, der0, der1, range);
+    let is_approx = [0.25, 0.5, 0.75].into_iter().all(|p| {
+        let t = range.0 * (1.0 - p) + range.1 * p;
+        bezier.subs(t).distance2(offset(t).0) < tol * tol
+    });
+    if is_approx || trials == 0 {
+        return bezier;
+    }
+    let t = (range.0 + range.1) / 2.0;
+    let mid = offset(t);
+    let curve0 = offset_hermite_approximation(offset, (range.0, t), (ends.0, mid), tol, trials - 1);
+    let curve1 = offset_hermite_approximation(offset, (t, range.1), (mid, ends.1), tol, trials - 1);
+    curve0
+        .try_concat(&curve1)
+        .expect("The offset pieces must be connected.")
+}
+
 #[test]
 fn cubic_bezier_interpolation_test() {
     let pt0 = Point2::new(0.0, 0.0);
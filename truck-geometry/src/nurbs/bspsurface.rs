@@ -350,6 +350,65 @@ impl<P: ControlPoint<f64>> BSplineSurface<P> {
     #[inline(always)]
     pub fn get_closure(&self) -> impl Fn(f64, f64) -> P + '_ { move |u, v| self.subs(u, v) }
 
+    /// Substitutes the grid of the parameters to the B-spline surface, i.e. the `[i][j]` element
+    /// of the result is `self.subs(us[i], vs[j])`.
+    ///
+    /// The basis functions are calculated only once for each parameter and reused across the
+    /// rows and the columns. The parameters need not be sorted, but the increasing parameters are
+    /// evaluated fastest. cf. [`BSplineCurve::subs_many`]
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::uniform_knot(2, 3), KnotVec::uniform_knot(3, 2));
+    /// let ctrl_pts = (0..5)
+    ///     .map(|i| {
+    ///         (0..5)
+    ///             .map(|j| Point3::new(i as f64, j as f64, ((i + 2 * j) % 3) as f64))
+    ///             .collect()
+    ///     })
+    ///     .collect();
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    ///
+    /// const N: usize = 30; // sample size
+    /// let us = (0..=N).map(|i| i as f64 / N as f64).collect::<Vec<_>>();
+    /// let vs = [0.9, 0.0, 0.35, 1.0, 0.5];
+    /// let grid = bspsurface.subs_grid(&us, &vs);
+    /// us.iter().zip(&grid).for_each(|(u, row)| {
+    ///     vs.iter().zip(row).for_each(|(v, pt)| assert_near!(bspsurface.subs(*u, *v), *pt));
+    /// });
+    /// ```
+    pub fn subs_grid(&self, us: &[f64], vs: &[f64]) -> Vec<Vec<P>> {
+        let (degree0, degree1) = self.degrees();
+        let (uknot_vec, vknot_vec) = self.knot_vecs();
+        let bases = |knot_vec: &KnotVec, degree: usize, params: &[f64]| {
+            let mut span = 0;
+            params
+                .iter()
+                .map(|t| knot_vec.nonzero_bspline_basis_functions(degree, *t, &mut span))
+                .collect::<Vec<_>>()
+        };
+        let (ubases, vbases) = (bases(uknot_vec, degree0, us), bases(vknot_vec, degree1, vs));
+        ubases
+            .iter()
+            .map(|(i, basis0)| {
+                vbases
+                    .iter()
+                    .map(|(j, basis1)| {
+                        let closure = |sum: P, (vec, b0): (&Vec<P>, &f64)| {
+                            let closure =
+                                |sum: P, (pt, b1): (&P, &f64)| sum + pt.to_vec() * (b0 * b1);
+                            vec[*j..].iter().zip(basis1).fold(sum, closure)
+                        };
+                        self.control_points[*i..]
+                            .iter()
+                            .zip(basis0)
+                            .fold(P::origin(), closure)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
     #[inline(always)]
     fn udelta_control_points(&self, i: usize, j: usize) -> P::Diff {
         if i == 0 {
@@ -999,6 +1058,112 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
         self
     }
 
+    /// Makes the B-spline surface clamped in both directions. cf. [`BSplineCurve::clamp`]
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (
+    ///     KnotVec::from(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]),
+    ///     KnotVec::bezier_knot(1),
+    /// );
+    /// let ctrl_pts = vec![
+    ///     vec![Vector2::new(0.0, 0.0), Vector2::new(0.0, 1.0)],
+    ///     vec![Vector2::new(1.0, 0.0), Vector2::new(1.0, 1.0)],
+    ///     vec![Vector2::new(2.0, 0.0), Vector2::new(2.0, 1.0)],
+    /// ];
+    /// let mut bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// assert!(!bspsurface.is_clamped());
+    /// bspsurface.clamp();
+    /// assert!(bspsurface.is_clamped());
+    /// assert_eq!(bspsurface.uknot_vec().len(), 10);
+    /// ```
+    pub fn clamp(&mut self) -> &mut Self {
+        let udegree = self.udegree();
+        let s = self.knot_vecs.0.multiplicity(0);
+        for _ in s..=udegree {
+            self.add_uknot(self.knot_vecs.0[0]);
+        }
+        let n = self.knot_vecs.0.len();
+        let s = self.knot_vecs.0.multiplicity(n - 1);
+        for _ in s..=udegree {
+            self.add_uknot(self.knot_vecs.0[n - 1]);
+        }
+
+        let vdegree = self.vdegree();
+        let s = self.knot_vecs.1.multiplicity(0);
+        for _ in s..=vdegree {
+            self.add_vknot(self.knot_vecs.1[0]);
+        }
+        let n = self.knot_vecs.1.len();
+        let s = self.knot_vecs.1.multiplicity(n - 1);
+        for _ in s..=vdegree {
+            self.add_vknot(self.knot_vecs.1[n - 1]);
+        }
+        self
+    }
+
+    /// Inserts all knots in `knots` to the first parameter `u` by Boehm's algorithm,
+    /// and do not change `self` as a surface.
+    /// # Remarks
+    /// Knots out of the range of the knot vector are ignored, and the multiplicity of each knot is
+    /// raised up to the degree. cf. [`BSplineCurve::refine`]
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(1), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Vector2::new(0.0, 0.0), Vector2::new(0.5, -1.0), Vector2::new(1.0, 0.0)],
+    ///     vec![Vector2::new(0.0, 1.0), Vector2::new(0.5, 2.0), Vector2::new(1.0, 1.0)],
+    /// ];
+    /// let mut bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let org_surface = bspsurface.clone();
+    /// bspsurface.urefine(&[0.0, 0.3, 0.5, 0.5, 1.0]);
+    /// assert_eq!(
+    ///     bspsurface.uknot_vec(),
+    ///     &KnotVec::from(vec![0.0, 0.0, 0.3, 0.5, 1.0, 1.0]),
+    /// );
+    /// assert!(bspsurface.near2_as_surface(&org_surface));
+    /// ```
+    pub fn urefine(&mut self, knots: &[f64]) -> &mut Self {
+        knots.iter().for_each(|x| {
+            if let Some(x) = self.knot_vecs.0.refinement_knot(self.udegree(), *x) {
+                self.add_uknot(x);
+            }
+        });
+        self
+    }
+
+    /// Inserts all knots in `knots` to the second parameter `v` by Boehm's algorithm,
+    /// and do not change `self` as a surface.
+    /// # Remarks
+    /// Knots out of the range of the knot vector are ignored, and the multiplicity of each knot is
+    /// raised up to the degree. cf. [`BSplineCurve::refine`]
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(1), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Vector2::new(0.0, 0.0), Vector2::new(0.5, -1.0), Vector2::new(1.0, 0.0)],
+    ///     vec![Vector2::new(0.0, 1.0), Vector2::new(0.5, 2.0), Vector2::new(1.0, 1.0)],
+    /// ];
+    /// let mut bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let org_surface = bspsurface.clone();
+    /// bspsurface.vrefine(&[0.0, 0.3, 0.5, 0.5, 0.5, 1.0]);
+    /// assert_eq!(
+    ///     bspsurface.vknot_vec(),
+    ///     &KnotVec::from(vec![0.0, 0.0, 0.0, 0.3, 0.5, 0.5, 1.0, 1.0, 1.0]),
+    /// );
+    /// assert!(bspsurface.near2_as_surface(&org_surface));
+    /// ```
+    pub fn vrefine(&mut self, knots: &[f64]) -> &mut Self {
+        knots.iter().for_each(|x| {
+            if let Some(x) = self.knot_vecs.1.refinement_knot(self.vdegree(), *x) {
+                self.add_vknot(x);
+            }
+        });
+        self
+    }
+
     /// Removes the uknot corresponding to the indice `idx`, and do not change `self` as a curve.
     /// If the knot cannot be removed, returns
     /// [`Error::CannotRemoveKnot`](./errors/enum.Error.html#variant.CannotRemoveKnot).
@@ -1217,18 +1382,22 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
     /// assert!(bspsurface.near2_as_surface(&org_surface));
     /// ```
     pub fn elevate_vdegree(&mut self) -> &mut Self {
-        let mut new_knot_vec = KnotVec::new();
-        for (i, vec) in self.control_points.iter_mut().enumerate() {
-            let knot_vec = self.knot_vecs.1.clone();
-            let ctrl_pts = vec.clone();
-            let mut curve = BSplineCurve::new(knot_vec, ctrl_pts);
-            curve.elevate_degree();
-            if i == 0 {
-                new_knot_vec = curve.knot_vec().clone();
-            }
-            *vec = curve.control_points;
-        }
-        self.knot_vecs.1 = new_knot_vec;
+        let knot_vec = &self.knot_vecs.1;
+        let mut curves = self
+            .control_points
+            .iter()
+            .map(|vec| {
+                let mut curve = BSplineCurve::new(knot_vec.clone(), vec.clone());
+                curve.elevate_degree_decomposed();
+                curve
+            })
+            .collect::<Vec<_>>();
+        BSplineCurve::remove_elevated_knots(&mut curves, knot_vec);
+        self.knot_vecs.1 = curves[0].knot_vec().clone();
+        self.control_points = curves
+            .into_iter()
+            .map(|curve| curve.control_points)
+            .collect();
         self
     }
 
@@ -1469,6 +1638,67 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
         res
     }
 
+    /// Separates `self` into the grid of Bezier patches by each knots.
+    ///
+    /// The `(i, j)`th patch is the one on the `i`th span of the `u`-knots and the `j`th span of
+    /// the `v`-knots. cf. [`BSplineCurve::bezier_decomposition`]
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::uniform_knot(2, 3), KnotVec::uniform_knot(1, 2));
+    /// let ctrl_pts = (0..5)
+    ///     .map(|i| {
+    ///         (0..3)
+    ///             .map(|j| Point3::new(i as f64, j as f64, ((i * j) % 3) as f64))
+    ///             .collect()
+    ///     })
+    ///     .collect();
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let beziers = bspsurface.bezier_decomposition();
+    /// assert_eq!(beziers.len(), 3);
+    /// assert_eq!(beziers[0].len(), 2);
+    ///
+    /// const N: usize = 10;
+    /// for (i, row) in beziers.iter().enumerate() {
+    ///     for (j, bezier) in row.iter().enumerate() {
+    ///         // full-multiplicity knot vectors
+    ///         assert_eq!(bezier.uknot_vec().to_single_multi().1, vec![3, 3]);
+    ///         assert_eq!(bezier.vknot_vec().to_single_multi().1, vec![2, 2]);
+    ///         for (k, l) in (0..=N).flat_map(|k| (0..=N).map(move |l| (k, l))) {
+    ///             let u = (i as f64 + k as f64 / N as f64) / 3.0;
+    ///             let v = (j as f64 + l as f64 / N as f64) / 2.0;
+    ///             assert_near!(bezier.subs(u, v), bspsurface.subs(u, v));
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn bezier_decomposition(&self) -> Vec<Vec<BSplineSurface<P>>> {
+        let mut bspsurface = self.clone();
+        bspsurface.clamp();
+        let (uknots, _) = self.knot_vecs.0.to_single_multi();
+        let (vknots, _) = self.knot_vecs.1.to_single_multi();
+        let (n, m) = (uknots.len(), vknots.len());
+
+        let mut strips = Vec::new();
+        for i in 2..n {
+            strips.push(bspsurface.ucut(uknots[n - i]));
+        }
+        strips.push(bspsurface);
+        strips.reverse();
+        strips
+            .into_iter()
+            .map(|mut strip| {
+                let mut row = Vec::new();
+                for j in 2..m {
+                    row.push(strip.vcut(vknots[m - j]));
+                }
+                row.push(strip);
+                row.reverse();
+                row
+            })
+            .collect()
+    }
+
     /// Creates a sectional curve with normalized knot vector from the parameter `p` to the parameter `q`.
     /// # Examples
     /// ```
@@ -1852,6 +2082,46 @@ impl<V: Bounded> BSplineSurface<V> {
     }
 }
 
+impl<V> BSplineSurface<V>
+where V: ControlPoint<f64> + Bounded<Scalar = f64> + Tolerance
+{
+    /// Returns the bounding box of the surface by the convex hull property.
+    ///
+    /// The box is the one of the control points of the clamped surface, which contains the
+    /// whole surface. Hence, it is larger than the exact bounding box in general, but is cheap and
+    /// useful for the broad phase of culling and collision detection. If the knot vectors are not
+    /// clamped, the control points are recomputed by knot insertion, since the surface is not
+    /// in the convex hull of the original control points.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 0.5, 1.0), Point3::new(0.0, 1.0, 0.0)],
+    ///     vec![Point3::new(0.5, 0.0, 1.0), Point3::new(0.5, 0.5, 2.0), Point3::new(0.5, 1.0, 1.0)],
+    ///     vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 0.5, 1.0), Point3::new(1.0, 1.0, 0.0)],
+    /// ];
+    /// let bspsurface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let bdb = bspsurface.bounding_box();
+    /// assert_eq!(bdb.min(), Point3::new(0.0, 0.0, 0.0));
+    /// assert_eq!(bdb.max(), Point3::new(1.0, 1.0, 2.0));
+    ///
+    /// const N: usize = 10;
+    /// for i in 0..=N {
+    ///     for j in 0..=N {
+    ///         let pt = bspsurface.subs(i as f64 / N as f64, j as f64 / N as f64);
+    ///         assert!(bdb.contains(pt));
+    ///     }
+    /// }
+    /// ```
+    pub fn bounding_box(&self) -> BoundingBox<V> {
+        match self.is_clamped() {
+            true => self.roughly_bounding_box(),
+            false => self.clone().clamp().roughly_bounding_box(),
+        }
+    }
+}
+
 impl<P: ControlPoint<f64>> ParameterDivision2D for BSplineSurface<P>
 where P: EuclideanSpace<Scalar = f64, Diff = <P as ControlPoint<f64>>::Diff>
         + MetricSpace<Metric = f64>
@@ -1863,7 +2133,8 @@ where P: EuclideanSpace<Scalar = f64, Diff = <P as ControlPoint<f64>>::Diff>
         range: ((f64, f64), (f64, f64)),
         tol: f64,
     ) -> (Vec<f64>, Vec<f64>) {
-        algo::surface::parameter_division(self, range, tol)
+        let subs_grid = |us: &[f64], vs: &[f64]| self.subs_grid(us, vs);
+        algo::surface::parameter_division_by_grid(self, range, tol, subs_grid)
     }
 }
 
@@ -200,19 +200,64 @@ impl KnotVec {
             return Err(Error::TooLargeDegree(n + 1, degree));
         }
 
-        let idx = {
-            let idx = self
-                .floor(t)
-                .unwrap_or_else(|| self.floor(self[0]).unwrap());
-            if idx == n {
-                n - self.multiplicity(n)
-            } else {
-                idx
+        let idx = self.basis_span(self.floor(t));
+        let mut res = vec![0.0; n];
+        self.basis_recursion(degree, t, idx, 0, &mut res);
+
+        res.truncate(n - degree);
+        Ok(res)
+    }
+
+    /// Calculates the nonzero B-spline basis functions at `t` with degree `degree`.
+    ///
+    /// Returns the index `i` of the first nonzero function and the values, which coincide with
+    /// `self.bspline_basis_functions(degree, t)[i..]` up to the trailing zeros. The interval
+    /// including `t` is searched forward from `span`, the interval found at the previous parameter,
+    /// if `t` is not less than the previous one, and by the binary search otherwise.
+    pub(super) fn nonzero_bspline_basis_functions(
+        &self,
+        degree: usize,
+        t: f64,
+        span: &mut usize,
+    ) -> (usize, Vec<f64>) {
+        let n = self.len() - 1;
+        let floor = |x: f64| self.0.partition_point(|s| *s <= x).checked_sub(1);
+        let floor = match self.0.get(*span) {
+            Some(s) if *s <= t => {
+                let mut i = *span;
+                while i < n && self[i + 1] <= t {
+                    i += 1;
+                }
+                Some(i)
             }
+            _ => floor(t),
         };
-        let mut res = vec![0.0; n];
-        res[idx] = 1.0;
+        *span = floor.unwrap_or(0);
+        let idx = self.basis_span(floor);
+        let lo = idx.saturating_sub(degree);
+        let mut res = vec![0.0; idx + 2 - lo];
+        self.basis_recursion(degree, t, idx, lo, &mut res);
+        res.truncate(usize::min(idx + 1, n - degree).saturating_sub(lo));
+        (lo, res)
+    }
+
+    /// Returns the index of the interval on which the basis functions are calculated,
+    /// where `floor` is the index of the last knot not greater than the parameter.
+    fn basis_span(&self, floor: Option<usize>) -> usize {
+        let n = self.len() - 1;
+        let idx = floor.unwrap_or_else(|| self.floor(self[0]).unwrap());
+        match idx == n {
+            true => n - self.multiplicity(n),
+            false => idx,
+        }
+    }
 
+    /// Calculates the B-spline basis functions recursively from the characteristic function of the
+    /// interval `idx`. `res` is the window of the functions from the index `lo`, i.e. the `i`th
+    /// function is stored in `res[i - lo]`, where `lo` must not be greater than `idx.saturating_sub(degree)`.
+    fn basis_recursion(&self, degree: usize, t: f64, idx: usize, lo: usize, res: &mut [f64]) {
+        let n = self.len() - 1;
+        res[idx - lo] = 1.0;
         for k in 1..=degree {
             let base = idx.saturating_sub(k);
             let delta = self[base + k] - self[base];
@@ -221,13 +266,10 @@ impl KnotVec {
             for i in base..=max {
                 let delta = self[i + k + 1] - self[i + 1];
                 let b = inv_or_zero(delta) * (self[i + k + 1] - t);
-                res[i] = a * res[i] + b * res[i + 1];
+                res[i - lo] = a * res[i - lo] + b * res[i + 1 - lo];
                 a = 1.0 - b;
             }
         }
-
-        res.truncate(n - degree);
-        Ok(res)
     }
 
     #[doc(hidden)]
@@ -515,6 +557,20 @@ impl KnotVec {
         vec.extend(std::iter::repeat(1.0).take(degree + 1));
         KnotVec(vec)
     }
+
+    /// Returns the knot which should be inserted for refining a B-spline of degree `degree` by `x`.
+    /// Returns `None` if `x` is out of the range or the multiplicity of `x` has already reached `degree`.
+    pub(super) fn refinement_knot(&self, degree: usize, x: f64) -> Option<f64> {
+        let (front, back) = (self[0], self[self.len() - 1]);
+        if !(front - TOLERANCE..=back + TOLERANCE).contains(&x) {
+            return None;
+        }
+        let multi = self.iter().filter(|t| (*t).near(&x)).count();
+        match multi >= usize::max(degree, 1) {
+            true => None,
+            false => Some(self.iter().find(|t| (*t).near(&x)).copied().unwrap_or(x)),
+        }
+    }
 }
 
 impl From<Vec<f64>> for KnotVec {
@@ -608,3 +664,37 @@ impl<'de> Deserialize<'de> for KnotVec {
         Self::try_from(vec).map_err(serde::de::Error::custom)
     }
 }
+
+#[cfg(test)]
+fn check_nonzero_basis_functions(knot_vec: &KnotVec, degree: usize) {
+    const N: usize = 100;
+    let (t0, t1) = (knot_vec[0] - 0.5, knot_vec[knot_vec.len() - 1] + 0.5);
+    let ts = (0..=N).map(|i| t0 + (t1 - t0) * i as f64 / N as f64);
+    // The span is searched forward for the increasing parameters, and by the binary search for
+    // the decreasing ones.
+    let mut span = 0;
+    ts.clone().chain(ts.rev()).for_each(|t| {
+        let all = knot_vec.bspline_basis_functions(degree, t);
+        let (lo, nonzero) = knot_vec.nonzero_bspline_basis_functions(degree, t, &mut span);
+        all.iter().enumerate().for_each(|(i, a)| {
+            let b = i.checked_sub(lo).and_then(|j| nonzero.get(j));
+            let b = b.copied().unwrap_or(0.0);
+            assert!(a.near(&b), "t = {t}, i = {i}: {a} != {b}");
+        });
+    });
+}
+
+#[test]
+fn nonzero_basis_functions_unclamped() {
+    let knot_vec = KnotVec::from(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    (0..=3).for_each(|degree| check_nonzero_basis_functions(&knot_vec, degree));
+}
+
+#[test]
+fn nonzero_basis_functions_repeated_knots() {
+    let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 1.0, 2.0, 2.0, 2.0, 3.0, 4.0, 4.0, 4.0]);
+    (0..=2).for_each(|degree| check_nonzero_basis_functions(&knot_vec, degree));
+    // the inner knot with the multiplicity more than the degree
+    let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 1.0, 1.0, 1.0, 1.0, 2.0, 2.0, 2.0]);
+    check_nonzero_basis_functions(&knot_vec, 2);
+}
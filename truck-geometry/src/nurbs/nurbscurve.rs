@@ -100,6 +100,90 @@ impl<V: Homogeneous<f64>> NurbsCurve<V> {
             .collect();
         Ok(Self(BSplineCurve::new_unchecked(knot_vec, control_points)))
     }
+
+    /// Returns the weights of the control points.
+    #[inline(always)]
+    pub fn weights(&self) -> Vec<f64> {
+        self.0.control_points.iter().map(|pt| pt.weight()).collect()
+    }
+
+    /// Sets the weight of the `idx`th control point to `weight` without moving the point.
+    /// # Panics
+    /// Panics if `idx` is out of range or `weight` is not positive.
+    #[inline(always)]
+    pub fn set_weight(&mut self, idx: usize, weight: f64) -> &mut Self {
+        self.try_set_weight(idx, weight)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Sets the weight of the `idx`th control point to `weight` without moving the point.
+    /// # Failures
+    /// If `weight` is not positive, returns [`Error::NonPositiveWeight`].
+    /// # Panics
+    /// Panics if `idx` is out of range.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let mut curve = NurbsCurve::new(BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![
+    ///         Vector3::new(0.0, 0.0, 1.0),
+    ///         Vector3::new(1.0, 1.0, 1.0),
+    ///         Vector3::new(2.0, 0.0, 1.0),
+    ///     ],
+    /// ));
+    /// curve.try_set_weight(1, 2.0).unwrap();
+    /// assert_eq!(curve.weights(), vec![1.0, 2.0, 1.0]);
+    /// // The control point does not move.
+    /// assert_near!(curve.control_point(1).to_point(), Point2::new(1.0, 1.0));
+    /// // The curve is pulled to the control point.
+    /// assert!(curve.subs(0.5).y > 0.5);
+    ///
+    /// assert!(curve.try_set_weight(1, 0.0).is_err());
+    /// ```
+    pub fn try_set_weight(&mut self, idx: usize, weight: f64) -> Result<&mut Self> {
+        if weight <= 0.0 {
+            return Err(Error::NonPositiveWeight(weight));
+        }
+        let pt = &mut self.0.control_points[idx];
+        *pt = V::from_point_weight(pt.to_point(), weight);
+        Ok(self)
+    }
+
+    /// Returns the non-rational B-spline curve if all weights are the same.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let mut curve = NurbsCurve::new(BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![
+    ///         Vector3::new(0.0, 0.0, 1.0),
+    ///         Vector3::new(2.0, 2.0, 2.0),
+    ///         Vector3::new(2.0, 0.0, 1.0),
+    ///     ],
+    /// ));
+    /// assert!(curve.try_into_bspline().is_none());
+    ///
+    /// curve.set_weight(1, 1.0);
+    /// let bspcurve = curve.try_into_bspline().unwrap();
+    /// assert_eq!(bspcurve.control_points()[1], Point2::new(1.0, 1.0));
+    /// ```
+    pub fn try_into_bspline(&self) -> Option<BSplineCurve<V::Point>> {
+        let weights = self.weights();
+        if !weights.iter().all(|w| w.near(&weights[0])) {
+            return None;
+        }
+        let control_points = self
+            .0
+            .control_points
+            .iter()
+            .map(|pt| pt.to_point())
+            .collect();
+        Some(BSplineCurve::new_unchecked(
+            self.0.knot_vec.clone(),
+            control_points,
+        ))
+    }
 }
 
 impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V>> NurbsCurve<V> {
@@ -221,6 +305,12 @@ impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V> + Tolerance> NurbsCurve<V
         self
     }
 
+    /// Inserts all knots in `knots`, and do not change `self` as a curve. cf.[`BSplineCurve::refine`]
+    pub fn refine(&mut self, knots: &[f64]) -> &mut Self {
+        self.0.refine(knots);
+        self
+    }
+
     /// Removes a knot corresponding to the indices `idx`, and do not change `self` as a curve.
     /// If cannot remove the knot, do not change `self` and return `self`.
     /// cf.[`BSplineCurve::remove_knot`]
@@ -250,6 +340,47 @@ impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V> + Tolerance> NurbsCurve<V
         self
     }
 
+    /// Separates `self` into rational Bezier curves by each knots.
+    ///
+    /// The homogeneous control points are split, so the weights are kept consistent.
+    /// cf.[`BSplineCurve::bezier_decomposition`]
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// // the unit circle by four quarter arcs
+    /// let knot_vec = KnotVec::from(vec![
+    ///     0.0, 0.0, 0.0, 0.25, 0.25, 0.5, 0.5, 0.75, 0.75, 1.0, 1.0, 1.0,
+    /// ]);
+    /// let w = f64::sqrt(0.5);
+    /// let ctrl_pts = vec![
+    ///     Vector3::new(1.0, 0.0, 1.0),
+    ///     Vector3::new(w, w, w),
+    ///     Vector3::new(0.0, 1.0, 1.0),
+    ///     Vector3::new(-w, w, w),
+    ///     Vector3::new(-1.0, 0.0, 1.0),
+    ///     Vector3::new(-w, -w, w),
+    ///     Vector3::new(0.0, -1.0, 1.0),
+    ///     Vector3::new(w, -w, w),
+    ///     Vector3::new(1.0, 0.0, 1.0),
+    /// ];
+    /// let circle = NurbsCurve::new(BSplineCurve::new(knot_vec, ctrl_pts));
+    /// let beziers = circle.bezier_decomposition();
+    /// assert_eq!(beziers.len(), 4);
+    ///
+    /// const N: usize = 100;
+    /// for (i, bezier) in beziers.iter().enumerate() {
+    ///     assert_eq!(bezier.control_points().len(), 3);
+    ///     for k in 0..=N {
+    ///         let t = 0.25 * (i as f64 + k as f64 / N as f64);
+    ///         assert_near!(bezier.subs(t), circle.subs(t));
+    ///     }
+    /// }
+    /// ```
+    pub fn bezier_decomposition(&self) -> Vec<Self> {
+        let iter = self.0.bezier_decomposition().into_iter();
+        iter.map(NurbsCurve::new).collect()
+    }
+
     /// Repeats `Self::try_remove_knot()` from the back knot in turn until the knot cannot be removed.
     /// cf.[`BSplineCurve::optimize`]
     pub fn optimize(&mut self) -> &mut Self {
@@ -399,7 +530,11 @@ where V::Point: MetricSpace<Metric = f64> + HashGen<f64>
     type Point = V::Point;
     #[inline(always)]
     fn parameter_division(&self, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<V::Point>) {
-        algo::curve::parameter_division(self, range, tol)
+        let subs_many = |ts: &[f64]| {
+            let pts = self.0.subs_many(ts);
+            pts.into_iter().map(|pt| pt.to_point()).collect()
+        };
+        algo::curve::parameter_division_by_batch(range, tol, subs_many)
     }
 }
 
@@ -501,6 +636,46 @@ where V::Point: Bounded<Scalar = f64>
     pub fn roughly_bounding_box(&self) -> BoundingBox<V::Point> {
         self.0.control_points.iter().map(|p| p.to_point()).collect()
     }
+
+    /// Returns the bounding box of the curve by the convex hull property.
+    ///
+    /// The box is the one of the dehomogenized control points of the clamped curve. Note that the
+    /// box of the homogeneous control points, i.e. the weighted ones, does not contain the curve.
+    /// If some weights of the clamped curve are not positive, the curve is not in the convex hull
+    /// of the control points and may be unbounded, so the infinite box is returned.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// // a quarter circle
+    /// let knot_vec = KnotVec::bezier_knot(2);
+    /// let ctrl_pts = vec![
+    ///     Vector3::new(1.0, 0.0, 1.0),
+    ///     Vector3::new(1.0, 1.0, 1.0) * f64::sqrt(0.5),
+    ///     Vector3::new(0.0, 1.0, 1.0),
+    /// ];
+    /// let curve = NurbsCurve::new(BSplineCurve::new(knot_vec, ctrl_pts));
+    /// let bdb = curve.bounding_box();
+    /// assert_near!(bdb.max(), Point2::new(1.0, 1.0));
+    ///
+    /// const N: usize = 100;
+    /// for i in 0..=N {
+    ///     let pt = curve.subs(i as f64 / N as f64);
+    ///     assert!(bdb.contains(pt));
+    /// }
+    /// ```
+    pub fn bounding_box(&self) -> BoundingBox<V::Point>
+    where V: ControlPoint<f64, Diff = V> + Tolerance {
+        let mut curve = self.clone();
+        if !curve.is_clamped() {
+            curve.clamp();
+        }
+        match curve.0.control_points.iter().all(|p| p.weight() > 0.0) {
+            true => curve.roughly_bounding_box(),
+            false => [V::Point::neg_infinity(), V::Point::infinity()]
+                .into_iter()
+                .collect(),
+        }
+    }
 }
 
 impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V>> ParametricCurve for NurbsCurve<V> {
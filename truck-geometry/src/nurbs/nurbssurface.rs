@@ -221,6 +221,88 @@ impl<V: Homogeneous<f64>> NurbsSurface<V> {
             control_points,
         )))
     }
+
+    /// Returns the weights of the control points.
+    #[inline(always)]
+    pub fn weights(&self) -> Vec<Vec<f64>> {
+        self.0
+            .control_points
+            .iter()
+            .map(|row| row.iter().map(|pt| pt.weight()).collect())
+            .collect()
+    }
+
+    /// Sets the weight of the control point with the indices `(idx0, idx1)` to `weight`
+    /// without moving the point.
+    /// # Panics
+    /// Panics if the indices are out of range or `weight` is not positive.
+    #[inline(always)]
+    pub fn set_weight(&mut self, idx0: usize, idx1: usize, weight: f64) -> &mut Self {
+        self.try_set_weight(idx0, idx1, weight)
+            .unwrap_or_else(|e| panic!("{}", e))
+    }
+
+    /// Sets the weight of the control point with the indices `(idx0, idx1)` to `weight`
+    /// without moving the point.
+    /// # Failures
+    /// If `weight` is not positive, returns [`Error::NonPositiveWeight`].
+    /// # Panics
+    /// Panics if the indices are out of range.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let control_points = (0..3)
+    ///     .map(|i| (0..3).map(|j| Vector4::new(i as f64, j as f64, 0.0, 1.0)).collect())
+    ///     .collect();
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2));
+    /// let mut surface = NurbsSurface::new(BSplineSurface::new(knot_vecs, control_points));
+    /// surface.try_set_weight(1, 2, 3.0).unwrap();
+    /// assert_eq!(surface.weights()[1], vec![1.0, 1.0, 3.0]);
+    /// assert_near!(surface.control_point(1, 2).to_point(), Point3::new(1.0, 2.0, 0.0));
+    ///
+    /// assert!(surface.try_set_weight(1, 2, -3.0).is_err());
+    /// ```
+    pub fn try_set_weight(&mut self, idx0: usize, idx1: usize, weight: f64) -> Result<&mut Self> {
+        if weight <= 0.0 {
+            return Err(Error::NonPositiveWeight(weight));
+        }
+        let pt = &mut self.0.control_points[idx0][idx1];
+        *pt = V::from_point_weight(pt.to_point(), weight);
+        Ok(self)
+    }
+
+    /// Returns the non-rational B-spline surface if all weights are the same.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let control_points = (0..3)
+    ///     .map(|i| (0..3).map(|j| Vector4::new(i as f64, j as f64, 0.0, 1.0) * 2.0).collect())
+    ///     .collect();
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2));
+    /// let mut surface = NurbsSurface::new(BSplineSurface::new(knot_vecs, control_points));
+    /// let bspsurface = surface.try_into_bspline().unwrap();
+    /// assert_near!(bspsurface.subs(0.3, 0.6), surface.subs(0.3, 0.6));
+    ///
+    /// surface.set_weight(0, 0, 1.0);
+    /// assert!(surface.try_into_bspline().is_none());
+    /// ```
+    pub fn try_into_bspline(&self) -> Option<BSplineSurface<V::Point>> {
+        let w0 = self.0.control_points[0][0].weight();
+        let control_points = self
+            .0
+            .control_points
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|pt| pt.weight().near(&w0).then(|| pt.to_point()))
+                    .collect::<Option<Vec<_>>>()
+            })
+            .collect::<Option<Vec<_>>>()?;
+        Some(BSplineSurface::new_unchecked(
+            self.0.knot_vecs.clone(),
+            control_points,
+        ))
+    }
 }
 
 impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V>> NurbsSurface<V> {
@@ -372,6 +454,20 @@ impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V> + Tolerance> NurbsSurface
         self.0.add_vknot(x);
         self
     }
+    /// Inserts all knots in `knots` to the first parameter `u`, and do not change `self` as a surface.
+    /// cf.[`BSplineSurface::urefine`]
+    #[inline(always)]
+    pub fn urefine(&mut self, knots: &[f64]) -> &mut Self {
+        self.0.urefine(knots);
+        self
+    }
+    /// Inserts all knots in `knots` to the second parameter `v`, and do not change `self` as a surface.
+    /// cf.[`BSplineSurface::vrefine`]
+    #[inline(always)]
+    pub fn vrefine(&mut self, knots: &[f64]) -> &mut Self {
+        self.0.vrefine(knots);
+        self
+    }
     /// Removes the uknot corresponding to the indice `idx`, and do not change `self` as a curve.
     /// If the knot cannot be removed, returns [`Error::CannotRemoveKnot`].
     #[inline(always)]
@@ -437,6 +533,14 @@ impl<V: Homogeneous<f64> + ControlPoint<f64, Diff = V> + Tolerance> NurbsSurface
     #[inline(always)]
     pub fn vcut(&mut self, v: f64) -> Self { Self::new(self.0.vcut(v)) }
 
+    /// Separates `self` into the grid of rational Bezier patches by each knots.
+    /// cf. [`BSplineSurface::bezier_decomposition`]
+    pub fn bezier_decomposition(&self) -> Vec<Vec<Self>> {
+        let grid = self.0.bezier_decomposition().into_iter();
+        grid.map(|row| row.into_iter().map(Self::new).collect())
+            .collect()
+    }
+
     /// Normalizes the knot vectors
     #[inline(always)]
     pub fn knot_normalize(&mut self) -> &mut Self {
@@ -583,6 +687,51 @@ where V::Point: Bounded<Scalar = f64>
             .map(|pt| pt.to_point())
             .collect()
     }
+
+    /// Returns the bounding box of the surface by the convex hull property.
+    ///
+    /// The box is the one of the dehomogenized control points of the clamped surface.
+    /// cf. [`NurbsCurve::bounding_box`]
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(1));
+    /// let w = f64::sqrt(0.5);
+    /// let ctrl_pts = vec![
+    ///     vec![Vector4::new(1.0, 0.0, 0.0, 1.0), Vector4::new(1.0, 0.0, 1.0, 1.0)],
+    ///     vec![Vector4::new(w, w, 0.0, w), Vector4::new(w, w, w, w)],
+    ///     vec![Vector4::new(0.0, 1.0, 0.0, 1.0), Vector4::new(0.0, 1.0, 1.0, 1.0)],
+    /// ];
+    /// let surface = NurbsSurface::new(BSplineSurface::new(knot_vecs, ctrl_pts));
+    /// let bdb = surface.bounding_box();
+    /// assert_near!(bdb.max(), Point3::new(1.0, 1.0, 1.0));
+    ///
+    /// const N: usize = 10;
+    /// for i in 0..=N {
+    ///     for j in 0..=N {
+    ///         let pt = surface.subs(i as f64 / N as f64, j as f64 / N as f64);
+    ///         assert!(bdb.contains(pt));
+    ///     }
+    /// }
+    /// ```
+    pub fn bounding_box(&self) -> BoundingBox<V::Point>
+    where V: ControlPoint<f64, Diff = V> + Tolerance {
+        let mut surface = self.0.clone();
+        if !surface.is_clamped() {
+            surface.clamp();
+        }
+        let positive = surface
+            .control_points
+            .iter()
+            .flatten()
+            .all(|p| p.weight() > 0.0);
+        match positive {
+            true => NurbsSurface::new(surface).roughly_bounding_box(),
+            false => [V::Point::neg_infinity(), V::Point::infinity()]
+                .into_iter()
+                .collect(),
+        }
+    }
 }
 
 impl<V: Clone> Invertible for NurbsSurface<V> {
@@ -634,7 +783,12 @@ where V::Point: MetricSpace<Metric = f64> + HashGen<f64>
         range: ((f64, f64), (f64, f64)),
         tol: f64,
     ) -> (Vec<f64>, Vec<f64>) {
-        algo::surface::parameter_division(self, range, tol)
+        let subs_grid = |us: &[f64], vs: &[f64]| {
+            let grid = self.0.subs_grid(us, vs);
+            let row = |row: Vec<V>| row.into_iter().map(|pt| pt.to_point()).collect();
+            grid.into_iter().map(row).collect()
+        };
+        algo::surface::parameter_division_by_grid(self, range, tol, subs_grid)
     }
 }
 
@@ -100,6 +100,7 @@ mod parabola;
 mod plane;
 mod sphere;
 mod torus;
+pub use plane::best_fit_plane;
 
 macro_rules! always_true {
     ($ty: tt) => {
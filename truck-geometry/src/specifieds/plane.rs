@@ -1,5 +1,7 @@
 use super::*;
 
+const JACOBI_TRIALS: usize = 50;
+
 impl Plane {
     /// Creates a new plane from three points.
     #[inline(always)]
@@ -211,3 +213,97 @@ impl From<Plane> for BSplineSurface<Point3> {
 impl ToSameGeometry<BSplineSurface<Point3>> for Plane {
     fn to_same_geometry(&self) -> BSplineSurface<Point3> { (*self).into() }
 }
+
+/// Returns the least-squares plane of `points` and the maximum distance from the plane to the
+/// points.
+///
+/// The origin of the plane is the centroid of the points, and the u-axis is the direction in
+/// which the points spread most. The axes are normalized. If the points are ordered as a polygon,
+/// the normal is oriented so that the polygon goes around counterclockwise.
+/// # Failures
+/// If the points are collinear or fewer than three, returns [`Error::CollinearPoints`].
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// // a slightly noisy square on the plane z = 1
+/// let points = [
+///     Point3::new(0.0, 0.0, 1.001),
+///     Point3::new(1.0, 0.0, 0.999),
+///     Point3::new(1.0, 1.0, 1.001),
+///     Point3::new(0.0, 1.0, 0.999),
+/// ];
+/// let (plane, deviation) = best_fit_plane(&points).unwrap();
+/// assert_near!(plane.normal(), Vector3::unit_z());
+/// assert_near!(plane.origin(), Point3::new(0.5, 0.5, 1.0));
+/// assert!(f64::abs(deviation - 0.001) < 1.0e-10);
+///
+/// // collinear points
+/// let points = [
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 1.0, 1.0),
+///     Point3::new(2.0, 2.0, 2.0),
+/// ];
+/// assert_eq!(best_fit_plane(&points).unwrap_err(), Error::CollinearPoints);
+/// ```
+pub fn best_fit_plane(points: &[Point3]) -> Result<(Plane, f64)> {
+    if points.len() < 3 {
+        return Err(Error::CollinearPoints);
+    }
+    let center = Point3::centroid(points);
+    let covariance = points.iter().fold(Matrix3::zero(), |sum, p| {
+        let v = p - center;
+        sum + Matrix3::from_cols(v * v.x, v * v.y, v * v.z)
+    });
+    let (eigenvalues, eigenvectors) = symmetric_eigen(covariance);
+    let mut indices = [0, 1, 2];
+    indices.sort_by(|i, j| eigenvalues[*i].total_cmp(&eigenvalues[*j]));
+    let [min, mid, max] = indices;
+    // the root mean square of the spread in the second direction
+    if f64::sqrt(f64::max(eigenvalues[mid], 0.0) / points.len() as f64) < TOLERANCE {
+        return Err(Error::CollinearPoints);
+    }
+    let n = points.len();
+    let orientation = (0..n).fold(Vector3::zero(), |sum, i| {
+        sum + (points[i] - center).cross(points[(i + 1) % n] - center)
+    });
+    let normal = match orientation.dot(eigenvectors[min]) < 0.0 {
+        true => -eigenvectors[min].normalize(),
+        false => eigenvectors[min].normalize(),
+    };
+    let u_axis = eigenvectors[max].normalize();
+    let plane = Plane::new(center, center + u_axis, center + normal.cross(u_axis));
+    let deviation = points
+        .iter()
+        .map(|p| (p - center).dot(normal).abs())
+        .fold(0.0, f64::max);
+    Ok((plane, deviation))
+}
+
+/// Returns the eigenvalues and the eigenvectors of the symmetric matrix by the Jacobi method.
+/// The `i`th column of the returned matrix is the eigenvector of the `i`th eigenvalue.
+fn symmetric_eigen(mut matrix: Matrix3) -> ([f64; 3], Matrix3) {
+    let mut eigenvectors = Matrix3::identity();
+    for _ in 0..JACOBI_TRIALS {
+        // the largest off-diagonal element
+        let (p, q) = [(0, 1), (0, 2), (1, 2)]
+            .into_iter()
+            .max_by(|(i, j), (k, l)| matrix[*i][*j].abs().total_cmp(&matrix[*k][*l].abs()))
+            .unwrap();
+        let scale = matrix[p][p].abs() + matrix[q][q].abs();
+        if matrix[p][q] == 0.0 || matrix[p][q].abs() <= f64::EPSILON * scale {
+            break;
+        }
+        let theta = (matrix[q][q] - matrix[p][p]) / (2.0 * matrix[p][q]);
+        let t = theta.signum() / (theta.abs() + f64::sqrt(theta * theta + 1.0));
+        let c = 1.0 / f64::sqrt(t * t + 1.0);
+        let s = t * c;
+        let mut rotation = Matrix3::identity();
+        rotation[p][p] = c;
+        rotation[q][q] = c;
+        rotation[q][p] = s;
+        rotation[p][q] = -s;
+        matrix = rotation.transpose() * matrix * rotation;
+        eigenvectors = eigenvectors * rotation;
+    }
+    ([matrix[0][0], matrix[1][1], matrix[2][2]], eigenvectors)
+}
@@ -1,5 +1,5 @@
 use proptest::prelude::*;
-use truck_geometry::prelude::*;
+use truck_geometry::{errors::Error, prelude::*};
 
 proptest! {
     #[test]
@@ -116,3 +116,258 @@ fn test_parameter_division() {
         assert!(value_middle.distance(param_middle) < tol);
     }
 }
+
+#[test]
+fn refine_preserves_curve() {
+    let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.0, 0.3, 0.6, 0.6, 1.0, 1.0, 1.0, 1.0]);
+    let control_points = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 2.0, 0.0),
+        Point3::new(2.0, -1.0, 1.0),
+        Point3::new(3.0, 1.0, -1.0),
+        Point3::new(4.0, 3.0, 0.0),
+        Point3::new(5.0, 0.0, 2.0),
+        Point3::new(6.0, 1.0, 1.0),
+    ];
+    let org_curve = BSplineCurve::new(knot_vec, control_points);
+
+    let mut curve = org_curve.clone();
+    curve.add_knot(0.45).add_knot(0.6);
+    curve.refine(&[0.0, 0.1, 0.3, 0.3, 0.3, 0.6, 0.6, 0.8, 1.0, 1.0, -1.0, 2.0]);
+    // 0.1, 0.3, 0.3, 0.8 are inserted; 0.3 and 0.6 reach the degree, and the ends are clamped.
+    assert_eq!(curve.knot_vec().len(), org_curve.knot_vec().len() + 6);
+    assert_eq!(curve.knot_vec().multiplicity(0), 4);
+    assert_eq!(curve.knot_vec().multiplicity(curve.knot_vec().len() - 1), 4);
+    assert_near!(curve.control_points()[0], org_curve.control_points()[0]);
+    assert_near!(
+        curve.control_points()[curve.control_points().len() - 1],
+        org_curve.control_points()[org_curve.control_points().len() - 1],
+    );
+    (0..=100).for_each(|i| {
+        let t = i as f64 / 100.0;
+        assert_near!(curve.subs(t), org_curve.subs(t));
+    });
+}
+
+#[test]
+fn offset_circle() {
+    let circle = UnitCircle::<Point2>::new();
+    let range = (0.0, 2.0 * std::f64::consts::PI);
+    let bspcurve = BSplineCurve::cubic_approximation(&circle, range, 1.0e-6, 1.0e-4, 10).unwrap();
+    let offset = bspcurve.offset(0.5, 1.0e-4);
+    assert_eq!(offset.range_tuple(), bspcurve.range_tuple());
+    (0..=100).for_each(|i| {
+        let t = range.1 * i as f64 / 100.0;
+        let radius = offset.subs(t).to_vec().magnitude();
+        assert!(f64::abs(radius - 1.5) < 1.0e-3, "{radius}");
+    });
+    let inner = bspcurve.offset(-0.5, 1.0e-4);
+    (0..=100).for_each(|i| {
+        let t = range.1 * i as f64 / 100.0;
+        let radius = inner.subs(t).to_vec().magnitude();
+        assert!(f64::abs(radius - 0.5) < 1.0e-3, "{radius}");
+    });
+}
+
+#[test]
+fn offset_zero_curvature() {
+    let bspcurve = BSplineCurve::new(
+        KnotVec::uniform_knot(3, 2),
+        vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(3.0, 0.0),
+            Point2::new(4.0, 0.0),
+        ],
+    );
+    let offset = bspcurve.offset(-0.25, 1.0e-4);
+    (0..=100).for_each(|i| {
+        let t = i as f64 / 100.0;
+        let pt = offset.subs(t);
+        assert_near!(pt, bspcurve.subs(t) + Vector2::new(0.0, 0.25));
+    });
+}
+
+#[test]
+fn offset_corner() {
+    let bspcurve = BSplineCurve::new(
+        KnotVec::from(vec![0.0, 0.0, 1.0, 2.0, 2.0]),
+        vec![
+            Point2::new(0.0, 0.0),
+            Point2::new(1.0, 0.0),
+            Point2::new(1.0, 1.0),
+        ],
+    );
+    let offset = bspcurve.offset(0.5, 1.0e-4);
+    let (t0, t1) = offset.range_tuple();
+    // the corner is connected by an arc whose length is a quarter of the circumference.
+    assert_near!(t1 - t0, 2.0 + 0.25 * std::f64::consts::PI);
+    assert_near!(offset.front(), Point2::new(0.0, -0.5));
+    assert_near!(offset.back(), Point2::new(1.5, 1.0));
+    (0..=100).for_each(|i| {
+        let t = t0 + (t1 - t0) * i as f64 / 100.0;
+        let pt = offset.subs(t);
+        let x = f64::clamp(pt.x, 0.0, 1.0);
+        let y = f64::clamp(pt.y, 0.0, 1.0);
+        let dist0 = pt.distance(Point2::new(x, 0.0));
+        let dist1 = pt.distance(Point2::new(1.0, y));
+        let dist = f64::min(dist0, dist1);
+        assert!(f64::abs(dist - 0.5) < 1.0e-3, "{dist}");
+    });
+}
+
+#[test]
+fn interpolate_points() {
+    let points = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(0.1, 0.0, 0.0),
+        Point3::new(0.1, 0.0, 0.0),
+        Point3::new(2.0, 1.0, 0.5),
+        Point3::new(3.0, 3.0, 1.0),
+        Point3::new(3.1, 3.2, 1.0),
+        Point3::new(5.0, 2.0, -1.0),
+        Point3::new(8.0, 0.0, 0.0),
+    ];
+    let params = BSplineCurve::centripetal_parameters(&points);
+    (1..=4).for_each(|degree| {
+        let curve = BSplineCurve::interpolate(&points, degree).unwrap();
+        assert_eq!(curve.degree(), degree);
+        assert_eq!(curve.control_points().len(), points.len() - 1);
+        params.iter().zip(&points).for_each(|(t, p)| {
+            assert_near!(curve.subs(*t), *p);
+        });
+    });
+
+    let points = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+    ];
+    assert_eq!(
+        BSplineCurve::interpolate(&points, 2),
+        Err(Error::TooFewPoints(2, 2)),
+    );
+    assert_eq!(
+        BSplineCurve::<Point3>::interpolate(&[], 2),
+        Err(Error::EmptyControlPoints),
+    );
+}
+
+#[test]
+fn approximate_points() {
+    let org_curve = BSplineCurve::new(
+        KnotVec::uniform_knot(3, 3),
+        vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 2.0, 0.0),
+            Point3::new(2.0, -1.0, 1.0),
+            Point3::new(4.0, 1.0, 0.0),
+            Point3::new(5.0, 3.0, -1.0),
+            Point3::new(7.0, 0.0, 0.0),
+        ],
+    );
+    let points = (0..=100)
+        .map(|i| org_curve.subs(i as f64 / 100.0))
+        .collect::<Vec<_>>();
+    let curve = BSplineCurve::approximate(&points, 3, 20).unwrap();
+    assert_eq!(curve.degree(), 3);
+    assert_eq!(curve.control_points().len(), 20);
+    assert_near!(curve.front(), points[0]);
+    assert_near!(curve.back(), points[100]);
+    points.iter().for_each(|p| {
+        let t = curve.search_nearest_parameter(*p, None, 100).unwrap();
+        assert!(curve.subs(t).distance(*p) < 1.0e-2);
+    });
+
+    assert_eq!(
+        BSplineCurve::approximate(&points, 3, 3),
+        Err(Error::TooFewPoints(3, 3)),
+    );
+    let curve = BSplineCurve::approximate(&points[..5], 3, 8).unwrap();
+    assert_eq!(curve.control_points().len(), 5);
+}
+
+#[test]
+fn elevate_degree_preserves_curve() {
+    let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.0, 0.3, 0.6, 0.6, 1.0, 1.0, 1.0, 1.0]);
+    let control_points = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 2.0, 0.0),
+        Point3::new(2.0, -1.0, 1.0),
+        Point3::new(3.0, 1.0, -1.0),
+        Point3::new(4.0, 3.0, 0.0),
+        Point3::new(5.0, 0.0, 2.0),
+        Point3::new(6.0, 1.0, 1.0),
+    ];
+    let org_curve = BSplineCurve::new(knot_vec, control_points);
+    let mut curve = org_curve.clone();
+    curve.elevate_degree();
+    assert_eq!(curve.degree(), 4);
+    // one more control point for each span
+    assert_eq!(
+        curve.control_points().len(),
+        org_curve.control_points().len() + 3
+    );
+    (0..=100).for_each(|i| {
+        let t = i as f64 / 100.0;
+        assert_near!(curve.subs(t), org_curve.subs(t));
+    });
+
+    let weights = [1.0, 0.5, 2.0, 1.0, 0.8, 1.5, 1.0];
+    let control_points = org_curve
+        .control_points()
+        .iter()
+        .zip(weights)
+        .map(|(p, w)| Vector4::new(p.x * w, p.y * w, p.z * w, w))
+        .collect();
+    let org_curve = NurbsCurve::new(BSplineCurve::new(
+        org_curve.knot_vec().clone(),
+        control_points,
+    ));
+    let mut curve = org_curve.clone();
+    curve.elevate_degree();
+    assert_eq!(
+        curve.control_points().len(),
+        org_curve.control_points().len() + 3
+    );
+    (0..=100).for_each(|i| {
+        let t = i as f64 / 100.0;
+        assert_near!(curve.subs(t), org_curve.subs(t));
+    });
+}
+
+#[test]
+fn subdivide_preserves_curve() {
+    let knot_vec = KnotVec::from(vec![0.0, 0.0, 0.0, 0.0, 0.3, 0.6, 0.6, 1.0, 1.0, 1.0, 1.0]);
+    let control_points = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 2.0, 0.0),
+        Point3::new(2.0, -1.0, 1.0),
+        Point3::new(3.0, 1.0, -1.0),
+        Point3::new(4.0, 3.0, 0.0),
+        Point3::new(5.0, 0.0, 2.0),
+        Point3::new(6.0, 1.0, 1.0),
+    ];
+    let curve = BSplineCurve::new(knot_vec, control_points);
+    [0.15, 0.3, 0.6, 0.85].into_iter().for_each(|t| {
+        let (curve0, curve1) = curve.subdivide(t);
+        assert!(curve0.is_clamped() && curve1.is_clamped());
+        assert_eq!(curve0.range_tuple(), (0.0, t));
+        assert_eq!(curve1.range_tuple(), (t, 1.0));
+        assert_near!(curve0.back(), curve1.front());
+        (0..=100).for_each(|i| {
+            let s = t * i as f64 / 100.0;
+            assert_near!(curve0.subs(s), curve.subs(s));
+            let s = t + (1.0 - t) * i as f64 / 100.0;
+            assert_near!(curve1.subs(s), curve.subs(s));
+        });
+    });
+
+    let (curve0, curve1) = curve.subdivide(0.0);
+    assert!(curve0.control_points().is_empty());
+    assert_eq!(curve1, curve);
+    let (curve0, curve1) = curve.subdivide(1.0);
+    assert_eq!(curve0, curve);
+    assert!(curve1.control_points().is_empty());
+}
@@ -0,0 +1,102 @@
+use truck_geometry::prelude::*;
+
+#[test]
+fn refine_preserves_surface() {
+    let knot_vecs = (
+        KnotVec::from(vec![0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0]),
+        KnotVec::bezier_knot(3),
+    );
+    let control_points = (0..4)
+        .map(|i| {
+            (0..4)
+                .map(|j| Point3::new(i as f64, j as f64, ((i * j) % 3) as f64 - 1.0))
+                .collect()
+        })
+        .collect();
+    let org_surface = BSplineSurface::new(knot_vecs, control_points);
+
+    let mut surface = org_surface.clone();
+    surface.urefine(&[0.0, 0.25, 0.5, 0.5, 1.0]);
+    surface.vrefine(&[0.0, 0.5, 0.5, 0.5, 0.5, 1.0]);
+    assert_eq!(
+        surface.uknot_vec(),
+        &KnotVec::from(vec![0.0, 0.0, 0.0, 0.25, 0.5, 0.5, 1.0, 1.0, 1.0]),
+    );
+    assert_eq!(
+        surface.vknot_vec(),
+        &KnotVec::from(vec![0.0, 0.0, 0.0, 0.0, 0.5, 0.5, 0.5, 1.0, 1.0, 1.0, 1.0]),
+    );
+    (0..=10).for_each(|i| {
+        (0..=10).for_each(|j| {
+            let (u, v) = (i as f64 / 10.0, j as f64 / 10.0);
+            assert_near!(surface.subs(u, v), org_surface.subs(u, v));
+        })
+    });
+}
+
+#[test]
+fn elevate_degree_preserves_surface() {
+    let knot_vecs = (
+        KnotVec::from(vec![0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0]),
+        KnotVec::from(vec![0.0, 0.0, 0.4, 0.7, 1.0, 1.0]),
+    );
+    let control_points = (0..4)
+        .map(|i| {
+            (0..4)
+                .map(|j| Point3::new(i as f64, j as f64, ((i * j) % 3) as f64 - 1.0))
+                .collect()
+        })
+        .collect();
+    let org_surface = BSplineSurface::new(knot_vecs, control_points);
+    let mut surface = org_surface.clone();
+    surface.elevate_udegree().elevate_vdegree();
+    assert_eq!((surface.udegree(), surface.vdegree()), (3, 2));
+    assert_eq!(surface.control_points().len(), 6);
+    assert_eq!(surface.control_points()[0].len(), 7);
+    (0..=10).for_each(|i| {
+        (0..=10).for_each(|j| {
+            let (u, v) = (i as f64 / 10.0, j as f64 / 10.0);
+            assert_near!(surface.subs(u, v), org_surface.subs(u, v));
+        })
+    });
+}
+
+#[test]
+fn bounding_box_contains_surface() {
+    let knot_vecs = (
+        KnotVec::uniform_knot(2, 3),
+        KnotVec::from(vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0]),
+    );
+    let control_points: Vec<Vec<Point3>> = (0..5)
+        .map(|i| {
+            (0..4)
+                .map(|j| Point3::new(i as f64, j as f64, ((i * j) % 3) as f64 - 1.0))
+                .collect()
+        })
+        .collect();
+    let surface = BSplineSurface::new(knot_vecs.clone(), control_points.clone());
+    let rational = NurbsSurface::new(BSplineSurface::new(
+        knot_vecs,
+        control_points
+            .iter()
+            .enumerate()
+            .map(|(i, vec)| {
+                vec.iter()
+                    .map(|p| Vector4::new(p.x, p.y, p.z, 1.0) * (1.0 + i as f64))
+                    .collect()
+            })
+            .collect(),
+    ));
+
+    let bdb = surface.bounding_box();
+    let rational_bdb = rational.bounding_box();
+    let ((u0, u1), (v0, v1)) = surface.range_tuple();
+    (0..=20).for_each(|i| {
+        (0..=20).for_each(|j| {
+            let u = u0 + (u1 - u0) * i as f64 / 20.0;
+            let v = v0 + (v1 - v0) * j as f64 / 20.0;
+            assert!(bdb.contains(surface.subs(u, v)));
+            assert!(rational_bdb.contains(rational.subs(u, v)));
+        })
+    });
+}
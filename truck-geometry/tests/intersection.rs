@@ -0,0 +1,295 @@
+use truck_geometry::prelude::*;
+
+const TOL: f64 = 0.001;
+
+#[test]
+fn plane_plane() {
+    let plane0 = Plane::new(
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+    let plane1 = Plane::new(
+        Point3::new(0.5, -1.0, -1.0),
+        Point3::new(0.5, 2.0, -1.0),
+        Point3::new(0.5, -1.0, 1.0),
+    );
+    let polylines = surface_surface_intersection(&plane0, &plane1, TOL);
+    assert_eq!(polylines.len(), 1);
+    let points = &polylines[0];
+    points.iter().for_each(|p| {
+        assert!(f64::abs(p.x - 0.5) < TOLERANCE);
+        assert!(p.z.so_small());
+    });
+    let (front, back) = (points[0], points[points.len() - 1]);
+    let (min, max) = match front.y < back.y {
+        true => (front.y, back.y),
+        false => (back.y, front.y),
+    };
+    assert!(min < TOL && max > 1.0 - TOL, "{min} {max}");
+}
+
+#[test]
+fn plane_sphere() {
+    let plane = Plane::new(
+        Point3::new(-2.0, -2.0, 0.5),
+        Point3::new(2.0, -2.0, 0.5),
+        Point3::new(-2.0, 2.0, 0.5),
+    );
+    let sphere = Sphere::new(Point3::origin(), 1.0);
+    let polylines = surface_surface_intersection(&sphere, &plane, TOL);
+    assert_eq!(polylines.len(), 1);
+    let points = &polylines[0];
+    assert!(points.len() > 4);
+    assert_near!(points[0], points[points.len() - 1]);
+    let center = Point3::new(0.0, 0.0, 0.5);
+    let radius = f64::sqrt(0.75);
+    points.iter().for_each(|p| {
+        assert_near!(p.z, 0.5);
+        assert_near!(p.distance(center), radius);
+    });
+    points.windows(2).for_each(|w| {
+        let mid = w[0].midpoint(w[1]);
+        assert!(radius - mid.distance(center) < TOL);
+    });
+}
+
+#[test]
+fn plane_torus_two_branches() {
+    let plane = Plane::new(
+        Point3::new(0.0, -3.0, -1.0),
+        Point3::new(0.0, 3.0, -1.0),
+        Point3::new(0.0, -3.0, 1.0),
+    );
+    let torus = Torus::new(Point3::origin(), 2.0, 0.5);
+    let polylines = surface_surface_intersection(&plane, &torus, TOL);
+    assert_eq!(polylines.len(), 2);
+    let mut signs = polylines
+        .iter()
+        .map(|polyline| {
+            let points = polyline;
+            assert_near!(points[0], points[points.len() - 1]);
+            let center = Point3::new(0.0, 2.0 * points[0].y.signum(), 0.0);
+            points.iter().for_each(|p| {
+                assert!(p.x.so_small());
+                assert_near!(p.distance(center), 0.5);
+            });
+            points[0].y.signum()
+        })
+        .collect::<Vec<_>>();
+    signs.sort_by(f64::total_cmp);
+    assert_eq!(signs, vec![-1.0, 1.0]);
+}
+
+#[test]
+fn tangential_contact() {
+    let plane = Plane::new(
+        Point3::new(-2.0, -2.0, 1.0),
+        Point3::new(2.0, -2.0, 1.0),
+        Point3::new(-2.0, 2.0, 1.0),
+    );
+    let sphere = Sphere::new(Point3::origin(), 1.0);
+    let polylines = surface_surface_intersection(&plane, &sphere, TOL);
+    assert_eq!(polylines.len(), 1);
+    assert_eq!(polylines[0].len(), 1);
+    assert_near!(polylines[0][0], Point3::new(0.0, 0.0, 1.0));
+}
+
+#[test]
+fn disjoint() {
+    let plane = Plane::new(
+        Point3::new(-2.0, -2.0, 3.0),
+        Point3::new(2.0, -2.0, 3.0),
+        Point3::new(-2.0, 2.0, 3.0),
+    );
+    let sphere = Sphere::new(Point3::origin(), 1.0);
+    assert!(surface_surface_intersection(&plane, &sphere, TOL).is_empty());
+}
+
+#[test]
+fn exact_plane_plane() {
+    let plane0 = Plane::new(
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+    let plane1 = Plane::new(
+        Point3::new(0.25, -1.0, -1.0),
+        Point3::new(0.75, -1.0, 1.0),
+        Point3::new(0.25, 2.0, -1.0),
+    );
+    let polylines = plane_plane_intersection(&plane0, &plane1);
+    assert_eq!(polylines.len(), 1);
+    let mut points = polylines[0].clone();
+    points.sort_by(|p, q| p.y.total_cmp(&q.y));
+    assert_near!(points[0], Point3::new(0.5, 0.0, 0.0));
+    assert_near!(points[1], Point3::new(0.5, 1.0, 0.0));
+
+    // the parallelograms are apart
+    let plane1 = Plane::new(
+        Point3::new(2.0, -1.0, -1.0),
+        Point3::new(2.0, 2.0, -1.0),
+        Point3::new(2.0, -1.0, 1.0),
+    );
+    assert!(plane_plane_intersection(&plane0, &plane1).is_empty());
+
+    // parallel
+    let plane1 = Plane::new(
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(0.0, 1.0, 1.0),
+    );
+    assert!(plane_plane_intersection(&plane0, &plane1).is_empty());
+}
+
+#[test]
+fn exact_plane_sphere() {
+    let plane = Plane::new(
+        Point3::new(-0.5, -0.5, 0.0),
+        Point3::new(0.5, -0.5, 0.0),
+        Point3::new(-0.5, 0.5, 0.0),
+    );
+    // the section circle with radius 0.6 goes out of the square four times.
+    let sphere = Sphere::new(Point3::new(0.0, 0.0, 0.8), 1.0);
+    let polylines = plane_sphere_intersection(&plane, &sphere, TOL);
+    assert_eq!(polylines.len(), 4);
+    polylines.iter().for_each(|points| {
+        points.iter().for_each(|p| {
+            assert!(p.z.so_small());
+            assert_near!(p.to_vec().magnitude(), 0.6);
+        });
+        points.windows(2).for_each(|w| {
+            let mid = w[0].midpoint(w[1]);
+            assert!(0.6 - mid.to_vec().magnitude() < TOL);
+        });
+        let (front, back) = (points[0], points[points.len() - 1]);
+        [front, back].into_iter().for_each(|p| {
+            let max = f64::max(p.x.abs(), p.y.abs());
+            assert!(f64::abs(max - 0.5) < TOL);
+        });
+        let (x, y) = (0.5, f64::sqrt(0.11));
+        assert!(f64::abs(front.x.abs() - x) < TOL || f64::abs(front.x.abs() - y) < TOL);
+    });
+
+    let sphere = Sphere::new(Point3::new(0.0, 0.0, 1.0), 1.0);
+    let polylines = plane_sphere_intersection(&plane, &sphere, TOL);
+    assert_eq!(polylines, vec![vec![Point3::origin()]]);
+
+    let sphere = Sphere::new(Point3::new(0.0, 0.0, 1.5), 1.0);
+    assert!(plane_sphere_intersection(&plane, &sphere, TOL).is_empty());
+}
+
+#[test]
+fn exact_plane_cylinder() {
+    let line = Line(Point3::new(1.0, 0.0, -0.25), Point3::new(1.0, 0.0, 0.25));
+    let cylinder = RevolutedCurve::by_revolution(line, Point3::origin(), Vector3::unit_z());
+    // the ellipse `x^2 + y^2 = 1, z = x / 2` is cut by the ends of the cylinder.
+    let plane = Plane::new(
+        Point3::new(-2.0, -2.0, -1.0),
+        Point3::new(2.0, -2.0, 1.0),
+        Point3::new(-2.0, 2.0, -1.0),
+    );
+    let polylines = plane_cylinder_intersection(&plane, &cylinder, TOL);
+    assert_eq!(polylines.len(), 2);
+    polylines.iter().for_each(|points| {
+        points.iter().for_each(|p| {
+            assert_near!(p.x * p.x + p.y * p.y, 1.0);
+            assert_near!(p.z, p.x / 2.0);
+        });
+        let (front, back) = (points[0], points[points.len() - 1]);
+        assert_near!(f64::abs(front.x), 0.5);
+        assert_near!(front.x, -back.x);
+        assert_near!(front.y, back.y);
+    });
+
+    // the plane parallel to the axis cuts the cylinder along two lines.
+    let line = Line(Point3::new(1.0, 0.0, -1.0), Point3::new(1.0, 0.0, 1.0));
+    let cylinder = RevolutedCurve::by_revolution(line, Point3::origin(), Vector3::unit_z());
+    let plane = |x: f64| {
+        Plane::new(
+            Point3::new(x, -2.0, -2.0),
+            Point3::new(x, 2.0, -2.0),
+            Point3::new(x, -2.0, 2.0),
+        )
+    };
+    let mut polylines = plane_cylinder_intersection(&plane(0.6), &cylinder, TOL);
+    assert_eq!(polylines.len(), 2);
+    polylines.iter_mut().for_each(|points| {
+        points.sort_by(|p, q| p.z.total_cmp(&q.z));
+    });
+    polylines.sort_by(|p, q| p[0].y.total_cmp(&q[0].y));
+    assert_near!(polylines[0][0], Point3::new(0.6, -0.8, -1.0));
+    assert_near!(polylines[0][1], Point3::new(0.6, -0.8, 1.0));
+    assert_near!(polylines[1][0], Point3::new(0.6, 0.8, -1.0));
+    assert_near!(polylines[1][1], Point3::new(0.6, 0.8, 1.0));
+
+    // tangential contact along the line
+    let polylines = plane_cylinder_intersection(&plane(1.0), &cylinder, TOL);
+    assert_eq!(polylines.len(), 1);
+    assert_eq!(polylines[0].len(), 2);
+    polylines[0].iter().for_each(|p| {
+        assert_near!(p.x, 1.0);
+        assert!(p.y.so_small());
+        assert_near!(p.z.abs(), 1.0);
+    });
+
+    assert!(plane_cylinder_intersection(&plane(1.5), &cylinder, TOL).is_empty());
+}
+
+fn nurbs_circle() -> NurbsCurve<Vector3> {
+    let w = f64::sqrt(0.5);
+    let knot_vec = KnotVec::from(vec![
+        0.0, 0.0, 0.0, 1.0, 1.0, 2.0, 2.0, 3.0, 3.0, 4.0, 4.0, 4.0,
+    ]);
+    let control_points = [
+        (1.0, 0.0, 1.0),
+        (1.0, 1.0, w),
+        (0.0, 1.0, 1.0),
+        (-1.0, 1.0, w),
+        (-1.0, 0.0, 1.0),
+        (-1.0, -1.0, w),
+        (0.0, -1.0, 1.0),
+        (1.0, -1.0, w),
+        (1.0, 0.0, 1.0),
+    ]
+    .map(|(x, y, w)| Vector3::new(x * w, y * w, w));
+    NurbsCurve::new(BSplineCurve::new(knot_vec, control_points.to_vec()))
+}
+
+#[test]
+fn line_circle() {
+    let circle = nurbs_circle();
+    let line = Line(Point2::new(-2.0, 0.5), Point2::new(2.0, 0.5));
+    let res = algo::curve::curve_intersections(&line, &circle, TOL).unwrap();
+    assert_eq!(res.len(), 2);
+    let x = f64::sqrt(0.75);
+    assert_near!(res[0].2, Point2::new(-x, 0.5));
+    assert_near!(res[1].2, Point2::new(x, 0.5));
+    res.iter().for_each(|(t0, t1, p)| {
+        assert_near!(line.subs(*t0), *p);
+        assert_near!(circle.subs(*t1), *p);
+    });
+
+    // tangential touch
+    let line = Line(Point2::new(-2.0, 1.0), Point2::new(2.0, 1.0));
+    let res = algo::curve::curve_intersections(&line, &circle, TOL).unwrap();
+    assert_eq!(res.len(), 1);
+    assert!(res[0].2.distance(Point2::new(0.0, 1.0)) < TOL);
+
+    let line = Line(Point2::new(-2.0, 1.5), Point2::new(2.0, 1.5));
+    let res = algo::curve::curve_intersections(&line, &circle, TOL).unwrap();
+    assert!(res.is_empty());
+}
+
+#[test]
+fn overlapping_lines() {
+    let line0 = Line(Point2::new(0.0, 0.0), Point2::new(2.0, 1.0));
+    let line1 = Line(Point2::new(1.0, 0.5), Point2::new(3.0, 1.5));
+    assert!(algo::curve::curve_intersections(&line0, &line1, TOL).is_none());
+
+    // the collinear lines touching at the end
+    let line1 = Line(Point2::new(2.0, 1.0), Point2::new(4.0, 2.0));
+    let res = algo::curve::curve_intersections(&line0, &line1, TOL).unwrap();
+    assert_eq!(res.len(), 1);
+    assert_near!(res[0].2, Point2::new(2.0, 1.0));
+}
@@ -54,3 +54,53 @@ fn test_parameter_division() {
         assert!(dist < tol, "large distance: {dist}");
     }
 }
+
+#[test]
+fn test_into_bspline() {
+    let knot_vec = KnotVec::uniform_knot(2, 3);
+    let ctrl_pts = vec![
+        Vector4::new(0.0, 0.0, 0.0, 1.0),
+        Vector4::new(2.0, 0.0, 0.0, 2.0),
+        Vector4::new(0.0, 3.0, 0.0, 3.0),
+        Vector4::new(0.0, 0.0, 2.0, 2.0),
+        Vector4::new(1.0, 1.0, 1.0, 1.0),
+    ];
+    let mut curve = NurbsCurve::new(BSplineCurve::new(knot_vec, ctrl_pts));
+    assert_eq!(curve.weights(), vec![1.0, 2.0, 3.0, 2.0, 1.0]);
+    assert!(curve.try_into_bspline().is_none());
+    let points = curve
+        .control_points()
+        .iter()
+        .map(|pt| pt.to_point())
+        .collect::<Vec<_>>();
+
+    // non-positive weights are rejected.
+    assert_eq!(
+        curve.try_set_weight(2, 0.0).unwrap_err(),
+        Error::NonPositiveWeight(0.0),
+    );
+    assert_eq!(curve.weights()[2], 3.0);
+
+    (0..5).for_each(|i| {
+        curve.set_weight(i, 1.5);
+    });
+    assert_eq!(curve.weights(), vec![1.5; 5]);
+    let bspcurve = curve.try_into_bspline().unwrap();
+    bspcurve
+        .control_points()
+        .iter()
+        .zip(&points)
+        .for_each(|(p, q)| assert_near!(p, q));
+    (0..=100).for_each(|i| {
+        let t = i as f64 / 100.0;
+        assert_near!(bspcurve.subs(t), curve.subs(t));
+    });
+}
+
+#[test]
+#[should_panic]
+fn set_non_positive_weight() {
+    let ctrl_pts = vec![Vector3::new(0.0, 0.0, 1.0), Vector3::new(1.0, 1.0, 1.0)];
+    let mut curve = NurbsCurve::new(BSplineCurve::new(KnotVec::bezier_knot(1), ctrl_pts));
+    curve.set_weight(1, -1.0);
+}
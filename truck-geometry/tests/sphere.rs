@@ -59,3 +59,22 @@ fn sphere_derivation_test() {
         }
     }
 }
+
+#[test]
+fn sphere_curvature_test() {
+    let sphere = Sphere::new(Point3::new(1.0, 2.0, 3.0), 1.0);
+    (1..20).for_each(|i| {
+        (0..20).for_each(|j| {
+            let u = PI * i as f64 / 20.0;
+            let v = 2.0 * PI * j as f64 / 20.0;
+            assert_near!(sphere.gaussian_curvature(u, v), 1.0);
+            assert_near!(sphere.mean_curvature(u, v), -1.0);
+        })
+    });
+    let sphere = Sphere::new(Point3::origin(), 2.0);
+    assert_near!(sphere.gaussian_curvature(1.0, 1.0), 0.25);
+    assert_near!(sphere.mean_curvature(1.0, 1.0), -0.5);
+    // degenerate at the poles
+    assert!(sphere.gaussian_curvature(0.0, 1.0).is_nan());
+    assert!(sphere.mean_curvature(PI, 1.0).is_nan());
+}
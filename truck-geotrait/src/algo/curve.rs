@@ -66,60 +66,240 @@ where
 /// # Panics
 ///
 /// `tol` must be more than `TOLERANCE`.
+#[inline(always)]
 pub fn parameter_division<C>(curve: &C, range: (f64, f64), tol: f64) -> (Vec<f64>, Vec<C::Point>)
 where
     C: ParametricCurve,
     C::Point: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>, {
-    nonpositive_tolerance!(tol);
-    sub_parameter_division(
-        curve,
-        range,
-        (curve.subs(range.0), curve.subs(range.1)),
-        tol,
-        100,
-    )
+    parameter_division_by_batch(range, tol, |ts| ts.iter().map(|t| curve.subs(*t)).collect())
 }
 
-fn sub_parameter_division<C>(
-    curve: &C,
+/// Creates the curve division, evaluating the curve by `subs_many`, which returns the points of the
+/// curve at the given parameters.
+///
+/// The division is the same as [`parameter_division`]. The curve is evaluated at the parameters of
+/// all the intervals to be divided at once, which is faster for the curves evaluating many
+/// parameters together, e.g. `BSplineCurve::subs_many` in `truck-geometry`.
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+pub fn parameter_division_by_batch<P>(
     range: (f64, f64),
-    ends: (C::Point, C::Point),
     tol: f64,
-    trials: usize,
-) -> (Vec<f64>, Vec<C::Point>)
+    subs_many: impl Fn(&[f64]) -> Vec<P>,
+) -> (Vec<f64>, Vec<P>)
 where
-    C: ParametricCurve,
-    C::Point: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>,
+    P: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>,
 {
-    let gen = ends.0.midpoint(ends.1);
-    let p = 0.5 + (0.2 * HashGen::hash1(gen) - 0.1);
-    let t = range.0 * (1.0 - p) + range.1 * p;
-    let mid = ends.0 + (ends.1 - ends.0) * p;
-    let dist2 = curve.subs(t).distance2(mid);
-    if dist2 < tol * tol || trials == 0 {
-        (vec![range.0, range.1], vec![ends.0, ends.1])
+    nonpositive_tolerance!(tol);
+    let mut params = vec![range.0, range.1];
+    let mut pts = subs_many(&params);
+    // whether each interval is to be checked, i.e. was created in the previous step
+    let mut checked = vec![true];
+    for _ in 0..100 {
+        let (ts, mids): (Vec<f64>, Vec<P>) = params
+            .windows(2)
+            .zip(pts.windows(2))
+            .zip(&checked)
+            .filter(|(_, checked)| **checked)
+            .map(|((range, ends), _)| {
+                let gen = ends[0].midpoint(ends[1]);
+                let p = 0.5 + (0.2 * HashGen::hash1(gen) - 0.1);
+                let t = range[0] * (1.0 - p) + range[1] * p;
+                let mid = ends[0] + (ends[1] - ends[0]) * p;
+                (t, mid)
+            })
+            .unzip();
+        let mut near = subs_many(&ts)
+            .into_iter()
+            .zip(mids)
+            .map(|(pt, mid)| pt.distance2(mid) < tol * tol);
+        let divided = checked
+            .iter()
+            .map(|checked| *checked && !near.next().unwrap())
+            .collect::<Vec<_>>();
+        if !divided.contains(&true) {
+            break;
+        }
+        let mid_params = params
+            .windows(2)
+            .zip(&divided)
+            .filter(|(_, divided)| **divided)
+            .map(|(range, _)| (range[0] + range[1]) / 2.0)
+            .collect::<Vec<_>>();
+        let mid_pts = subs_many(&mid_params);
+        let mut mids = mid_params.into_iter().zip(mid_pts);
+        let (mut new_params, mut new_pts) = (vec![params[0]], vec![pts[0]]);
+        checked.clear();
+        divided.into_iter().enumerate().for_each(|(i, divided)| {
+            if divided {
+                let (t, pt) = mids.next().unwrap();
+                new_params.push(t);
+                new_pts.push(pt);
+                checked.push(true);
+            }
+            new_params.push(params[i + 1]);
+            new_pts.push(pts[i + 1]);
+            checked.push(divided);
+        });
+        (params, pts) = (new_params, new_pts);
+    }
+    (params, pts)
+}
+
+/// Returns `n` parameters, including both ends, dividing `curve` into parts of equal arc length.
+///
+/// The speed `|curve.der(t)|` is integrated by the adaptive Simpson method, and the cumulative
+/// arc length is inverted by Newton's method. `tol` is the tolerance of the arc length.
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+pub fn arc_length_parameters<C>(curve: &C, n: usize, tol: f64) -> Vec<f64>
+where
+    C: BoundedCurve,
+    C::Point: EuclideanSpace<Scalar = f64, Diff = C::Vector>,
+    C::Vector: InnerSpace<Scalar = f64>, {
+    nonpositive_tolerance!(tol);
+    let (t0, t1) = curve.range_tuple();
+    match n {
+        0 => return Vec::new(),
+        1 => return vec![t0],
+        _ => {}
+    }
+    let speed = move |t: f64| curve.der(t).magnitude();
+    let mut table = vec![(t0, 0.0)];
+    let whole = simpson(&speed, (t0, t1));
+    sub_arc_length_table(&speed, (t0, t1), whole, tol, 20, &mut table);
+    let length = table[table.len() - 1].1;
+
+    let mut params = Vec::with_capacity(n);
+    params.push(t0);
+    (1..n - 1).for_each(|i| {
+        let target = length * i as f64 / (n - 1) as f64;
+        let idx = table
+            .partition_point(|(_, l)| *l < target)
+            .clamp(1, table.len() - 1);
+        let ((ta, la), (tb, lb)) = (table[idx - 1], table[idx]);
+        let mut t = match lb - la > 0.0 {
+            true => ta + (tb - ta) * (target - la) / (lb - la),
+            false => ta,
+        };
+        for _ in 0..10 {
+            let err = la + simpson(&speed, (ta, t)) - target;
+            let v = speed(t);
+            if err.abs() < tol || v.so_small() {
+                break;
+            }
+            t = f64::clamp(t - err / v, ta, tb);
+        }
+        params.push(t);
+    });
+    params.push(t1);
+    params
+}
+
+fn simpson(f: &impl Fn(f64) -> f64, (a, b): (f64, f64)) -> f64 {
+    (b - a) / 6.0 * (f(a) + 4.0 * f((a + b) / 2.0) + f(b))
+}
+
+fn sub_arc_length_table(
+    speed: &impl Fn(f64) -> f64,
+    range: (f64, f64),
+    whole: f64,
+    tol: f64,
+    trials: usize,
+    table: &mut Vec<(f64, f64)>,
+) {
+    let mid = (range.0 + range.1) / 2.0;
+    let left = simpson(speed, (range.0, mid));
+    let right = simpson(speed, (mid, range.1));
+    let delta = left + right - whole;
+    if delta.abs() < 15.0 * tol || trials == 0 {
+        let last = table[table.len() - 1].1;
+        table.push((mid, last + left + delta / 30.0));
+        table.push((range.1, last + left + right + delta / 15.0));
     } else {
-        let mid_param = (range.0 + range.1) / 2.0;
-        let mid_value = curve.subs(mid_param);
-        let (mut params, mut pts) = sub_parameter_division(
-            curve,
-            (range.0, mid_param),
-            (ends.0, mid_value),
-            tol,
-            trials - 1,
-        );
-        let _ = (params.pop(), pts.pop());
-        let (new_params, new_pts) = sub_parameter_division(
-            curve,
-            (mid_param, range.1),
-            (mid_value, ends.1),
-            tol,
-            trials - 1,
-        );
-        params.extend(new_params);
-        pts.extend(new_pts);
-        (params, pts)
+        sub_arc_length_table(speed, (range.0, mid), left, tol / 2.0, trials - 1, table);
+        sub_arc_length_table(speed, (mid, range.1), right, tol / 2.0, trials - 1, table);
+    }
+}
+
+/// Returns the Frenet frame `(tangent, normal, binormal)` of `curve` at `t`.
+///
+/// At inflection points, where `curve.der(t) × curve.der2(t)` vanishes, the normal and the binormal
+/// are not defined and their components are `f64::NAN`. Use [`rotation_minimizing_frames`] for
+/// the moving frames along curves with inflection points.
+pub fn frenet_frame<C: ParametricCurve3D>(curve: &C, t: f64) -> (Vector3, Vector3, Vector3) {
+    let der = curve.der(t);
+    let cross = der.cross(curve.der2(t));
+    let tangent = der.normalize();
+    let binormal = match cross.so_small() {
+        true => Vector3::new(f64::NAN, f64::NAN, f64::NAN),
+        false => cross.normalize(),
+    };
+    (tangent, binormal.cross(tangent), binormal)
+}
+
+/// Returns the torsion of `curve` at `t`.
+///
+/// The third derivation is approximated by the central difference of `der2`.
+/// At inflection points, where `curve.der(t) × curve.der2(t)` vanishes, returns `f64::NAN`.
+pub fn torsion<C: ParametricCurve3D>(curve: &C, t: f64) -> f64 {
+    let cross = curve.der(t).cross(curve.der2(t));
+    if cross.so_small() {
+        return f64::NAN;
     }
+    let h = 1.0e-4 * (1.0 + t.abs());
+    let der3 = (curve.der2(t + h) - curve.der2(t - h)) / (2.0 * h);
+    cross.dot(der3) / cross.magnitude2()
+}
+
+/// Returns the rotation minimizing frames of `curve` at `params` by the double reflection method.
+///
+/// Each frame is the matrix whose columns are the tangent, the normal and the binormal.
+/// The first frame is the Frenet frame if it is defined, otherwise the normal is chosen
+/// arbitrarily. The following frames are propagated without twist, so they are stable at
+/// inflection points where the Frenet normal flips. `params` should be sorted and dense
+/// enough to follow the curve.
+pub fn rotation_minimizing_frames<C: ParametricCurve3D>(curve: &C, params: &[f64]) -> Vec<Matrix3> {
+    let Some(t) = params.first() else {
+        return Vec::new();
+    };
+    let (tangent, normal, _) = frenet_frame(curve, *t);
+    let normal = match normal.x.is_nan() {
+        true => {
+            let axis = [Vector3::unit_x(), Vector3::unit_y(), Vector3::unit_z()]
+                .into_iter()
+                .min_by(|x, y| f64::total_cmp(&x.dot(tangent).abs(), &y.dot(tangent).abs()))
+                .unwrap();
+            (axis - tangent * axis.dot(tangent)).normalize()
+        }
+        false => normal,
+    };
+    let reflect = |vec: Vector3, axis: Vector3| match axis.so_small() {
+        true => vec,
+        false => vec - axis * (2.0 * axis.dot(vec) / axis.magnitude2()),
+    };
+    let mut frame = (curve.subs(*t), tangent, normal);
+    let mut res = Vec::with_capacity(params.len());
+    res.push(Matrix3::from_cols(tangent, normal, tangent.cross(normal)));
+    params.iter().skip(1).for_each(|t| {
+        let (point0, tangent0, normal0) = frame;
+        let point1 = curve.subs(*t);
+        let tangent1 = curve.der(*t).normalize();
+        let v1 = point1 - point0;
+        let (normal, tangent) = (reflect(normal0, v1), reflect(tangent0, v1));
+        let normal1 = reflect(normal, tangent1 - tangent);
+        res.push(Matrix3::from_cols(
+            tangent1,
+            normal1,
+            tangent1.cross(normal1),
+        ));
+        frame = (point1, tangent1, normal1);
+    });
+    res
 }
 
 #[derive(Clone, Debug)]
@@ -224,3 +404,179 @@ where
 {
     surface::search_parameter(&SubSurface { curve0, curve1 }, P::origin(), hint, trials)
 }
+
+/// Computes the intersection points of two planar curves.
+///
+/// Returns the list of `(t0, t1, p)` sorted by `t0`, where `p` is the intersection point
+/// `curve0.subs(t0) == curve1.subs(t1)`.
+///
+/// # Details
+/// The candidates of the intersections are the pairs of the segments whose bounding boxes meet,
+/// found by subdividing the polylines which approximate the curves within `tol`. The candidates
+/// are refined by Newton's method. The intersections nearer than `tol` are merged into one,
+/// and hence a tangential touch is counted once.
+///
+/// Returns `None` if the curves overlap, i.e. there are infinitely many intersections.
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+pub fn curve_intersections<C0, C1>(
+    curve0: &C0,
+    curve1: &C1,
+    tol: f64,
+) -> Option<Vec<(f64, f64, Point2)>>
+where
+    C0: BoundedCurve<Point = Point2, Vector = Vector2>,
+    C1: BoundedCurve<Point = Point2, Vector = Vector2>,
+{
+    nonpositive_tolerance!(tol);
+    let (range0, range1) = (curve0.range_tuple(), curve1.range_tuple());
+    let (params0, pts0) = parameter_division(curve0, range0, tol);
+    let (params1, pts1) = parameter_division(curve1, range1, tol);
+    let mut candidates = Vec::new();
+    sub_segment_pairs(
+        (&pts0, 0..pts0.len() - 1),
+        (&pts1, 0..pts1.len() - 1),
+        tol,
+        &mut candidates,
+    );
+
+    let in_range = |t: f64, (t0, t1): (f64, f64)| t0 - TOLERANCE <= t && t <= t1 + TOLERANCE;
+    let mut res = Vec::<(f64, f64, Point2)>::new();
+    let mut push = |x: (f64, f64, Point2)| {
+        if res.iter().all(|y| x.2.distance(y.2) >= tol) {
+            res.push(x);
+        }
+    };
+    for (i, j) in candidates {
+        let (a0, a1) = (params0[i], params0[i + 1]);
+        let (b0, b1) = (params1[j], params1[j + 1]);
+        let common = common_samples(curve0, curve1, (a0, a1), (b0, b1));
+        let min = common.iter().min_by(|x, y| x.0.total_cmp(&y.0));
+        let max = common.iter().max_by(|x, y| x.0.total_cmp(&y.0));
+        if let (Some(&(t0, _, p0)), Some(&(t1, _, p1))) = (min, max) {
+            // The curves overlap if the middle of the distinct common points is also common.
+            let pt = curve0.subs((t0 + t1) / 2.0);
+            if p0.distance(p1) >= tol && search_parameter_in_range(curve1, pt, (b0, b1)).is_some() {
+                return None;
+            }
+        }
+        // The sample points may be intersections, e.g. the ends of the collinear curves.
+        common.into_iter().for_each(&mut push);
+        let (s, r) = segment_parameters([pts0[i], pts0[i + 1]], [pts1[j], pts1[j + 1]]);
+        let hint = (a0 + (a1 - a0) * s, b0 + (b1 - b0) * r);
+        let solution = search_intersection_parameter(curve0, curve1, hint, 100).or_else(|| {
+            // Newton's method may fail at a tangential touch.
+            search_closest_parameter(curve0, curve1, hint, 100)
+                .filter(|(t0, t1)| curve0.subs(*t0).near(&curve1.subs(*t1)))
+        });
+        if let Some((t0, t1)) = solution {
+            if in_range(t0, range0) && in_range(t1, range1) {
+                let t0 = f64::clamp(t0, range0.0, range0.1);
+                let t1 = f64::clamp(t1, range1.0, range1.1);
+                push((t0, t1, curve0.subs(t0)));
+            }
+        }
+    }
+    res.sort_by(|x, y| x.0.total_cmp(&y.0));
+    Some(res)
+}
+
+/// Collects the pairs of the segments whose bounding boxes meet.
+fn sub_segment_pairs(
+    (pts0, range0): (&[Point2], std::ops::Range<usize>),
+    (pts1, range1): (&[Point2], std::ops::Range<usize>),
+    tol: f64,
+    res: &mut Vec<(usize, usize)>,
+) {
+    let bounding_box = |pts: &[Point2]| {
+        pts.iter().fold(
+            (
+                Point2::new(f64::INFINITY, f64::INFINITY),
+                Point2::new(f64::NEG_INFINITY, f64::NEG_INFINITY),
+            ),
+            |(min, max), p| {
+                (
+                    Point2::new(f64::min(min.x, p.x), f64::min(min.y, p.y)),
+                    Point2::new(f64::max(max.x, p.x), f64::max(max.y, p.y)),
+                )
+            },
+        )
+    };
+    let (min0, max0) = bounding_box(&pts0[range0.start..=range0.end]);
+    let (min1, max1) = bounding_box(&pts1[range1.start..=range1.end]);
+    let disjoint = min0.x > max1.x + tol
+        || min1.x > max0.x + tol
+        || min0.y > max1.y + tol
+        || min1.y > max0.y + tol;
+    if disjoint {
+        return;
+    }
+    match (range0.len(), range1.len()) {
+        (1, 1) => res.push((range0.start, range1.start)),
+        (len0, len1) if len0 >= len1 => {
+            let mid = range0.start + len0 / 2;
+            sub_segment_pairs((pts0, range0.start..mid), (pts1, range1.clone()), tol, res);
+            sub_segment_pairs((pts0, mid..range0.end), (pts1, range1), tol, res);
+        }
+        (_, len1) => {
+            let mid = range1.start + len1 / 2;
+            sub_segment_pairs((pts0, range0.clone()), (pts1, range1.start..mid), tol, res);
+            sub_segment_pairs((pts0, range0), (pts1, mid..range1.end), tol, res);
+        }
+    }
+}
+
+/// Returns the parameters of the closest points of the segments.
+fn segment_parameters([p0, p1]: [Point2; 2], [q0, q1]: [Point2; 2]) -> (f64, f64) {
+    let (a, b, c) = (p1 - p0, q1 - q0, q0 - p0);
+    let det = a.perp_dot(b);
+    match det.so_small() {
+        // the parallel segments
+        true => (0.5, 0.5),
+        false => (
+            f64::clamp(c.perp_dot(b) / det, 0.0, 1.0),
+            f64::clamp(c.perp_dot(a) / det, 0.0, 1.0),
+        ),
+    }
+}
+
+/// Returns the sample points of the segments which are on both curves.
+fn common_samples<C0, C1>(
+    curve0: &C0,
+    curve1: &C1,
+    (a0, a1): (f64, f64),
+    (b0, b1): (f64, f64),
+) -> Vec<(f64, f64, Point2)>
+where
+    C0: BoundedCurve<Point = Point2, Vector = Vector2>,
+    C1: BoundedCurve<Point = Point2, Vector = Vector2>,
+{
+    let mut res = Vec::new();
+    for t in [a0, (a0 + a1) / 2.0, a1] {
+        let pt = curve0.subs(t);
+        if let Some(s) = search_parameter_in_range(curve1, pt, (b0, b1)) {
+            res.push((t, s, pt));
+        }
+    }
+    for s in [b0, (b0 + b1) / 2.0, b1] {
+        let pt = curve1.subs(s);
+        if let Some(t) = search_parameter_in_range(curve0, pt, (a0, a1)) {
+            res.push((t, s, pt));
+        }
+    }
+    res
+}
+
+/// Searches the parameter of `point` in the range of `curve` by the hint in `hint_range`.
+fn search_parameter_in_range<C>(curve: &C, point: Point2, hint_range: (f64, f64)) -> Option<f64>
+where C: BoundedCurve<Point = Point2, Vector = Vector2> {
+    let hint = presearch(curve, point, hint_range, 8);
+    let t = search_parameter(curve, point, hint, 100)?;
+    let (t0, t1) = curve.range_tuple();
+    match t0 - TOLERANCE <= t && t <= t1 + TOLERANCE {
+        true => Some(f64::clamp(t, t0, t1)),
+        false => None,
+    }
+}
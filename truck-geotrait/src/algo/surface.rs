@@ -170,74 +170,101 @@ where
 #[inline(always)]
 pub fn parameter_division<S>(
     surface: &S,
-    (urange, vrange): ((f64, f64), (f64, f64)),
+    range: ((f64, f64), (f64, f64)),
     tol: f64,
 ) -> (Vec<f64>, Vec<f64>)
 where
     S: ParametricSurface,
     S::Point: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>,
 {
-    nonpositive_tolerance!(tol);
-    let (mut udiv, mut vdiv) = (vec![urange.0, urange.1], vec![vrange.0, vrange.1]);
-    sub_parameter_division(surface, (&mut udiv, &mut vdiv), tol);
-    (udiv, vdiv)
+    let subs_grid = |us: &[f64], vs: &[f64]| {
+        us.iter()
+            .map(|u| vs.iter().map(|v| surface.subs(*u, *v)).collect())
+            .collect()
+    };
+    parameter_division_by_grid(surface, range, tol, subs_grid)
 }
 
-fn sub_parameter_division<S>(surface: &S, (udiv, vdiv): (&mut Vec<f64>, &mut Vec<f64>), tol: f64)
+/// Creates the surface division, evaluating the surface on the grids by `subs_grid`, which returns
+/// the points of the surface at `(us[i], vs[j])` as the `j`th element of the `i`th vector.
+///
+/// The division is the same as [`parameter_division`]. The surface is evaluated at the grid points
+/// of the division at once, which is faster for the surfaces evaluating grids together, e.g.
+/// `BSplineSurface::subs_grid` in `truck-geometry`.
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+pub fn parameter_division_by_grid<S>(
+    surface: &S,
+    (urange, vrange): ((f64, f64), (f64, f64)),
+    tol: f64,
+    subs_grid: impl Fn(&[f64], &[f64]) -> Vec<Vec<S::Point>>,
+) -> (Vec<f64>, Vec<f64>)
 where
     S: ParametricSurface,
-    S::Point: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>, {
-    let mut divide_flag0 = vec![false; udiv.len() - 1];
-    let mut divide_flag1 = vec![false; vdiv.len() - 1];
+    S::Point: EuclideanSpace<Scalar = f64> + MetricSpace<Metric = f64> + HashGen<f64>,
+{
+    nonpositive_tolerance!(tol);
+    let (mut udiv, mut vdiv) = (vec![urange.0, urange.1], vec![vrange.0, vrange.1]);
+    loop {
+        let mut divide_flag0 = vec![false; udiv.len() - 1];
+        let mut divide_flag1 = vec![false; vdiv.len() - 1];
+        let mids = |div: &Vec<f64>| {
+            div.windows(2)
+                .map(|t| (t[0] + t[1]) / 2.0)
+                .collect::<Vec<_>>()
+        };
+        let corners = subs_grid(&udiv, &vdiv);
+        let gens = subs_grid(&mids(&udiv), &mids(&vdiv));
 
-    for (u, ub) in udiv.windows(2).zip(&mut divide_flag0) {
-        for (v, vb) in vdiv.windows(2).zip(&mut divide_flag1) {
-            if *ub && *vb {
-                continue;
-            }
-            let (u_gen, v_gen) = ((u[0] + u[1]) / 2.0, (v[0] + v[1]) / 2.0);
-            let gen = surface.subs(u_gen, v_gen);
-            let p = 0.5 + (0.2 * HashGen::hash1(gen) - 0.1);
-            let q = 0.5 + (0.2 * HashGen::hash1(gen) - 0.1);
-            let u0 = u[0] * (1.0 - p) + u[1] * p;
-            let v0 = v[0] * (1.0 - q) + v[1] * q;
-            let p0 = surface.subs(u0, v0);
-            let pt00 = surface.subs(u[0], v[0]);
-            let pt01 = surface.subs(u[0], v[1]);
-            let pt10 = surface.subs(u[1], v[0]);
-            let pt11 = surface.subs(u[1], v[1]);
-            let pt = S::Point::from_vec(
-                pt00.to_vec() * (1.0 - p) * (1.0 - q)
-                    + pt01.to_vec() * (1.0 - p) * q
-                    + pt10.to_vec() * p * (1.0 - q)
-                    + pt11.to_vec() * p * q,
-            );
-            let far = p0.distance2(pt) > tol * tol;
+        for (i, (u, ub)) in udiv.windows(2).zip(&mut divide_flag0).enumerate() {
+            for (j, (v, vb)) in vdiv.windows(2).zip(&mut divide_flag1).enumerate() {
+                if *ub && *vb {
+                    continue;
+                }
+                let gen = gens[i][j];
+                let p = 0.5 + (0.2 * HashGen::hash1(gen) - 0.1);
+                let q = 0.5 + (0.2 * HashGen::hash1(gen) - 0.1);
+                let u0 = u[0] * (1.0 - p) + u[1] * p;
+                let v0 = v[0] * (1.0 - q) + v[1] * q;
+                let p0 = surface.subs(u0, v0);
+                let pt00 = corners[i][j];
+                let pt01 = corners[i][j + 1];
+                let pt10 = corners[i + 1][j];
+                let pt11 = corners[i + 1][j + 1];
+                let pt = S::Point::from_vec(
+                    pt00.to_vec() * (1.0 - p) * (1.0 - q)
+                        + pt01.to_vec() * (1.0 - p) * q
+                        + pt10.to_vec() * p * (1.0 - q)
+                        + pt11.to_vec() * p * q,
+                );
+                let far = p0.distance2(pt) > tol * tol;
 
-            *ub = *ub || far;
-            *vb = *vb || far;
+                *ub = *ub || far;
+                *vb = *vb || far;
+            }
         }
-    }
 
-    let mut new_udiv = vec![udiv[0]];
-    for (u, ub) in udiv.windows(2).zip(divide_flag0) {
-        if ub {
-            new_udiv.push((u[0] + u[1]) / 2.0);
+        let mut new_udiv = vec![udiv[0]];
+        for (u, ub) in udiv.windows(2).zip(divide_flag0) {
+            if ub {
+                new_udiv.push((u[0] + u[1]) / 2.0);
+            }
+            new_udiv.push(u[1]);
         }
-        new_udiv.push(u[1]);
-    }
 
-    let mut new_vdiv = vec![vdiv[0]];
-    for (v, vb) in vdiv.windows(2).zip(divide_flag1) {
-        if vb {
-            new_vdiv.push((v[0] + v[1]) / 2.0);
+        let mut new_vdiv = vec![vdiv[0]];
+        for (v, vb) in vdiv.windows(2).zip(divide_flag1) {
+            if vb {
+                new_vdiv.push((v[0] + v[1]) / 2.0);
+            }
+            new_vdiv.push(v[1]);
         }
-        new_vdiv.push(v[1]);
-    }
 
-    if udiv.len() != new_udiv.len() || vdiv.len() != new_vdiv.len() {
-        *udiv = new_udiv;
-        *vdiv = new_vdiv;
-        sub_parameter_division(surface, (udiv, vdiv), tol);
+        if udiv.len() == new_udiv.len() && vdiv.len() == new_vdiv.len() {
+            return (udiv, vdiv);
+        }
+        (udiv, vdiv) = (new_udiv, new_vdiv);
     }
 }
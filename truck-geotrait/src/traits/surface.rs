@@ -1,4 +1,5 @@
 use super::*;
+use truck_base::tolerance::Origin;
 
 type Tuple = (f64, f64);
 /// Parametric surface
@@ -100,16 +101,62 @@ pub trait ParametricSurface3D: ParametricSurface<Point = Point3, Vector = Vector
     fn normal(&self, u: f64, v: f64) -> Vector3 {
         self.uder(u, v).cross(self.vder(u, v)).normalize()
     }
+    /// Returns the Gaussian curvature at `(u, v)`.
+    ///
+    /// Returns `f64::NAN` at degenerate points, i.e. where `uder × vder` vanishes.
+    #[inline(always)]
+    fn gaussian_curvature(&self, u: f64, v: f64) -> f64 {
+        match fundamental_forms(self, u, v) {
+            Some(([e, f, g], [l, m, n])) => (l * n - m * m) / (e * g - f * f),
+            None => f64::NAN,
+        }
+    }
+    /// Returns the mean curvature at `(u, v)`.
+    /// The sign depends on the orientation of [`ParametricSurface3D::normal`].
+    ///
+    /// Returns `f64::NAN` at degenerate points, i.e. where `uder × vder` vanishes.
+    #[inline(always)]
+    fn mean_curvature(&self, u: f64, v: f64) -> f64 {
+        match fundamental_forms(self, u, v) {
+            Some(([e, f, g], [l, m, n])) => (e * n - 2.0 * f * m + g * l) / (2.0 * (e * g - f * f)),
+            None => f64::NAN,
+        }
+    }
+}
+
+/// Returns the coefficients `([E, F, G], [L, M, N])` of the first and second fundamental forms.
+fn fundamental_forms<S>(surface: &S, u: f64, v: f64) -> Option<([f64; 3], [f64; 3])>
+where S: ParametricSurface3D {
+    let (uder, vder) = (surface.uder(u, v), surface.vder(u, v));
+    if uder.cross(vder).so_small() {
+        return None;
+    }
+    let normal = surface.normal(u, v);
+    let first = [uder.dot(uder), uder.dot(vder), vder.dot(vder)];
+    let second = [
+        surface.uuder(u, v).dot(normal),
+        surface.uvder(u, v).dot(normal),
+        surface.vvder(u, v).dot(normal),
+    ];
+    Some((first, second))
 }
 
 impl<S: ParametricSurface3D> ParametricSurface3D for &S {
     #[inline(always)]
     fn normal(&self, u: f64, v: f64) -> Vector3 { (*self).normal(u, v) }
+    #[inline(always)]
+    fn gaussian_curvature(&self, u: f64, v: f64) -> f64 { (*self).gaussian_curvature(u, v) }
+    #[inline(always)]
+    fn mean_curvature(&self, u: f64, v: f64) -> f64 { (*self).mean_curvature(u, v) }
 }
 
 impl<S: ParametricSurface3D> ParametricSurface3D for Box<S> {
     #[inline(always)]
     fn normal(&self, u: f64, v: f64) -> Vector3 { (**self).normal(u, v) }
+    #[inline(always)]
+    fn gaussian_curvature(&self, u: f64, v: f64) -> f64 { (**self).gaussian_curvature(u, v) }
+    #[inline(always)]
+    fn mean_curvature(&self, u: f64, v: f64) -> f64 { (**self).mean_curvature(u, v) }
 }
 
 /// Bounded surface with parametric range i.e. it is guaranteed that the return value of `parameter_range` is not `Bound::Unbounded`.
@@ -1,4 +1,4 @@
-use truck_base::{cgmath64::*, tolerance::*};
+use truck_base::{assert_near, cgmath64::*, tolerance::*};
 use truck_geotrait::*;
 mod polynomial;
 use polynomial::PolyCurve;
@@ -185,3 +185,111 @@ fn polycurve_intersection_point() {
     println!("searching intersection point error: {}", 10 - count);
     assert!(count >= 7);
 }
+
+#[test]
+fn polycurve_arc_length_parameters() {
+    // x = 0.001 t^3, y = t: the speed varies from 1 to about 30.
+    let coef = vec![
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(0.001, 0.0, 0.0),
+    ];
+    let poly = PolyCurve::<Point3>(coef);
+    let params = algo::curve::arc_length_parameters(&poly, 21, 1.0e-3);
+    assert_eq!(params.len(), 21);
+    assert_eq!((params[0], params[20]), poly.range_tuple());
+    let arc_length = |t0: f64, t1: f64| {
+        (0..1000).fold(0.0, |sum, i| {
+            let s0 = t0 + (t1 - t0) * i as f64 / 1000.0;
+            let s1 = t0 + (t1 - t0) * (i + 1) as f64 / 1000.0;
+            sum + poly.subs(s0).distance(poly.subs(s1))
+        })
+    };
+    let lengths = params
+        .windows(2)
+        .map(|p| arc_length(p[0], p[1]))
+        .collect::<Vec<_>>();
+    let mean = lengths.iter().sum::<f64>() / lengths.len() as f64;
+    lengths
+        .iter()
+        .for_each(|len| assert!(f64::abs(len - mean) < 0.01 * mean, "{len} {mean}"));
+}
+
+#[derive(Clone, Debug)]
+struct Helix {
+    radius: f64,
+    pitch: f64,
+}
+
+impl ParametricCurve for Helix {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, t: f64) -> Point3 {
+        Point3::new(
+            self.radius * f64::cos(t),
+            self.radius * f64::sin(t),
+            self.pitch * t,
+        )
+    }
+    fn der(&self, t: f64) -> Vector3 {
+        Vector3::new(
+            -self.radius * f64::sin(t),
+            self.radius * f64::cos(t),
+            self.pitch,
+        )
+    }
+    fn der2(&self, t: f64) -> Vector3 {
+        Vector3::new(-self.radius * f64::cos(t), -self.radius * f64::sin(t), 0.0)
+    }
+}
+
+#[test]
+fn helix_frenet_frame() {
+    let (a, b) = (2.0, 0.5);
+    let helix = Helix {
+        radius: a,
+        pitch: b,
+    };
+    let c = f64::sqrt(a * a + b * b);
+    (0..20).for_each(|i| {
+        let t = i as f64 / 2.0 - 5.0;
+        let (tangent, normal, binormal) = algo::curve::frenet_frame(&helix, t);
+        let (s, co) = (f64::sin(t), f64::cos(t));
+        assert_near!(tangent, Vector3::new(-a * s, a * co, b) / c);
+        assert_near!(normal, Vector3::new(-co, -s, 0.0));
+        assert_near!(binormal, Vector3::new(b * s, -b * co, a) / c);
+        let torsion = algo::curve::torsion(&helix, t);
+        assert!(f64::abs(torsion - b / (c * c)) < 1.0e-6, "{torsion}");
+    });
+}
+
+#[test]
+fn helix_rotation_minimizing_frames() {
+    let (a, b) = (2.0, 0.5);
+    let helix = Helix {
+        radius: a,
+        pitch: b,
+    };
+    let c = f64::sqrt(a * a + b * b);
+    const N: usize = 1000;
+    let params = (0..=N)
+        .map(|i| 4.0 * std::f64::consts::PI * i as f64 / N as f64)
+        .collect::<Vec<_>>();
+    let frames = algo::curve::rotation_minimizing_frames(&helix, &params);
+    assert_eq!(frames.len(), N + 1);
+    params.iter().zip(&frames).for_each(|(t, frame)| {
+        let (tangent, normal, binormal) = algo::curve::frenet_frame(&helix, *t);
+        assert_near!(frame.x, tangent);
+        assert_near!(frame.x.cross(frame.y), frame.z);
+        assert!(frame.y.dot(frame.x).abs() < 1.0e-10);
+        // The rotation minimizing frame rotates against the Frenet frame with the rate of -torsion.
+        let theta = -b * t / c;
+        let ans = normal * f64::cos(theta) + binormal * f64::sin(theta);
+        assert!(
+            (frame.y - ans).magnitude() < 1.0e-3,
+            "{t} {:?} {ans:?}",
+            frame.y
+        );
+    });
+}
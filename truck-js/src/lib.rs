@@ -25,12 +25,16 @@ pub trait IntoWasm: Sized {
 }
 
 mod shape;
-pub use shape::{AbstractShape, Edge, Face, Shell, Solid, Vertex, Wire};
+pub use shape::{AbstractShape, Edge, Face, Shell, Solid, StepHeaderDescriptor, Vertex, Wire};
 /// the building model utility API
 pub mod builder;
 mod polygon;
-/// the boolean operators: `and`, `or`, `not`.
+/// the boolean operators: `and`, `or`, `not`, and `boolean_and`, `boolean_or`, `boolean_not` with tolerances.
 pub mod shapeops;
 pub use polygon::{PolygonBuffer, PolygonMesh, StlType};
-/// STEP IO
+/// measurement utility: bounding boxes and distances
+pub mod measure;
+/// STEP input
 pub mod step_in;
+/// STEP output
+pub mod step_out;
@@ -0,0 +1,57 @@
+use crate::*;
+use truck_meshalgo::prelude::*;
+use truck_modeling::{BoundedCurve, ParameterDivision1D};
+
+#[wasm_bindgen]
+impl AbstractShape {
+    /// Returns the bounding box `[min_x, min_y, min_z, max_x, max_y, max_z]`.
+    ///
+    /// # Details
+    /// The curves and surfaces are meshed with the tolerance `0.01`, so the bounding box of the
+    /// curved shape is approximate.
+    /// If the shape has no vertices, e.g. an empty shell, the minimum is `Infinity` and the maximum
+    /// is `-Infinity`.
+    pub fn bounding_box(&self) -> Box<[f64]> {
+        const TOL: f64 = 0.01;
+        let bdb: BoundingBox<Point3> = if let Some(vertex) = self.as_vertex() {
+            std::iter::once(vertex.point()).collect()
+        } else if let Some(edge) = self.as_edge() {
+            curve_bounding_box(edge, TOL)
+        } else if let Some(wire) = self.as_wire() {
+            wire.edge_iter()
+                .map(|edge| curve_bounding_box(edge, TOL))
+                .fold(BoundingBox::new(), |a, b| a + b)
+        } else if let Some(face) = self.as_face() {
+            let shell = truck_modeling::Shell::from(vec![truck_modeling::Face::clone(face)]);
+            shell.triangulation(TOL).to_polygon().bounding_box()
+        } else if let Some(shell) = self.as_shell() {
+            shell.triangulation(TOL).to_polygon().bounding_box()
+        } else {
+            let solid = self.as_solid().unwrap();
+            solid.triangulation(TOL).to_polygon().bounding_box()
+        };
+        let (min, max) = (bdb.min(), bdb.max());
+        Box::new([min[0], min[1], min[2], max[0], max[1], max[2]])
+    }
+}
+
+fn curve_bounding_box(edge: &truck_modeling::Edge, tol: f64) -> BoundingBox<Point3> {
+    let curve = edge.curve();
+    curve
+        .parameter_division(curve.range_tuple(), tol)
+        .1
+        .into_iter()
+        .collect()
+}
+
+/// Returns the distance between two points.
+#[wasm_bindgen]
+pub fn distance(point0: &[f64], point1: &[f64]) -> f64 {
+    assert!(
+        point0.len() == 3 && point1.len() == 3,
+        "the points are not 3-dimensional!"
+    );
+    let point0 = Point3::new(point0[0], point0[1], point0[2]);
+    let point1 = Point3::new(point1[0], point1[1], point1[2]);
+    point0.distance(point1)
+}
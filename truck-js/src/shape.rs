@@ -88,6 +88,53 @@ enum SubAbstractShape {
     Solid(Solid),
 }
 
+/// the meshing tolerance used if it is not specified
+const MESHING_TOLERANCE: f64 = 0.01;
+/// the minimum meshing tolerance, which prevents the tab from hanging
+const MIN_MESHING_TOLERANCE: f64 = 1.0e-4;
+
+/// Returns the meshing tolerance clamped by [`MIN_MESHING_TOLERANCE`].
+fn meshing_tolerance(tol: Option<f64>) -> f64 {
+    let tol = tol.unwrap_or(MESHING_TOLERANCE);
+    match tol >= MIN_MESHING_TOLERANCE {
+        true => tol,
+        false => {
+            gloo::console::warn!(format!(
+                "meshing tolerance {tol:e} is clamped to {MIN_MESHING_TOLERANCE:e}"
+            ));
+            MIN_MESHING_TOLERANCE
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl AbstractShape {
+    /// Meshes the face, shell or solid.
+    ///
+    /// # Details
+    /// The tolerance is `0.01` if `tol` is not specified, and clamped to be no less than `1.0e-4`.
+    /// Returns `undefined` and reports to the console for vertices, edges and wires.
+    pub fn to_polygon(&self, tol: Option<f64>) -> Option<PolygonMesh> {
+        let tol = meshing_tolerance(tol);
+        match &self.0 {
+            SubAbstractShape::Face(face) => {
+                let shell = truck_modeling::Shell::from(vec![face.0.clone()]);
+                Some(shell.triangulation(tol).to_polygon().into_wasm())
+            }
+            SubAbstractShape::Shell(shell) => {
+                Some(shell.triangulation(tol).to_polygon().into_wasm())
+            }
+            SubAbstractShape::Solid(solid) => {
+                Some(solid.triangulation(tol).to_polygon().into_wasm())
+            }
+            _ => {
+                gloo::console::error!("only faces, shells and solids can be meshed.");
+                None
+            }
+        }
+    }
+}
+
 /// Describe STEP file header
 #[derive(Clone, Debug, AsRef, Deref, DerefMut, From, Into)]
 #[wasm_bindgen]
@@ -95,22 +142,28 @@ pub struct StepHeaderDescriptor(truck_stepio::out::StepHeaderDescriptor);
 
 #[wasm_bindgen]
 impl StepHeaderDescriptor {
+    /// file name
     #[wasm_bindgen(getter)]
     pub fn filename(&self) -> JsString { self.file_name.as_str().into() }
+    /// set file name
     #[wasm_bindgen(setter)]
     pub fn set_filename(&mut self, filename: JsString) {
         self.file_name = filename.as_string().unwrap_or_default();
     }
+    /// time stamp
     #[wasm_bindgen(getter)]
     pub fn time_stamp(&self) -> JsString { self.time_stamp.as_str().into() }
+    /// set time stamp
     #[wasm_bindgen(setter)]
     pub fn set_time_stamp(&mut self, time_stamp: JsString) {
         self.time_stamp = time_stamp.as_string().unwrap_or_default();
     }
+    /// authors
     #[wasm_bindgen(getter)]
     pub fn authors(&self) -> Vec<JsString> {
         self.authors.iter().map(|s| s.as_str().into()).collect()
     }
+    /// set authors
     #[wasm_bindgen(setter)]
     pub fn set_authors(&mut self, authors: Vec<JsString>) {
         self.authors = authors
@@ -118,6 +171,7 @@ impl StepHeaderDescriptor {
             .map(|s| s.as_string().unwrap_or_default())
             .collect();
     }
+    /// organization
     #[wasm_bindgen(getter)]
     pub fn organization(&self) -> Vec<JsString> {
         self.organization
@@ -125,6 +179,7 @@ impl StepHeaderDescriptor {
             .map(|s| s.as_str().into())
             .collect()
     }
+    /// set organization
     #[wasm_bindgen(setter)]
     pub fn set_organization(&mut self, organization: Vec<JsString>) {
         self.organization = organization
@@ -132,17 +187,35 @@ impl StepHeaderDescriptor {
             .map(|s| s.as_string().unwrap_or_default())
             .collect();
     }
+    /// preprocessor version
+    #[wasm_bindgen(getter)]
+    pub fn preprocessor_version(&self) -> Option<JsString> {
+        self.preprocessor_version.as_deref().map(Into::into)
+    }
+    /// set preprocessor version
+    #[wasm_bindgen(setter)]
+    pub fn set_preprocessor_version(&mut self, preprocessor_version: Option<JsString>) {
+        self.preprocessor_version = preprocessor_version.and_then(|s| s.as_string());
+    }
+    /// originating system
     #[wasm_bindgen(getter)]
-    pub fn organization_system(&self) -> JsString { self.organization_system.as_str().into() }
+    pub fn originating_system(&self) -> Option<JsString> {
+        self.originating_system.as_deref().map(Into::into)
+    }
+    /// set originating system
     #[wasm_bindgen(setter)]
-    pub fn set_organization_system(&mut self, organization_system: JsString) {
-        self.organization_system = organization_system.as_string().unwrap_or_default();
+    pub fn set_originating_system(&mut self, originating_system: Option<JsString>) {
+        self.originating_system = originating_system.and_then(|s| s.as_string());
     }
+    /// authorization
     #[wasm_bindgen(getter)]
-    pub fn authorization(&self) -> JsString { self.authorization.as_str().into() }
+    pub fn authorization(&self) -> Option<JsString> {
+        self.authorization.as_deref().map(Into::into)
+    }
+    /// set authorization
     #[wasm_bindgen(setter)]
-    pub fn set_authorization(&mut self, authorization: JsString) {
-        self.authorization = authorization.as_string().unwrap_or_default();
+    pub fn set_authorization(&mut self, authorization: Option<JsString>) {
+        self.authorization = authorization.and_then(|s| s.as_string());
     }
 }
 
@@ -151,8 +224,10 @@ macro_rules! impl_shape {
         #[wasm_bindgen]
         impl $type {
             /// meshing shape
-            pub fn to_polygon(&self, tol: f64) -> PolygonMesh {
-                self.triangulation(tol).to_polygon().into_wasm()
+            ///
+            /// The tolerance is `0.01` if `tol` is not specified, and clamped to be no less than `1.0e-4`.
+            pub fn to_polygon(&self, tol: Option<f64>) -> PolygonMesh {
+                self.triangulation(meshing_tolerance(tol)).to_polygon().into_wasm()
             }
             /// read shape from json
             pub fn from_json(data: &[u8]) -> Option<$type> {
@@ -1,20 +1,39 @@
 use crate::{wasm_bindgen, IntoWasm, Solid};
+use truck_modeling::TOLERANCE;
 use truck_shapeops as shapeops;
 
 const SHAPEOPS_TOLERANCE: f64 = 0.05;
 
+/// Converts the result of the boolean operation, warning the cause of the failure on the console.
+fn report(
+    result: Result<truck_modeling::Solid, shapeops::ShapeOpsError>,
+    tol: f64,
+) -> Option<Solid> {
+    result
+        .map_err(|e| gloo::console::warn!(format!("{e} (tolerance: {tol})")))
+        .ok()
+        .map(IntoWasm::into_wasm)
+}
+
+/// Returns `false` and warns on the console if `tol` is too small.
+fn check_tolerance(tol: f64) -> bool {
+    let valid = tol >= TOLERANCE;
+    if !valid {
+        gloo::console::warn!(format!("tolerance must be no less than {TOLERANCE:e}"));
+    }
+    valid
+}
+
 /// and operator
 #[wasm_bindgen]
 pub fn and(solid0: &Solid, solid1: &Solid, tol: Option<f64>) -> Option<Solid> {
-    let tol = tol.unwrap_or(SHAPEOPS_TOLERANCE);
-    shapeops::and(solid0, solid1, tol).map(IntoWasm::into_wasm)
+    boolean_and(solid0, solid1, tol.unwrap_or(SHAPEOPS_TOLERANCE))
 }
 
 /// or operator
 #[wasm_bindgen]
 pub fn or(solid0: &Solid, solid1: &Solid, tol: Option<f64>) -> Option<Solid> {
-    let tol = tol.unwrap_or(SHAPEOPS_TOLERANCE);
-    shapeops::or(solid0, solid1, tol).map(IntoWasm::into_wasm)
+    boolean_or(solid0, solid1, tol.unwrap_or(SHAPEOPS_TOLERANCE))
 }
 
 /// not operator
@@ -24,3 +43,38 @@ pub fn not(solid: &Solid) -> Solid {
     solid.not();
     solid
 }
+
+/// The intersection of two solids.
+///
+/// Returns `null` and warns the cause on the console if the operation fails.
+#[wasm_bindgen]
+pub fn boolean_and(solid0: &Solid, solid1: &Solid, tol: f64) -> Option<Solid> {
+    if !check_tolerance(tol) {
+        return None;
+    }
+    report(shapeops::and_with_report(solid0, solid1, tol), tol)
+}
+
+/// The union of two solids.
+///
+/// Returns `null` and warns the cause on the console if the operation fails.
+#[wasm_bindgen]
+pub fn boolean_or(solid0: &Solid, solid1: &Solid, tol: f64) -> Option<Solid> {
+    if !check_tolerance(tol) {
+        return None;
+    }
+    report(shapeops::or_with_report(solid0, solid1, tol), tol)
+}
+
+/// The difference `solid0 - solid1`, i.e. the intersection of `solid0` and the complement of `solid1`.
+///
+/// Returns `null` and warns the cause on the console if the operation fails.
+#[wasm_bindgen]
+pub fn boolean_not(solid0: &Solid, solid1: &Solid, tol: f64) -> Option<Solid> {
+    if !check_tolerance(tol) {
+        return None;
+    }
+    let mut solid1 = truck_modeling::Solid::clone(solid1);
+    solid1.not();
+    report(shapeops::and_with_report(solid0, &solid1, tol), tol)
+}
@@ -38,8 +38,14 @@ impl Table {
     pub fn from_step(step_str: &str) -> Option<Table> {
         Some(Table(truck_stepio::r#in::Table::from_step(step_str)?))
     }
-    /// get shell indices
-    pub fn shell_indices(&self) -> Vec<u64> { self.0.shell.keys().copied().collect() }
+    /// get shell indices in ascending order
+    pub fn shell_indices(&self) -> Vec<u64> {
+        self.0
+            .sorted_shells()
+            .into_iter()
+            .map(|(idx, _)| idx)
+            .collect()
+    }
     /// get shape from indices
     pub fn get_shape(&self, idx: u64) -> Option<ShapeFromStep> {
         let stepshell = self.shell.get(&idx)?;
@@ -0,0 +1,30 @@
+use crate::*;
+
+/// Writes `shape` to a STEP string.
+///
+/// # Details
+/// Only faces, shells and solids can be written: a face is written as a shell with one face.
+/// Returns `undefined` and reports the reason to the console for vertices, edges and wires.
+///
+/// The default header is used if `header` is not given.
+///
+/// # Remarks
+/// The output is intended for the shapes made by the modeling API. The results of the boolean
+/// operators are also written, with their intersection curves as `INTERSECTION_CURVE` entities,
+/// but other CAD systems are not guaranteed to read them.
+#[wasm_bindgen]
+pub fn to_step(shape: &AbstractShape, header: Option<StepHeaderDescriptor>) -> Option<String> {
+    let header =
+        header.unwrap_or_else(|| truck_stepio::out::StepHeaderDescriptor::default().into());
+    if let Some(solid) = shape.as_solid() {
+        Some(solid.to_step(header))
+    } else if let Some(shell) = shape.as_shell() {
+        Some(shell.to_step(header))
+    } else if let Some(face) = shape.as_face() {
+        let shell = truck_modeling::Shell::from(vec![truck_modeling::Face::clone(face)]);
+        Some(shell.into_wasm().to_step(header))
+    } else {
+        gloo::console::error!("only faces, shells and solids can be written to STEP.");
+        None
+    }
+}
@@ -34,3 +34,69 @@ Deno.test("rseep torus", async () => {
   const readVec = await Deno.readFile("../resources/shape/torus.json");
   assertEquals(vec, readVec);
 });
+
+Deno.test("step output of tsweep cube", async () => {
+  await init(Deno.readFile("./pkg/truck_js_bg.wasm"));
+  const v = Truck.vertex(-0.5, -0.5, -0.5);
+  const e = Truck.tsweep(v.upcast(), [1.0, 0.0, 0.0]);
+  const f = Truck.tsweep(e, [0.0, 1.0, 0.0]);
+  const abst = Truck.tsweep(f, [0.0, 0.0, 1.0]);
+  const step = Truck.to_step(abst);
+  const table = Truck.Table.from_step(step);
+  const indices = table.shell_indices();
+  assertEquals(indices.length, 1);
+  const shape = table.get_shape(indices[0]);
+  assertEquals(shape.to_polygon(0.01).to_buffer().index_buffer().length > 0, true);
+
+  assertEquals(Truck.to_step(v.upcast()), undefined);
+});
+
+Deno.test("boolean operators with tolerance", async () => {
+  await init(Deno.readFile("./pkg/truck_js_bg.wasm"));
+  const v = Truck.vertex(-0.5, -0.5, -0.5);
+  const e = Truck.tsweep(v.upcast(), [1.0, 0.0, 0.0]);
+  const f = Truck.tsweep(e, [0.0, 1.0, 0.0]);
+  const abst = Truck.tsweep(f, [0.0, 0.0, 1.0]);
+  const cube1 = Truck.translated(abst, [0.5, 0.5, 0.5]).into_solid();
+  const cube0 = abst.into_solid();
+
+  const or = Truck.boolean_or(cube0, cube1, 0.05);
+  assertEquals(or.to_polygon(0.01).to_buffer().index_buffer().length > 0, true);
+  const and = Truck.boolean_and(cube0, cube1, 0.05);
+  assertEquals(and.to_polygon(0.01).to_buffer().index_buffer().length > 0, true);
+  const not = Truck.boolean_not(cube0, cube1, 0.05);
+  assertEquals(not.to_polygon(0.01).to_buffer().index_buffer().length > 0, true);
+
+  assertEquals(Truck.boolean_or(cube0, cube1, 0.0), undefined);
+});
+
+Deno.test("meshing tolerance", async () => {
+  await init(Deno.readFile("./pkg/truck_js_bg.wasm"));
+  const v = Truck.vertex(0.75, 0.0, 0.25);
+  const w = Truck.rsweep(v.upcast(), [0.75, 0.0, 0.0], [0.0, 1.0, 0.0], 7.0);
+  const torus = Truck.rsweep(w, [0.0, 0.0, 0.0], [0.0, 0.0, 1.0], 7.0);
+  const coarse = torus.to_polygon(0.1).to_buffer().index_buffer().length;
+  const fine = torus.to_polygon(0.001).to_buffer().index_buffer().length;
+  assertEquals(coarse < fine, true);
+  const defaultLength = torus.to_polygon().to_buffer().index_buffer().length;
+  assertEquals(coarse < defaultLength && defaultLength < fine, true);
+
+  // too small tolerances are clamped.
+  const clamped = torus.to_polygon(1.0e-10).to_buffer().index_buffer().length;
+  const minimum = torus.to_polygon(1.0e-4).to_buffer().index_buffer().length;
+  assertEquals(clamped, minimum);
+
+  assertEquals(v.upcast().to_polygon(), undefined);
+});
+
+Deno.test("bounding box and distance", async () => {
+  await init(Deno.readFile("./pkg/truck_js_bg.wasm"));
+  const v = Truck.vertex(0.0, 0.0, 0.0);
+  const e = Truck.tsweep(v.upcast(), [1.0, 0.0, 0.0]);
+  const f = Truck.tsweep(e, [0.0, 1.0, 0.0]);
+  const abst = Truck.tsweep(f, [0.0, 0.0, 1.0]);
+  const bdb = abst.bounding_box();
+  assertEquals(Array.from(bdb), [0.0, 0.0, 0.0, 1.0, 1.0, 1.0]);
+  assertEquals(Array.from(e.bounding_box()), [0.0, 0.0, 0.0, 1.0, 0.0, 0.0]);
+  assertEquals(Truck.distance(bdb.slice(0, 3), bdb.slice(3, 6)), Math.sqrt(3.0));
+});
@@ -0,0 +1,529 @@
+use super::collision::{colliding_segment_pairs, disjoint_bdbs, sorted_endpoints};
+use super::*;
+use array_macro::array;
+use itertools::Itertools;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+/// Boolean operations of meshes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BoolOp {
+    /// the union of two meshes
+    Union,
+    /// the intersection of two meshes
+    Intersection,
+    /// the difference of the first mesh and the second mesh
+    Difference,
+}
+
+/// Calculates the boolean operation of two closed meshes at the triangle level.
+///
+/// Returns `None` if `a` or `b` is not closed, i.e. its shell condition is not [`ShellCondition::Closed`].
+///
+/// # Details
+/// The candidates of interfering triangles are narrowed down in the same way as [`Collision`].
+/// Each triangle is split by the section curves of the other mesh, and the pieces are kept or
+/// removed according to whether they are inside the other mesh or not.
+/// The pieces on the coplanar overlapping faces are kept only once if the orientations of the faces
+/// coincide, and are removed if the orientations are opposite, except for the difference.
+///
+/// # Remarks
+/// - The adjacent faces of the inputs must share the indices of positions. Use
+/// [`OptimizingFilter::put_together_same_attrs`] in advance.
+/// - The output has only positions, and the positions within [`TOLERANCE`] are identified.
+///
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// let cube = |origin: Point3| {
+///     let positions = (0..8)
+///         .map(|i| {
+///             let (x, y, z) = (i & 1, (i >> 1) & 1, i >> 2);
+///             origin + Vector3::new(x as f64, y as f64, z as f64)
+///         })
+///         .collect();
+///     let faces = Faces::from_iter(&[
+///         [0, 2, 3, 1],
+///         [4, 5, 7, 6],
+///         [0, 1, 5, 4],
+///         [2, 6, 7, 3],
+///         [0, 4, 6, 2],
+///         [1, 3, 7, 5],
+///     ]);
+///     PolygonMesh::new(
+///         StandardAttributes {
+///             positions,
+///             ..Default::default()
+///         },
+///         faces,
+///     )
+/// };
+/// let a = cube(Point3::new(0.0, 0.0, 0.0));
+/// let b = cube(Point3::new(0.5, 0.5, 0.5));
+///
+/// let union = mesh_boolean(&a, &b, BoolOp::Union).unwrap();
+/// assert_eq!(union.shell_condition(), ShellCondition::Closed);
+/// assert_near!(union.volume(), 1.875);
+/// ```
+///
+/// [`Collision`]: crate::analyzers::Collision
+/// [`OptimizingFilter::put_together_same_attrs`]: crate::filters::OptimizingFilter::put_together_same_attrs
+pub fn mesh_boolean(a: &PolygonMesh, b: &PolygonMesh, op: BoolOp) -> Option<PolygonMesh> {
+    if a.shell_condition() != ShellCondition::Closed
+        || b.shell_condition() != ShellCondition::Closed
+    {
+        return None;
+    }
+    let tris0 = triangles(a);
+    let tris1 = triangles(b);
+    let mut candidates0 = vec![Vec::new(); tris0.len()];
+    let mut candidates1 = vec![Vec::new(); tris1.len()];
+    if let Some(tri) = tris0.first() {
+        let unit = hash::take_one_unit(tri[0]);
+        let endpoints = sorted_endpoints(tris0.iter().copied(), tris1.iter().copied(), unit);
+        colliding_segment_pairs(endpoints)
+            .filter(|(i, j)| !disjoint_bdbs(tris0[*i], tris1[*j]))
+            .for_each(|(i, j)| {
+                candidates0[i].push(j);
+                candidates1[j].push(i);
+            });
+    }
+
+    let mut pool = PointPool::default();
+    let mut faces = Vec::<[usize; 3]>::new();
+    tris0
+        .iter()
+        .zip(&candidates0)
+        .for_each(|(tri, candidates)| {
+            let others = candidates.iter().map(|j| tris1[*j]).collect::<Vec<_>>();
+            split_triangle(*tri, &others, &mut pool)
+                .into_iter()
+                .for_each(|face| {
+                    let location = locate(face.map(|i| pool.points[i]), &others, b);
+                    let keep = match op {
+                        BoolOp::Union => matches!(location, Location::Outside | Location::OnSame),
+                        BoolOp::Intersection => {
+                            matches!(location, Location::Inside | Location::OnSame)
+                        }
+                        BoolOp::Difference => {
+                            matches!(location, Location::Outside | Location::OnOpposite)
+                        }
+                    };
+                    if keep {
+                        faces.push(face);
+                    }
+                });
+        });
+    tris1
+        .iter()
+        .zip(&candidates1)
+        .for_each(|(tri, candidates)| {
+            let others = candidates.iter().map(|i| tris0[*i]).collect::<Vec<_>>();
+            split_triangle(*tri, &others, &mut pool)
+                .into_iter()
+                .for_each(|face| {
+                    // The pieces on the coplanar faces are always taken from `a`.
+                    match (op, locate(face.map(|i| pool.points[i]), &others, a)) {
+                        (BoolOp::Union, Location::Outside) => faces.push(face),
+                        (BoolOp::Intersection, Location::Inside) => faces.push(face),
+                        (BoolOp::Difference, Location::Inside) => {
+                            faces.push([face[2], face[1], face[0]])
+                        }
+                        _ => {}
+                    }
+                });
+        });
+
+    let mut indices = HashMap::<usize, usize>::default();
+    let mut positions = Vec::new();
+    let faces = faces
+        .into_iter()
+        .map(|face| {
+            face.map(|i| {
+                *indices.entry(i).or_insert_with(|| {
+                    positions.push(pool.points[i]);
+                    positions.len() - 1
+                })
+            })
+        })
+        .collect::<Vec<_>>();
+    Some(PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::from_iter(faces),
+    ))
+}
+
+fn triangles(mesh: &PolygonMesh) -> Vec<[Point3; 3]> {
+    mesh.faces()
+        .triangle_iter()
+        .map(|face| face.map(|v| mesh.positions()[v.pos]))
+        .filter(|tri| !(tri[1] - tri[0]).cross(tri[2] - tri[0]).so_small())
+        .collect()
+}
+
+/// the points identified within `TOLERANCE`
+#[derive(Clone, Debug, Default)]
+struct PointPool {
+    points: Vec<Point3>,
+    grid: HashMap<[i64; 3], Vec<usize>>,
+}
+
+impl PointPool {
+    #[inline(always)]
+    fn cell(point: Point3) -> [i64; 3] {
+        array![i => f64::floor(point[i] / (2.0 * TOLERANCE)) as i64; 3]
+    }
+
+    fn insert(&mut self, point: Point3) -> usize {
+        let [x, y, z] = Self::cell(point);
+        let found = (-1..=1)
+            .flat_map(|i| (-1..=1).flat_map(move |j| (-1..=1).map(move |k| [x + i, y + j, z + k])))
+            .filter_map(|cell| self.grid.get(&cell))
+            .flatten()
+            .find(|i| self.points[**i].distance2(point) < TOLERANCE2);
+        if let Some(i) = found {
+            return *i;
+        }
+        let idx = self.points.len();
+        self.points.push(point);
+        self.grid.entry([x, y, z]).or_default().push(idx);
+        idx
+    }
+}
+
+/// the orthonormal coordinate system on the plane of a triangle
+#[derive(Clone, Copy, Debug)]
+struct Frame {
+    origin: Point3,
+    normal: Vector3,
+    u: Vector3,
+    v: Vector3,
+}
+
+impl Frame {
+    fn new(tri: [Point3; 3]) -> Self {
+        let normal = (tri[1] - tri[0]).cross(tri[2] - tri[0]).normalize();
+        let u = (tri[1] - tri[0]).normalize();
+        Self {
+            origin: tri[0],
+            normal,
+            u,
+            v: normal.cross(u),
+        }
+    }
+    #[inline(always)]
+    fn project(&self, point: Point3) -> Point2 {
+        let vec = point - self.origin;
+        Point2::new(vec.dot(self.u), vec.dot(self.v))
+    }
+    #[inline(always)]
+    fn lift(&self, point: Point2) -> Point3 { self.origin + point.x * self.u + point.y * self.v }
+}
+
+/// Returns the section of `other` by the plane of the triangle whose normal is `normal`.
+fn plane_section(origin: Point3, normal: Vector3, other: [Point3; 3]) -> Vec<[Point3; 2]> {
+    let dist = other.map(|p| match (p - origin).dot(normal) {
+        d if d.abs() < TOLERANCE => 0.0,
+        d => d,
+    });
+    if dist.iter().all(|d| *d == 0.0) {
+        return (0..3).map(|i| [other[i], other[(i + 1) % 3]]).collect();
+    }
+    let points = (0..3)
+        .filter_map(|i| {
+            let j = (i + 1) % 3;
+            if dist[i] == 0.0 {
+                Some(other[i])
+            } else if dist[i] * dist[j] < 0.0 {
+                let t = dist[i] / (dist[i] - dist[j]);
+                Some(other[i] + (other[j] - other[i]) * t)
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+    match points.len() {
+        2 => vec![[points[0], points[1]]],
+        _ => Vec::new(),
+    }
+}
+
+/// signed distance from `point` to the line through `p` and `q`, positive on the left.
+#[inline(always)]
+fn signed_distance(p: Point2, q: Point2, point: Point2) -> f64 {
+    let dir = q - p;
+    dir.perp_dot(point - p) / dir.magnitude()
+}
+
+/// Clips the segment by the counter-clockwise triangle.
+fn clip_segment(seg: [Point2; 2], tri: [Point2; 3]) -> Option<[Point2; 2]> {
+    let (mut t0, mut t1) = (0.0, 1.0);
+    for i in 0..3 {
+        let (p, q) = (tri[i], tri[(i + 1) % 3]);
+        let (d0, d1) = (signed_distance(p, q, seg[0]), signed_distance(p, q, seg[1]));
+        if d0 < -TOLERANCE && d1 < -TOLERANCE {
+            return None;
+        } else if d0 < -TOLERANCE || d1 < -TOLERANCE {
+            let t = d0 / (d0 - d1);
+            match d0 < d1 {
+                true => t0 = f64::max(t0, t),
+                false => t1 = f64::min(t1, t),
+            }
+        }
+    }
+    let (p, q) = (
+        seg[0] + (seg[1] - seg[0]) * t0,
+        seg[0] + (seg[1] - seg[0]) * t1,
+    );
+    match t0 < t1 && p.distance2(q) > TOLERANCE2 {
+        true => Some([p, q]),
+        false => None,
+    }
+}
+
+/// Returns the parameter of the proper crossing point of two segments.
+fn crossing_parameter(seg0: [Point2; 2], seg1: [Point2; 2]) -> Option<f64> {
+    let d0 = signed_distance(seg1[0], seg1[1], seg0[0]);
+    let d1 = signed_distance(seg1[0], seg1[1], seg0[1]);
+    let e0 = signed_distance(seg0[0], seg0[1], seg1[0]);
+    let e1 = signed_distance(seg0[0], seg0[1], seg1[1]);
+    let crossing = (d0 < -TOLERANCE && d1 > TOLERANCE || d0 > TOLERANCE && d1 < -TOLERANCE)
+        && (e0 < -TOLERANCE && e1 > TOLERANCE || e0 > TOLERANCE && e1 < -TOLERANCE);
+    match crossing {
+        true => Some(d0 / (d0 - d1)),
+        false => None,
+    }
+}
+
+/// Returns the parameter of `point` if it is on the interior of the segment.
+fn parameter_on_segment(seg: [Point2; 2], point: Point2) -> Option<f64> {
+    let dir = seg[1] - seg[0];
+    let t = dir.dot(point - seg[0]) / dir.magnitude2();
+    let on = 0.0 < t
+        && t < 1.0
+        && point.distance2(seg[0]) > TOLERANCE2
+        && point.distance2(seg[1]) > TOLERANCE2
+        && signed_distance(seg[0], seg[1], point).abs() < TOLERANCE;
+    match on {
+        true => Some(t),
+        false => None,
+    }
+}
+
+/// planar straight line graph on a triangle
+#[derive(Clone, Debug, Default)]
+struct Arrangement {
+    ids: Vec<usize>,
+    coords: Vec<Point2>,
+    local: HashMap<usize, usize>,
+}
+
+impl Arrangement {
+    fn insert(&mut self, frame: &Frame, pool: &mut PointPool, point: Point3) -> usize {
+        let id = pool.insert(point);
+        *self.local.entry(id).or_insert_with(|| {
+            self.ids.push(id);
+            self.coords.push(frame.project(pool.points[id]));
+            self.ids.len() - 1
+        })
+    }
+
+    fn segment(&self, [i, j]: [usize; 2]) -> [Point2; 2] { [self.coords[i], self.coords[j]] }
+}
+
+/// Splits the triangle by the section of `others`, and returns the triangles of pieces.
+fn split_triangle(
+    tri: [Point3; 3],
+    others: &[[Point3; 3]],
+    pool: &mut PointPool,
+) -> Vec<[usize; 3]> {
+    let frame = Frame::new(tri);
+    let mut arrangement = Arrangement::default();
+    let corners = tri.map(|p| arrangement.insert(&frame, pool, p));
+    let tri2d = tri.map(|p| frame.project(p));
+    let mut segments = others
+        .iter()
+        .flat_map(|other| plane_section(frame.origin, frame.normal, *other))
+        .filter_map(|seg| clip_segment(seg.map(|p| frame.project(p)), tri2d))
+        .map(|seg| seg.map(|p| arrangement.insert(&frame, pool, frame.lift(p))))
+        .filter(|[i, j]| i != j)
+        .collect::<Vec<_>>();
+
+    // add the crossing points of segments
+    let len = segments.len();
+    (0..len).for_each(|i| {
+        (i + 1..len).for_each(|j| {
+            let (seg0, seg1) = (
+                arrangement.segment(segments[i]),
+                arrangement.segment(segments[j]),
+            );
+            if let Some(t) = crossing_parameter(seg0, seg1) {
+                let point = seg0[0] + (seg0[1] - seg0[0]) * t;
+                arrangement.insert(&frame, pool, frame.lift(point));
+            }
+        })
+    });
+
+    // split all segments by the points on them
+    segments.extend([0, 1, 2].map(|i| [corners[i], corners[(i + 1) % 3]]));
+    let mut edges = HashSet::<[usize; 2]>::default();
+    segments.into_iter().for_each(|seg| {
+        let seg2d = arrangement.segment(seg);
+        let mut points = arrangement
+            .coords
+            .iter()
+            .enumerate()
+            .filter_map(|(i, p)| Some((parameter_on_segment(seg2d, *p)?, i)))
+            .collect::<Vec<_>>();
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let chain = std::iter::once(seg[0])
+            .chain(points.into_iter().map(|(_, i)| i))
+            .chain(std::iter::once(seg[1]))
+            .collect::<Vec<_>>();
+        chain.windows(2).for_each(|edge| {
+            if edge[0] != edge[1] {
+                edges.insert([usize::min(edge[0], edge[1]), usize::max(edge[0], edge[1])]);
+            }
+        });
+    });
+
+    let mut adjacency = vec![Vec::<usize>::new(); arrangement.coords.len()];
+    edges.iter().for_each(|[i, j]| {
+        adjacency[*i].push(*j);
+        adjacency[*j].push(*i);
+    });
+    connect_components(&arrangement.coords, &mut adjacency, corners[0]);
+    adjacency.iter_mut().enumerate().for_each(|(i, neighbors)| {
+        let center = arrangement.coords[i];
+        let angle = |j: &usize| {
+            let vec = arrangement.coords[*j] - center;
+            f64::atan2(vec.y, vec.x)
+        };
+        neighbors.sort_by(|a, b| angle(a).total_cmp(&angle(b)));
+    });
+
+    let positions = arrangement
+        .coords
+        .iter()
+        .map(|p| Point3::new(p.x, p.y, 0.0))
+        .collect::<Vec<_>>();
+    extract_faces(&arrangement.coords, &adjacency)
+        .into_iter()
+        .flat_map(|polygon| ear_clipping(&positions, polygon, |_| true).0)
+        .map(|face| face.map(|i| arrangement.ids[i]))
+        .collect()
+}
+
+/// Marks the vertices connected to `start`.
+fn visit(start: usize, adjacency: &[Vec<usize>], reached: &mut [bool]) {
+    let mut stack = vec![start];
+    reached[start] = true;
+    while let Some(i) = stack.pop() {
+        adjacency[i].iter().for_each(|j| {
+            if !reached[*j] {
+                reached[*j] = true;
+                stack.push(*j);
+            }
+        });
+    }
+}
+
+/// Connects the components which are not connected to `root` by bridges.
+fn connect_components(coords: &[Point2], adjacency: &mut [Vec<usize>], root: usize) {
+    let len = coords.len();
+    let mut reached = vec![false; len];
+    visit(root, adjacency, &mut reached);
+    while let Some(start) = (0..len).find(|i| !reached[*i] && !adjacency[*i].is_empty()) {
+        let mut component = vec![false; len];
+        visit(start, adjacency, &mut component);
+        let component = (0..len).filter(|i| component[*i]).collect::<Vec<_>>();
+        let connected = (0..len).filter(|i| reached[*i]).collect::<Vec<_>>();
+        let mut pairs = component
+            .iter()
+            .flat_map(|i| connected.iter().map(move |j| (*i, *j)))
+            .collect::<Vec<_>>();
+        pairs.sort_by(|a, b| {
+            let dist = |(i, j): &(usize, usize)| coords[*i].distance2(coords[*j]);
+            dist(a).total_cmp(&dist(b))
+        });
+        let bridge = pairs.into_iter().find(|(i, j)| {
+            let seg = [coords[*i], coords[*j]];
+            let crossing = adjacency.iter().enumerate().any(|(k, neighbors)| {
+                neighbors.iter().any(|l| {
+                    ![*i, *j].contains(&k)
+                        && ![*i, *j].contains(l)
+                        && crossing_parameter(seg, [coords[k], coords[*l]]).is_some()
+                })
+            });
+            let through = (0..len).any(|k| parameter_on_segment(seg, coords[k]).is_some());
+            !crossing && !through
+        });
+        if let Some((i, j)) = bridge {
+            adjacency[i].push(j);
+            adjacency[j].push(i);
+        }
+        component.into_iter().for_each(|i| reached[i] = true);
+    }
+}
+
+/// Returns the counter-clockwise boundaries of the faces of the planar graph.
+fn extract_faces(coords: &[Point2], adjacency: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    let mut used = HashSet::<(usize, usize)>::default();
+    let mut faces = Vec::new();
+    adjacency.iter().enumerate().for_each(|(i, neighbors)| {
+        neighbors.iter().for_each(|j| {
+            let mut half_edge = (i, *j);
+            let mut polygon = Vec::new();
+            while used.insert(half_edge) {
+                let (u, v) = half_edge;
+                polygon.push(u);
+                let neighbors = &adjacency[v];
+                let idx = neighbors.iter().position(|w| *w == u).unwrap();
+                half_edge = (v, neighbors[(idx + neighbors.len() - 1) % neighbors.len()]);
+            }
+            let area = polygon
+                .iter()
+                .circular_tuple_windows()
+                .map(|(p, q)| coords[*p].to_vec().perp_dot(coords[*q].to_vec()))
+                .sum::<f64>();
+            if area > TOLERANCE2 {
+                faces.push(polygon);
+            }
+        })
+    });
+    faces
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Location {
+    Inside,
+    Outside,
+    OnSame,
+    OnOpposite,
+}
+
+/// Locates the triangle with respect to the closed mesh whose triangles near `tri` are `others`.
+fn locate(tri: [Point3; 3], others: &[[Point3; 3]], mesh: &PolygonMesh) -> Location {
+    let normal = (tri[1] - tri[0]).cross(tri[2] - tri[0]).normalize();
+    let center = Point3::centroid(&tri);
+    let on = others.iter().find_map(|other| {
+        let frame = Frame::new(*other);
+        let parallel = frame.normal.cross(normal).so_small();
+        let on_plane = (center - frame.origin).dot(frame.normal).abs() < TOLERANCE;
+        let other2d = other.map(|p| frame.project(p));
+        let center2d = frame.project(center);
+        let inside = (0..3)
+            .all(|i| signed_distance(other2d[i], other2d[(i + 1) % 3], center2d) > -TOLERANCE);
+        match parallel && on_plane && inside {
+            true if frame.normal.dot(normal) > 0.0 => Some(Location::OnSame),
+            true => Some(Location::OnOpposite),
+            false => None,
+        }
+    });
+    on.unwrap_or_else(|| match mesh.inside(center) {
+        true => Location::Inside,
+        false => Location::Outside,
+    })
+}
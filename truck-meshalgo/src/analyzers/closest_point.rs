@@ -0,0 +1,186 @@
+use super::*;
+use crate::tessellation::{MeshableShape, PolylineableCurve, RobustMeshableSurface};
+use truck_topology::{FaceID, Solid};
+
+const TRIALS: usize = 100;
+
+/// Searches the nearest point on the boundary of shapes.
+pub trait ClosestPoint<S> {
+    /// Returns the nearest point to `point` on the boundary of the shape and the id of the face
+    /// on which the nearest point is.
+    ///
+    /// # Details
+    /// The nearest point is searched on the tessellation with tolerance `tol`, and refined on the
+    /// surface of the face. The refined point is adopted only if it is in the trimmed domain of
+    /// the face, and hence the returned point is on the boundary of the face when the nearest
+    /// point on the untrimmed surface is out of the face.
+    ///
+    /// The point in the solid is also projected onto the boundary. If some faces are equally
+    /// nearest, the face that comes first in `face_iter` is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    ///
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// let (point, face_id) = cube.closest_point(Point3::new(0.5, 0.5, 1.2), 0.01);
+    /// assert_near!(point, Point3::new(0.5, 0.5, 1.0));
+    /// let face = cube.face_iter().find(|face| face.id() == face_id).unwrap();
+    /// assert!(face.vertex_iter().all(|v| v.point().z == 1.0));
+    ///
+    /// // the nearest point to the point in the solid
+    /// let (point, _) = cube.closest_point(Point3::new(0.5, 0.1, 0.5), 0.01);
+    /// assert_near!(point, Point3::new(0.5, 0.0, 0.5));
+    /// ```
+    fn closest_point(&self, point: Point3, tol: f64) -> (Point3, FaceID<S>);
+
+    /// Returns the same as [`ClosestPoint::closest_point`], searching on the pre-built
+    /// tessellation `meshed` instead of tessellating the shape on each query.
+    ///
+    /// # Details
+    /// `meshed` must be the tessellation of `self` with tolerance `tol`, e.g. the returned value
+    /// of `self.triangulation(tol)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    ///
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// // tessellates only once for all queries
+    /// let meshed = cube.triangulation(0.01);
+    /// let (point, _) = cube.closest_point_on_mesh(&meshed, Point3::new(0.5, 0.5, 1.2), 0.01);
+    /// assert_near!(point, Point3::new(0.5, 0.5, 1.0));
+    /// let (point, _) = cube.closest_point_on_mesh(&meshed, Point3::new(-0.3, 0.5, 0.5), 0.01);
+    /// assert_near!(point, Point3::new(0.0, 0.5, 0.5));
+    /// ```
+    fn closest_point_on_mesh(
+        &self,
+        meshed: &Solid<Point3, PolylineCurve<Point3>, Option<PolygonMesh>>,
+        point: Point3,
+        tol: f64,
+    ) -> (Point3, FaceID<S>);
+}
+
+impl<C, S> ClosestPoint<S> for Solid<Point3, C, S>
+where
+    C: PolylineableCurve,
+    S: RobustMeshableSurface,
+{
+    fn closest_point(&self, point: Point3, tol: f64) -> (Point3, FaceID<S>) {
+        nonpositive_tolerance!(tol);
+        self.closest_point_on_mesh(&self.triangulation(tol), point, tol)
+    }
+
+    fn closest_point_on_mesh(
+        &self,
+        meshed: &Solid<Point3, PolylineCurve<Point3>, Option<PolygonMesh>>,
+        point: Point3,
+        tol: f64,
+    ) -> (Point3, FaceID<S>) {
+        nonpositive_tolerance!(tol);
+        self.face_iter()
+            .zip(meshed.face_iter())
+            .filter_map(|(face, meshed_face)| {
+                let mesh = meshed_face.surface()?;
+                let (near, uv) = nearest_on_mesh(&mesh, point)?;
+                let surface = face.surface();
+                let refined = uv
+                    .and_then(|uv| surface.search_nearest_parameter(point, Some(uv), TRIALS))
+                    .map(|(u, v)| surface.subs(u, v))
+                    .filter(|p| p.distance2(point) < near.distance2(point))
+                    .filter(|p| in_domain(&mesh, *p, tol));
+                Some((refined.unwrap_or(near), face.id()))
+            })
+            .min_by(|(p, _), (q, _)| p.distance2(point).total_cmp(&q.distance2(point)))
+            .expect("a solid has at least one face.")
+    }
+}
+
+/// Returns whether the point on the surface is in the trimmed domain tessellated into `mesh`.
+pub(super) fn in_domain(mesh: &PolygonMesh, point: Point3, tol: f64) -> bool {
+    matches!(nearest_on_mesh(mesh, point), Some((q, _)) if q.distance(point) < tol * 2.0)
+}
+
+/// Returns the nearest point on `mesh` and the interpolated parameter of the surface.
+fn nearest_on_mesh(mesh: &PolygonMesh, point: Point3) -> Option<(Point3, Option<(f64, f64)>)> {
+    let (positions, uv_coords) = (mesh.positions(), mesh.uv_coords());
+    mesh.faces()
+        .triangle_iter()
+        .map(|tri| {
+            let pts = [0, 1, 2].map(|i| positions[tri[i].pos]);
+            let [a, b, c] = barycentric_nearest(point, pts);
+            let p =
+                Point3::from_vec(pts[0].to_vec() * a + pts[1].to_vec() * b + pts[2].to_vec() * c);
+            let uv = match (tri[0].uv, tri[1].uv, tri[2].uv) {
+                (Some(i), Some(j), Some(k)) => {
+                    let uv = uv_coords[i] * a + uv_coords[j] * b + uv_coords[k] * c;
+                    Some((uv.x, uv.y))
+                }
+                _ => None,
+            };
+            (p, uv)
+        })
+        .min_by(|(p, _), (q, _)| p.distance2(point).total_cmp(&q.distance2(point)))
+}
+
+/// Returns the barycentric coordinate of the nearest point to `p` on the triangle.
+///
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// let tri = [
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+/// ];
+/// // the nearest point is in the interior of the triangle.
+/// let coord = barycentric_nearest(Point3::new(0.25, 0.25, 1.0), tri);
+/// assert_near!(Vector3::from(coord), Vector3::new(0.5, 0.25, 0.25));
+/// // the nearest point is on the edge.
+/// let coord = barycentric_nearest(Point3::new(1.0, 1.0, 0.0), tri);
+/// assert_near!(Vector3::from(coord), Vector3::new(0.0, 0.5, 0.5));
+/// ```
+pub fn barycentric_nearest(p: Point3, [a, b, c]: [Point3; 3]) -> [f64; 3] {
+    let (ab, ac) = (b - a, c - a);
+    let (d1, d2) = (ab.dot(p - a), ac.dot(p - a));
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return [1.0, 0.0, 0.0];
+    }
+    let (d3, d4) = (ab.dot(p - b), ac.dot(p - b));
+    if d3 >= 0.0 && d4 <= d3 {
+        return [0.0, 1.0, 0.0];
+    }
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        let v = d1 / (d1 - d3);
+        return [1.0 - v, v, 0.0];
+    }
+    let (d5, d6) = (ab.dot(p - c), ac.dot(p - c));
+    if d6 >= 0.0 && d5 <= d6 {
+        return [0.0, 0.0, 1.0];
+    }
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        let w = d2 / (d2 - d6);
+        return [1.0 - w, 0.0, w];
+    }
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && d4 - d3 >= 0.0 && d5 - d6 >= 0.0 {
+        let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+        return [0.0, 1.0 - w, w];
+    }
+    // the nearest point is in the interior of the triangle.
+    let denom = va + vb + vc;
+    let (v, w) = (vb / denom, vc / denom);
+    [1.0 - v - w, v, w]
+}
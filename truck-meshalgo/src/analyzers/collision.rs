@@ -43,7 +43,7 @@ enum EndPointType {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
-struct EndPoint {
+pub(super) struct EndPoint {
     entity: f64,
     r#type: EndPointType,
     segnum: usize,
@@ -76,7 +76,7 @@ fn tri_to_seg(tri: [Point3; 3], unit: Vector3) -> (f64, f64) {
     (f64::min(f64::min(a, b), c), f64::max(f64::max(a, b), c))
 }
 
-fn sorted_endpoints<I, J>(iter0: I, iter1: J, unit: Vector3) -> Vec<EndPoint>
+pub(super) fn sorted_endpoints<I, J>(iter0: I, iter1: J, unit: Vector3) -> Vec<EndPoint>
 where
     I: IntoIterator<Item = [Point3; 3]>,
     J: IntoIterator<Item = [Point3; 3]>, {
@@ -97,7 +97,9 @@ where
     res
 }
 
-fn colliding_segment_pairs(sort_endpoints: Vec<EndPoint>) -> impl Iterator<Item = (usize, usize)> {
+pub(super) fn colliding_segment_pairs(
+    sort_endpoints: Vec<EndPoint>,
+) -> impl Iterator<Item = (usize, usize)> {
     let mut current = [Vec::<usize>::new(), Vec::<usize>::new()];
     sort_endpoints
         .into_iter()
@@ -133,7 +135,7 @@ fn colliding_segment_pairs(sort_endpoints: Vec<EndPoint>) -> impl Iterator<Item
         .flatten()
 }
 
-fn disjoint_bdbs(tri0: [Point3; 3], tri1: [Point3; 3]) -> bool {
+pub(super) fn disjoint_bdbs(tri0: [Point3; 3], tri1: [Point3; 3]) -> bool {
     let bdb0: BoundingBox<Point3> = tri0.iter().collect();
     let bdb1: BoundingBox<Point3> = tri1.iter().collect();
     bdb0.max()[0] < bdb1.min()[0]
@@ -171,7 +173,26 @@ fn collide_seg_triangle(seg: [Point3; 2], tri: [Point3; 3]) -> Option<Point3> {
     }
 }
 
-fn collide_triangles(tri0: [Point3; 3], tri1: [Point3; 3]) -> Option<(Point3, Point3)> {
+/// Returns the interference line of two triangles if they collide.
+///
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// let tri0 = [
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+/// ];
+/// let tri1 = [
+///     Point3::new(0.2, 0.2, -1.0),
+///     Point3::new(0.2, 0.2, 1.0),
+///     Point3::new(-1.0, 0.2, 0.0),
+/// ];
+/// let (p, q) = collide_triangles(tri0, tri1).unwrap();
+/// assert_near!(p.y, 0.2);
+/// assert_near!(q.y, 0.2);
+/// ```
+pub fn collide_triangles(tri0: [Point3; 3], tri1: [Point3; 3]) -> Option<(Point3, Point3)> {
     let mut tuple = (None, None);
     [
         collide_seg_triangle([tri0[0], tri0[1]], tri1),
@@ -0,0 +1,63 @@
+use super::*;
+
+/// Returns the indices of the degenerate faces, i.e. the faces whose area is less than `area_tol`
+/// or which have a corner whose angle is less than `angle_tol` radians.
+///
+/// # Details
+/// The indices are the ones in the order of `mesh.face_iter()`. The faces with coincident vertices,
+/// whose areas and angles vanish, are also degenerate. Use [`remove_degenerate`] to remove them.
+///
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// let positions = vec![
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+///     // nearly on the line between the points 1 and 2
+///     Point3::new(0.5, 0.501, 0.0),
+///     Point3::new(0.0, 0.0, 1.0),
+/// ];
+/// let faces = Faces::from_iter(&[[0, 1, 2], [1, 3, 2], [0, 1, 4], [0, 4, 2]]);
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions,
+///         ..Default::default()
+///     },
+///     faces,
+/// );
+/// assert_eq!(degenerate_faces(&mesh, 1.0e-6, 0.01), vec![1]);
+/// // The small area is also degenerate.
+/// assert_eq!(degenerate_faces(&mesh, 1.0, 0.01), vec![0, 1, 2, 3]);
+/// ```
+///
+/// [`remove_degenerate`]: crate::filters::remove_degenerate
+pub fn degenerate_faces(mesh: &PolygonMesh, area_tol: f64, angle_tol: f64) -> Vec<usize> {
+    let positions = mesh.positions();
+    mesh.face_iter()
+        .enumerate()
+        .filter(|(_, face)| {
+            let points = face.iter().map(|v| positions[v.pos]).collect::<Vec<_>>();
+            is_degenerate(&points, area_tol, angle_tol)
+        })
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Returns whether the polygon is degenerate in the sense of [`degenerate_faces`].
+pub(crate) fn is_degenerate(points: &[Point3], area_tol: f64, angle_tol: f64) -> bool {
+    let n = points.len();
+    let area = (0..n)
+        .fold(Vector3::zero(), |sum, i| {
+            sum + points[i].to_vec().cross(points[(i + 1) % n].to_vec())
+        })
+        .magnitude()
+        / 2.0;
+    area < area_tol
+        || (0..n).any(|i| {
+            let p = points[i];
+            let (v0, v1) = (points[(i + n - 1) % n] - p, points[(i + 1) % n] - p);
+            // The corner with a zero-length edge has no angle.
+            v0.so_small() || v1.so_small() || v0.angle(v1).0 < angle_tol
+        })
+}
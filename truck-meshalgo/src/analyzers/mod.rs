@@ -1,17 +1,35 @@
 use crate::*;
 
+mod boolean;
+#[cfg(feature = "tessellation")]
+mod closest_point;
 mod collision;
+mod degenerate;
 mod in_out_judge;
+mod planar_regions;
 mod point_cloud;
+#[cfg(feature = "tessellation")]
+mod ray_casting;
 mod splitting;
 mod topology;
 mod volume;
 
-pub use collision::Collision;
+pub use boolean::{mesh_boolean, BoolOp};
+#[cfg(feature = "tessellation")]
+pub use closest_point::{barycentric_nearest, ClosestPoint};
+pub use collision::{collide_triangles, Collision};
+pub use degenerate::degenerate_faces;
+#[cfg(feature = "filters")]
+pub(crate) use degenerate::is_degenerate;
 pub use in_out_judge::IncludingPointInDomain;
+pub use planar_regions::segment_planar_regions;
 pub use point_cloud::WithPointCloud;
+#[cfg(feature = "tessellation")]
+pub use ray_casting::RayCasting;
 pub use splitting::ExperimentalSplitters;
 pub use splitting::Splitting;
 pub use topology::Topology;
 pub use truck_topology::shell::ShellCondition;
 pub use volume::CalcVolume;
+#[cfg(feature = "tessellation")]
+pub use volume::ShapeVolume;
@@ -0,0 +1,82 @@
+use super::*;
+
+/// Groups the faces into the planar regions, i.e. the connected sets of coplanar faces.
+///
+/// # Details
+/// Each region is grown from its first face through the shared edges, and a face is added if
+/// the angle between its normal and the one of the first face is less than `normal_tol` radians.
+/// Returns the indices of faces in the order of `mesh.face_iter()`, and each region is sorted.
+/// A curved part of the mesh is divided into many small regions, which are left for the caller.
+///
+/// # Remarks
+/// - The faces are connected only if they share the indices of positions. Use
+///   [`OptimizingFilter::put_together_same_attrs`] in advance if the positions are duplicated.
+/// - The faces meeting at a T-junction, i.e. a vertex on the edge of the other face, are not
+///   connected. Hence, such faces are separated even if they are coplanar.
+///
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// // two squares meeting at a T-junction and a slope
+/// let positions = vec![
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(1.0, 1.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+///     Point3::new(1.0, 0.5, 0.0),
+///     Point3::new(2.0, 0.0, 0.0),
+///     Point3::new(2.0, 1.0, 0.0),
+///     Point3::new(3.0, 0.0, 1.0),
+///     Point3::new(3.0, 1.0, 1.0),
+/// ];
+/// let faces = Faces::from_iter(&[
+///     [0, 1, 2].as_ref(),
+///     &[0, 2, 3],
+///     &[1, 5, 4],
+///     &[4, 5, 6, 2],
+///     &[5, 7, 8, 6],
+/// ]);
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions,
+///         ..Default::default()
+///     },
+///     faces,
+/// );
+/// let regions = segment_planar_regions(&mesh, 0.01);
+/// assert_eq!(regions, vec![vec![0, 1], vec![2, 3], vec![4]]);
+/// ```
+///
+/// [`OptimizingFilter::put_together_same_attrs`]: crate::filters::OptimizingFilter::put_together_same_attrs
+pub fn segment_planar_regions(mesh: &PolygonMesh, normal_tol: f64) -> Vec<Vec<usize>> {
+    let positions = mesh.positions();
+    let normals = mesh
+        .face_iter()
+        .map(|face| FaceNormal::new(positions, face, 0).normal)
+        .collect::<Vec<_>>();
+    let adjacency = mesh.faces().face_adjacency(false);
+    let cos = f64::cos(normal_tol);
+    let mut unchecked = vec![true; normals.len()];
+    let mut regions = Vec::new();
+    for first in 0..normals.len() {
+        if !unchecked[first] {
+            continue;
+        }
+        unchecked[first] = false;
+        let mut stack = vec![first];
+        let mut region = vec![first];
+        while let Some(cursor) = stack.pop() {
+            for i in &adjacency[cursor] {
+                // The degenerate faces, whose normals are NaN, are not coplanar to any faces.
+                if unchecked[*i] && normals[*i].dot(normals[first]) >= cos {
+                    unchecked[*i] = false;
+                    region.push(*i);
+                    stack.push(*i);
+                }
+            }
+        }
+        region.sort();
+        regions.push(region);
+    }
+    regions
+}
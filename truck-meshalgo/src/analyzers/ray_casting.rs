@@ -0,0 +1,143 @@
+use super::{closest_point::in_domain, *};
+use crate::tessellation::{MeshableShape, PolylineableCurve, RobustMeshableSurface};
+use truck_topology::{FaceID, Solid};
+
+const TRIALS: usize = 100;
+
+/// Casts rays to shapes.
+pub trait RayCasting<S> {
+    /// Returns the intersections of the shape and the ray from `origin` in the direction `dir`.
+    /// Each intersection consists of the ray parameter `t`, the point `origin + t * dir`, and the
+    /// id of the face. The intersections are sorted by the ray parameter.
+    ///
+    /// # Details
+    /// The ray is intersected with the tessellation with tolerance `tol`, and the intersections
+    /// are refined on the surfaces in their trimmed domains. Hence, the intersections with analytic
+    /// surfaces are exact.
+    ///
+    /// The intersections nearer than `tol` are merged into the first one, so the ray passing
+    /// through an edge hits only one of the adjacent faces. The tangent ray may or may not hit
+    /// the face, depending on the tessellation.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    ///
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// let origin = Point3::new(0.5, 0.5, -1.0);
+    /// let hits = cube.ray_intersections(origin, Vector3::unit_z(), 0.01);
+    /// assert_eq!(hits.len(), 2);
+    /// assert_near!(hits[0].0, 1.0);
+    /// assert_near!(hits[0].1, Point3::new(0.5, 0.5, 0.0));
+    /// assert_near!(hits[1].0, 2.0);
+    /// assert_near!(hits[1].1, Point3::new(0.5, 0.5, 1.0));
+    ///
+    /// // the ray does not go backward.
+    /// let origin = Point3::new(0.5, 0.5, 2.0);
+    /// assert!(cube.ray_intersections(origin, Vector3::unit_z(), 0.01).is_empty());
+    /// ```
+    fn ray_intersections(
+        &self,
+        origin: Point3,
+        dir: Vector3,
+        tol: f64,
+    ) -> Vec<(f64, Point3, FaceID<S>)>;
+}
+
+impl<C, S> RayCasting<S> for Solid<Point3, C, S>
+where
+    C: PolylineableCurve,
+    S: RobustMeshableSurface,
+{
+    fn ray_intersections(
+        &self,
+        origin: Point3,
+        dir: Vector3,
+        tol: f64,
+    ) -> Vec<(f64, Point3, FaceID<S>)> {
+        nonpositive_tolerance!(tol);
+        let meshed = self.triangulation(tol);
+        let mut hits = self
+            .face_iter()
+            .zip(meshed.face_iter())
+            .filter_map(|(face, meshed_face)| Some((face, meshed_face.surface()?)))
+            .flat_map(|(face, mesh)| {
+                let surface = face.surface();
+                mesh_intersections(&mesh, origin, dir)
+                    .into_iter()
+                    .map(|(t, uv)| {
+                        let refined = uv
+                            .and_then(|uv| newton_intersection(&surface, origin, dir, uv, t))
+                            .filter(|(_, p)| in_domain(&mesh, *p, tol));
+                        let (t, p) = refined.unwrap_or((t, origin + t * dir));
+                        (t, p, face.id())
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .filter(|(t, _, _)| *t >= -TOLERANCE)
+            .collect::<Vec<_>>();
+        hits.sort_by(|x, y| x.0.total_cmp(&y.0));
+        hits.dedup_by(|x, y| x.1.distance(y.1) < tol);
+        hits
+    }
+}
+
+/// Returns the ray parameters and the interpolated surface parameters of the intersections
+/// with triangles of `mesh`.
+fn mesh_intersections(
+    mesh: &PolygonMesh,
+    origin: Point3,
+    dir: Vector3,
+) -> Vec<(f64, Option<(f64, f64)>)> {
+    let (positions, uv_coords) = (mesh.positions(), mesh.uv_coords());
+    mesh.faces()
+        .triangle_iter()
+        .filter_map(|tri| {
+            let [p0, p1, p2] = [0, 1, 2].map(|i| positions[tri[i].pos]);
+            let mat = Matrix3::from_cols(p0 - p1, p0 - p2, dir);
+            if mat.determinant().so_small() {
+                return None;
+            }
+            let uvt = mat.invert()? * (p0 - origin);
+            let (s, r, t) = (uvt[0], uvt[1], uvt[2]);
+            // The boundaries of triangles are included, and the duplicated hits are merged later.
+            if s < -TOLERANCE || r < -TOLERANCE || s + r > 1.0 + TOLERANCE {
+                return None;
+            }
+            let uv = match (tri[0].uv, tri[1].uv, tri[2].uv) {
+                (Some(i), Some(j), Some(k)) => {
+                    let uv = uv_coords[i] * (1.0 - s - r) + uv_coords[j] * s + uv_coords[k] * r;
+                    Some((uv.x, uv.y))
+                }
+                _ => None,
+            };
+            Some((t, uv))
+        })
+        .collect()
+}
+
+/// Solves `surface(u, v) == origin + t * dir` by Newton's method.
+fn newton_intersection<S: RobustMeshableSurface>(
+    surface: &S,
+    origin: Point3,
+    dir: Vector3,
+    (mut u, mut v): (f64, f64),
+    mut t: f64,
+) -> Option<(f64, Point3)> {
+    for _ in 0..TRIALS {
+        let p = surface.subs(u, v);
+        let diff = p - (origin + t * dir);
+        if diff.so_small() {
+            return Some((t, p));
+        }
+        let mat = Matrix3::from_cols(surface.uder(u, v), surface.vder(u, v), -dir);
+        let delta = mat.invert()? * diff;
+        (u, v, t) = (u - delta[0], v - delta[1], t - delta[2]);
+    }
+    None
+}
@@ -149,6 +149,40 @@ impl CalcVolume for PolygonMesh {
     }
 }
 
+/// Calculate the volume of shapes by tessellation.
+#[cfg(feature = "tessellation")]
+pub trait ShapeVolume {
+    /// Returns the signed volume of the shape, integrated on the tessellation with tolerance `tol`.
+    ///
+    /// # Details
+    /// The volume is negative if the faces are directed inward. The volumes of voids,
+    /// the boundaries whose faces are directed inward, are subtracted from the outer one.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    ///
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    /// assert_near!(cube.volume(0.01), 1.0);
+    ///
+    /// let inverted = Solid::new(vec![cube.boundaries()[0].face_iter().map(Face::inverse).collect()]);
+    /// assert_near!(inverted.volume(0.01), -1.0);
+    /// ```
+    fn volume(&self, tol: f64) -> f64;
+}
+
+#[cfg(feature = "tessellation")]
+impl<T: tessellation::MeshableShape> ShapeVolume for T {
+    fn volume(&self, tol: f64) -> f64 {
+        use crate::tessellation::MeshedShape;
+        self.triangulation(tol).to_polygon().volume()
+    }
+}
+
 fn point_triangles(poly: &PolygonMesh) -> impl Iterator<Item = [Point3; 3]> + '_ {
     poly.faces()
         .triangle_iter()
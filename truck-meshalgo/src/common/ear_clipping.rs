@@ -0,0 +1,56 @@
+use super::*;
+use itertools::Itertools;
+
+/// Triangulates the polygon given by the indices of `positions` without adding vertices.
+///
+/// The ears are judged with respect to the normal of the polygon by Newell's method. An ear is
+/// clipped only if it is convex, contains no other vertices of the polygon and satisfies
+/// `admissible`, and the smallest one is clipped first. Returns the triangles and the rest of the
+/// polygon, which has three or more vertices if no more ears can be clipped, e.g. if the polygon
+/// is degenerate.
+pub(crate) fn ear_clipping(
+    positions: &[Point3],
+    mut polygon: Vec<usize>,
+    admissible: impl Fn([usize; 3]) -> bool,
+) -> (Vec<[usize; 3]>, Vec<usize>) {
+    let normal = polygon
+        .iter()
+        .circular_tuple_windows()
+        .map(|(i, j)| positions[*i].to_vec().cross(positions[*j].to_vec()))
+        .sum::<Vector3>();
+    if normal.so_small() {
+        return (Vec::new(), polygon);
+    }
+    let normal = normal.normalize();
+    let mut triangles = Vec::with_capacity(polygon.len().saturating_sub(2));
+    while polygon.len() >= 3 {
+        let n = polygon.len();
+        let ear = |i: usize| [polygon[(i + n - 1) % n], polygon[i], polygon[(i + 1) % n]];
+        let smallest = (0..n)
+            .filter_map(|i| {
+                let tri = ear(i);
+                let [p, q, r] = tri.map(|k| positions[k]);
+                let area = (q - p).cross(r - q).dot(normal);
+                if area < TOLERANCE * (q - p).magnitude() {
+                    return None;
+                }
+                let inside = |x: Point3| {
+                    [(p, q), (q, r), (r, p)].into_iter().all(|(s, t)| {
+                        (t - s).cross(x - s).dot(normal) > -TOLERANCE * (t - s).magnitude()
+                    })
+                };
+                let contains = polygon
+                    .iter()
+                    .filter(|k| !tri.contains(k))
+                    .any(|k| inside(positions[*k]));
+                (!contains && admissible(tri)).then_some((i, area))
+            })
+            .min_by(|(_, x), (_, y)| x.total_cmp(y));
+        let Some((i, _)) = smallest else {
+            break;
+        };
+        triangles.push(ear(i));
+        polygon.remove(i);
+    }
+    (triangles, polygon)
+}
@@ -1,6 +1,10 @@
 use crate::*;
 
 mod adjacency;
+#[cfg(any(feature = "analyzers", feature = "filters"))]
+mod ear_clipping;
 mod face_normal;
 pub(super) use adjacency::Adjacency;
+#[cfg(any(feature = "analyzers", feature = "filters"))]
+pub(super) use ear_clipping::ear_clipping;
 pub(super) use face_normal::FaceNormal;
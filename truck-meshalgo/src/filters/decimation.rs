@@ -0,0 +1,298 @@
+use super::*;
+use rustc_hash::FxHashMap as HashMap;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Reduces polygons of meshes.
+pub trait Decimation {
+    /// Reduces the number of faces to `target_faces` by the quadric error edge collapse.
+    ///
+    /// # Details
+    /// All faces are triangulated. The vertices on the boundary edges or the non-manifold edges
+    /// are not moved nor removed, and the edges whose collapse flips the normal of a triangle are
+    /// not collapsed.
+    /// Hence, the number of faces can be more than `target_faces` if there is no collapsible edge.
+    ///
+    /// # Remarks
+    /// - The adjacent faces must share the indices of positions. Use [`OptimizingFilter::put_together_same_attrs`] in advance.
+    /// - The normals are not updated. Use [`NormalFilters`] to recompute them.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// // a grid on the plane z = 0
+    /// const N: usize = 10;
+    /// let positions = (0..=N)
+    ///     .flat_map(|i| (0..=N).map(move |j| Point3::new(i as f64, j as f64, 0.0)))
+    ///     .collect::<Vec<_>>();
+    /// let faces = Faces::from_iter((0..N).flat_map(|i| {
+    ///     (0..N).map(move |j| {
+    ///         let k = i * (N + 1) + j;
+    ///         [k, k + N + 1, k + N + 2, k + 1]
+    ///     })
+    /// }));
+    /// let mut mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     faces,
+    /// );
+    ///
+    /// mesh.decimate(100);
+    /// assert!(mesh.faces().len() <= 100);
+    /// // the boundary is preserved.
+    /// assert_eq!(mesh.extract_boundaries()[0].len(), 4 * N);
+    /// // the shape is not changed.
+    /// assert!(mesh.positions().iter().all(|p| p.z.so_small()));
+    /// ```
+    ///
+    /// [`OptimizingFilter::put_together_same_attrs`]: crate::filters::OptimizingFilter::put_together_same_attrs
+    /// [`NormalFilters`]: crate::filters::NormalFilters
+    fn decimate(&mut self, target_faces: usize) -> &mut Self;
+}
+
+impl Decimation for PolygonMesh {
+    fn decimate(&mut self, target_faces: usize) -> &mut Self {
+        if self.faces().len() <= target_faces {
+            return self;
+        }
+        let tri_faces = self.faces().triangle_iter().collect::<Vec<_>>();
+        let mut decimator = Decimator::new(self.positions(), tri_faces);
+        decimator.collapse_until(target_faces);
+        {
+            let editor = self.debug_editor();
+            editor.attributes.positions = decimator.positions;
+            let tri_faces = decimator.faces.into_iter().flatten().collect();
+            *editor.faces = Faces::from_tri_and_quad_faces(tri_faces, Vec::new());
+        }
+        self.remove_unused_attrs()
+    }
+}
+
+/// the candidate of the edge collapse, ordered by the reversed cost.
+#[derive(Clone, Copy, Debug)]
+struct Collapse {
+    cost: f64,
+    vertices: (usize, usize),
+    versions: (usize, usize),
+    point: Point3,
+}
+
+impl PartialEq for Collapse {
+    fn eq(&self, other: &Self) -> bool { self.cmp(other) == Ordering::Equal }
+}
+
+impl Eq for Collapse {}
+
+impl PartialOrd for Collapse {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl Ord for Collapse {
+    fn cmp(&self, other: &Self) -> Ordering { other.cost.total_cmp(&self.cost) }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct Decimator {
+    pub(super) positions: Vec<Point3>,
+    quadrics: Vec<Matrix4>,
+    pub(super) faces: Vec<Option<[Vertex; 3]>>,
+    pub(super) vertex_faces: Vec<Vec<usize>>,
+    pub(super) locked: Vec<bool>,
+    versions: Vec<usize>,
+    face_count: usize,
+}
+
+impl Decimator {
+    pub(super) fn new(positions: &[Point3], mut tri_faces: Vec<[Vertex; 3]>) -> Self {
+        tri_faces.retain(|face| {
+            face[0].pos != face[1].pos && face[1].pos != face[2].pos && face[2].pos != face[0].pos
+        });
+        let len = positions.len();
+        let mut quadrics = vec![Matrix4::zero(); len];
+        let mut vertex_faces = vec![Vec::new(); len];
+        let mut edge_faces = HashMap::<(usize, usize), usize>::default();
+        tri_faces.iter().enumerate().for_each(|(i, face)| {
+            let [p, q, r] = face.map(|v| positions[v.pos]);
+            let normal = (q - p).cross(r - p);
+            if !normal.so_small() {
+                let normal = normal.normalize();
+                let plane = normal.extend(-normal.dot(p.to_vec()));
+                let quadric = Matrix4::from_cols(
+                    plane * plane.x,
+                    plane * plane.y,
+                    plane * plane.z,
+                    plane * plane.w,
+                );
+                face.iter().for_each(|v| quadrics[v.pos] += quadric);
+            }
+            (0..3).for_each(|k| {
+                let (v0, v1) = (face[k].pos, face[(k + 1) % 3].pos);
+                vertex_faces[v0].push(i);
+                let edge = (usize::min(v0, v1), usize::max(v0, v1));
+                *edge_faces.entry(edge).or_default() += 1;
+            });
+        });
+        // The vertices on the boundary or non-manifold edges are not moved.
+        let mut locked = vec![false; len];
+        edge_faces
+            .into_iter()
+            .filter(|(_, count)| *count != 2)
+            .for_each(|((v0, v1), _)| {
+                locked[v0] = true;
+                locked[v1] = true;
+            });
+        Self {
+            positions: positions.to_vec(),
+            quadrics,
+            face_count: tri_faces.len(),
+            faces: tri_faces.into_iter().map(Some).collect(),
+            vertex_faces,
+            locked,
+            versions: vec![0; len],
+        }
+    }
+
+    pub(super) fn neighbors(&self, v: usize) -> Vec<usize> {
+        let mut neighbors = self.vertex_faces[v]
+            .iter()
+            .filter_map(|i| self.faces[*i])
+            .flat_map(|face| face.map(|w| w.pos))
+            .filter(|w| *w != v)
+            .collect::<Vec<_>>();
+        neighbors.sort_unstable();
+        neighbors.dedup();
+        neighbors
+    }
+
+    /// Returns the collapse of `v1` into `v0`, or the inverse if `v1` is locked.
+    fn candidate(&self, v0: usize, v1: usize) -> Option<Collapse> {
+        let (v0, v1) = match (self.locked[v0], self.locked[v1]) {
+            (true, true) => return None,
+            (false, true) => (v1, v0),
+            _ => (v0, v1),
+        };
+        let quadric = self.quadrics[v0] + self.quadrics[v1];
+        let error = |p: Point3| {
+            let vec = p.to_homogeneous();
+            f64::max(vec.dot(quadric * vec), 0.0)
+        };
+        let (p0, p1) = (self.positions[v0], self.positions[v1]);
+        let mat = Matrix3::from_cols(
+            quadric.x.truncate(),
+            quadric.y.truncate(),
+            quadric.z.truncate(),
+        );
+        let optimal = match mat.determinant().so_small() {
+            true => None,
+            false => mat
+                .invert()
+                .map(|inv| Point3::from_vec(-(inv * quadric.w.truncate()))),
+        };
+        let points = match self.locked[v0] {
+            true => [Some(p0), None, None, None],
+            false => [Some(p0), Some(p1), Some(p0.midpoint(p1)), optimal],
+        };
+        let (cost, point) = points
+            .into_iter()
+            .flatten()
+            .map(|p| (error(p), p))
+            .min_by(|a, b| a.0.total_cmp(&b.0))?;
+        Some(Collapse {
+            cost,
+            vertices: (v0, v1),
+            versions: (self.versions[v0], self.versions[v1]),
+            point,
+        })
+    }
+
+    pub(super) fn collapsible(&self, v0: usize, v1: usize, point: Point3) -> bool {
+        // link condition: only the two faces including the edge share the neighbors.
+        let (neighbors0, neighbors1) = (self.neighbors(v0), self.neighbors(v1));
+        let common = neighbors0
+            .iter()
+            .filter(|w| neighbors1.binary_search(w).is_ok())
+            .count();
+        if common != 2 {
+            return false;
+        }
+        [v0, v1].into_iter().all(|v| {
+            self.vertex_faces[v]
+                .iter()
+                .filter_map(|i| self.faces[*i])
+                .all(|face| {
+                    let indices = face.map(|w| w.pos);
+                    if indices.contains(&v0) && indices.contains(&v1) {
+                        return true;
+                    }
+                    let old = indices.map(|i| self.positions[i]);
+                    let new = indices.map(|i| if i == v { point } else { self.positions[i] });
+                    let old_normal = (old[1] - old[0]).cross(old[2] - old[0]);
+                    let new_normal = (new[1] - new[0]).cross(new[2] - new[0]);
+                    !new_normal.so_small() && old_normal.dot(new_normal) > 0.0
+                })
+        })
+    }
+
+    pub(super) fn collapse(&mut self, v0: usize, v1: usize, point: Point3) {
+        self.positions[v0] = point;
+        let quadric = self.quadrics[v1];
+        self.quadrics[v0] += quadric;
+        let faces1 = std::mem::take(&mut self.vertex_faces[v1]);
+        faces1.iter().for_each(|i| {
+            let Some(face) = self.faces[*i].as_mut() else {
+                return;
+            };
+            if face.iter().any(|w| w.pos == v0) {
+                self.faces[*i] = None;
+                self.face_count -= 1;
+            } else {
+                face.iter_mut()
+                    .filter(|w| w.pos == v1)
+                    .for_each(|w| w.pos = v0);
+            }
+        });
+        let faces = &self.faces;
+        let vertex_faces = &mut self.vertex_faces[v0];
+        vertex_faces.extend(faces1);
+        vertex_faces.retain(|i| faces[*i].is_some());
+        vertex_faces.sort_unstable();
+        vertex_faces.dedup();
+        self.versions[v0] += 1;
+        self.versions[v1] += 1;
+    }
+
+    fn collapse_until(&mut self, target_faces: usize) {
+        let this = &*self;
+        let mut heap = (0..this.positions.len())
+            .flat_map(|v0| {
+                this.neighbors(v0)
+                    .into_iter()
+                    .filter(move |v1| v0 < *v1)
+                    .filter_map(move |v1| this.candidate(v0, v1))
+            })
+            .collect::<BinaryHeap<_>>();
+        while self.face_count > target_faces {
+            let Some(Collapse {
+                vertices: (v0, v1),
+                versions,
+                point,
+                ..
+            }) = heap.pop()
+            else {
+                break;
+            };
+            if versions != (self.versions[v0], self.versions[v1])
+                || !self.collapsible(v0, v1, point)
+            {
+                continue;
+            }
+            self.collapse(v0, v1, point);
+            self.neighbors(v0)
+                .into_iter()
+                .filter_map(|w| self.candidate(v0, w))
+                .for_each(|collapse| heap.push(collapse));
+        }
+    }
+}
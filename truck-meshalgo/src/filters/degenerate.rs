@@ -0,0 +1,162 @@
+use super::{decimation::Decimator, *};
+use crate::analyzers::{degenerate_faces, is_degenerate};
+
+/// Removes the degenerate faces detected by [`degenerate_faces`] with the same tolerances.
+///
+/// # Details
+/// All faces are triangulated if some faces are degenerate. A cap, which has two small angles
+/// beside the longest edge, is removed by flipping the longest edge. The other degenerate triangles,
+/// e.g. the needles which have one small angle, are removed by collapsing the shortest edges into
+/// their middle points. The edges are collapsed only if the link condition holds and the normals
+/// of the neighbor triangles are not flipped, and are flipped only if the new triangles are not
+/// degenerate. Hence, no hole is created and a closed mesh is kept closed. The degenerate faces
+/// which cannot be removed in such ways are left.
+///
+/// # Remarks
+/// - The adjacent faces must share the indices of positions. Use [`OptimizingFilter::put_together_same_attrs`] in advance.
+/// - The vertices on the boundary edges or the non-manifold edges are not moved.
+/// - The normals are not updated. Use [`NormalFilters`] to recompute them.
+///
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// // an octahedron whose top vertex is doubled
+/// let positions = vec![
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(-1.0, 0.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+///     Point3::new(0.0, -1.0, 0.0),
+///     Point3::new(0.0, 0.0, 1.0),
+///     Point3::new(0.0, 0.0, -1.0),
+///     Point3::new(-0.001, -0.001, 0.998),
+/// ];
+/// let faces = Faces::from_iter(&[
+///     [0, 2, 4],
+///     [2, 1, 4],
+///     [1, 3, 6],
+///     [3, 4, 6],
+///     [4, 1, 6],
+///     [3, 0, 4],
+///     [2, 0, 5],
+///     [1, 2, 5],
+///     [3, 1, 5],
+///     [0, 3, 5],
+/// ]);
+/// let mut mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions,
+///         ..Default::default()
+///     },
+///     faces,
+/// );
+/// assert_eq!(degenerate_faces(&mesh, 1.0e-4, 0.05), vec![3, 4]);
+///
+/// remove_degenerate(&mut mesh, 1.0e-4, 0.05);
+/// assert!(degenerate_faces(&mesh, 1.0e-4, 0.05).is_empty());
+/// assert_eq!(mesh.faces().len(), 8);
+/// assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+/// ```
+///
+/// [`OptimizingFilter::put_together_same_attrs`]: crate::filters::OptimizingFilter::put_together_same_attrs
+/// [`NormalFilters`]: crate::filters::NormalFilters
+pub fn remove_degenerate(mesh: &mut PolygonMesh, area_tol: f64, angle_tol: f64) {
+    if degenerate_faces(mesh, area_tol, angle_tol).is_empty() {
+        return;
+    }
+    let tri_faces = mesh.faces().triangle_iter().collect::<Vec<_>>();
+    let mut remover = Decimator::new(mesh.positions(), tri_faces);
+    // Each collapse decreases the faces, and each flip decreases the degenerate faces.
+    loop {
+        let removed = (0..remover.faces.len()).fold(false, |removed, i| {
+            remover.remove_degenerate(i, area_tol, angle_tol) || removed
+        });
+        if !removed {
+            break;
+        }
+    }
+    {
+        let editor = mesh.debug_editor();
+        editor.attributes.positions = remover.positions;
+        let tri_faces = remover.faces.into_iter().flatten().collect();
+        *editor.faces = Faces::from_tri_and_quad_faces(tri_faces, Vec::new());
+    }
+    mesh.remove_unused_attrs();
+}
+
+impl Decimator {
+    fn degenerate(&self, face: [Vertex; 3], area_tol: f64, angle_tol: f64) -> bool {
+        is_degenerate(&face.map(|v| self.positions[v.pos]), area_tol, angle_tol)
+    }
+
+    /// Removes the `i`th face if it is degenerate, and returns whether it is removed.
+    fn remove_degenerate(&mut self, i: usize, area_tol: f64, angle_tol: f64) -> bool {
+        let Some(face) = self.faces[i] else {
+            return false;
+        };
+        if !self.degenerate(face, area_tol, angle_tol) {
+            return false;
+        }
+        let p = face.map(|v| self.positions[v.pos]);
+        let lengths = [0, 1, 2].map(|k| p[k].distance(p[(k + 1) % 3]));
+        let compare = |k: &usize, l: &usize| lengths[*k].total_cmp(&lengths[*l]);
+        let short = (0..3).min_by(compare).unwrap();
+        if !lengths[short].so_small() {
+            let angles = [0, 1, 2].map(|k| (p[(k + 1) % 3] - p[k]).angle(p[(k + 2) % 3] - p[k]));
+            // A cap has two small angles beside the longest edge.
+            if angles.iter().filter(|angle| angle.0 < angle_tol).count() >= 2 {
+                let long = (0..3).max_by(compare).unwrap();
+                return self.flip(i, long, area_tol, angle_tol);
+            }
+        }
+        let (v0, v1) = (face[short].pos, face[(short + 1) % 3].pos);
+        let point = self.positions[v0].midpoint(self.positions[v1]);
+        let collapsible = !self.locked[v0] && !self.locked[v1] && self.collapsible(v0, v1, point);
+        if collapsible {
+            self.collapse(v0, v1, point);
+        }
+        collapsible
+    }
+
+    /// Flips the `k`th edge of the `i`th face, and returns whether it is flipped.
+    fn flip(&mut self, i: usize, k: usize, area_tol: f64, angle_tol: f64) -> bool {
+        let Some(face) = self.faces[i] else {
+            return false;
+        };
+        let (a, b, c) = (face[k], face[(k + 1) % 3], face[(k + 2) % 3]);
+        // the face on the other side of the edge
+        let others = self.vertex_faces[a.pos]
+            .iter()
+            .filter(|j| **j != i)
+            .filter_map(|j| Some((*j, self.faces[*j]?)))
+            .filter(|(_, other)| other.iter().any(|v| v.pos == b.pos))
+            .collect::<Vec<_>>();
+        let [(j, other)] = others[..] else {
+            return false;
+        };
+        let Some(m) = (0..3).find(|m| other[*m].pos == b.pos && other[(m + 1) % 3].pos == a.pos)
+        else {
+            return false;
+        };
+        let d = other[(m + 2) % 3];
+        if d.pos == c.pos || self.neighbors(c.pos).contains(&d.pos) {
+            return false;
+        }
+        let normal = |face: [Vertex; 3]| {
+            let p = face.map(|v| self.positions[v.pos]);
+            (p[1] - p[0]).cross(p[2] - p[0])
+        };
+        let (new0, new1) = ([a, d, c], [d, b, c]);
+        let valid = [new0, new1].into_iter().all(|face| {
+            !self.degenerate(face, area_tol, angle_tol) && normal(face).dot(normal(other)) > 0.0
+        });
+        if valid {
+            self.faces[i] = Some(new0);
+            self.faces[j] = Some(new1);
+            self.vertex_faces[a.pos].retain(|f| *f != j);
+            self.vertex_faces[b.pos].retain(|f| *f != i);
+            self.vertex_faces[c.pos].push(j);
+            self.vertex_faces[d.pos].push(i);
+        }
+        valid
+    }
+}
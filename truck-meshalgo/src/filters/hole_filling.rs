@@ -0,0 +1,105 @@
+use super::*;
+use crate::analyzers::Topology;
+use itertools::Itertools;
+use rustc_hash::FxHashSet as HashSet;
+
+/// Fills the holes of the mesh whose boundaries consist of at most `max_boundary_edges` edges.
+///
+/// # Details
+/// Each boundary extracted by [`Topology::extract_boundaries`] is triangulated by ear clipping,
+/// where the ear with the minimal area is clipped first. The ears which are concave with respect to
+/// the average normal of the boundary or make non-manifold edges are postponed, and hence even the
+/// non-planar holes are filled by fans.
+///
+/// The boundaries passing through the same vertex twice, e.g. two holes sharing a vertex, are
+/// left for the caller.
+///
+/// # Remarks
+/// - The adjacent faces must share the indices of positions. Use [`OptimizingFilter::put_together_same_attrs`] in advance.
+/// - The new faces have no normals nor texture coordinates. Use [`NormalFilters`] to add normals.
+///
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// // a cube without the top face
+/// let positions = vec![
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(1.0, 1.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+///     Point3::new(0.0, 0.0, 1.0),
+///     Point3::new(1.0, 0.0, 1.0),
+///     Point3::new(1.0, 1.0, 1.0),
+///     Point3::new(0.0, 1.0, 1.0),
+/// ];
+/// let faces = Faces::from_iter(&[
+///     [0, 3, 2, 1],
+///     [0, 1, 5, 4],
+///     [1, 2, 6, 5],
+///     [2, 3, 7, 6],
+///     [3, 0, 4, 7],
+/// ]);
+/// let mut mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions,
+///         ..Default::default()
+///     },
+///     faces,
+/// );
+///
+/// // The hole has four edges.
+/// fill_holes(&mut mesh, 3);
+/// assert_eq!(mesh.shell_condition(), ShellCondition::Oriented);
+///
+/// fill_holes(&mut mesh, 4);
+/// assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+/// assert_near!(mesh.volume(), 1.0);
+/// ```
+///
+/// [`OptimizingFilter::put_together_same_attrs`]: crate::filters::OptimizingFilter::put_together_same_attrs
+/// [`NormalFilters`]: crate::filters::NormalFilters
+pub fn fill_holes(mesh: &mut PolygonMesh, max_boundary_edges: usize) {
+    let mut edges = mesh
+        .face_iter()
+        .flat_map(|face| {
+            face.iter()
+                .circular_tuple_windows()
+                .map(|(v, w)| [usize::min(v.pos, w.pos), usize::max(v.pos, w.pos)])
+        })
+        .collect::<HashSet<_>>();
+    let positions = mesh.positions();
+    let new_faces = mesh
+        .extract_boundaries()
+        .into_iter()
+        .filter(|boundary| 3 <= boundary.len() && boundary.len() <= max_boundary_edges)
+        .filter(|boundary| boundary.iter().all_unique())
+        .flat_map(|mut boundary| {
+            // The new faces go around the boundary in the opposite direction.
+            boundary.reverse();
+            clip_ears(positions, boundary, &mut edges)
+        })
+        .collect::<Vec<_>>();
+    let editor = mesh.debug_editor();
+    new_faces
+        .into_iter()
+        .for_each(|face| editor.faces.push(face));
+}
+
+/// Triangulates the polygon by clipping the ears with the minimal areas.
+///
+/// The diagonals already in `edges` are not used, and the new diagonals are added to `edges`.
+fn clip_ears(
+    positions: &[Point3],
+    polygon: Vec<usize>,
+    edges: &mut HashSet<[usize; 2]>,
+) -> Vec<[usize; 3]> {
+    let diagonal = |[a, _, c]: [usize; 3]| [usize::min(a, c), usize::max(a, c)];
+    let (mut triangles, rest) =
+        ear_clipping(positions, polygon, |tri| !edges.contains(&diagonal(tri)));
+    // The rest of the degenerate polygon is divided into a fan.
+    triangles.extend((2..rest.len()).map(|i| [rest[0], rest[i - 1], rest[i]]));
+    triangles.iter().for_each(|tri| {
+        edges.insert(diagonal(*tri));
+    });
+    triangles
+}
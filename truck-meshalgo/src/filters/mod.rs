@@ -1,12 +1,24 @@
 use crate::*;
 use polygon_mesh::PolygonMeshEditor;
 
+mod decimation;
+#[cfg(feature = "analyzers")]
+mod degenerate;
+#[cfg(feature = "analyzers")]
+mod hole_filling;
 mod normal_filters;
 mod optimizing;
+mod smoothing;
 mod structuring;
 mod subdivision;
 
+pub use decimation::Decimation;
+#[cfg(feature = "analyzers")]
+pub use degenerate::remove_degenerate;
+#[cfg(feature = "analyzers")]
+pub use hole_filling::fill_holes;
 pub use normal_filters::NormalFilters;
-pub use optimizing::OptimizingFilter;
+pub use optimizing::{weld_vertices, OptimizingFilter};
+pub use smoothing::smooth_taubin;
 pub use structuring::StructuringFilter;
 pub use subdivision::Subdivision;
@@ -96,6 +96,86 @@ pub trait OptimizingFilter {
     fn put_together_same_attrs(&mut self, tol: f64) -> &mut Self;
 }
 
+/// Welds the positions within the distance `tol`, and removes the faces which become degenerate.
+///
+/// # Details
+/// Unlike [`OptimizingFilter::put_together_same_attrs`], `tol` is the absolute distance of positions.
+/// If `merge_attrs == true`, the texture coordinates and the normals within [`TOLERANCE`] are also merged.
+/// The unused attributes are removed at the end.
+///
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// // the cube whose triangles do not share positions, like the one read from STL.
+/// let corners = [
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(1.0, 1.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+///     Point3::new(0.0, 0.0, 1.0),
+///     Point3::new(1.0, 0.0, 1.0),
+///     Point3::new(1.0, 1.0, 1.0),
+///     Point3::new(0.0, 1.0, 1.0),
+/// ];
+/// let quads = [
+///     [0, 3, 2, 1],
+///     [0, 1, 5, 4],
+///     [1, 2, 6, 5],
+///     [2, 3, 7, 6],
+///     [3, 0, 4, 7],
+///     [4, 5, 6, 7],
+/// ];
+/// let positions = quads
+///     .iter()
+///     .flat_map(|[a, b, c, d]| [a, b, c, a, c, d])
+///     // perturbation less than the tolerance
+///     .enumerate()
+///     .map(|(i, j)| corners[*j] + Vector3::new(1.0e-5, 0.0, 0.0) * (i % 3) as f64)
+///     .collect::<Vec<_>>();
+/// let faces = Faces::from_iter((0..12).map(|i| [3 * i, 3 * i + 1, 3 * i + 2]));
+/// let mut mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions,
+///         ..Default::default()
+///     },
+///     faces,
+/// );
+/// assert_eq!(mesh.positions().len(), 36);
+/// assert_ne!(mesh.shell_condition(), ShellCondition::Closed);
+///
+/// weld_vertices(&mut mesh, 1.0e-4, false);
+/// assert_eq!(mesh.positions().len(), 8);
+/// assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+///
+/// // The triangles collapsed by welding are removed.
+/// weld_vertices(&mut mesh, 2.0, false);
+/// assert!(mesh.faces().is_empty());
+/// assert!(mesh.positions().is_empty());
+/// ```
+pub fn weld_vertices(mesh: &mut PolygonMesh, tol: f64, merge_attrs: bool) {
+    let mut editor = mesh.debug_editor();
+    let PolygonMeshEditor {
+        attributes:
+            StandardAttributes {
+                positions,
+                uv_coords,
+                normals,
+            },
+        faces,
+        ..
+    } = &mut editor;
+    let pos_map = sub_put_together_same_attrs(positions, tol);
+    all_pos_mut(faces).for_each(|idx| *idx = pos_map[*idx]);
+    if merge_attrs {
+        let uv_map = sub_put_together_same_attrs(uv_coords, TOLERANCE);
+        all_uv_mut(faces).for_each(|idx| *idx = uv_map[*idx]);
+        let nor_map = sub_put_together_same_attrs(normals, TOLERANCE);
+        all_nor_mut(faces).for_each(|idx| *idx = nor_map[*idx]);
+    }
+    drop(editor);
+    mesh.remove_degenerate_faces().remove_unused_attrs();
+}
+
 fn all_pos_mut(faces: &mut Faces) -> impl Iterator<Item = &mut usize> {
     faces.face_iter_mut().flatten().map(move |v| &mut v.pos)
 }
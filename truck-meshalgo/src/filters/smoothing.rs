@@ -0,0 +1,129 @@
+use super::*;
+use rustc_hash::FxHashMap as HashMap;
+
+/// Smooths the positions of the mesh by the Taubin's method.
+///
+/// Each iteration consists of two Laplacian steps, the shrinking step by `lambda` and
+/// the inflating step by `mu`, where `0 < lambda < -mu`. The typical values are
+/// `lambda = 0.33` and `mu = -0.34`.
+///
+/// The vertices on the boundary and the non-manifold vertices are pinned.
+///
+/// # Remarks
+/// - The adjacent faces must share the indices of positions. Use [`OptimizingFilter::put_together_same_attrs`] in advance.
+/// - The normals are not updated. Use [`NormalFilters`] to recompute them.
+///
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// // a bump on a square
+/// let positions = vec![
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(1.0, 1.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+///     Point3::new(0.5, 0.5, 1.0),
+/// ];
+/// let mut mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions,
+///         ..Default::default()
+///     },
+///     Faces::from_iter(&[[0, 1, 4], [1, 2, 4], [2, 3, 4], [3, 0, 4]]),
+/// );
+///
+/// smooth_taubin(&mut mesh, 0.5, -0.53, 10);
+/// // the boundary is pinned.
+/// assert_eq!(mesh.positions()[0], Point3::new(0.0, 0.0, 0.0));
+/// // the bump is smoothed.
+/// assert!(mesh.positions()[4].z < 0.1);
+/// ```
+///
+/// [`OptimizingFilter::put_together_same_attrs`]: crate::filters::OptimizingFilter::put_together_same_attrs
+/// [`NormalFilters`]: crate::filters::NormalFilters
+pub fn smooth_taubin(mesh: &mut PolygonMesh, lambda: f64, mu: f64, iterations: usize) {
+    let adjacency = movable_adjacency(mesh.faces(), mesh.positions().len());
+    let editor = mesh.debug_editor();
+    let positions = &mut editor.attributes.positions;
+    (0..iterations).for_each(|_| {
+        laplacian_step(positions, &adjacency, lambda);
+        laplacian_step(positions, &adjacency, mu);
+    });
+}
+
+fn laplacian_step(positions: &mut [Point3], adjacency: &[(usize, Vec<usize>)], factor: f64) {
+    let displacements = adjacency
+        .iter()
+        .map(|(v, neighbors)| {
+            let sum = neighbors
+                .iter()
+                .map(|w| positions[*w] - positions[*v])
+                .sum::<Vector3>();
+            sum / neighbors.len() as f64 * factor
+        })
+        .collect::<Vec<_>>();
+    adjacency
+        .iter()
+        .zip(displacements)
+        .for_each(|((v, _), displacement)| positions[*v] += displacement);
+}
+
+/// the neighbors of each vertex which is neither on the boundary nor non-manifold
+fn movable_adjacency(faces: &Faces, len: usize) -> Vec<(usize, Vec<usize>)> {
+    let mut edge_faces = HashMap::<(usize, usize), usize>::default();
+    // pairs of the previous and the next vertices in each face, that is, the link of the vertex.
+    let mut links = vec![Vec::new(); len];
+    faces.face_iter().for_each(|face| {
+        let n = face.len();
+        (0..n).for_each(|i| {
+            let (v0, v1) = (face[i].pos, face[(i + 1) % n].pos);
+            let edge = (usize::min(v0, v1), usize::max(v0, v1));
+            *edge_faces.entry(edge).or_default() += 1;
+            links[v1].push((v0, face[(i + 2) % n].pos));
+        })
+    });
+    let mut pinned = vec![false; len];
+    edge_faces
+        .into_iter()
+        .filter(|(_, count)| *count != 2)
+        .for_each(|((v0, v1), _)| {
+            pinned[v0] = true;
+            pinned[v1] = true;
+        });
+    links
+        .into_iter()
+        .enumerate()
+        .filter(|(v, link)| !pinned[*v] && !link.is_empty())
+        .filter_map(|(v, link)| {
+            let mut neighbors = link
+                .iter()
+                .flat_map(|(w0, w1)| [*w0, *w1])
+                .collect::<Vec<_>>();
+            neighbors.sort_unstable();
+            neighbors.dedup();
+            Some((v, neighbors)).filter(|_| is_connected(&link))
+        })
+        .collect()
+}
+
+/// Returns whether the link of the vertex is connected, i.e. the vertex is manifold.
+fn is_connected(link: &[(usize, usize)]) -> bool {
+    let mut reached = vec![link[0].0, link[0].1];
+    let mut remained = link[1..].to_vec();
+    while !remained.is_empty() {
+        let len = remained.len();
+        remained.retain(
+            |(w0, w1)| match reached.contains(w0) || reached.contains(w1) {
+                true => {
+                    reached.extend([*w0, *w1]);
+                    false
+                }
+                false => true,
+            },
+        );
+        if remained.len() == len {
+            return false;
+        }
+    }
+    true
+}
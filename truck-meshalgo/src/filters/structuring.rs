@@ -1,8 +1,13 @@
 use super::*;
+use itertools::Itertools;
 
 /// triangulation, quadrangulation, give a structure
 pub trait StructuringFilter {
     /// triangulate all n-gons
+    /// # Details
+    /// The convex faces are divided into the fans from the first vertices. The non-convex faces are
+    /// triangulated by ear clipping, so that no triangles are inverted. The indices of the positions,
+    /// texture coordinates and normals of the vertices are preserved.
     /// # Examples
     /// ```
     /// use truck_polymesh::*;
@@ -35,6 +40,38 @@ pub trait StructuringFilter {
     /// assert_eq!(mesh.faces().len(), 12);
     /// ```
     fn triangulate(&mut self) -> &mut Self;
+    /// Returns the triangulated clone of `self`. See [`StructuringFilter::triangulate`].
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// use truck_meshalgo::filters::*;
+    ///
+    /// // L-shaped hexagon, which is not convex
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions: vec![
+    ///             Point3::new(0.0, 0.0, 0.0),
+    ///             Point3::new(2.0, 0.0, 0.0),
+    ///             Point3::new(2.0, 1.0, 0.0),
+    ///             Point3::new(1.0, 1.0, 0.0),
+    ///             Point3::new(1.0, 2.0, 0.0),
+    ///             Point3::new(0.0, 2.0, 0.0),
+    ///         ],
+    ///         ..Default::default()
+    ///     },
+    ///     Faces::from_iter(&[&[0, 1, 2, 3, 4, 5]]),
+    /// );
+    ///
+    /// let triangulated = mesh.triangulated();
+    /// assert_eq!(mesh.faces().len(), 1);
+    /// assert_eq!(triangulated.faces().tri_faces().len(), 4);
+    /// // All triangles face upward.
+    /// triangulated.faces().triangle_iter().for_each(|tri| {
+    ///     let p = tri.map(|v| triangulated.positions()[v.pos]);
+    ///     assert!((p[1] - p[0]).cross(p[2] - p[0]).z > 0.0);
+    /// });
+    /// ```
+    fn triangulated(&self) -> Self;
     /// join two triangles into one quadrangle.
     /// # Arguments
     /// * `plane_tol` - the tolerance for determining that four points are in the same plane
@@ -88,10 +125,19 @@ pub trait StructuringFilter {
 
 impl StructuringFilter for PolygonMesh {
     fn triangulate(&mut self) -> &mut Self {
-        let tri_faces = self.faces().triangle_iter().collect::<Vec<_>>();
+        let positions = self.positions();
+        let tri_faces = self
+            .face_iter()
+            .flat_map(|face| triangulate_face(positions, face))
+            .collect::<Vec<_>>();
         *self.debug_editor().faces = Faces::from_tri_and_quad_faces(tri_faces, Vec::new());
         self
     }
+    fn triangulated(&self) -> Self {
+        let mut mesh = self.clone();
+        mesh.triangulate();
+        mesh
+    }
     fn quadrangulate(&mut self, plane_tol: f64, score_tol: f64) -> &mut Self {
         nonpositive_tolerance!(plane_tol, 0.0);
         nonpositive_tolerance!(score_tol, 0.0);
@@ -101,6 +147,38 @@ impl StructuringFilter for PolygonMesh {
     }
 }
 
+/// Divides convex faces into fans and clips the ears of non-convex faces.
+fn triangulate_face(positions: &[Point3], face: &[Vertex]) -> Vec<[Vertex; 3]> {
+    // Newell's method
+    let normal = face
+        .iter()
+        .circular_tuple_windows()
+        .map(|(v, w)| positions[v.pos].to_vec().cross(positions[w.pos].to_vec()))
+        .sum::<Vector3>();
+    let turn = |[a, b, c]: [Vertex; 3]| {
+        let [p, q, r] = [positions[a.pos], positions[b.pos], positions[c.pos]];
+        (q - p).cross(r - q).dot(normal)
+    };
+    let convex = face
+        .iter()
+        .circular_tuple_windows()
+        .all(|(a, b, c)| turn([*a, *b, *c]) >= 0.0);
+    if convex {
+        return (2..face.len())
+            .map(|i| [face[0], face[i - 1], face[i]])
+            .collect();
+    }
+
+    let points = face.iter().map(|v| positions[v.pos]).collect::<Vec<_>>();
+    let (mut triangles, rest) = ear_clipping(&points, (0..face.len()).collect(), |_| true);
+    // The degenerate polygons may have no ears.
+    triangles.extend((2..rest.len()).map(|i| [rest[0], rest[i - 1], rest[i]]));
+    triangles
+        .into_iter()
+        .map(|tri| tri.map(|i| face[i]))
+        .collect()
+}
+
 trait SubStructureFilter {
     fn create_face_edge_list(&self, plane_tol: f64, score_tol: f64) -> Vec<FaceEdge>;
     fn reflect_face_edge_list(&mut self, list: Vec<FaceEdge>);
@@ -167,6 +167,79 @@ pub trait MeshableShape {
     fn triangulation(&self, tol: f64) -> Self::MeshedShape;
 }
 
+/// Trait for tessellating `Shell` and `Solid` with the tolerance of each face.
+pub trait AdaptiveMeshableShape: MeshableShape {
+    /// Face of the shape, passed to the tolerance function of [`AdaptiveMeshableShape::adaptive_triangulation`].
+    type Face;
+    /// Tessellates shapes with the tolerances given by `tolerance` for each face.
+    ///
+    /// The edges shared by several faces are divided by the finest tolerance of them,
+    /// so the boundaries of the meshes of adjacent faces are matched.
+    ///
+    /// # Panics
+    ///
+    /// The returned tolerances must be more than `TOLERANCE`.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    ///
+    /// // a cylinder
+    /// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    /// let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    /// let disk = builder::try_attach_plane(vec![circle]).unwrap();
+    /// let cylinder: Solid = builder::tsweep(&disk, Vector3::unit_z());
+    ///
+    /// // only curved faces are tessellated finely.
+    /// let mesh = cylinder.adaptive_triangulation(|face| match face.surface() {
+    ///     Surface::Plane(_) => 0.1,
+    ///     _ => 0.005,
+    /// });
+    /// let mut polygon = mesh.to_polygon();
+    /// polygon.put_together_same_attrs(TOLERANCE);
+    /// assert_eq!(polygon.shell_condition(), ShellCondition::Closed);
+    ///
+    /// let coarse = cylinder.triangulation(0.1).to_polygon();
+    /// assert!(polygon.faces().len() > coarse.faces().len());
+    /// ```
+    /// The flat faces are tessellated with fewer triangles than by the uniform finest tolerance.
+    /// ```
+    /// use truck_meshalgo::prelude::*;
+    /// use truck_modeling::*;
+    ///
+    /// // a disk and the side of a cylinder apart from the disk
+    /// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    /// let circle = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    /// let disk = builder::try_attach_plane(vec![circle]).unwrap();
+    /// let v = builder::vertex(Point3::new(1.0, 0.0, 1.0));
+    /// let origin = Point3::new(0.0, 0.0, 1.0);
+    /// let circle = builder::rsweep(&v, origin, Vector3::unit_z(), Rad(7.0));
+    /// let mut shell: Shell = builder::tsweep(&circle, Vector3::unit_z());
+    /// shell.push(disk);
+    ///
+    /// let adaptive = shell.adaptive_triangulation(|face| match face.surface() {
+    ///     Surface::Plane(_) => 0.1,
+    ///     _ => 0.005,
+    /// });
+    /// let uniform = shell.triangulation(0.005);
+    ///
+    /// let faces = shell.face_iter().zip(adaptive.face_iter()).zip(uniform.face_iter());
+    /// faces.for_each(|((face, adaptive), uniform)| {
+    ///     let adaptive = adaptive.surface().unwrap().faces().len();
+    ///     let uniform = uniform.surface().unwrap().faces().len();
+    ///     match face.surface() {
+    ///         Surface::Plane(_) => assert!(adaptive < uniform),
+    ///         _ => assert_eq!(adaptive, uniform),
+    ///     }
+    /// });
+    /// ```
+    fn adaptive_triangulation(
+        &self,
+        tolerance: impl FnMut(&Self::Face) -> f64,
+    ) -> Self::MeshedShape;
+}
+
 /// Trait for tessellating `Shell` and `Solid` in `truck-modeling`.
 pub trait RobustMeshableShape {
     /// Shape whose edges are made polylines and faces polygon surface.
@@ -269,6 +342,18 @@ impl<C: PolylineableCurve, S: MeshableSurface> MeshableShape for Shell<Point3, C
     }
 }
 
+impl<C: PolylineableCurve, S: MeshableSurface> AdaptiveMeshableShape for Shell<Point3, C, S> {
+    type Face = Face<Point3, C, S>;
+    fn adaptive_triangulation(
+        &self,
+        tolerance: impl FnMut(&Self::Face) -> f64,
+    ) -> Self::MeshedShape {
+        let tols = self.face_iter().map(tolerance).collect::<Vec<_>>();
+        tols.iter().for_each(|tol| nonpositive_tolerance!(*tol));
+        triangulation::shell_adaptive_tessellation(self, &tols, triangulation::by_search_parameter)
+    }
+}
+
 impl<C: PolylineableCurve, S: RobustMeshableSurface> RobustMeshableShape for Shell<Point3, C, S> {
     type MeshedShape = Shell<Point3, PolylineCurve, Option<PolygonMesh>>;
     fn robust_triangulation(&self, tol: f64) -> Self::MeshedShape {
@@ -301,6 +386,21 @@ impl<C: PolylineableCurve, S: MeshableSurface> MeshableShape for Solid<Point3, C
     }
 }
 
+impl<C: PolylineableCurve, S: MeshableSurface> AdaptiveMeshableShape for Solid<Point3, C, S> {
+    type Face = Face<Point3, C, S>;
+    fn adaptive_triangulation(
+        &self,
+        mut tolerance: impl FnMut(&Self::Face) -> f64,
+    ) -> Self::MeshedShape {
+        let boundaries = self
+            .boundaries()
+            .iter()
+            .map(|shell| shell.adaptive_triangulation(&mut tolerance))
+            .collect::<Vec<_>>();
+        Solid::new(boundaries)
+    }
+}
+
 impl<C: PolylineableCurve, S: RobustMeshableSurface> RobustMeshableShape for Solid<Point3, C, S> {
     type MeshedShape = Solid<Point3, PolylineCurve, Option<PolygonMesh>>;
     fn robust_triangulation(&self, tol: f64) -> Self::MeshedShape {
@@ -321,6 +421,20 @@ impl<C: PolylineableCurve, S: MeshableSurface> MeshableShape for CompressedShell
     }
 }
 
+impl<C: PolylineableCurve, S: MeshableSurface> AdaptiveMeshableShape
+    for CompressedShell<Point3, C, S>
+{
+    type Face = CompressedFace<S>;
+    fn adaptive_triangulation(
+        &self,
+        tolerance: impl FnMut(&Self::Face) -> f64,
+    ) -> Self::MeshedShape {
+        let tols = self.faces.iter().map(tolerance).collect::<Vec<_>>();
+        tols.iter().for_each(|tol| nonpositive_tolerance!(*tol));
+        triangulation::cshell_adaptive_tessellation(self, &tols, triangulation::by_search_parameter)
+    }
+}
+
 impl<C: PolylineableCurve, S: RobustMeshableSurface> RobustMeshableShape
     for CompressedShell<Point3, C, S>
 {
@@ -343,6 +457,23 @@ impl<C: PolylineableCurve, S: MeshableSurface> MeshableShape for CompressedSolid
     }
 }
 
+impl<C: PolylineableCurve, S: MeshableSurface> AdaptiveMeshableShape
+    for CompressedSolid<Point3, C, S>
+{
+    type Face = CompressedFace<S>;
+    fn adaptive_triangulation(
+        &self,
+        mut tolerance: impl FnMut(&Self::Face) -> f64,
+    ) -> Self::MeshedShape {
+        let boundaries = self
+            .boundaries
+            .iter()
+            .map(|shell| shell.adaptive_triangulation(&mut tolerance))
+            .collect::<Vec<_>>();
+        CompressedSolid { boundaries }
+    }
+}
+
 impl<C: PolylineableCurve, S: RobustMeshableSurface> RobustMeshableShape
     for CompressedSolid<Point3, C, S>
 {
@@ -357,4 +488,115 @@ impl<C: PolylineableCurve, S: RobustMeshableSurface> RobustMeshableShape
     }
 }
 
+/// Tessellates `solid` into a single polygon mesh whose vertices on the shared edges are welded.
+///
+/// # Details
+/// The edges shared by adjacent faces are divided only once, so the boundaries of the meshes of
+/// the faces coincide. The positions are welded within `2.0 * TOLERANCE`, which absorbs the errors of
+/// the re-evaluation on the surfaces, and the collapsed triangles are removed. The normals and
+/// texture coordinates are kept for each face, so the sharp edges are preserved in shading.
+/// The faces are oriented outward along the orientations of the faces of `solid`.
+///
+/// The result is closed if all faces are triangulated. The faces failed to be triangulated are
+/// skipped, as [`MeshableShape::triangulation`].
+///
+/// # Panics
+///
+/// `tol` must be more than `TOLERANCE`.
+///
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// use truck_modeling::*;
+///
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// let mesh = tessellate_solid(&cube, 0.01);
+/// assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+/// assert_eq!(mesh.positions().len(), 8);
+/// assert!(mesh.extract_boundaries().is_empty());
+/// ```
+pub fn tessellate_solid<C: PolylineableCurve, S: MeshableSurface>(
+    solid: &Solid<Point3, C, S>,
+    tol: f64,
+) -> PolygonMesh {
+    let mut mesh = solid.triangulation(tol).to_polygon();
+    filters::weld_vertices(&mut mesh, TOLERANCE * 2.0, false);
+    mesh
+}
+
+/// Tessellates `solid` as [`tessellate_solid`], reporting the progress to `progress`.
+///
+/// `progress` is called with the fraction of the tessellated faces, which increases monotonically
+/// from `0.0` to `1.0`. If `progress` returns `false`, the tessellation is aborted after the current
+/// face, and `None` is returned. `solid` is not changed in any case.
+///
+/// # Remarks
+/// The faces are tessellated one by one in the current thread, since `progress` may not be called
+/// from other threads. Hence, it is slower than [`tessellate_solid`] for the solid with many faces.
+///
+/// # Examples
+/// ```
+/// use std::cell::RefCell;
+/// use truck_meshalgo::prelude::*;
+/// use truck_modeling::*;
+///
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// let reported = RefCell::new(Vec::new());
+/// let progress = |x: f32| {
+///     reported.borrow_mut().push(x);
+///     true
+/// };
+/// let mesh = tessellate_solid_with_progress(&cube, 0.01, &progress).unwrap();
+/// assert_eq!(mesh.positions().len(), 8);
+/// let reported = reported.into_inner();
+/// assert_eq!(reported.len(), 8);
+/// assert!(reported.windows(2).all(|x| x[0] < x[1]));
+///
+/// // aborted at the half
+/// let progress = |x: f32| x < 0.5;
+/// assert!(tessellate_solid_with_progress(&cube, 0.01, &progress).is_none());
+/// ```
+pub fn tessellate_solid_with_progress<C: PolylineableCurve, S: MeshableSurface>(
+    solid: &Solid<Point3, C, S>,
+    tol: f64,
+    progress: &dyn Fn(f32) -> bool,
+) -> Option<PolygonMesh> {
+    nonpositive_tolerance!(tol);
+    let solid = solid.compress();
+    let total = solid
+        .boundaries
+        .iter()
+        .map(|shell| shell.faces.len())
+        .sum::<usize>();
+    // The last report is after welding vertices.
+    let fraction = |done: usize| done as f32 / (total + 1) as f32;
+    if !progress(0.0) {
+        return None;
+    }
+    let mut done = 0;
+    let mut step = || {
+        done += 1;
+        progress(fraction(done))
+    };
+    let boundaries = solid
+        .boundaries
+        .iter()
+        .map(|shell| {
+            let sp = triangulation::by_search_parameter;
+            triangulation::cshell_tessellation_with_progress(shell, tol, sp, &mut step)
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let mut mesh = CompressedSolid { boundaries }.to_polygon();
+    filters::weld_vertices(&mut mesh, TOLERANCE * 2.0, false);
+    progress(1.0).then_some(mesh)
+}
+
 mod triangulation;
@@ -59,21 +59,25 @@ where
     C: PolylineableCurve + 'a,
     S: PreMeshableSurface + 'a,
 {
+    // The vertices and edges are listed in the order of their first appearances in the faces,
+    // which does not depend on the addresses of the topological elements, i.e. their ids.
     let vmap: HashMap<_, _> = shell
-        .vertex_par_iter()
+        .vertex_iter()
+        .unique_by(|v| v.id())
         .map(|v| (v.id(), v.mapped(Point3::clone)))
         .collect();
-    let eset: HashMap<_, _> = shell.edge_par_iter().map(move |e| (e.id(), e)).collect();
-    let edge_map: HashMap<_, _> = eset
-        .into_par_iter()
-        .map(move |(id, edge)| {
+    let edges = shell.edge_iter().unique_by(|e| e.id()).collect::<Vec<_>>();
+    let poly_edges = edges
+        .par_iter()
+        .map(|edge| {
             let v0 = vmap.get(&edge.absolute_front().id()).unwrap();
             let v1 = vmap.get(&edge.absolute_back().id()).unwrap();
             let curve = edge.curve();
             let poly = PolylineCurve::from_curve(&curve, curve.range_tuple(), tol);
-            (id, Edge::debug_new(v0, v1, poly))
+            Edge::debug_new(v0, v1, poly)
         })
-        .collect();
+        .collect::<Vec<_>>();
+    let edge_map: HashMap<_, _> = edges.iter().map(|e| e.id()).zip(poly_edges).collect();
     let create_edge = |edge: &Edge<Point3, C>| -> Edge<_, _> {
         let new_edge = edge_map.get(&edge.id()).unwrap();
         match edge.orientation() {
@@ -155,6 +159,48 @@ where
     S: PreMeshableSurface + 'a,
 {
     let vertices = shell.vertices.clone();
+    let edges = cshell_edges_tessellation(shell, tol);
+    let tessellate_face = |face: &CompressedFace<S>| cface_tessellation(face, &edges, tol, &sp);
+    #[cfg(not(target_arch = "wasm32"))]
+    let faces = shell.faces.par_iter().map(tessellate_face).collect();
+    #[cfg(target_arch = "wasm32")]
+    let faces = shell.faces.iter().map(tessellate_face).collect();
+    MeshedCShell {
+        vertices,
+        edges,
+        faces,
+    }
+}
+
+/// Tessellates faces one by one, and calls `step` after each face.
+/// Returns `None` as soon as `step` returns `false`.
+pub(super) fn cshell_tessellation_with_progress<C: PolylineableCurve, S: PreMeshableSurface>(
+    shell: &CompressedShell<Point3, C, S>,
+    tol: f64,
+    sp: impl SP<S>,
+    step: &mut impl FnMut() -> bool,
+) -> Option<MeshedCShell> {
+    let vertices = shell.vertices.clone();
+    let edges = cshell_edges_tessellation(shell, tol);
+    let faces = shell
+        .faces
+        .iter()
+        .map(|face| {
+            let face = cface_tessellation(face, &edges, tol, &sp);
+            step().then_some(face)
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(MeshedCShell {
+        vertices,
+        edges,
+        faces,
+    })
+}
+
+fn cshell_edges_tessellation<C: PolylineableCurve, S>(
+    shell: &CompressedShell<Point3, C, S>,
+    tol: f64,
+) -> Vec<CompressedEdge<PolylineCurve>> {
     let tessellate_edge = |edge: &CompressedEdge<C>| {
         let curve = &edge.curve;
         CompressedEdge {
@@ -163,37 +209,161 @@ where
         }
     };
     #[cfg(not(target_arch = "wasm32"))]
-    let edges: Vec<_> = shell.edges.par_iter().map(tessellate_edge).collect();
+    let iter = shell.edges.par_iter();
     #[cfg(target_arch = "wasm32")]
-    let edges: Vec<_> = shell.edges.iter().map(tessellate_edge).collect();
-    let tessellate_face = |face: &CompressedFace<S>| {
-        let boundaries = face.boundaries.clone();
-        let surface = &face.surface;
-        let create_edge = |edge_idx: &CompressedEdgeIndex| match edge_idx.orientation {
-            true => Some(edges.get(edge_idx.index)?.curve.clone()),
-            false => Some(edges.get(edge_idx.index)?.curve.inverse()),
-        };
-        let create_boundary = |wire: &Vec<CompressedEdgeIndex>| {
-            let wire_iter = wire.iter().filter_map(create_edge);
-            PolyBoundaryPiece::try_new(surface, wire_iter, &sp)
-        };
-        let preboundary: Option<Vec<_>> = boundaries.iter().map(create_boundary).collect();
-        let polygon: Option<PolygonMesh> = (|| {
-            let boundary = PolyBoundary::new(preboundary?, &surface, tol);
-            Some(trimming_tessellation(&surface, &boundary, tol))
-        })();
-        CompressedFace {
-            boundaries,
-            orientation: face.orientation,
-            surface: polygon,
+    let iter = shell.edges.iter();
+    iter.map(tessellate_edge).collect()
+}
+
+fn cface_tessellation<S: PreMeshableSurface>(
+    face: &CompressedFace<S>,
+    edges: &[CompressedEdge<PolylineCurve>],
+    tol: f64,
+    sp: &impl SP<S>,
+) -> CompressedFace<Option<PolygonMesh>> {
+    let boundaries = face.boundaries.clone();
+    let surface = &face.surface;
+    let create_edge = |edge_idx: &CompressedEdgeIndex| match edge_idx.orientation {
+        true => Some(edges.get(edge_idx.index)?.curve.clone()),
+        false => Some(edges.get(edge_idx.index)?.curve.inverse()),
+    };
+    let create_boundary = |wire: &Vec<CompressedEdgeIndex>| {
+        let wire_iter = wire.iter().filter_map(create_edge);
+        PolyBoundaryPiece::try_new(surface, wire_iter, sp)
+    };
+    let preboundary: Option<Vec<_>> = boundaries.iter().map(create_boundary).collect();
+    let polygon: Option<PolygonMesh> = (|| {
+        let boundary = PolyBoundary::new(preboundary?, &surface, tol);
+        Some(trimming_tessellation(&surface, &boundary, tol))
+    })();
+    CompressedFace {
+        boundaries,
+        orientation: face.orientation,
+        surface: polygon,
+    }
+}
+
+/// Tessellates faces by the tolerances of each face
+pub(super) fn shell_adaptive_tessellation<C, S>(
+    shell: &Shell<Point3, C, S>,
+    tols: &[f64],
+    sp: impl SP<S>,
+) -> MeshedShell
+where
+    C: PolylineableCurve,
+    S: PreMeshableSurface,
+{
+    // The edges shared by several faces are divided by the finest tolerance to avoid cracks.
+    // The edges are listed in the order of their first appearances in the faces.
+    let mut edge_tols = Vec::<(Edge<Point3, C>, f64)>::new();
+    let mut edge_indices = HashMap::<EdgeID<C>, usize>::default();
+    shell.face_iter().zip(tols).for_each(|(face, tol)| {
+        face.edge_iter().for_each(|edge| {
+            let idx = *edge_indices.entry(edge.id()).or_insert_with(|| {
+                edge_tols.push((edge.clone(), *tol));
+                edge_tols.len() - 1
+            });
+            edge_tols[idx].1 = f64::min(edge_tols[idx].1, *tol);
+        })
+    });
+    let vmap: HashMap<_, _> = shell
+        .vertex_iter()
+        .unique_by(|v| v.id())
+        .map(|v| (v.id(), v.mapped(Point3::clone)))
+        .collect();
+    let edge_map: HashMap<_, _> = edge_tols
+        .into_iter()
+        .map(|(edge, tol)| {
+            let v0 = vmap.get(&edge.absolute_front().id()).unwrap();
+            let v1 = vmap.get(&edge.absolute_back().id()).unwrap();
+            let curve = edge.curve();
+            let poly = PolylineCurve::from_curve(&curve, curve.range_tuple(), tol);
+            (edge.id(), Edge::debug_new(v0, v1, poly))
+        })
+        .collect();
+    let create_edge = |edge: &Edge<Point3, C>| -> Edge<_, _> {
+        let new_edge = edge_map.get(&edge.id()).unwrap();
+        match edge.orientation() {
+            true => new_edge.clone(),
+            false => new_edge.inverse(),
         }
     };
+    let create_boundary =
+        |wire: &Wire<Point3, C>| -> Wire<_, _> { wire.edge_iter().map(create_edge).collect() };
+    let create_face = |(face, tol): (&Face<Point3, C, S>, &f64)| -> Face<_, _, _> {
+        let wires: Vec<_> = face
+            .absolute_boundaries()
+            .iter()
+            .map(create_boundary)
+            .collect();
+        shell_create_polygon(&face.surface(), wires, face.orientation(), *tol, &sp)
+    };
     #[cfg(not(target_arch = "wasm32"))]
-    let faces = shell.faces.par_iter().map(tessellate_face).collect();
+    let res = shell
+        .face_par_iter()
+        .zip(tols.par_iter())
+        .map(create_face)
+        .collect();
     #[cfg(target_arch = "wasm32")]
-    let faces = shell.faces.iter().map(tessellate_face).collect();
+    let res = shell.face_iter().zip(tols).map(create_face).collect();
+    res
+}
+
+/// Tessellates faces by the tolerances of each face
+pub(super) fn cshell_adaptive_tessellation<C, S>(
+    shell: &CompressedShell<Point3, C, S>,
+    tols: &[f64],
+    sp: impl SP<S>,
+) -> MeshedCShell
+where
+    C: PolylineableCurve,
+    S: PreMeshableSurface,
+{
+    // The edges shared by several faces are divided by the finest tolerance to avoid cracks.
+    // The edges not included in any faces are divided by the coarsest one.
+    let max_tol = tols.iter().copied().fold(TOLERANCE, f64::max);
+    let mut edge_tols = vec![max_tol; shell.edges.len()];
+    shell.faces.iter().zip(tols).for_each(|(face, tol)| {
+        face.boundaries.iter().flatten().for_each(|edge_idx| {
+            if let Some(edge_tol) = edge_tols.get_mut(edge_idx.index) {
+                *edge_tol = f64::min(*edge_tol, *tol);
+            }
+        })
+    });
+    let compressed_edge = |(edge, tol): (&CompressedEdge<C>, &f64)| {
+        let curve = &edge.curve;
+        CompressedEdge {
+            vertices: edge.vertices,
+            curve: PolylineCurve::from_curve(curve, curve.range_tuple(), *tol),
+        }
+    };
+    #[cfg(not(target_arch = "wasm32"))]
+    let edges: Vec<_> = shell
+        .edges
+        .par_iter()
+        .zip(edge_tols.par_iter())
+        .map(compressed_edge)
+        .collect();
+    #[cfg(target_arch = "wasm32")]
+    let edges: Vec<_> = shell
+        .edges
+        .iter()
+        .zip(&edge_tols)
+        .map(compressed_edge)
+        .collect();
+    let tessellate_face =
+        |(face, tol): (&CompressedFace<S>, &f64)| cface_tessellation(face, &edges, *tol, &sp);
+    #[cfg(not(target_arch = "wasm32"))]
+    let faces = shell
+        .faces
+        .par_iter()
+        .zip(tols.par_iter())
+        .map(tessellate_face)
+        .collect();
+    #[cfg(target_arch = "wasm32")]
+    let faces = shell.faces.iter().zip(tols).map(tessellate_face).collect();
     MeshedCShell {
-        vertices,
+        vertices: shell.vertices.clone(),
         edges,
         faces,
     }
@@ -664,3 +834,26 @@ fn par_bench() {
     });
     println!("{}ms", instant.elapsed().as_millis());
 }
+
+#[test]
+#[cfg(not(target_arch = "wasm32"))]
+fn par_consistency() {
+    use truck_modeling::*;
+    const JSON: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../resources/shape/bottle.json"
+    ));
+    let solid: Solid = serde_json::from_str(JSON).unwrap();
+    let shell = solid.into_boundaries().pop().unwrap();
+
+    let par = shell_tessellation(&shell, 0.01, by_search_parameter);
+    let single = shell_tessellation_single_thread(&shell, 0.01, by_search_parameter);
+    assert_eq!(par.to_polygon(), single.to_polygon());
+
+    let cshell = shell.compress();
+    let par = cshell_tessellation(&cshell, 0.01, by_search_parameter);
+    let single =
+        cshell_tessellation_with_progress(&cshell, 0.01, by_search_parameter, &mut || true)
+            .unwrap();
+    assert_eq!(par.to_polygon(), single.to_polygon());
+}
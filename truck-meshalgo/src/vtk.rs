@@ -191,6 +191,123 @@ impl ToDataSet for PolygonMesh {
     }
 }
 
+/// Converts the polygon mesh into an unstructured grid.
+///
+/// # Details
+/// The triangles, quadrangles and the other polygons are converted into the cells of
+/// [`CellType::Triangle`], [`CellType::Quad`] and [`CellType::Polygon`], respectively.
+/// The texture coordinates and the normals are added as the point-data arrays `TCoords` and `Normals`
+/// only if the mesh has them.
+///
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// use vtkio::model::*;
+/// let positions = vec![
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(1.0, 1.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+///     Point3::new(-0.5, 0.5, 0.0),
+/// ];
+/// let faces: &[&[usize]] = &[&[0, 1, 2, 3], &[0, 2, 3], &[0, 1, 2, 3, 4]];
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions,
+///         ..Default::default()
+///     },
+///     Faces::from_iter(faces),
+/// );
+/// let DataSet::UnstructuredGrid { pieces, .. } = to_unstructured_grid(&mesh) else {
+///     unreachable!()
+/// };
+/// let Piece::Inline(piece) = &pieces[0] else {
+///     unreachable!()
+/// };
+/// // The faces are sorted: triangles, quadrangles, and the others.
+/// let types = vec![CellType::Triangle, CellType::Quad, CellType::Polygon];
+/// assert_eq!(piece.cells.types, types);
+/// assert!(piece.data.point.is_empty());
+/// ```
+pub fn to_unstructured_grid(mesh: &PolygonMesh) -> DataSet {
+    let polygon = mesh.expands(identity);
+    let flatten_points = polygon
+        .attributes()
+        .iter()
+        .flat_map::<[f64; 3], _>(|attr| attr.position.into())
+        .collect::<Vec<_>>();
+    let mut point = Vec::new();
+    if !mesh.uv_coords().is_empty() {
+        let flatten_uvs = polygon
+            .attributes()
+            .iter()
+            .flat_map(|attr| match attr.uv_coord {
+                Some(uv) => uv.into(),
+                None => [f64::NAN; 2],
+            })
+            .collect::<Vec<_>>();
+        point.push(Attribute::DataArray(DataArray {
+            name: "TCoords".to_owned(),
+            elem: ElementType::TCoords(2),
+            data: IOBuffer::F64(flatten_uvs),
+        }));
+    }
+    if !mesh.normals().is_empty() {
+        let flatten_normals = polygon
+            .attributes()
+            .iter()
+            .flat_map(|attr| match attr.normal {
+                Some(normal) => normal.into(),
+                None => [f64::NAN; 3],
+            })
+            .collect::<Vec<_>>();
+        point.push(Attribute::DataArray(DataArray {
+            name: "Normals".to_owned(),
+            elem: ElementType::Normals,
+            data: IOBuffer::F64(flatten_normals),
+        }));
+    }
+    let types = polygon
+        .face_iter()
+        .map(|face| match face.len() {
+            3 => CellType::Triangle,
+            4 => CellType::Quad,
+            _ => CellType::Polygon,
+        })
+        .collect();
+    DataSet::UnstructuredGrid {
+        meta: None,
+        pieces: vec![Piece::Inline(Box::new(UnstructuredGridPiece {
+            points: IOBuffer::F64(flatten_points),
+            cells: Cells {
+                cell_verts: to_vertex_numbers(polygon.faces()),
+                types,
+            },
+            data: Attributes {
+                point,
+                ..Default::default()
+            },
+        }))],
+    }
+}
+
+/// Writes the polygon mesh to `writer` as an XML unstructured grid file, `.vtu`.
+///
+/// The cells and the point-data arrays are the ones of [`to_unstructured_grid`].
+pub fn write_vtu<W: std::io::Write>(
+    mesh: &PolygonMesh,
+    writer: W,
+) -> std::result::Result<(), vtkio::Error> {
+    let vtk = Vtk {
+        version: (1, 0).into(),
+        title: String::new(),
+        byte_order: ByteOrder::LittleEndian,
+        file_path: None,
+        data: to_unstructured_grid(mesh),
+    };
+    vtk.write_xml(writer)
+}
+
 fn hash_point(p: Point3) -> Option<[i64; 3]> {
     (p / (TOLERANCE * 50.0) - Vector3::new(0.5, 0.5, 0.5))
         .cast()
@@ -0,0 +1,75 @@
+use super::*;
+
+fn cuboid(min: Point3, max: Point3) -> PolygonMesh {
+    let positions = (0..8)
+        .map(|i| {
+            let x = if i & 1 == 0 { min.x } else { max.x };
+            let y = if i & 2 == 0 { min.y } else { max.y };
+            let z = if i & 4 == 0 { min.z } else { max.z };
+            Point3::new(x, y, z)
+        })
+        .collect();
+    let faces = Faces::from_iter(&[
+        [0, 2, 3, 1],
+        [4, 5, 7, 6],
+        [0, 1, 5, 4],
+        [2, 6, 7, 3],
+        [0, 4, 6, 2],
+        [1, 3, 7, 5],
+    ]);
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    )
+}
+
+fn check_volumes(a: &PolygonMesh, b: &PolygonMesh, volumes: [f64; 3]) {
+    [BoolOp::Union, BoolOp::Intersection, BoolOp::Difference]
+        .into_iter()
+        .zip(volumes)
+        .for_each(|(op, volume)| {
+            let mesh = mesh_boolean(a, b, op).unwrap();
+            assert_eq!(mesh.shell_condition(), ShellCondition::Closed, "{op:?}");
+            assert!(f64::abs(mesh.volume() - volume) < 1.0e-6, "{op:?}");
+        });
+}
+
+#[test]
+fn overlapping_cuboids() {
+    let a = cuboid(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+    let b = cuboid(Point3::new(0.5, 0.25, 0.4), Point3::new(1.5, 1.25, 1.4));
+    let overlap = 0.5 * 0.75 * 0.6;
+    check_volumes(&a, &b, [2.0 - overlap, overlap, 1.0 - overlap]);
+}
+
+#[test]
+fn disjoint_and_including_cuboids() {
+    let a = cuboid(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+    let b = cuboid(Point3::new(2.0, 0.0, 0.0), Point3::new(3.0, 1.0, 1.0));
+    check_volumes(&a, &b, [2.0, 0.0, 1.0]);
+    let c = cuboid(Point3::new(0.25, 0.25, 0.25), Point3::new(0.75, 0.75, 0.75));
+    check_volumes(&a, &c, [1.0, 0.125, 0.875]);
+}
+
+#[test]
+fn coplanar_cuboids() {
+    let a = cuboid(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+    let b = cuboid(Point3::new(0.5, 0.0, 0.0), Point3::new(1.5, 1.0, 1.0));
+    check_volumes(&a, &b, [1.5, 0.5, 0.5]);
+    let c = cuboid(Point3::new(0.5, 0.25, 0.0), Point3::new(1.5, 0.75, 1.0));
+    check_volumes(&a, &c, [1.25, 0.25, 0.75]);
+}
+
+#[test]
+fn open_mesh() {
+    let a = cuboid(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0));
+    let b = cuboid(Point3::new(0.5, 0.5, 0.5), Point3::new(1.5, 1.5, 1.5));
+    let faces = Faces::from_iter(&b.faces().quad_faces()[..5]);
+    let b = PolygonMesh::new(b.attributes().clone(), faces);
+    assert_eq!(b.shell_condition(), ShellCondition::Oriented);
+    assert!(mesh_boolean(&a, &b, BoolOp::Union).is_none());
+    assert!(mesh_boolean(&b, &a, BoolOp::Union).is_none());
+}
@@ -0,0 +1,62 @@
+use super::*;
+use std::f64::consts::PI;
+use truck_modeling::*;
+
+#[test]
+fn closest_point_on_cube() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    cube.face_iter().for_each(|face| {
+        let surface = face.oriented_surface();
+        let (center, normal) = (surface.subs(0.5, 0.5), surface.normal(0.5, 0.5));
+        let (point, face_id) = cube.closest_point(center + normal * 0.1, 0.01);
+        assert_near!(point, center);
+        assert_eq!(face_id, face.id());
+    });
+    // the nearest point is on the edge of the faces.
+    let (point, _) = cube.closest_point(Point3::new(1.5, 0.5, 1.5), 0.01);
+    assert_near!(point, Point3::new(1.0, 0.5, 1.0));
+}
+
+#[test]
+fn closest_point_on_sphere() {
+    let v = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+    let wire: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_x(), Rad(PI));
+    let shell = builder::cone(&wire, Vector3::unit_y(), Rad(7.0));
+    let sphere = Solid::new(vec![shell]);
+    let dirs = [
+        Vector3::new(1.0, 2.0, 3.0),
+        Vector3::new(-2.0, 0.5, 1.0),
+        Vector3::new(0.3, -1.0, -0.2),
+    ];
+    dirs.into_iter().map(Vector3::normalize).for_each(|dir| {
+        // The tessellation is refined on the sphere.
+        let (point, _) = sphere.closest_point(Point3::from_vec(dir * 2.0), 0.01);
+        assert_near!(point, Point3::from_vec(dir));
+        let (point, _) = sphere.closest_point(Point3::from_vec(dir * 0.5), 0.01);
+        assert_near!(point, Point3::from_vec(dir));
+    });
+}
+
+#[test]
+fn closest_point_on_prebuilt_mesh() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let meshed = cube.triangulation(0.01);
+    let points = [
+        Point3::new(0.5, 0.5, 1.2),
+        Point3::new(1.5, 0.5, 1.5),
+        Point3::new(0.5, 0.1, 0.5),
+        Point3::new(-0.2, 0.3, 0.7),
+    ];
+    points.into_iter().for_each(|p| {
+        let (point0, face_id0) = cube.closest_point(p, 0.01);
+        let (point1, face_id1) = cube.closest_point_on_mesh(&meshed, p, 0.01);
+        assert_near!(point0, point1);
+        assert_eq!(face_id0, face_id1);
+    });
+}
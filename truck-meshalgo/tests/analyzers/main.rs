@@ -1,9 +1,13 @@
 use truck_meshalgo::prelude::*;
 
+mod boolean;
+mod closest_point;
 mod collision;
 #[path = "../common/mod.rs"]
 mod common;
+mod planar_regions;
 mod point_cloud;
+mod ray_casting;
 mod splitting;
 mod topology;
 mod volume;
@@ -0,0 +1,62 @@
+use super::*;
+
+#[test]
+fn cube_stl() {
+    let cube = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(1.0, 1.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+                Point3::new(0.0, 0.0, 1.0),
+                Point3::new(1.0, 0.0, 1.0),
+                Point3::new(1.0, 1.0, 1.0),
+                Point3::new(0.0, 1.0, 1.0),
+            ],
+            ..Default::default()
+        },
+        Faces::from_iter(&[
+            [3, 2, 1, 0],
+            [0, 1, 5, 4],
+            [1, 2, 6, 5],
+            [2, 3, 7, 6],
+            [3, 0, 4, 7],
+            [4, 5, 6, 7],
+        ]),
+    );
+    let mut buffer = Vec::new();
+    stl::write(&cube, &mut buffer, stl::StlType::Binary).unwrap();
+    let mesh = stl::read(buffer.as_slice(), stl::StlType::Binary).unwrap();
+    assert_eq!(mesh.faces().len(), 12);
+
+    let regions = segment_planar_regions(&mesh, 0.01);
+    assert_eq!(regions.len(), 6);
+    regions.iter().for_each(|region| {
+        assert_eq!(region.len(), 2);
+        let normals = region
+            .iter()
+            .map(|i| {
+                let face = &mesh.faces()[*i];
+                let p = face
+                    .iter()
+                    .map(|v| mesh.positions()[v.pos])
+                    .collect::<Vec<_>>();
+                (p[1] - p[0]).cross(p[2] - p[0]).normalize()
+            })
+            .collect::<Vec<_>>();
+        assert_near!(normals[0], normals[1]);
+    });
+}
+
+#[test]
+fn curved_regions() {
+    let mesh = common::shapes::sphere(Point3::origin(), 1.0, 16, 16);
+    let regions = segment_planar_regions(&mesh, 0.01);
+    // Each face forms a region of its own.
+    assert_eq!(regions.len(), mesh.faces().len());
+
+    // A large tolerance merges the neighbor faces.
+    let regions = segment_planar_regions(&mesh, 0.5);
+    assert!(regions.len() < mesh.faces().len());
+}
@@ -0,0 +1,51 @@
+use super::*;
+use std::f64::consts::PI;
+use truck_modeling::*;
+
+#[test]
+fn ray_through_sphere() {
+    let v = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+    let wire: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_x(), Rad(PI));
+    let shell = builder::cone(&wire, Vector3::unit_y(), Rad(7.0));
+    let sphere = Solid::new(vec![shell]);
+
+    let origin = Point3::new(0.3, 0.2, -3.0);
+    let hits = sphere.ray_intersections(origin, Vector3::unit_z(), 0.01);
+    assert_eq!(hits.len(), 2);
+    let depth = f64::sqrt(1.0 - 0.3 * 0.3 - 0.2 * 0.2);
+    assert_near!(hits[0].0, 3.0 - depth);
+    assert_near!(hits[0].1, Point3::new(0.3, 0.2, -depth));
+    assert_near!(hits[1].0, 3.0 + depth);
+    assert_near!(hits[1].1, Point3::new(0.3, 0.2, depth));
+
+    // from the inside of the sphere
+    let hits = sphere.ray_intersections(Point3::origin(), Vector3::new(1.0, 1.0, 1.0), 0.01);
+    assert_eq!(hits.len(), 1);
+    assert_near!(hits[0].0, 1.0 / f64::sqrt(3.0));
+
+    let origin = Point3::new(1.5, 0.0, -3.0);
+    assert!(sphere
+        .ray_intersections(origin, Vector3::unit_z(), 0.01)
+        .is_empty());
+}
+
+#[test]
+fn ray_through_edges() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+
+    // The ray passes through the edges on the y = z = 0 and y = z = 1.
+    let origin = Point3::new(0.5, -1.0, -1.0);
+    let hits = cube.ray_intersections(origin, Vector3::new(0.0, 1.0, 1.0), 0.01);
+    assert_eq!(hits.len(), 2);
+    assert_near!(hits[0].1, Point3::new(0.5, 0.0, 0.0));
+    assert_near!(hits[1].1, Point3::new(0.5, 1.0, 1.0));
+    hits.iter().for_each(|(_, p, face_id)| {
+        let face = cube.face_iter().find(|face| face.id() == *face_id).unwrap();
+        let surface = face.surface();
+        let (u, v) = surface.search_parameter(*p, None, 100).unwrap();
+        assert_near!(surface.subs(u, v), *p);
+    });
+}
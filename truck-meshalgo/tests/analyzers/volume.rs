@@ -44,3 +44,52 @@ proptest! {
         assert_near!(msolid.center_of_gravity().to_point(), trans.transform_point(grav));
     }
 }
+
+#[test]
+fn mirrored_solid() {
+    let v = builder::vertices([(0.0, 0.0, 0.0), (2.0, 0.0, 0.0), (0.0, 1.0, 0.0)]);
+    let wire: Wire = (0..3)
+        .map(|i| builder::line(&v[i], &v[(i + 1) % 3]))
+        .collect();
+    let face: Face = builder::try_attach_plane(vec![wire]).unwrap();
+    // a skewed prism, which is chiral
+    let solid: Solid = builder::tsweep(&face, Vector3::new(1.0, 0.5, 1.0));
+    let plane = Plane::new(
+        Point3::new(0.0, 0.0, -1.0),
+        Point3::new(1.0, 0.0, -1.0),
+        Point3::new(0.0, 1.0, -1.0),
+    );
+    let mirrored = builder::mirror(&solid, &plane);
+    assert!(Solid::try_new(mirrored.boundaries().clone()).is_ok());
+
+    let volume0 = solid.triangulation(0.01).collect_option().unwrap().volume();
+    let volume1 = mirrored
+        .triangulation(0.01)
+        .collect_option()
+        .unwrap()
+        .volume();
+    assert!(volume0 > 0.0);
+    assert_near!(volume0, volume1);
+}
+
+fn cube(origin: Point3, size: f64) -> Solid {
+    let v = builder::vertex(origin);
+    let e = builder::tsweep(&v, size * Vector3::unit_x());
+    let f = builder::tsweep(&e, size * Vector3::unit_y());
+    builder::tsweep(&f, size * Vector3::unit_z())
+}
+
+#[test]
+fn solid_volume() {
+    let unit_cube = cube(Point3::origin(), 1.0);
+    assert_near!(unit_cube.volume(0.01), 1.0);
+
+    let outer = cube(Point3::origin(), 2.0);
+    let inner = cube(Point3::new(0.5, 0.5, 0.5), 1.0);
+    let void: Shell = inner.boundaries()[0]
+        .face_iter()
+        .map(Face::inverse)
+        .collect();
+    let hollow = Solid::new(vec![outer.boundaries()[0].clone(), void]);
+    assert_near!(hollow.volume(0.01), 7.0);
+}
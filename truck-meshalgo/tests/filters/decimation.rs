@@ -0,0 +1,26 @@
+use std::f64::consts::PI;
+use truck_meshalgo::prelude::*;
+use truck_modeling::*;
+
+#[test]
+fn decimate_sphere() {
+    let v = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+    let wire: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_x(), Rad(PI));
+    let shell: Shell = builder::cone(&wire, Vector3::unit_y(), Rad(7.0));
+    let mut mesh = shell.triangulation(0.001).to_polygon();
+    mesh.put_together_same_attrs(TOLERANCE)
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+
+    let len = mesh.faces().triangle_iter().len();
+    let target = len / 10;
+    mesh.decimate(target);
+    assert!(mesh.faces().len() <= target + target / 10);
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    mesh.positions()
+        .iter()
+        .for_each(|p| assert!(f64::abs(p.to_vec().magnitude() - 1.0) < 0.02));
+    let volume = mesh.volume();
+    assert!(f64::abs(volume - 4.0 * PI / 3.0) < 0.1, "{volume}");
+}
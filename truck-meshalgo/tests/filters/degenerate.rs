@@ -0,0 +1,54 @@
+use truck_meshalgo::prelude::*;
+
+#[test]
+fn remove_three_slivers() {
+    let octahedron = [
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(-1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, -1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(0.0, 0.0, -1.0),
+    ];
+    // nearly on the edge between the points 0 and 2
+    let middle = octahedron[0].midpoint(octahedron[2]);
+    let cap_apex = middle + (octahedron[4] - middle) * 0.001;
+    // nearly at the point 4
+    let centroid = Point3::centroid(&[octahedron[1], octahedron[3], octahedron[4]]);
+    let needle_apex = octahedron[4] + (centroid - octahedron[4]) * 0.001;
+    let positions = octahedron
+        .into_iter()
+        .chain([cap_apex, needle_apex])
+        .collect::<Vec<_>>();
+    let faces = Faces::from_iter(&[
+        [0, 2, 6],
+        [2, 4, 6],
+        [4, 0, 6],
+        [2, 1, 4],
+        [1, 3, 7],
+        [3, 4, 7],
+        [4, 1, 7],
+        [3, 0, 4],
+        [2, 0, 5],
+        [1, 2, 5],
+        [3, 1, 5],
+        [0, 3, 5],
+    ]);
+    let mut mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        faces,
+    );
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    assert_eq!(degenerate_faces(&mesh, 1.0e-6, 0.05), vec![0, 5, 6]);
+
+    remove_degenerate(&mut mesh, 1.0e-6, 0.05);
+    assert!(degenerate_faces(&mesh, 1.0e-6, 0.05).is_empty());
+    // The cap is flipped, and the needles are collapsed.
+    assert_eq!(mesh.faces().len(), 10);
+    assert_eq!(mesh.positions().len(), 7);
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    assert!(f64::abs(mesh.volume() - 4.0 / 3.0) < 1.0e-3);
+}
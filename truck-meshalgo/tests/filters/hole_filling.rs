@@ -0,0 +1,33 @@
+use std::f64::consts::PI;
+use truck_meshalgo::prelude::*;
+use truck_modeling::*;
+
+#[test]
+fn fill_sphere_holes() {
+    let v = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+    let wire: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_x(), Rad(PI));
+    let shell: Shell = builder::cone(&wire, Vector3::unit_y(), Rad(7.0));
+    let mut mesh = shell.triangulation(0.01).to_polygon();
+    mesh.put_together_same_attrs(TOLERANCE)
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+    let volume = mesh.volume();
+
+    // remove two triangles apart from each other
+    let len = mesh.faces().len();
+    let faces = Faces::from_iter(
+        mesh.face_iter()
+            .enumerate()
+            .filter(|(i, _)| *i != 0 && *i != len / 2)
+            .map(|(_, face)| face),
+    );
+    let mut holed = PolygonMesh::new(mesh.attributes().clone(), faces);
+    assert_ne!(holed.shell_condition(), ShellCondition::Closed);
+    assert_eq!(holed.extract_boundaries().len(), 2);
+
+    fill_holes(&mut holed, 8);
+    assert_eq!(holed.shell_condition(), ShellCondition::Closed);
+    assert!(holed.extract_boundaries().is_empty());
+    assert!(f64::abs(holed.volume() - volume) < 1.0e-3);
+}
@@ -1,4 +1,8 @@
+mod decimation;
+mod degenerate;
+mod hole_filling;
 mod normal_filter;
 mod optimizing;
+mod smoothing;
 mod structuring;
 mod subdivision;
@@ -0,0 +1,48 @@
+use std::f64::consts::PI;
+use truck_meshalgo::prelude::*;
+use truck_modeling::*;
+
+fn normal_deviation(mesh: &PolygonMesh) -> f64 {
+    let (sum, count) = mesh
+        .faces()
+        .triangle_iter()
+        .map(|tri| {
+            let [p, q, r] = tri.map(|v| mesh.positions()[v.pos]);
+            let normal = (q - p).cross(r - p).normalize();
+            let radial = (p.to_vec() + q.to_vec() + r.to_vec()).normalize();
+            1.0 - normal.dot(radial).abs()
+        })
+        .fold((0.0, 0), |(sum, count), x| (sum + x * x, count + 1));
+    sum / count as f64
+}
+
+#[test]
+fn taubin_noisy_sphere() {
+    let v = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+    let wire: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_x(), Rad(PI));
+    let shell: Shell = builder::cone(&wire, Vector3::unit_y(), Rad(7.0));
+    let mut mesh = shell.triangulation(0.01).to_polygon();
+    mesh.put_together_same_attrs(TOLERANCE)
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    let volume0 = mesh.volume();
+
+    // deterministic noise in the radial direction
+    let noisy = mesh
+        .positions()
+        .iter()
+        .enumerate()
+        .map(|(i, p)| p + p.to_vec() * 0.01 * f64::sin(i as f64 * 12.9898))
+        .collect::<Vec<_>>();
+    mesh.debug_editor().attributes.positions = noisy;
+    let deviation0 = normal_deviation(&mesh);
+
+    smooth_taubin(&mut mesh, 0.33, -0.34, 10);
+    let deviation1 = normal_deviation(&mesh);
+    assert!(deviation1 < deviation0 * 0.5, "{deviation0} {deviation1}");
+    let volume1 = mesh.volume();
+    assert!(
+        f64::abs(volume1 - volume0) < volume0 * 0.01,
+        "{volume0} {volume1}"
+    );
+}
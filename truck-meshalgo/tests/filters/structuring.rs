@@ -106,3 +106,52 @@ fn quadrangulate_test() {
     tri_mesh.quadrangulate(TOLERANCE, TOLERANCE);
     assert_eq!(tri_mesh.faces(), quad_mesh.faces());
 }
+
+#[test]
+fn triangulate_nonconvex_test() {
+    // arrow-shaped pentagon, concave at the vertex 3.
+    let positions = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(2.0, 0.0, 0.0),
+        Point3::new(2.0, 2.0, 0.0),
+        Point3::new(1.0, 0.5, 0.0),
+        Point3::new(0.0, 2.0, 0.0),
+    ];
+    let normals = vec![Vector3::unit_z()];
+    let face = (0..5)
+        .map(|i| StandardVertex {
+            pos: i,
+            uv: None,
+            nor: Some(0),
+        })
+        .collect::<Vec<_>>();
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            normals,
+            ..Default::default()
+        },
+        Faces::from_iter([face]),
+    );
+    let area = 2.0 * 2.0 - 2.0 * 1.5 / 2.0;
+
+    let triangulated = mesh.triangulated();
+    assert_eq!(triangulated.faces().tri_faces().len(), 3);
+    assert_eq!(triangulated.faces().len(), 3);
+    let sum = triangulated
+        .faces()
+        .triangle_iter()
+        .map(|tri| {
+            assert!(tri.iter().all(|v| v.nor == Some(0)));
+            let p = tri.map(|v| triangulated.positions()[v.pos]);
+            let cross = (p[1] - p[0]).cross(p[2] - p[0]);
+            assert!(cross.z > 0.0, "inverted triangle: {tri:?}");
+            cross.z / 2.0
+        })
+        .sum::<f64>();
+    assert_near!(sum, area);
+
+    let mut mesh = mesh;
+    mesh.triangulate();
+    assert_eq!(mesh, triangulated);
+}
@@ -33,6 +33,40 @@ fn solid_is_closed() {
     }
 }
 
+#[test]
+fn tessellated_solid_is_closed() {
+    for (i, json) in read_jsons().into_iter().enumerate() {
+        let solid: Solid = serde_json::from_reader(json.as_slice()).unwrap();
+        let poly = tessellate_solid(&solid, 0.01);
+        assert_eq!(
+            poly.shell_condition(),
+            ShellCondition::Closed,
+            "not closed: file no. {i}"
+        );
+        assert!(poly.extract_boundaries().is_empty(), "file no. {i}");
+    }
+}
+
+#[test]
+fn deterministic_tessellation() {
+    let to_obj = |mesh: &PolygonMesh| {
+        let mut obj = Vec::new();
+        obj::write(mesh, &mut obj).unwrap();
+        obj
+    };
+    for (i, json) in read_jsons().into_iter().enumerate() {
+        // The two solids have the same geometry but the different ids.
+        let solid0: Solid = serde_json::from_reader(json.as_slice()).unwrap();
+        let solid1: Solid = serde_json::from_reader(json.as_slice()).unwrap();
+        let obj0 = to_obj(&solid0.triangulation(0.01).to_polygon());
+        let obj1 = to_obj(&solid1.triangulation(0.01).to_polygon());
+        assert!(obj0 == obj1, "different outputs: file no. {i}");
+        let obj0 = to_obj(&solid0.adaptive_triangulation(|_| 0.01).to_polygon());
+        let obj1 = to_obj(&solid1.adaptive_triangulation(|_| 0.01).to_polygon());
+        assert!(obj0 == obj1, "different outputs: file no. {i}");
+    }
+}
+
 #[test]
 fn csolid_is_closed() {
     for (i, json) in read_jsons().into_iter().enumerate() {
@@ -133,3 +167,62 @@ fn special_cylinder_csolid() {
         .remove_unused_attrs();
     assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
 }
+
+#[test]
+fn filleted_cube_is_closed() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x() * 2.0);
+    let f = builder::tsweep(&e, Vector3::unit_y() * 2.0);
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z() * 2.0);
+    let edge_id = cube.edge_iter().next().unwrap().id();
+    let solid = builder::fillet_edge(&cube, edge_id, 1.0).unwrap();
+    let mut poly = solid.triangulation(0.01).to_polygon();
+    poly.put_together_same_attrs(TOLERANCE * 2.0)
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    assert_eq!(poly.shell_condition(), ShellCondition::Closed);
+}
+
+#[test]
+fn trimmed_face_with_hole() {
+    let v = builder::vertices([
+        (-1.0, -1.0, 0.0),
+        (1.0, -1.0, 0.0),
+        (1.0, 1.0, 0.0),
+        (-1.0, 1.0, 0.0),
+    ]);
+    let outer: Wire = (0..4)
+        .map(|i| builder::line(&v[i], &v[(i + 1) % 4]))
+        .collect();
+    let v = builder::vertex(Point3::new(0.5, 0.0, 0.0));
+    let inner: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    let plane = Plane::new(
+        Point3::new(-1.0, -1.0, 0.0),
+        Point3::new(1.0, -1.0, 0.0),
+        Point3::new(-1.0, 1.0, 0.0),
+    );
+    let face = builder::trimmed_face(plane.into(), outer, vec![inner]).unwrap();
+
+    let poly = Shell::from(vec![face]).triangulation(0.01).to_polygon();
+    let positions = poly.positions();
+    let area = poly.faces().triangle_iter().fold(0.0, |sum, tri| {
+        let [p, q, r] = [
+            positions[tri[0].pos],
+            positions[tri[1].pos],
+            positions[tri[2].pos],
+        ];
+        let center = (p.to_vec() + q.to_vec() + r.to_vec()) / 3.0;
+        assert!(
+            center.magnitude() > 0.5 - 0.01,
+            "triangle in the hole: {center:?}"
+        );
+        let cross = (q - p).cross(r - p);
+        // the normals of triangles are the same as the plane.
+        assert!(cross.z > 0.0);
+        sum + cross.magnitude() / 2.0
+    });
+    assert!(
+        f64::abs(area - (4.0 - std::f64::consts::PI * 0.25)) < 0.02,
+        "{area}"
+    );
+}
@@ -576,3 +576,38 @@ fn cshell() {
         shell_unstructured_grid()
     );
 }
+
+#[test]
+fn unstructured_grid() {
+    let mut mesh = truck_simple_cube();
+    mesh.add_naive_normals(true);
+    let num_points = mesh.expands(std::convert::identity).attributes().len();
+    let DataSet::UnstructuredGrid { pieces, .. } = to_unstructured_grid(&mesh) else {
+        unreachable!()
+    };
+    let Piece::Inline(piece) = &pieces[0] else {
+        unreachable!()
+    };
+    let mut types = vec![CellType::Triangle; 2];
+    types.extend([CellType::Quad; 5]);
+    assert_eq!(piece.cells.types, types);
+    assert_eq!(piece.points.len(), num_points * 3);
+    assert_eq!(piece.data.point.len(), 1);
+    let Attribute::DataArray(normals) = &piece.data.point[0] else {
+        unreachable!()
+    };
+    assert_eq!(normals.name, "Normals");
+    assert_eq!(normals.data.len(), num_points * 3);
+
+    let mut vtu = Vec::new();
+    write_vtu(&mesh, &mut vtu).unwrap();
+    let vtk = Vtk::parse_xml(vtu.as_slice()).unwrap();
+    let DataSet::UnstructuredGrid { pieces, .. } = vtk.data else {
+        panic!("not an unstructured grid")
+    };
+    let Piece::Inline(read_piece) = &pieces[0] else {
+        unreachable!()
+    };
+    assert_eq!(read_piece.cells.types, types);
+    assert_eq!(read_piece.points.len(), num_points * 3);
+}
@@ -1,3 +1,4 @@
+pub use crate::path_sweep::FrameMode;
 use crate::{
     errors::Error,
     geom_impls::{self, ArcConnector, ExtrudeConnector, LineConnector, RevoluteConnector},
@@ -12,6 +13,7 @@ type Edge<C> = truck_topology::Edge<Point3, C>;
 type Wire<C> = truck_topology::Wire<Point3, C>;
 type Face<C, S> = truck_topology::Face<Point3, C, S>;
 type Shell<C, S> = truck_topology::Shell<Point3, C, S>;
+type Solid<C, S> = truck_topology::Solid<Point3, C, S>;
 
 /// Creates and returns a vertex by a three dimensional point.
 /// # Examples
@@ -395,6 +397,106 @@ pub fn scaled<T: Mapped<Matrix4>>(elem: &T, origin: Point3, scalars: Vector3) ->
     transformed(elem, mat2 * mat1 * mat0)
 }
 
+/// Returns the mirror image of a solid with respect to `plane`.
+///
+/// Since the reflection reverses the orientation, all faces of the result are inverted so that
+/// the result is oriented outward again.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// let plane = Plane::new(
+///     Point3::origin(),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+/// );
+/// let mirrored = builder::mirror(&cube, &plane);
+/// assert!(Solid::try_new(mirrored.boundaries().clone()).is_ok());
+///
+/// // the normals are directed outward.
+/// let center = Point3::new(0.5, 0.5, -0.5);
+/// for face in mirrored.face_iter() {
+///     let surface = face.oriented_surface();
+///     let p = surface.subs(0.5, 0.5);
+///     assert!(surface.normal(0.5, 0.5).dot(p - center) > 0.0);
+/// }
+/// ```
+pub fn mirror<C, S>(solid: &Solid<C, S>, plane: &Plane) -> Solid<C, S>
+where Solid<C, S>: Mapped<Matrix4> {
+    let (n, o) = (plane.normal(), plane.origin());
+    let outer = Matrix3::from_cols(n * n.x, n * n.y, n * n.z);
+    let mat = Matrix4::from(Matrix3::identity() - outer * 2.0);
+    let trsl = Matrix4::from_translation(n * (2.0 * n.dot(o.to_vec())));
+    let mut mirrored = transformed(solid, trsl * mat);
+    mirrored.not();
+    mirrored
+}
+
+/// Returns `count` copies of a vertex, an edge, a wire, a face, a shell or a solid,
+/// translated by `step` one after another.
+///
+/// The first copy is not translated. If `count == 0`, returns the empty vector.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let pattern = builder::linear_pattern(&v, Vector3::new(0.0, 2.0, 0.0), 4);
+/// assert_eq!(pattern.len(), 4);
+/// for (i, v) in pattern.iter().enumerate() {
+///     assert_near!(v.point(), Point3::new(1.0, 2.0 * i as f64, 0.0));
+/// }
+/// ```
+pub fn linear_pattern<T: Mapped<Matrix4>>(elem: &T, step: Vector3, count: usize) -> Vec<T> {
+    (0..count)
+        .map(|i| translated(elem, step * i as f64))
+        .collect()
+}
+
+/// Returns `count` copies of a vertex, an edge, a wire, a face, a shell or a solid,
+/// arranged evenly around the axis through `origin`.
+///
+/// The `i`-th copy is rotated by `2π i / count` rad. If `count == 0`, returns the empty vector.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use std::f64::consts::PI;
+///
+/// let v = builder::vertex(Point3::new(2.0, 0.0, 0.0));
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// let pattern = builder::circular_pattern(&cube, Point3::origin(), Vector3::unit_z(), 6);
+/// assert_eq!(pattern.len(), 6);
+/// let centroid = |solid: &Solid| {
+///     let points = solid.vertex_iter().map(|v| v.point()).collect::<Vec<_>>();
+///     points.iter().fold(Point3::origin(), |c, p| c + p.to_vec()) / points.len() as f64
+/// };
+/// let c0 = centroid(&cube);
+/// for (i, solid) in pattern.iter().enumerate() {
+///     let mat = Matrix3::from_angle_z(Rad(PI / 3.0 * i as f64));
+///     assert_near!(centroid(solid), Point3::from_vec(mat * c0.to_vec()));
+/// }
+/// ```
+pub fn circular_pattern<T: Mapped<Matrix4>>(
+    elem: &T,
+    origin: Point3,
+    axis: Vector3,
+    count: usize,
+) -> Vec<T> {
+    (0..count)
+        .map(|i| {
+            let angle = PI * 2.0 * (i as f64 / count as f64);
+            rotated(elem, origin, axis, angle)
+        })
+        .collect()
+}
+
 /// Sweeps a vertex, an edge, a wire, a face, or a shell by a vector.
 ///
 /// # Examples
@@ -710,6 +812,446 @@ where
     shell
 }
 
+/// Fillets a convex edge of a solid by the rolling ball with `radius`.
+///
+/// The edge is replaced by a cylindrical face, the two adjacent faces are trimmed along the tangent lines,
+/// and the corners of the faces at the ends of the edge are rounded by arcs.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// // a cube whose side length is 2
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x() * 2.0);
+/// let f = builder::tsweep(&e, Vector3::unit_y() * 2.0);
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z() * 2.0);
+///
+/// let edge_id = cube.edge_iter().next().unwrap().id();
+/// let filleted = builder::fillet_edge(&cube, edge_id, 1.0).unwrap();
+/// let shell = &filleted.boundaries()[0];
+/// // the fillet face is added.
+/// assert_eq!(shell.len(), 7);
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// assert!(filleted.is_geometric_consistent());
+/// ```
+/// # Failures
+/// - If the faces around the edge are not planes, or some end of the edge is shared by more than three faces,
+/// then returns `Error::UnsupportedFilletEdge`.
+/// - If the edge is concave, then returns `Error::ConcaveEdge`.
+/// - If `radius` is not positive or is too large for the adjacent faces, then returns `Error::InvalidFilletRadius`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+///
+/// // an L-shaped prism
+/// let v = builder::vertices([
+///     (0.0, 0.0, 0.0),
+///     (2.0, 0.0, 0.0),
+///     (2.0, 1.0, 0.0),
+///     (1.0, 1.0, 0.0),
+///     (1.0, 2.0, 0.0),
+///     (0.0, 2.0, 0.0),
+/// ]);
+/// let wire: Wire = (0..6).map(|i| builder::line(&v[i], &v[(i + 1) % 6])).collect();
+/// let face: Face = builder::try_attach_plane(vec![wire]).unwrap();
+/// let solid: Solid = builder::tsweep(&face, Vector3::unit_z());
+///
+/// // the vertical edge whose midpoint is `p`
+/// let find_edge = |p: Point3| {
+///     solid
+///         .edge_iter()
+///         .find(|edge| edge.front().point().midpoint(edge.back().point()).near(&p))
+///         .unwrap()
+///         .id()
+/// };
+///
+/// // the edge at the inner corner is concave.
+/// let edge_id = find_edge(Point3::new(1.0, 1.0, 0.5));
+/// assert_eq!(
+///     builder::fillet_edge(&solid, edge_id, 0.5).unwrap_err(),
+///     Error::ConcaveEdge,
+/// );
+///
+/// // the radius is too large.
+/// let edge_id = find_edge(Point3::new(2.0, 0.0, 0.5));
+/// assert_eq!(
+///     builder::fillet_edge(&solid, edge_id, 2.0).unwrap_err(),
+///     Error::InvalidFilletRadius,
+/// );
+/// assert!(builder::fillet_edge(&solid, edge_id, 0.5).is_ok());
+/// ```
+#[inline(always)]
+pub fn fillet_edge(
+    solid: &crate::Solid,
+    edge_id: crate::EdgeID,
+    radius: f64,
+) -> Result<crate::Solid> {
+    crate::fillet::fillet_edge(solid, edge_id, radius)
+}
+
+/// Lofts the sections `wires` by B-spline surfaces of degree `degree` in the lofting direction.
+///
+/// The `j`-th edges of all wires are interpolated by one face, so the wires must have the same
+/// number of edges. The boundary of the returned shell consists of the first and the last wires,
+/// and caps are not generated. To make a solid, attach planes to them by [`try_attach_plane`].
+/// If `degree` is zero, it is regarded as one.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use std::f64::consts::PI;
+///
+/// let circle = |radius: f64, z: f64| -> Wire {
+///     let v = builder::vertex(Point3::new(radius, 0.0, z));
+///     builder::rsweep(&v, Point3::new(0.0, 0.0, z), Vector3::unit_z(), Rad(2.0 * PI))
+/// };
+/// // a barrel
+/// let wires = [circle(1.0, 0.0), circle(1.5, 1.0), circle(1.0, 2.0)];
+/// let barrel = builder::loft(&wires, 2).unwrap();
+/// assert_eq!(barrel.len(), 3);
+/// assert_eq!(barrel.shell_condition(), ShellCondition::Oriented);
+/// assert_eq!(barrel.extract_boundaries().len(), 2);
+/// assert!(barrel.is_geometric_consistent());
+///
+/// // the barrel passes through the middle circle
+/// for face in barrel.iter() {
+///     let surface = face.surface();
+///     for i in 0..=10 {
+///         let p = surface.subs(i as f64 / 10.0, 0.5);
+///         assert_near!(p.z, 1.0);
+///         assert_near!(f64::hypot(p.x, p.y), 1.5);
+///     }
+/// }
+/// ```
+/// # Failures
+/// - If the number of wires is not more than `degree`, then returns `Error::TooFewSections`.
+/// - If the wires have different numbers of edges, then returns `Error::NotSameNumberOfEdges`.
+/// - If some wire is not continuous, closed and open wires are mixed, or some sections coincide,
+/// then returns `Error::InconsistentSections`.
+/// - If some edge is an intersection curve, then returns `Error::IntersectionCurveSection`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+/// use std::f64::consts::PI;
+///
+/// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let circle: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(2.0 * PI));
+/// let moved = builder::translated(&circle, Vector3::unit_z());
+/// assert_eq!(builder::loft(&[circle.clone(), moved.clone()], 2).unwrap_err(), Error::TooFewSections);
+///
+/// let v = builder::vertices([(1.0, 0.0, 2.0), (-1.0, 0.0, 2.0)]);
+/// let line: Wire = vec![builder::line(&v[0], &v[1])].into();
+/// assert_eq!(
+///     builder::loft(&[circle, moved, line], 2).unwrap_err(),
+///     Error::NotSameNumberOfEdges,
+/// );
+///
+/// // the intersection curve of the planes z = 0 and y = 0
+/// let v = builder::vertices([(0.0, 0.0, 0.0), (1.0, 0.0, 0.0)]);
+/// let plane = |p: Point3| Surface::Plane(Plane::new(Point3::origin(), Point3::new(1.0, 0.0, 0.0), p));
+/// let leader = BSplineCurve::new(KnotVec::bezier_knot(1), vec![v[0].point(), v[1].point()]);
+/// let curve = IntersectionCurve::new(
+///     plane(Point3::new(0.0, 1.0, 0.0)),
+///     plane(Point3::new(0.0, 0.0, 1.0)),
+///     leader,
+/// );
+/// let wire: Wire = vec![Edge::new(&v[0], &v[1], Curve::from(curve))].into();
+/// let moved = builder::translated(&wire, Vector3::unit_z());
+/// assert_eq!(
+///     builder::loft(&[wire, moved], 1).unwrap_err(),
+///     Error::IntersectionCurveSection,
+/// );
+/// ```
+#[inline(always)]
+pub fn loft(wires: &[crate::Wire], degree: usize) -> Result<crate::Shell> {
+    crate::loft::loft(wires, degree)
+}
+
+/// Hollows out a solid with the uniform wall thickness `thickness`, and opens the faces `open_faces`.
+///
+/// Each face except the open ones is offset inward by `thickness`, and each open face gets a hole
+/// whose boundary is the inner wire. If there are no open faces, the result has an inner void.
+/// Now, only the solids whose faces are all planes are supported.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// // make a bucket by opening the top face
+/// let top = cube.boundaries()[0]
+///     .iter()
+///     .find(|face| face.vertex_iter().all(|v| v.point().z.near(&1.0)))
+///     .unwrap()
+///     .id();
+/// let bucket = builder::shell_solid(&cube, 0.1, &[top]).unwrap();
+/// assert_eq!(bucket.boundaries().len(), 1);
+/// let shell = &bucket.boundaries()[0];
+/// assert_eq!(shell.len(), 11);
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// assert!(bucket.is_geometric_consistent());
+///
+/// // without open faces, the solid has a void.
+/// let hollow = builder::shell_solid(&cube, 0.1, &[]).unwrap();
+/// assert_eq!(hollow.boundaries().len(), 2);
+/// ```
+/// # Failures
+/// - If `thickness` is not positive, or is so large that some offset edge is collapsed or flipped,
+/// then returns `Error::InvalidThickness`.
+/// - If some face is not a plane, some open faces are adjacent, or the offset planes do not meet
+/// at some vertex, then returns `Error::UnsupportedShelling`.
+/// ```
+/// use truck_modeling::{*, errors::Error};
+///
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+/// assert_eq!(builder::shell_solid(&cube, 0.6, &[]).unwrap_err(), Error::InvalidThickness);
+/// ```
+#[inline(always)]
+pub fn shell_solid(
+    solid: &crate::Solid,
+    thickness: f64,
+    open_faces: &[crate::FaceID],
+) -> Result<crate::Solid> {
+    crate::hollow::shell_solid(solid, thickness, open_faces)
+}
+
+/// Sweeps a closed planar wire `profile` along the helix around the axis through `origin`.
+///
+/// The profile rotates `turns` times around `axis`, advancing `pitch` for each turn. The helix is
+/// right-handed if `pitch` is positive, and left-handed if negative. The side faces are B-spline
+/// surfaces which interpolate the profiles sampled along the helix, and both ends are capped by planes.
+/// # Remarks
+/// - `axis` must be normalized. If not, panics occurs in debug mode.
+/// - If `|pitch|` is smaller than the width of the profile along `axis`, the result self-intersects.
+/// # Panics
+/// Panics if `turns` is zero or if `profile` is not a closed planar wire.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use std::f64::consts::PI;
+///
+/// // a coil spring
+/// let v = builder::vertex(Point3::new(3.5, 0.0, 0.0));
+/// let circle = builder::rsweep(&v, Point3::new(3.0, 0.0, 0.0), Vector3::unit_y(), Rad(2.0 * PI));
+/// let coil = builder::helical_sweep(&circle, Point3::origin(), Vector3::unit_z(), 2.0, 3.0);
+/// let shell = &coil.boundaries()[0];
+/// // three side faces and two caps
+/// assert_eq!(shell.len(), 5);
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// // the end profile is 6 above the start one.
+/// let end = &shell[4];
+/// end.vertex_iter().for_each(|v| assert!(f64::abs(v.point().z - 6.0) <= 0.5 + TOLERANCE));
+/// ```
+pub fn helical_sweep(
+    profile: &crate::Wire,
+    origin: Point3,
+    axis: Vector3,
+    pitch: f64,
+    turns: f64,
+) -> crate::Solid {
+    debug_assert!(axis.magnitude().near(&1.0));
+    assert!(!turns.so_small(), "the number of turns must not be zero: {turns}");
+    const DIVISION_PER_TURN: f64 = 16.0;
+    let division = usize::max((turns.abs() * DIVISION_PER_TURN).ceil() as usize, 3);
+    let motion = |t: f64| {
+        let mat0 = Matrix4::from_translation(-origin.to_vec());
+        let mat1 = Matrix4::from_axis_angle(axis, PI * 2.0 * turns * t);
+        let mat2 = Matrix4::from_translation(origin.to_vec() + axis * pitch * turns * t);
+        mat2 * mat1 * mat0
+    };
+    let wires = (0..=division)
+        .map(|i| match i {
+            0 => profile.clone(),
+            _ => transformed(profile, motion(i as f64 / division as f64)),
+        })
+        .collect::<Vec<_>>();
+    let mut shell = loft(&wires, 3).expect("the profile must be a continuous wire");
+    let cap0: crate::Face = try_attach_plane(vec![wires[0].inverse()])
+        .expect("the profile must be a closed planar wire");
+    let cap1: crate::Face = try_attach_plane(vec![wires[division].clone()]).unwrap();
+
+    // the start cap must face backward along the helix.
+    let points = profile.vertex_iter().map(|v| v.point()).collect::<Vec<_>>();
+    let center = points.iter().fold(Point3::origin(), |c, p| c + p.to_vec()) / points.len() as f64;
+    let tangent = axis.cross(center - origin) * (2.0 * PI.0) + axis * pitch;
+    let inverted = cap0.oriented_surface().normal(0.0, 0.0).dot(tangent) * turns > 0.0;
+    shell.push(cap0);
+    shell.push(cap1);
+    if inverted {
+        shell.face_iter_mut().for_each(|face| {
+            face.invert();
+        });
+    }
+    crate::Solid::new(vec![shell])
+}
+
+/// Sweeps a closed planar wire `profile` along the wire `path`.
+///
+/// The profile should be placed at the front vertex of the path, perpendicular to the path. It is
+/// translated along the straight edges and carried by the moving frames `frame` along the curved
+/// ones. At each corner of the path, the profile is rotated by the minimal rotation between the
+/// tangents, and the adjacent extrusions are mitred, i.e. cut by the plane bisecting the corner.
+/// If `path` is open, both ends are capped by planes.
+/// # Remarks
+/// If `path` is closed and not planar, the twist of the profile accumulated along the path is
+/// absorbed in the last edge.
+/// # Failures
+/// - If `profile` is not a closed planar wire, returns the error of closedness or planarity.
+/// - If `path` is empty or not continuous, or it is a closed wire with only one edge,
+/// returns `Error::InvalidSweepPath`.
+/// - If the profile is too large for a corner, the swept profile intersects itself at the inside
+/// of the corner, and `Error::SelfIntersectingSweep` is returned. Turning back is also an error.
+/// # Examples
+/// ```
+/// use truck_modeling::{*, errors::Error};
+///
+/// // a square profile on the yz-plane
+/// let v = builder::vertex(Point3::new(0.0, -0.5, -0.5));
+/// let e = builder::tsweep(&v, Vector3::unit_y());
+/// let square: Face = builder::tsweep(&e, Vector3::unit_z());
+/// let profile = square.boundaries()[0].clone();
+///
+/// // an L-shaped path
+/// let v = builder::vertices([(0.0, 0.0, 0.0), (3.0, 0.0, 0.0), (3.0, 3.0, 0.0)]);
+/// let path: Wire = vec![builder::line(&v[0], &v[1]), builder::line(&v[1], &v[2])].into();
+///
+/// let elbow = builder::sweep_along_path(&profile, &path, builder::FrameMode::RotationMinimizing)
+///     .unwrap();
+/// let shell = &elbow.boundaries()[0];
+/// // four faces for each segment and two caps
+/// assert_eq!(shell.len(), 10);
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// // the outer corner of the miter
+/// assert!(shell.vertex_iter().any(|v| v.point().near(&Point3::new(3.5, -0.5, 0.5))));
+///
+/// // the profile is too large for the corner.
+/// let large = builder::scaled(&profile, Point3::origin(), Vector3::new(1.0, 8.0, 8.0));
+/// assert_eq!(
+///     builder::sweep_along_path(&large, &path, builder::FrameMode::Frenet).unwrap_err(),
+///     Error::SelfIntersectingSweep,
+/// );
+/// ```
+#[inline(always)]
+pub fn sweep_along_path(
+    profile: &crate::Wire,
+    path: &crate::Wire,
+    frame: FrameMode,
+) -> Result<crate::Solid> {
+    crate::path_sweep::sweep_along_path(profile, path, frame)
+}
+
+/// Tilts the faces `faces` of a solid by `angle` for pulling out the solid along `pull_direction`.
+///
+/// Each face is rotated about its intersection line with the neutral plane, which is perpendicular
+/// to `pull_direction` and passes through the lowest point of the solid. If `angle` is positive,
+/// the faces lean inward as going along `pull_direction`. The vertices and the edges are moved so
+/// that the topology of the solid is kept. Now, only the solids whose faces are all planes are supported.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let mut solid: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// // draft the side walls
+/// let walls = solid
+///     .face_iter()
+///     .filter(|face| face.vertex_iter().any(|v| v.point().z.near(&0.0)))
+///     .filter(|face| face.vertex_iter().any(|v| v.point().z.near(&1.0)))
+///     .map(|face| face.id())
+///     .collect::<Vec<_>>();
+/// assert_eq!(walls.len(), 4);
+/// let angle = Rad::from(Deg(3.0));
+/// builder::apply_draft(&mut solid, &walls, Vector3::unit_z(), angle).unwrap();
+///
+/// let shrink = f64::tan(angle.0);
+/// solid.vertex_iter().for_each(|v| {
+///     let p = v.point();
+///     match p.z.near(&0.0) {
+///         true => assert!(p.x.near(&0.0) || p.x.near(&1.0)),
+///         false => assert!(p.x.near(&shrink) || p.x.near(&(1.0 - shrink))),
+///     }
+/// });
+/// assert_eq!(solid.boundaries()[0].shell_condition(), ShellCondition::Closed);
+/// assert!(solid.is_geometric_consistent());
+/// ```
+/// # Failures
+/// - If some face is not a plane, some drafted face is perpendicular to `pull_direction`, or
+/// the planes do not meet at some vertex, then returns `Error::UnsupportedDraft`.
+/// - If some edge is collapsed or flipped, then returns `Error::TooLargeDraftAngle`.
+///
+/// In both cases, `solid` is not changed.
+#[inline(always)]
+pub fn apply_draft(
+    solid: &mut crate::Solid,
+    faces: &[crate::FaceID],
+    pull_direction: Vector3,
+    angle: Rad<f64>,
+) -> Result<()> {
+    crate::draft::apply_draft(solid, faces, pull_direction, angle)
+}
+
+/// Returns a face trimming `surface` by the outer boundary `outer` and the holes `inners`.
+///
+/// The wires are oriented so that `outer` goes counterclockwise and `inners` go clockwise in the
+/// parameter space of `surface`. Hence, the normal of the face is the same as that of `surface`.
+/// # Examples
+/// ```
+/// use truck_modeling::{*, errors::Error};
+/// let v = builder::vertices([
+///     (-1.0, -1.0, 0.0),
+///     (1.0, -1.0, 0.0),
+///     (1.0, 1.0, 0.0),
+///     (-1.0, 1.0, 0.0),
+/// ]);
+/// let outer: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+/// let v = builder::vertex((0.5, 0.0, 0.0));
+/// let inner: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+///
+/// // The parameter range of the plane is the unit square, which covers the wires.
+/// let o = Point3::new(-2.0, -2.0, 0.0);
+/// let (p, q) = (Point3::new(2.0, -2.0, 0.0), Point3::new(-2.0, 2.0, 0.0));
+/// let plane = Plane::new(o, p, q);
+/// let face = builder::trimmed_face(plane.into(), outer.clone(), vec![inner.clone()]).unwrap();
+/// assert_eq!(face.boundaries().len(), 2);
+/// assert_eq!(face.boundaries()[0][0].id(), outer[0].id());
+/// // The counterclockwise circle is inverted to make a hole.
+/// let (hole, last) = (&face.boundaries()[1], &inner[inner.len() - 1]);
+/// assert_eq!(hole[0].id(), last.id());
+/// assert_ne!(hole[0].orientation(), last.orientation());
+///
+/// // the wires are not on the plane
+/// let plane = Plane::new(o + Vector3::unit_z(), p + Vector3::unit_z(), q + Vector3::unit_z());
+/// assert_eq!(
+///     builder::trimmed_face(plane.into(), outer, vec![inner]).unwrap_err(),
+///     Error::WireNotOnSurface,
+/// );
+/// ```
+/// # Failures
+/// - If some wire is not closed or some wires share a vertex, then returns the corresponding
+/// topological error.
+/// - If some point of the wires does not lie on `surface` or is out of its parameter range,
+/// then returns `Error::WireNotOnSurface`.
+/// - If the wires intersect themselves or each other in the parameter space, then returns
+/// `Error::SelfIntersectingWire`.
+///
+/// # Remarks
+/// The wires are checked by the polygons sampling the edges, and must not go across the seams of
+/// periodic surfaces.
+#[inline(always)]
+pub fn trimmed_face(
+    surface: crate::Surface,
+    outer: crate::Wire,
+    inners: Vec<crate::Wire>,
+) -> Result<crate::Face> {
+    crate::trimmed::trimmed_face(surface, outer, inners)
+}
+
 #[cfg(test)]
 mod partial_torus {
     use crate::*;
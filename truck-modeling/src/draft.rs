@@ -0,0 +1,85 @@
+use crate::{errors::Error, geom_impls, *};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+pub(super) fn apply_draft(
+    solid: &mut Solid,
+    faces: &[FaceID],
+    pull_direction: Vector3,
+    angle: Rad<f64>,
+) -> Result<()> {
+    let pull = pull_direction.normalize();
+    let drafted = faces.iter().copied().collect::<HashSet<_>>();
+    // the neutral plane is the lowest plane along the pull direction.
+    let neutral = solid
+        .vertex_iter()
+        .map(|v| pull.dot(v.point().to_vec()))
+        .min_by(f64::total_cmp)
+        .unwrap_or(0.0);
+
+    let mut new_faces = Vec::new();
+    let mut vertex_planes = HashMap::<VertexID, (Vertex, Vec<(Vector3, f64)>)>::default();
+    for face in solid.face_iter() {
+        let Surface::Plane(plane) = face.surface() else {
+            return Err(Error::UnsupportedDraft);
+        };
+        let plane = match drafted.contains(&face.id()) {
+            true => {
+                let n = match face.orientation() {
+                    true => plane.normal(),
+                    false => -plane.normal(),
+                };
+                let axis = n.cross(pull);
+                if axis.so_small() {
+                    return Err(Error::UnsupportedDraft);
+                }
+                // a point on the intersection line of the face and the neutral plane
+                let (nd, c) = (n.dot(pull), n.dot(plane.origin().to_vec()));
+                let det = 1.0 - nd * nd;
+                let pivot = n * (c - nd * neutral) / det + pull * (neutral - nd * c) / det;
+                let mat0 = Matrix4::from_translation(-pivot);
+                let mat1 = Matrix4::from_axis_angle(axis.normalize(), angle);
+                let mat2 = Matrix4::from_translation(pivot);
+                plane.transformed(mat2 * mat1 * mat0)
+            }
+            false => plane,
+        };
+        let n = plane.normal();
+        let constant = n.dot(plane.origin().to_vec());
+        face.vertex_iter().for_each(|v| {
+            let entry = vertex_planes.entry(v.id());
+            let planes = &mut entry.or_insert_with(|| (v.clone(), Vec::new())).1;
+            planes.push((n, constant));
+        });
+        new_faces.push((face.clone(), plane));
+    }
+
+    let new_points = vertex_planes
+        .into_values()
+        .map(|(v, planes)| {
+            let point = geom_impls::intersection_point(&planes).ok_or(Error::UnsupportedDraft)?;
+            Ok((v.id(), (v, point)))
+        })
+        .collect::<Result<HashMap<_, _>>>()?;
+    let mut new_edges = HashMap::default();
+    for edge in solid.edge_iter() {
+        let Curve::Line(_) = edge.curve() else {
+            return Err(Error::UnsupportedDraft);
+        };
+        let (v0, v1) = (edge.absolute_front(), edge.absolute_back());
+        let (p0, p1) = (new_points[&v0.id()].1, new_points[&v1.id()].1);
+        // the edge is collapsed or flipped if the angle is too large.
+        if (p1 - p0).dot(v1.point() - v0.point()) < TOLERANCE {
+            return Err(Error::TooLargeDraftAngle);
+        }
+        new_edges.insert(edge.id(), (edge, Line(p0, p1)));
+    }
+
+    new_points.into_values().for_each(|(v, p)| v.set_point(p));
+    new_edges
+        .into_values()
+        .for_each(|(edge, line)| edge.set_curve(line.into()));
+    new_faces
+        .into_iter()
+        .for_each(|(face, plane)| face.set_surface(plane.into()));
+    Ok(())
+}
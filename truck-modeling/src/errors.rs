@@ -14,6 +14,69 @@ pub enum Error {
     /// cf. [`builder::try_wire_homotopy`](../builder/fn.try_wire_homotopy.html)
     #[error("The wires must contain the same number of edges to create a homotopy.")]
     NotSameNumberOfEdges,
+    /// the specified edge is not contained in the solid.
+    #[error("The edge is not contained in the solid.")]
+    EdgeNotFound,
+    /// tried to fillet an edge whose neighborhood is not supported.
+    /// cf. [`builder::fillet_edge`](../builder/fn.fillet_edge.html)
+    #[error("The faces around the edge must be planes, and each end of the edge must be shared by exactly three faces.")]
+    UnsupportedFilletEdge,
+    /// tried to fillet a concave edge.
+    /// cf. [`builder::fillet_edge`](../builder/fn.fillet_edge.html)
+    #[error("Cannot fillet a concave edge.")]
+    ConcaveEdge,
+    /// the fillet radius is not positive, or is too large for the faces adjacent to the edge.
+    /// cf. [`builder::fillet_edge`](../builder/fn.fillet_edge.html)
+    #[error("The fillet radius must be positive and small enough for the adjacent faces.")]
+    InvalidFilletRadius,
+    /// the number of sections is not more than the degree of lofting.
+    /// cf. [`builder::loft`](../builder/fn.loft.html)
+    #[error("The number of sections must be more than the degree of lofting.")]
+    TooFewSections,
+    /// the sections of lofting are not continuous, coincide, or are mixed closed and open wires.
+    /// cf. [`builder::loft`](../builder/fn.loft.html)
+    #[error("The sections must be distinct continuous wires, and must be all closed or all open.")]
+    InconsistentSections,
+    /// some edge of the sections of lofting is an intersection curve, which cannot be lofted.
+    /// cf. [`builder::loft`](../builder/fn.loft.html)
+    #[error("The edges of the sections must not be intersection curves.")]
+    IntersectionCurveSection,
+    /// the thickness is not positive, or is too large for the walls.
+    /// cf. [`builder::shell_solid`](../builder/fn.shell_solid.html)
+    #[error("The thickness must be positive and smaller than the walls.")]
+    InvalidThickness,
+    /// tried to shell a solid which is not supported.
+    /// cf. [`builder::shell_solid`](../builder/fn.shell_solid.html)
+    #[error("The faces must be planes, the open faces must not be adjacent, and the offset planes must meet at each vertex.")]
+    UnsupportedShelling,
+    /// tried to draft a solid which is not supported.
+    /// cf. [`builder::apply_draft`](../builder/fn.apply_draft.html)
+    #[error("The faces must be planes, the drafted faces must not be perpendicular to the pull direction, and the planes must meet at each vertex.")]
+    UnsupportedDraft,
+    /// the draft angle is too large for the solid.
+    /// cf. [`builder::apply_draft`](../builder/fn.apply_draft.html)
+    #[error("The draft angle is too large for the solid.")]
+    TooLargeDraftAngle,
+    /// some point of the wire does not lie on the surface or its parameter domain.
+    /// cf. [`builder::trimmed_face`](../builder/fn.trimmed_face.html)
+    #[error("The wires must lie on the surface and in its parameter domain.")]
+    WireNotOnSurface,
+    /// the trimming wires intersect themselves or each other in the parameter space.
+    /// cf. [`builder::trimmed_face`](../builder/fn.trimmed_face.html)
+    #[error("The trimming wires must not intersect in the parameter space.")]
+    SelfIntersectingWire,
+    /// the constraints of the sketch cannot be satisfied at the same time.
+    /// cf. [`sketch::Sketch::solve`](../sketch/struct.Sketch.html#method.solve)
+    #[error("The constraints of the sketch are inconsistent.")]
+    InconsistentConstraints,
+    /// the path of sweeping is empty, not continuous, or a closed wire with only one edge.
+    /// cf. [`builder::sweep_along_path`](../builder/fn.sweep_along_path.html)
+    #[error("The path must be a continuous wire, and a closed path must have two edges at least.")]
+    InvalidSweepPath,
+    /// the swept profile intersects itself at the inside of a corner of the path.
+    /// cf. [`builder::sweep_along_path`](../builder/fn.sweep_along_path.html)
+    #[error("The profile is too large for the corners of the path.")]
+    SelfIntersectingSweep,
 }
 
 #[test]
@@ -31,6 +94,7 @@ fn print_messages() {
     )
     .unwrap();
     writeln!(&mut std::io::stderr(), "{}\n", Error::WireNotInOnePlane).unwrap();
+    writeln!(&mut std::io::stderr(), "{}\n", Error::UnsupportedFilletEdge).unwrap();
     writeln!(
         &mut std::io::stderr(),
         "*******************************************************"
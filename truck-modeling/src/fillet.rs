@@ -0,0 +1,212 @@
+use crate::{errors::Error, geom_impls, *};
+use rustc_hash::FxHashMap as HashMap;
+
+pub(super) fn fillet_edge(solid: &Solid, edge_id: EdgeID, radius: f64) -> Result<Solid> {
+    if radius < TOLERANCE {
+        return Err(Error::InvalidFilletRadius);
+    }
+    let mut shells = solid.boundaries().clone();
+    let shell = shells
+        .iter_mut()
+        .find(|shell| shell.edge_iter().any(|edge| edge.id() == edge_id))
+        .ok_or(Error::EdgeNotFound)?;
+    *shell = fillet_shell(shell, edge_id, radius)?;
+    Ok(Solid::try_new(shells)?)
+}
+
+/// the index of the face, the oriented boundary wire and the position of the edge in the wire
+type EdgePosition = (usize, Wire, usize);
+
+fn edge_positions(shell: &Shell, edge_id: EdgeID) -> Vec<EdgePosition> {
+    shell
+        .face_iter()
+        .enumerate()
+        .flat_map(|(i, face)| {
+            face.boundaries().into_iter().filter_map(move |wire| {
+                let idx = wire.edge_iter().position(|edge| edge.id() == edge_id)?;
+                Some((i, wire, idx))
+            })
+        })
+        .collect()
+}
+
+fn plane_normal(face: &Face) -> Result<Vector3> {
+    match face.oriented_surface() {
+        Surface::Plane(plane) => Ok(plane.normal()),
+        _ => Err(Error::UnsupportedFilletEdge),
+    }
+}
+
+/// the projection onto the plane through `origin` with `normal` along `axis`
+fn projection(axis: Vector3, origin: Point3, normal: Vector3) -> Matrix4 {
+    let k = axis.dot(normal);
+    let outer = Matrix3::from_cols(axis * normal.x, axis * normal.y, axis * normal.z);
+    let mat = Matrix3::identity() - outer / k;
+    Matrix4::from_translation(axis * normal.dot(origin.to_vec()) / k) * Matrix4::from(mat)
+}
+
+/// Cuts `edge` at `point`, and returns the part which does not contain `vertex`.
+fn trimmed_edge(edge: &Edge, vertex: &Vertex, point: Point3) -> Result<(Vertex, Edge)> {
+    let mut curve0 = edge.curve();
+    let (t0, t1) = curve0.range_tuple();
+    let t = curve0
+        .search_parameter(point, None, 100)
+        .filter(|t| t0 + TOLERANCE < *t && *t < t1 - TOLERANCE)
+        .ok_or(Error::InvalidFilletRadius)?;
+    let curve1 = curve0.cut(t);
+    let new_vertex = Vertex::new(point);
+    let new_edge = match edge.absolute_front() == vertex {
+        true => Edge::new(&new_vertex, edge.absolute_back(), curve1),
+        false => Edge::new(edge.absolute_front(), &new_vertex, curve0),
+    };
+    Ok((new_vertex, new_edge))
+}
+
+/// Returns the face which has `edge_a` and `edge_b` as adjacent edges.
+fn end_face(shell: &Shell, face_a: usize, edge_a: &Edge, edge_b: &Edge) -> Result<usize> {
+    let positions = edge_positions(shell, edge_a.id());
+    let (i, wire, idx) = positions
+        .iter()
+        .find(|(i, _, _)| *i != face_a)
+        .ok_or(Error::UnsupportedFilletEdge)?;
+    let prev = &wire[(idx + wire.len() - 1) % wire.len()];
+    let next = &wire[(idx + 1) % wire.len()];
+    match positions.len() == 2 && (prev.id() == edge_b.id() || next.id() == edge_b.id()) {
+        true => Ok(*i),
+        false => Err(Error::UnsupportedFilletEdge),
+    }
+}
+
+fn fillet_shell(shell: &Shell, edge_id: EdgeID, radius: f64) -> Result<Shell> {
+    let positions = edge_positions(shell, edge_id);
+    let [(a, wire_a, idx_a), (b, wire_b, idx_b)] = positions.as_slice() else {
+        return Err(Error::UnsupportedFilletEdge);
+    };
+    let (a, b) = (*a, *b);
+    let (len_a, len_b) = (wire_a.len(), wire_b.len());
+    let edge = &wire_a[*idx_a];
+    let (v0, v1) = (edge.front(), edge.back());
+    // edges of the adjacent faces around the filleted edge
+    let (edge_a0, edge_a1) = (
+        &wire_a[(idx_a + len_a - 1) % len_a],
+        &wire_a[(idx_a + 1) % len_a],
+    );
+    let (edge_b0, edge_b1) = (
+        &wire_b[(idx_b + 1) % len_b],
+        &wire_b[(idx_b + len_b - 1) % len_b],
+    );
+    if a == b || edge_a0.id() == edge_b0.id() || edge_a1.id() == edge_b1.id() {
+        return Err(Error::UnsupportedFilletEdge);
+    }
+
+    let (na, nb) = (plane_normal(&shell[a])?, plane_normal(&shell[b])?);
+    let (p0, p1) = (v0.point(), v1.point());
+    let axis = (p1 - p0).normalize();
+    if na.cross(axis).dot(nb) > -TOLERANCE {
+        return Err(Error::ConcaveEdge);
+    }
+    // the center of the rolling ball relative to the edge
+    let center = -(na + nb) * radius / (1.0 + na.dot(nb));
+    let (pa, pb) = (p0 + center + na * radius, p0 + center + nb * radius);
+    let transit = p0 + center + (na + nb).normalize() * radius;
+    let section: Curve = geom_impls::circle_arc_by_three_points(pa, pb, transit).to_same_geometry();
+
+    let fillet_end = |vertex: &Vertex, edge_a: &Edge, edge_b: &Edge| -> Result<_> {
+        let e = end_face(shell, a, edge_a, edge_b)?;
+        let ne = plane_normal(&shell[e])?;
+        if axis.dot(ne).so_small() {
+            return Err(Error::UnsupportedFilletEdge);
+        }
+        let mat = projection(axis, vertex.point(), ne);
+        let (va, new_edge_a) = trimmed_edge(edge_a, vertex, mat.transform_point(pa))?;
+        let (vb, new_edge_b) = trimmed_edge(edge_b, vertex, mat.transform_point(pb))?;
+        let arc = Edge::new(&va, &vb, section.transformed(mat));
+        Ok((new_edge_a, new_edge_b, arc))
+    };
+    let (new_edge_a0, new_edge_b0, arc0) = fillet_end(v0, edge_a0, edge_b0)?;
+    let (new_edge_a1, new_edge_b1, arc1) = fillet_end(v1, edge_a1, edge_b1)?;
+
+    let line = |edge0: &Edge, edge1: &Edge| {
+        let (v0, v1) = (edge0.front(), edge1.front());
+        Edge::new(v0, v1, Line(v0.point(), v1.point()).into())
+    };
+    let line_a = line(&arc0, &arc1);
+    let line_b = line(&arc0.inverse(), &arc1.inverse());
+    let fillet_wire = wire![
+        line_a.inverse(),
+        arc0.clone(),
+        line_b.clone(),
+        arc1.inverse()
+    ];
+    let surface: Surface = HomotopySurface::new(arc0.curve(), arc1.curve()).to_same_geometry();
+    let fillet_face = Face::try_new(vec![fillet_wire], surface)?;
+
+    let mut edge_map = HashMap::default();
+    edge_map.insert(edge_a0.id(), new_edge_a0);
+    edge_map.insert(edge_a1.id(), new_edge_a1);
+    edge_map.insert(edge_b0.id(), new_edge_b0);
+    edge_map.insert(edge_b1.id(), new_edge_b1);
+    let arcs = [arc0, arc1];
+    let mut new_shell = shell
+        .face_iter()
+        .enumerate()
+        .map(|(i, face)| {
+            if i != a && i != b {
+                return replaced_face(face, &edge_map, &arcs);
+            }
+            let new_edge = if i == a { &line_a } else { &line_b };
+            let new_edge = match edge.orientation() {
+                true => new_edge.clone(),
+                false => new_edge.inverse(),
+            };
+            let mut edge_map = edge_map.clone();
+            edge_map.insert(edge_id, new_edge);
+            replaced_face(face, &edge_map, &arcs)
+        })
+        .collect::<Result<Shell>>()?;
+    new_shell.push(fillet_face);
+    Ok(new_shell)
+}
+
+/// Replaces the edges of `face` by `edge_map`, and fills the gaps by `arcs`.
+fn replaced_face(face: &Face, edge_map: &HashMap<EdgeID, Edge>, arcs: &[Edge]) -> Result<Face> {
+    let wires = face.boundaries();
+    let replaced = wires
+        .iter()
+        .flat_map(|wire| wire.edge_iter())
+        .any(|edge| edge_map.contains_key(&edge.id()));
+    if !replaced {
+        return Ok(face.clone());
+    }
+    let wires = wires
+        .into_iter()
+        .map(|wire| {
+            let edges = wire
+                .edge_iter()
+                .map(|edge| match edge_map.get(&edge.id()) {
+                    Some(new_edge) if edge.orientation() => new_edge.clone(),
+                    Some(new_edge) => new_edge.inverse(),
+                    None => edge.clone(),
+                })
+                .collect::<Vec<_>>();
+            let mut new_wire = Wire::new();
+            for (i, edge) in edges.iter().enumerate() {
+                new_wire.push_back(edge.clone());
+                let (p, q) = (edge.back(), edges[(i + 1) % edges.len()].front());
+                if p != q {
+                    let arc = arcs
+                        .iter()
+                        .find_map(|arc| match (arc.front(), arc.back()) {
+                            (v0, v1) if v0 == p && v1 == q => Some(arc.clone()),
+                            (v0, v1) if v0 == q && v1 == p => Some(arc.inverse()),
+                            _ => None,
+                        })
+                        .ok_or(Error::UnsupportedFilletEdge)?;
+                    new_wire.push_back(arc);
+                }
+            }
+            Ok(new_wire)
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Face::try_new(wires, face.oriented_surface())?)
+}
@@ -39,6 +39,25 @@ pub(super) fn circle_arc(
     Processor::with_transform(unit_arc, axis_trsf)
 }
 
+/// Returns the intersection point of `planes`, each of which is given by the normal and the constant.
+pub(super) fn intersection_point(planes: &[(Vector3, f64)]) -> Option<Point3> {
+    let (ata, atc) =
+        planes
+            .iter()
+            .fold((Matrix3::zero(), Vector3::zero()), |(mat, vec), (n, c)| {
+                let outer = Matrix3::from_cols(n * n.x, n * n.y, n * n.z);
+                (mat + outer, vec + n * *c)
+            });
+    if ata.determinant().so_small() {
+        return None;
+    }
+    let point = Point3::from_vec(ata.invert()? * atc);
+    let on_planes = planes
+        .iter()
+        .all(|(n, c)| (n.dot(point.to_vec()) - c).so_small());
+    on_planes.then_some(point)
+}
+
 fn closed_polyline_orientation<'a>(pts: impl IntoIterator<Item = &'a Vec<Point3>>) -> bool {
     pts.into_iter()
         .flat_map(|vec| vec.iter().circular_tuple_windows())
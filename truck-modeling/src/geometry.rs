@@ -162,6 +162,14 @@ impl ParametricSurface3D for Surface {
     fn normal(&self, u: f64, v: f64) -> Vector3 {
         derive_surface_method!(self, ParametricSurface3D::normal, u, v)
     }
+    #[inline(always)]
+    fn gaussian_curvature(&self, u: f64, v: f64) -> f64 {
+        derive_surface_method!(self, ParametricSurface3D::gaussian_curvature, u, v)
+    }
+    #[inline(always)]
+    fn mean_curvature(&self, u: f64, v: f64) -> f64 {
+        derive_surface_method!(self, ParametricSurface3D::mean_curvature, u, v)
+    }
 }
 
 impl Transformed<Matrix4> for Surface {
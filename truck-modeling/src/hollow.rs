@@ -0,0 +1,118 @@
+use crate::{errors::Error, geom_impls, *};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+pub(super) fn shell_solid(solid: &Solid, thickness: f64, open_faces: &[FaceID]) -> Result<Solid> {
+    if thickness < TOLERANCE {
+        return Err(Error::InvalidThickness);
+    }
+    let open_faces = open_faces.iter().copied().collect::<HashSet<_>>();
+    let boundaries = solid
+        .boundaries()
+        .iter()
+        .map(|shell| hollow_shell(shell, thickness, &open_faces))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Solid::try_new(boundaries.into_iter().flatten().collect())?)
+}
+
+fn hollow_shell(shell: &Shell, thickness: f64, open_faces: &HashSet<FaceID>) -> Result<Vec<Shell>> {
+    let planes = shell
+        .face_iter()
+        .map(|face| match face.oriented_surface() {
+            Surface::Plane(plane) => Ok(plane),
+            _ => Err(Error::UnsupportedShelling),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let is_open = shell
+        .face_iter()
+        .map(|face| open_faces.contains(&face.id()))
+        .collect::<Vec<_>>();
+    // the open faces are not offset.
+    let offset_planes = planes
+        .iter()
+        .zip(&is_open)
+        .map(|(plane, open)| {
+            let n = plane.normal();
+            let offset = if *open { 0.0 } else { thickness };
+            (n, n.dot(plane.origin().to_vec()) - offset)
+        })
+        .collect::<Vec<_>>();
+
+    let mut vertex_faces = HashMap::<VertexID, Vec<usize>>::default();
+    let mut edge_faces = HashMap::<EdgeID, (Edge, Vec<usize>)>::default();
+    shell.face_iter().enumerate().for_each(|(i, face)| {
+        face.edge_iter().for_each(|edge| {
+            let entry = edge_faces.entry(edge.id());
+            entry
+                .or_insert_with(|| (edge.clone(), Vec::new()))
+                .1
+                .push(i);
+            let faces = vertex_faces.entry(edge.front().id()).or_default();
+            if !faces.contains(&i) {
+                faces.push(i);
+            }
+        })
+    });
+    let adjacent_open = edge_faces
+        .values()
+        .any(|(_, faces)| faces.iter().all(|i| is_open[*i]));
+    if adjacent_open {
+        return Err(Error::UnsupportedShelling);
+    }
+
+    let inner_vertices = vertex_faces
+        .into_iter()
+        .map(|(id, faces)| {
+            let planes = faces.iter().map(|i| offset_planes[*i]).collect::<Vec<_>>();
+            let point =
+                geom_impls::intersection_point(&planes).ok_or(Error::UnsupportedShelling)?;
+            Ok((id, Vertex::new(point)))
+        })
+        .collect::<Result<HashMap<_, _>>>()?;
+    let inner_edges = edge_faces
+        .into_iter()
+        .map(|(id, (edge, _))| {
+            let v0 = &inner_vertices[&edge.absolute_front().id()];
+            let v1 = &inner_vertices[&edge.absolute_back().id()];
+            let (p0, p1) = (v0.point(), v1.point());
+            let dir = edge.absolute_back().point() - edge.absolute_front().point();
+            // the offset edge is collapsed or flipped if the wall is too thin.
+            if (p1 - p0).dot(dir) < TOLERANCE {
+                return Err(Error::InvalidThickness);
+            }
+            Ok((id, Edge::new(v0, v1, Line(p0, p1).into())))
+        })
+        .collect::<Result<HashMap<_, _>>>()?;
+    let inner_wire = |wire: &Wire| {
+        wire.edge_iter()
+            .map(|edge| match edge.orientation() {
+                true => inner_edges[&edge.id()].clone(),
+                false => inner_edges[&edge.id()].inverse(),
+            })
+            .collect::<Wire>()
+    };
+
+    let mut outer_shell = Shell::new();
+    let mut inner_shell = Shell::new();
+    for (i, face) in shell.face_iter().enumerate() {
+        let boundaries = face.boundaries();
+        let inner_wires = boundaries.iter().map(inner_wire);
+        if is_open[i] {
+            let mut wires = boundaries.clone();
+            wires.extend(inner_wires.map(|wire| wire.inverse()));
+            outer_shell.push(Face::try_new(wires, Surface::Plane(planes[i]))?);
+        } else {
+            let trsl = Matrix4::from_translation(-planes[i].normal() * thickness);
+            let plane = planes[i].transformed(trsl);
+            let inner_face = Face::try_new(inner_wires.collect(), Surface::Plane(plane))?;
+            outer_shell.push(face.clone());
+            inner_shell.push(inner_face.inverse());
+        }
+    }
+    match is_open.contains(&true) {
+        true => {
+            outer_shell.append(&mut inner_shell);
+            Ok(vec![outer_shell])
+        }
+        false => Ok(vec![outer_shell, inner_shell]),
+    }
+}
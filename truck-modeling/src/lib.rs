@@ -91,6 +91,12 @@ pub mod topo_traits {
             division: usize,
         ) -> Swept;
     }
+
+    /// Planarity of a topological element.
+    pub trait Planarity {
+        /// Returns the least-squares plane if all points are within `tol` from the plane.
+        fn is_planar(&self, tol: f64) -> Option<crate::Plane>;
+    }
 }
 pub use topo_traits::*;
 
@@ -100,10 +106,19 @@ pub type Result<T> = std::result::Result<T, errors::Error>;
 /// the building model utility API
 pub mod builder;
 mod closed_sweep;
+mod draft;
 /// declare errors
 pub mod errors;
+mod fillet;
 mod geom_impls;
+mod hollow;
+mod loft;
 mod mapped;
 mod multi_sweep;
+mod path_sweep;
+mod planarity;
+/// planar sketches with geometric constraints
+pub mod sketch;
 mod sweep;
 mod topo_impls;
+mod trimmed;
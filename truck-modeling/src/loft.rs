@@ -0,0 +1,133 @@
+use crate::{errors::Error, *};
+
+pub(super) fn loft(wires: &[Wire], degree: usize) -> Result<Shell> {
+    let degree = usize::max(degree, 1);
+    if wires.len() <= degree {
+        return Err(Error::TooFewSections);
+    }
+    let len = wires[0].len();
+    if wires.iter().any(|wire| wire.len() != len) {
+        return Err(Error::NotSameNumberOfEdges);
+    }
+    let intersection_curve = |edge: &Edge| matches!(edge.curve(), Curve::IntersectionCurve(_));
+    if wires
+        .iter()
+        .flat_map(Wire::edge_iter)
+        .any(intersection_curve)
+    {
+        return Err(Error::IntersectionCurveSection);
+    }
+    let closed = wires[0].is_closed();
+    let consistent = |wire: &Wire| wire.is_continuous() && wire.is_closed() == closed;
+    if len == 0 || !wires.iter().all(consistent) {
+        return Err(Error::InconsistentSections);
+    }
+
+    let params = section_parameters(wires);
+    let n = params.len() - 1;
+    let inner_knots =
+        (1..=n - degree).map(|j| params[j..j + degree].iter().sum::<f64>() / degree as f64);
+    let knots = std::iter::repeat(0.0)
+        .take(degree + 1)
+        .chain(inner_knots)
+        .chain(std::iter::repeat(1.0).take(degree + 1))
+        .collect::<Vec<_>>();
+    let vknot_vec = KnotVec::from(knots);
+
+    let surfaces = (0..len)
+        .map(|j| -> Result<BSplineSurface<Vector4>> {
+            let mut curves = wires
+                .iter()
+                .map(|wire| wire[j].oriented_curve().lift_up())
+                .collect::<Vec<_>>();
+            let (curve0, others) = curves.split_first_mut().unwrap();
+            curve0.knot_normalize();
+            (0..2).for_each(|_| {
+                others.iter_mut().for_each(|curve| {
+                    curve0.syncro_degree(curve);
+                    curve0.syncro_knots(curve);
+                })
+            });
+            let control_points = (0..curve0.control_points().len())
+                .map(|k| -> Result<Vec<Vector4>> {
+                    let parameter_points = params
+                        .iter()
+                        .zip(&curves)
+                        .map(|(t, curve)| (*t, *curve.control_point(k)))
+                        .collect::<Vec<_>>();
+                    let column = BSplineCurve::try_interpole(vknot_vec.clone(), parameter_points)
+                        .map_err(|_| Error::InconsistentSections)?;
+                    Ok(column.destruct().1)
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let uknot_vec = curves[0].knot_vec().clone();
+            Ok(BSplineSurface::new(
+                (uknot_vec, vknot_vec.clone()),
+                control_points,
+            ))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let (wire0, wire1) = (&wires[0], &wires[n]);
+    let vertex = |wire: &Wire, k: usize| match k < len {
+        true => wire[k].front().clone(),
+        false => wire[len - 1].back().clone(),
+    };
+    let rail = |k: usize| {
+        let curve = match k < len {
+            true => surfaces[k].column_curve(0),
+            false => surfaces[len - 1].column_curve(surfaces[len - 1].control_points().len() - 1),
+        };
+        let curve = Curve::NurbsCurve(NurbsCurve::new(curve));
+        Edge::new(&vertex(wire0, k), &vertex(wire1, k), curve)
+    };
+    let rails = (0..len + !closed as usize).map(rail).collect::<Vec<_>>();
+    let shell = surfaces
+        .into_iter()
+        .enumerate()
+        .map(|(j, surface)| {
+            let (rail0, rail1) = (&rails[j], &rails[(j + 1) % rails.len()]);
+            let wire = wire![
+                wire0[j].clone(),
+                rail1.clone(),
+                wire1[j].inverse(),
+                rail0.inverse(),
+            ];
+            Face::new(vec![wire], NurbsSurface::new(surface).into())
+        })
+        .collect();
+    Ok(shell)
+}
+
+/// the centripetal parameters of sections, computed by the vertices and the middle points of edges
+fn section_parameters(wires: &[Wire]) -> Vec<f64> {
+    let points = wires
+        .iter()
+        .map(|wire| {
+            wire.edge_iter()
+                .flat_map(|edge| {
+                    let curve = edge.oriented_curve();
+                    let (t0, t1) = curve.range_tuple();
+                    [curve.subs(t0), curve.subs((t0 + t1) / 2.0), curve.subs(t1)]
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let mut sum = 0.0;
+    let mut params = std::iter::once(0.0)
+        .chain(points.windows(2).map(|pts| {
+            let dist = pts[0]
+                .iter()
+                .zip(&pts[1])
+                .map(|(p, q)| p.distance(*q))
+                .sum::<f64>()
+                / pts[0].len() as f64;
+            sum += f64::sqrt(dist);
+            sum
+        }))
+        .collect::<Vec<_>>();
+    if sum > 0.0 {
+        params.iter_mut().for_each(|t| *t /= sum);
+    }
+    params
+}
@@ -0,0 +1,206 @@
+use crate::{errors::Error, *};
+use algo::curve::{frenet_frame, rotation_minimizing_frames};
+
+const DIVISION: usize = 16;
+const SAMPLES_PER_EDGE: usize = 4;
+
+/// The moving frames carrying the profile along the curved edges of the path.
+///
+/// cf. [`builder::sweep_along_path`](../builder/fn.sweep_along_path.html)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FrameMode {
+    /// The Frenet frames. The profile follows the principal normal of the path. On straight parts
+    /// of the path, where the Frenet frame is not defined, the rotation minimizing frames are used.
+    Frenet,
+    /// The rotation minimizing frames. The profile does not twist around the path.
+    RotationMinimizing,
+}
+
+pub(super) fn sweep_along_path(profile: &Wire, path: &Wire, frame: FrameMode) -> Result<Solid> {
+    if !profile.is_closed() {
+        return Err(truck_topology::errors::Error::NotClosedWire.into());
+    }
+    // a closed path needs two edges at least, since the sections at its ends are shared.
+    let closed = path.is_closed();
+    if path.is_empty() || !path.is_continuous() || (closed && path.len() < 2) {
+        return Err(Error::InvalidSweepPath);
+    }
+    let curves = path
+        .edge_iter()
+        .map(|edge| edge.oriented_curve())
+        .collect::<Vec<_>>();
+    let tangent = |curve: &Curve, t: f64| curve.der(t).normalize();
+    let start_tangent = |curve: &Curve| tangent(curve, curve.range_tuple().0);
+    let end_tangent = |curve: &Curve| tangent(curve, curve.range_tuple().1);
+
+    // the start cap decides the orientation even if the path is closed.
+    let cap0: Face = builder::try_attach_plane(vec![profile.inverse()])?;
+    let inverted = cap0
+        .oriented_surface()
+        .normal(0.0, 0.0)
+        .dot(start_tangent(&curves[0]))
+        > 0.0;
+
+    let first_section = match closed {
+        true => {
+            let (d_in, d_out) = (
+                end_tangent(&curves[curves.len() - 1]),
+                start_tangent(&curves[0]),
+            );
+            let origin = curves[0].front();
+            miter_section(profile, origin, d_out, d_in, d_out)?
+        }
+        false => profile.clone(),
+    };
+    let mut moving = profile.clone();
+    let mut section = first_section.clone();
+    let mut shell = Shell::new();
+    for (i, curve) in curves.iter().enumerate() {
+        let mut sections = vec![section];
+        let end = match matches!(curve, Curve::Line(_)) {
+            true => builder::translated(&moving, curve.back() - curve.front()),
+            false => {
+                let (t0, t1) = curve.range_tuple();
+                let params = (0..=DIVISION)
+                    .map(|k| t0 + (t1 - t0) * k as f64 / DIVISION as f64)
+                    .collect::<Vec<_>>();
+                let frames = moving_frames(curve, &params, frame);
+                let wires = frames
+                    .iter()
+                    .zip(&params)
+                    .skip(1)
+                    .map(|(mat, t)| {
+                        let translation0 = Matrix4::from_translation(-curve.front().to_vec());
+                        let rotation = Matrix4::from(*mat * frames[0].transpose());
+                        let translation1 = Matrix4::from_translation(curve.subs(*t).to_vec());
+                        builder::transformed(&moving, translation1 * rotation * translation0)
+                    })
+                    .collect::<Vec<_>>();
+                sections.extend(wires[..DIVISION - 1].iter().cloned());
+                wires[DIVISION - 1].clone()
+            }
+        };
+        let last = i + 1 == curves.len();
+        section = match (last, closed) {
+            (true, true) => first_section.clone(),
+            (true, false) => end.clone(),
+            (false, _) => {
+                let (d_in, d_out) = (end_tangent(curve), start_tangent(&curves[i + 1]));
+                let origin = curve.back();
+                moving = builder::transformed(&end, corner_rotation(origin, d_in, d_out)?);
+                miter_section(&end, origin, d_in, d_in, d_out)?
+            }
+        };
+        sections.push(section.clone());
+        if self_intersecting(curve, &sections) {
+            return Err(Error::SelfIntersectingSweep);
+        }
+        let degree = usize::min(sections.len() - 1, 3);
+        shell.extend(builder::loft(&sections, degree)?);
+    }
+
+    if !closed {
+        shell.push(cap0);
+        shell.push(builder::try_attach_plane(vec![section])?);
+    }
+    if inverted {
+        shell.face_iter_mut().for_each(|face| {
+            face.invert();
+        });
+    }
+    Ok(Solid::try_new(vec![shell])?)
+}
+
+/// Returns the frames of the curve at `params` as the matrices whose columns are the tangent,
+/// the normal and the binormal.
+fn moving_frames(curve: &Curve, params: &[f64], frame: FrameMode) -> Vec<Matrix3> {
+    let frenet = || {
+        params
+            .iter()
+            .map(|t| {
+                let (tangent, normal, binormal) = frenet_frame(curve, *t);
+                match normal.x.is_nan() {
+                    true => None,
+                    false => Some(Matrix3::from_cols(tangent, normal, binormal)),
+                }
+            })
+            .collect::<Option<Vec<_>>>()
+    };
+    let frenet = match frame {
+        FrameMode::Frenet => frenet(),
+        FrameMode::RotationMinimizing => None,
+    };
+    frenet.unwrap_or_else(|| rotation_minimizing_frames(curve, params))
+}
+
+/// Returns the minimal rotation around `origin` which maps `d_in` to `d_out`.
+fn corner_rotation(origin: Point3, d_in: Vector3, d_out: Vector3) -> Result<Matrix4> {
+    let axis = d_in.cross(d_out);
+    if axis.so_small() {
+        return match d_in.dot(d_out) > 0.0 {
+            true => Ok(Matrix4::identity()),
+            false => Err(Error::SelfIntersectingSweep),
+        };
+    }
+    let angle = d_in.angle(d_out);
+    let translation0 = Matrix4::from_translation(-origin.to_vec());
+    let rotation = Matrix4::from_axis_angle(axis.normalize(), angle);
+    let translation1 = Matrix4::from_translation(origin.to_vec());
+    Ok(translation1 * rotation * translation0)
+}
+
+/// Projects `wire` along `dir` onto the miter plane through `origin`, which bisects the corner
+/// from `d_in` to `d_out`.
+fn miter_section(
+    wire: &Wire,
+    origin: Point3,
+    dir: Vector3,
+    d_in: Vector3,
+    d_out: Vector3,
+) -> Result<Wire> {
+    let normal = d_in + d_out;
+    if normal.so_small() {
+        return Err(Error::SelfIntersectingSweep);
+    }
+    let normal = normal.normalize();
+    if d_in.cross(d_out).so_small() {
+        return Ok(wire.clone());
+    }
+    // p -> p - dir * <p - origin, normal> / <dir, normal>
+    let coef = dir / dir.dot(normal);
+    let linear =
+        Matrix3::identity() - Matrix3::from_cols(coef * normal.x, coef * normal.y, coef * normal.z);
+    let translation = Matrix4::from_translation(coef * origin.to_vec().dot(normal));
+    Ok(builder::transformed(
+        wire,
+        translation * Matrix4::from(linear),
+    ))
+}
+
+/// Returns whether the consecutive sections along `curve` go backward at some point, which means
+/// that the swept profile intersects itself inside the corners.
+fn self_intersecting(curve: &Curve, sections: &[Wire]) -> bool {
+    let (t0, t1) = curve.range_tuple();
+    let n = sections.len() - 1;
+    let samples = sections.iter().map(section_samples).collect::<Vec<_>>();
+    samples.windows(2).enumerate().any(|(k, pair)| {
+        let t = t0 + (t1 - t0) * (k as f64 + 0.5) / n as f64;
+        let dir = curve.der(t);
+        pair[0]
+            .iter()
+            .zip(&pair[1])
+            .any(|(p, q)| (q - p).dot(dir) < TOLERANCE)
+    })
+}
+
+/// Returns the sample points of the section, which correspond between the sections.
+fn section_samples(wire: &Wire) -> Vec<Point3> {
+    wire.edge_iter()
+        .flat_map(|edge| {
+            let curve = edge.oriented_curve();
+            let (t0, t1) = curve.range_tuple();
+            (0..SAMPLES_PER_EDGE)
+                .map(move |i| curve.subs(t0 + (t1 - t0) * i as f64 / SAMPLES_PER_EDGE as f64))
+        })
+        .collect()
+}
@@ -0,0 +1,45 @@
+use crate::*;
+
+const SAMPLES_PER_EDGE: usize = 8;
+
+impl Planarity for Wire {
+    /// Returns the least-squares plane of the points sampled on the edges if all of them are
+    /// within `tol` from the plane. The normal of the plane is oriented so that a closed wire
+    /// goes around counterclockwise.
+    ///
+    /// Returns `None` if the wire is not planar or the points on the wire are collinear.
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// // a slightly noisy square on the plane z = 1
+    /// let v = builder::vertices([
+    ///     (0.0, 0.0, 1.0001),
+    ///     (1.0, 0.0, 0.9999),
+    ///     (1.0, 1.0, 1.0001),
+    ///     (0.0, 1.0, 0.9999),
+    /// ]);
+    /// let wire: Wire = (0..4).map(|i| builder::line(&v[i], &v[(i + 1) % 4])).collect();
+    /// let plane = wire.is_planar(1.0e-3).unwrap();
+    /// assert_near!(plane.normal(), Vector3::unit_z());
+    /// assert!(wire.is_planar(1.0e-5).is_none());
+    /// ```
+    fn is_planar(&self, tol: f64) -> Option<Plane> {
+        let mut points = self
+            .edge_iter()
+            .flat_map(|edge| {
+                let curve = edge.oriented_curve();
+                let (t0, t1) = curve.range_tuple();
+                (0..SAMPLES_PER_EDGE)
+                    .map(move |i| curve.subs(t0 + (t1 - t0) * i as f64 / SAMPLES_PER_EDGE as f64))
+            })
+            .collect::<Vec<_>>();
+        if !self.is_closed() {
+            points.extend(self.back_vertex().map(|v| v.point()));
+        }
+        let (plane, deviation) = best_fit_plane(&points).ok()?;
+        match deviation <= tol {
+            true => Some(plane),
+            false => None,
+        }
+    }
+}
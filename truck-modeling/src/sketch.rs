@@ -0,0 +1,269 @@
+use crate::{errors::Error, *};
+
+const TRIALS: usize = 100;
+
+/// Geometric constraints on the points and lines of [`Sketch`].
+///
+/// The points and lines are specified by the indices returned by [`Sketch::add_point`] and
+/// [`Sketch::add_line`], respectively.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Constraint {
+    /// The distance between two points is the given value.
+    Distance(usize, usize, f64),
+    /// Two points coincide.
+    Coincident(usize, usize),
+    /// The line is horizontal, i.e. parallel to the x-axis.
+    Horizontal(usize),
+    /// The line is vertical, i.e. parallel to the y-axis.
+    Vertical(usize),
+    /// Two lines are parallel.
+    Parallel(usize, usize),
+}
+
+/// The degrees of freedom of a solved [`Sketch`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SketchStatus {
+    /// The remaining degrees of freedom, including the three ones of the rigid motions of the
+    /// whole sketch.
+    pub dof: usize,
+    /// The number of the redundant constraints, which are consistent with the others.
+    pub redundant: usize,
+}
+
+/// A planar sketch consisting of points and lines with geometric constraints.
+///
+/// # Examples
+/// ```
+/// use truck_modeling::{sketch::*, *};
+///
+/// let mut sketch = Sketch::new();
+/// let p = [(0.0, 0.0), (1.0, 0.1), (0.2, 0.9)].map(|(x, y)| sketch.add_point(Point2::new(x, y)));
+/// let l = [(0, 1), (1, 2), (2, 0)].map(|(i, j)| sketch.add_line(p[i], p[j]));
+/// sketch.add_constraint(Constraint::Distance(p[0], p[1], 3.0));
+/// sketch.add_constraint(Constraint::Distance(p[1], p[2], 4.0));
+/// sketch.add_constraint(Constraint::Distance(p[2], p[0], 5.0));
+/// sketch.add_constraint(Constraint::Horizontal(l[0]));
+///
+/// // Only the translation remains.
+/// let status = sketch.solve().unwrap();
+/// assert_eq!(status, SketchStatus { dof: 2, redundant: 0 });
+/// let pts = sketch.points();
+/// assert_near!(pts[0].distance(pts[1]), 3.0);
+/// assert_near!(pts[1].distance(pts[2]), 4.0);
+/// assert_near!(pts[2].distance(pts[0]), 5.0);
+/// assert_near!(pts[0].y, pts[1].y);
+///
+/// // build a wire on the xy-plane
+/// let v = builder::vertices(pts.iter().map(|p| Point3::new(p.x, p.y, 0.0)));
+/// let wire: Wire = sketch.lines().iter().map(|&[i, j]| builder::line(&v[i], &v[j])).collect();
+/// assert!(wire.is_closed());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Sketch {
+    points: Vec<Point2>,
+    lines: Vec<[usize; 2]>,
+    constraints: Vec<Constraint>,
+}
+
+impl Sketch {
+    /// Creates an empty sketch.
+    #[inline(always)]
+    pub fn new() -> Self { Self::default() }
+    /// Adds a point and returns its index.
+    #[inline(always)]
+    pub fn add_point(&mut self, point: Point2) -> usize {
+        self.points.push(point);
+        self.points.len() - 1
+    }
+    /// Adds a line from the point `p0` to the point `p1` and returns its index.
+    /// # Panics
+    /// Panics if the points are not in the sketch.
+    pub fn add_line(&mut self, p0: usize, p1: usize) -> usize {
+        assert!(
+            p0 < self.points.len() && p1 < self.points.len(),
+            "the points are not in the sketch."
+        );
+        self.lines.push([p0, p1]);
+        self.lines.len() - 1
+    }
+    /// Adds a constraint.
+    /// # Panics
+    /// Panics if the points or lines of the constraint are not in the sketch.
+    pub fn add_constraint(&mut self, constraint: Constraint) {
+        let (np, nl) = (self.points.len(), self.lines.len());
+        let valid = match constraint {
+            Constraint::Distance(p0, p1, _) | Constraint::Coincident(p0, p1) => p0 < np && p1 < np,
+            Constraint::Horizontal(l) | Constraint::Vertical(l) => l < nl,
+            Constraint::Parallel(l0, l1) => l0 < nl && l1 < nl,
+        };
+        assert!(
+            valid,
+            "the elements of the constraint are not in the sketch."
+        );
+        self.constraints.push(constraint);
+    }
+    /// Returns the points.
+    #[inline(always)]
+    pub fn points(&self) -> &[Point2] { &self.points }
+    /// Returns the lines as the pairs of the indices of points.
+    #[inline(always)]
+    pub fn lines(&self) -> &[[usize; 2]] { &self.lines }
+    /// Returns the constraints.
+    #[inline(always)]
+    pub fn constraints(&self) -> &[Constraint] { &self.constraints }
+
+    /// Moves the points to satisfy all constraints by the Gauss-Newton method, starting from the
+    /// current positions.
+    ///
+    /// Returns the degrees of freedom at the solution. The under-constrained sketch is also solved,
+    /// and its remaining degrees of freedom are reported. The over-constrained sketch is solved if
+    /// the constraints are consistent, and the number of the redundant ones is reported.
+    /// # Failures
+    /// If the constraints are inconsistent, i.e. the residuals do not converge to zero,
+    /// returns `Error::InconsistentConstraints`. In that case, the points are not changed.
+    pub fn solve(&mut self) -> Result<SketchStatus> {
+        let mut x = self.points.clone();
+        for _ in 0..TRIALS {
+            let (residuals, jacobian) = self.evaluate(&x);
+            if residuals.iter().all(|r| r.so_small()) {
+                let rank = rank(jacobian);
+                self.points = x;
+                return Ok(SketchStatus {
+                    dof: self.points.len() * 2 - rank,
+                    redundant: residuals.len() - rank,
+                });
+            }
+            // the damped normal equation, which is solvable even if under-constrained
+            let n = x.len() * 2;
+            let mut matrix = vec![vec![0.0; n]; n];
+            let mut vector = vec![0.0; n];
+            for (row, r) in jacobian.iter().zip(&residuals) {
+                vector.iter_mut().zip(row).for_each(|(v, a)| *v -= a * r);
+                matrix.iter_mut().zip(row).for_each(|(mrow, a)| {
+                    mrow.iter_mut().zip(row).for_each(|(m, b)| *m += a * b);
+                });
+            }
+            let max = (0..n).map(|i| matrix[i][i]).fold(1.0, f64::max);
+            (0..n).for_each(|i| matrix[i][i] += max * 1.0e-10);
+            let delta = solve_linear(matrix, vector).ok_or(Error::InconsistentConstraints)?;
+            x.iter_mut().enumerate().for_each(|(i, p)| {
+                *p += Vector2::new(delta[2 * i], delta[2 * i + 1]);
+            });
+        }
+        Err(Error::InconsistentConstraints)
+    }
+
+    /// Returns the residuals of the constraints and their Jacobian matrix.
+    fn evaluate(&self, x: &[Point2]) -> (Vec<f64>, Vec<Vec<f64>>) {
+        let n = x.len() * 2;
+        let mut residuals = Vec::new();
+        let mut jacobian = Vec::new();
+        let mut push = |r: f64, grads: &[(usize, Vector2)]| {
+            let mut row = vec![0.0; n];
+            grads.iter().for_each(|(i, g)| {
+                row[2 * i] += g.x;
+                row[2 * i + 1] += g.y;
+            });
+            residuals.push(r);
+            jacobian.push(row);
+        };
+        for constraint in &self.constraints {
+            match *constraint {
+                Constraint::Distance(p0, p1, d) => {
+                    let v = x[p1] - x[p0];
+                    let dist = v.magnitude();
+                    let dir = match dist.so_small() {
+                        true => Vector2::unit_x(),
+                        false => v / dist,
+                    };
+                    push(dist - d, &[(p0, -dir), (p1, dir)]);
+                }
+                Constraint::Coincident(p0, p1) => {
+                    let v = x[p1] - x[p0];
+                    push(v.x, &[(p0, -Vector2::unit_x()), (p1, Vector2::unit_x())]);
+                    push(v.y, &[(p0, -Vector2::unit_y()), (p1, Vector2::unit_y())]);
+                }
+                Constraint::Horizontal(l) => {
+                    let [p0, p1] = self.lines[l];
+                    let v = x[p1] - x[p0];
+                    push(v.y, &[(p0, -Vector2::unit_y()), (p1, Vector2::unit_y())]);
+                }
+                Constraint::Vertical(l) => {
+                    let [p0, p1] = self.lines[l];
+                    let v = x[p1] - x[p0];
+                    push(v.x, &[(p0, -Vector2::unit_x()), (p1, Vector2::unit_x())]);
+                }
+                Constraint::Parallel(l0, l1) => {
+                    let ([p0, p1], [q0, q1]) = (self.lines[l0], self.lines[l1]);
+                    let (u, w) = (x[p1] - x[p0], x[q1] - x[q0]);
+                    // the derivations of `u.perp_dot(w)` by `u` and `w`
+                    let (du, dw) = (Vector2::new(w.y, -w.x), Vector2::new(-u.y, u.x));
+                    push(u.perp_dot(w), &[(p0, -du), (p1, du), (q0, -dw), (q1, dw)]);
+                }
+            }
+        }
+        (residuals, jacobian)
+    }
+}
+
+/// Solves the linear equation by Gaussian elimination with partial pivoting.
+fn solve_linear(mut matrix: Vec<Vec<f64>>, mut vector: Vec<f64>) -> Option<Vec<f64>> {
+    let n = vector.len();
+    for k in 0..n {
+        let pivot = (k..n).max_by(|i, j| matrix[*i][k].abs().total_cmp(&matrix[*j][k].abs()))?;
+        if matrix[pivot][k] == 0.0 {
+            return None;
+        }
+        matrix.swap(k, pivot);
+        vector.swap(k, pivot);
+        let (upper, lower) = matrix.split_at_mut(k + 1);
+        let (vk, lower_vector) = (vector[k], &mut vector[k + 1..]);
+        lower.iter_mut().zip(lower_vector).for_each(|(row, v)| {
+            let coef = eliminate(row, &upper[k], k);
+            *v -= coef * vk;
+        });
+    }
+    let mut res = vec![0.0; n];
+    for (k, row) in matrix.iter().enumerate().rev() {
+        let sum = row[k + 1..]
+            .iter()
+            .zip(&res[k + 1..])
+            .map(|(a, b)| a * b)
+            .sum::<f64>();
+        res[k] = (vector[k] - sum) / row[k];
+    }
+    Some(res)
+}
+
+/// Returns the rank of the matrix by Gaussian elimination with complete pivoting.
+fn rank(mut matrix: Vec<Vec<f64>>) -> usize {
+    let (m, n) = (matrix.len(), matrix.first().map_or(0, Vec::len));
+    let mut rank = 0;
+    for k in 0..usize::min(m, n) {
+        let pivot = (k..m)
+            .flat_map(|i| (k..n).map(move |j| (i, j)))
+            .max_by(|(i0, j0), (i1, j1)| matrix[*i0][*j0].abs().total_cmp(&matrix[*i1][*j1].abs()));
+        let Some((pi, pj)) = pivot else { break };
+        if matrix[pi][pj].abs() < TOLERANCE {
+            break;
+        }
+        matrix.swap(k, pi);
+        matrix.iter_mut().for_each(|row| row.swap(k, pj));
+        let (upper, lower) = matrix.split_at_mut(k + 1);
+        lower.iter_mut().for_each(|row| {
+            eliminate(row, &upper[k], k);
+        });
+        rank += 1;
+    }
+    rank
+}
+
+/// Subtracts `pivot_row` from `row` to make `row[k]` zero, and returns the coefficient.
+fn eliminate(row: &mut [f64], pivot_row: &[f64], k: usize) -> f64 {
+    let coef = row[k] / pivot_row[k];
+    row.iter_mut()
+        .zip(pivot_row)
+        .skip(k)
+        .for_each(|(a, b)| *a -= coef * b);
+    coef
+}
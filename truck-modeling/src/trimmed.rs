@@ -0,0 +1,90 @@
+use crate::{errors::Error, *};
+
+const TRIALS: usize = 100;
+const DIVISION: usize = 16;
+
+pub(super) fn trimmed_face(surface: Surface, outer: Wire, inners: Vec<Wire>) -> Result<Face> {
+    let mut wires = std::iter::once(outer).chain(inners).collect::<Vec<_>>();
+    let polygons = wires
+        .iter()
+        .map(|wire| parameter_polygon(&surface, wire))
+        .collect::<Result<Vec<_>>>()?;
+    if self_intersecting(&polygons) {
+        return Err(Error::SelfIntersectingWire);
+    }
+    // the outer boundary goes counterclockwise, and the inner ones go clockwise.
+    wires
+        .iter_mut()
+        .zip(&polygons)
+        .enumerate()
+        .for_each(|(i, (wire, polygon))| {
+            if (signed_area(polygon) > 0.0) != (i == 0) {
+                wire.invert();
+            }
+        });
+    Ok(Face::try_new(wires, surface)?)
+}
+
+/// Returns the polygon in the parameter space of `surface` approximating `wire`.
+fn parameter_polygon(surface: &Surface, wire: &Wire) -> Result<Vec<Point2>> {
+    if !wire.is_closed() {
+        return Err(truck_topology::errors::Error::NotClosedWire.into());
+    }
+    let (urange, vrange) = surface.try_range_tuple();
+    let in_range = |t: f64, range: Option<(f64, f64)>| match range {
+        Some((t0, t1)) => t0 - TOLERANCE <= t && t <= t1 + TOLERANCE,
+        None => true,
+    };
+    let mut hint = None;
+    wire.edge_iter()
+        .flat_map(|edge| {
+            let curve = edge.oriented_curve();
+            let (t0, t1) = curve.range_tuple();
+            (0..DIVISION).map(move |i| curve.subs(t0 + (t1 - t0) * i as f64 / DIVISION as f64))
+        })
+        .map(|pt| {
+            let (u, v) = surface
+                .search_parameter(pt, hint, TRIALS)
+                .or_else(|| surface.search_parameter(pt, None, TRIALS))
+                .ok_or(Error::WireNotOnSurface)?;
+            if !in_range(u, urange) || !in_range(v, vrange) {
+                return Err(Error::WireNotOnSurface);
+            }
+            hint = Some((u, v));
+            Ok(Point2::new(u, v))
+        })
+        .collect()
+}
+
+/// Returns the doubled signed area of `polygon`, which is positive if `polygon` is counterclockwise.
+fn signed_area(polygon: &[Point2]) -> f64 {
+    let n = polygon.len();
+    (0..n).fold(0.0, |sum, i| {
+        let (p, q) = (polygon[i], polygon[(i + 1) % n]);
+        sum + p.to_vec().perp_dot(q.to_vec())
+    })
+}
+
+/// Returns whether some non-adjacent segments of `polygons` cross each other.
+fn self_intersecting(polygons: &[Vec<Point2>]) -> bool {
+    let segments = polygons
+        .iter()
+        .enumerate()
+        .flat_map(|(k, polygon)| {
+            let n = polygon.len();
+            (0..n).map(move |i| (k, i, n, polygon[i], polygon[(i + 1) % n]))
+        })
+        .collect::<Vec<_>>();
+    let orient = |a: Point2, b: Point2, c: Point2| (b - a).perp_dot(c - a);
+    segments
+        .iter()
+        .enumerate()
+        .any(|(idx, &(k0, i0, n, a, b))| {
+            segments[idx + 1..].iter().any(|&(k1, i1, _, c, d)| {
+                let adjacent = k0 == k1 && (i1 == i0 + 1 || (i0 == 0 && i1 == n - 1));
+                !adjacent
+                    && orient(a, b, c) * orient(a, b, d) < 0.0
+                    && orient(c, d, a) * orient(c, d, b) < 0.0
+            })
+        })
+}
@@ -0,0 +1,41 @@
+use std::f64::consts::PI;
+use truck_modeling::*;
+
+fn cube() -> Solid {
+    let v = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    builder::tsweep(&f, Vector3::unit_z())
+}
+
+#[test]
+fn inverted_face_normal() {
+    let cube = cube();
+    cube.face_iter().for_each(|face| {
+        let inverse = face.inverse();
+        let n0 = face.oriented_normal(0.5, 0.5);
+        let n1 = inverse.oriented_normal(0.5, 0.5);
+        assert_near!(n0, -n1);
+        assert_near!(
+            face.surface().normal(0.5, 0.5),
+            inverse.surface().normal(0.5, 0.5)
+        );
+    });
+}
+
+#[test]
+fn outward_oriented_shell() {
+    let cube = cube();
+    let shell = &cube.boundaries()[0];
+    assert_eq!(shell.is_outward_oriented(), Some(true));
+    let inverted = shell.face_iter().map(Face::inverse).collect::<Shell>();
+    assert_eq!(inverted.is_outward_oriented(), Some(false));
+    let mut open = shell.clone();
+    open.pop();
+    assert_eq!(open.is_outward_oriented(), None);
+
+    let v = builder::vertex(Point3::new(0.0, 0.5, 0.0));
+    let wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_x(), Rad(PI));
+    let sphere: Shell = builder::cone(&wire, Vector3::unit_y(), Rad(7.0));
+    assert_eq!(sphere.is_outward_oriented(), Some(true));
+}
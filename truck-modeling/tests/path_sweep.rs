@@ -0,0 +1,104 @@
+use truck_modeling::{builder::FrameMode, errors::Error, *};
+
+fn square_profile() -> Wire {
+    let v = builder::vertex(Point3::new(0.0, -0.5, -0.5));
+    let e = builder::tsweep(&v, Vector3::unit_y());
+    let square: Face = builder::tsweep(&e, Vector3::unit_z());
+    square.boundaries()[0].clone()
+}
+
+fn vertex_points(shell: &Shell) -> Vec<Point3> {
+    let mut ids = std::collections::HashSet::new();
+    shell
+        .vertex_iter()
+        .filter(|v| ids.insert(v.id()))
+        .map(|v| v.point())
+        .collect()
+}
+
+#[test]
+fn mitred_elbow() {
+    let profile = square_profile();
+    let v = builder::vertices([(0.0, 0.0, 0.0), (3.0, 0.0, 0.0), (3.0, 3.0, 0.0)]);
+    let path: Wire = vec![builder::line(&v[0], &v[1]), builder::line(&v[1], &v[2])].into();
+    for frame in [FrameMode::Frenet, FrameMode::RotationMinimizing] {
+        let elbow = builder::sweep_along_path(&profile, &path, frame).unwrap();
+        let shell = &elbow.boundaries()[0];
+        assert_eq!(shell.len(), 10);
+        assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+        // the vertices at the corner are on the miter plane x + y = 3.
+        let points = vertex_points(shell);
+        let corner = points
+            .iter()
+            .filter(|p| 0.0 < p.x && p.y < 2.0)
+            .collect::<Vec<_>>();
+        assert_eq!(corner.len(), 4);
+        corner.iter().for_each(|p| assert_near!(p.x + p.y, 3.0));
+        // the end cap
+        let end = points.iter().filter(|p| p.y.near(&3.0)).collect::<Vec<_>>();
+        assert_eq!(end.len(), 4);
+        end.iter().for_each(|p| {
+            assert_near!((p.x - 3.0).abs(), 0.5);
+            assert_near!(p.z.abs(), 0.5);
+        });
+    }
+}
+
+#[test]
+fn closed_path() {
+    let profile = square_profile();
+    let v = builder::vertices([
+        (0.0, 0.0, 0.0),
+        (3.0, 0.0, 0.0),
+        (3.0, 3.0, 0.0),
+        (0.0, 3.0, 0.0),
+    ]);
+    let path: Wire = (0..4)
+        .map(|i| builder::line(&v[i], &v[(i + 1) % 4]))
+        .collect();
+    let frame = builder::sweep_along_path(&profile, &path, FrameMode::RotationMinimizing).unwrap();
+    let shell = &frame.boundaries()[0];
+    assert_eq!(shell.len(), 16);
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+    assert_eq!(vertex_points(shell).len(), 16);
+
+    // the profile is too large for the corners.
+    let large = builder::scaled(&profile, Point3::origin(), Vector3::new(1.0, 4.0, 4.0));
+    assert_eq!(
+        builder::sweep_along_path(&large, &path, FrameMode::RotationMinimizing).unwrap_err(),
+        Error::SelfIntersectingSweep,
+    );
+}
+
+#[test]
+fn curved_path() {
+    let profile = square_profile();
+    let v = builder::vertices([(0.0, 0.0, 0.0), (3.0, 0.0, 0.0), (5.0, 2.0, 0.0)]);
+    let transit = Point3::new(3.0 + f64::sqrt(2.0), 2.0 - f64::sqrt(2.0), 0.0);
+    let path: Wire = vec![
+        builder::line(&v[0], &v[1]),
+        builder::circle_arc(&v[1], &v[2], transit),
+    ]
+    .into();
+    for frame in [FrameMode::Frenet, FrameMode::RotationMinimizing] {
+        let pipe = builder::sweep_along_path(&profile, &path, frame).unwrap();
+        let shell = &pipe.boundaries()[0];
+        assert_eq!(shell.len(), 10);
+        assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+        // the end profile is perpendicular to the end tangent.
+        let end = &shell[9];
+        end.vertex_iter().for_each(|v| {
+            let p = v.point();
+            assert_near!(p.y, 2.0);
+            assert_near!(f64::max((p.x - 5.0).abs(), p.z.abs()), 0.5);
+        });
+    }
+
+    // the path turning back
+    let v = builder::vertices([(0.0, 0.0, 0.0), (3.0, 0.0, 0.0), (1.0, 0.0, 0.0)]);
+    let path: Wire = vec![builder::line(&v[0], &v[1]), builder::line(&v[1], &v[2])].into();
+    assert_eq!(
+        builder::sweep_along_path(&profile, &path, FrameMode::Frenet).unwrap_err(),
+        Error::SelfIntersectingSweep,
+    );
+}
@@ -0,0 +1,44 @@
+use truck_modeling::*;
+
+fn noisy_square(noise: f64) -> Wire {
+    let v = builder::vertices([
+        (0.0, 0.0, noise),
+        (1.0, 0.0, -noise),
+        (1.0, 1.0, noise),
+        (0.0, 1.0, -noise),
+    ]);
+    (0..4)
+        .map(|i| builder::line(&v[i], &v[(i + 1) % 4]))
+        .collect()
+}
+
+#[test]
+fn noisy_square_is_planar() {
+    let wire = noisy_square(1.0e-4);
+    let plane = wire.is_planar(1.0e-3).unwrap();
+    assert_near!(plane.normal(), Vector3::unit_z());
+    assert_near!(plane.origin(), Point3::new(0.5, 0.5, 0.0));
+    // the normal follows the orientation of the wire.
+    let plane = wire.inverse().is_planar(1.0e-3).unwrap();
+    assert_near!(plane.normal(), -Vector3::unit_z());
+    // too noisy
+    assert!(wire.is_planar(1.0e-5).is_none());
+    assert!(noisy_square(0.1).is_planar(1.0e-3).is_none());
+}
+
+#[test]
+fn curved_wire() {
+    let v = builder::vertices([(1.0, 0.0, 0.0), (-1.0, 0.0, 0.0)]);
+    let wire: Wire = vec![
+        builder::circle_arc(&v[0], &v[1], Point3::new(0.0, 0.0, 1.0)),
+        builder::line(&v[1], &v[0]),
+    ]
+    .into();
+    let plane = wire.is_planar(1.0e-6).unwrap();
+    assert_near!(plane.normal().dot(Vector3::unit_y()).abs(), 1.0);
+
+    // collinear
+    let v = builder::vertices([(0.0, 0.0, 0.0), (1.0, 1.0, 1.0), (2.0, 2.0, 2.0)]);
+    let wire: Wire = vec![builder::line(&v[0], &v[1]), builder::line(&v[1], &v[2])].into();
+    assert!(wire.is_planar(1.0).is_none());
+}
@@ -0,0 +1,104 @@
+use truck_modeling::{errors::Error, sketch::*, *};
+
+fn triangle(lengths: [f64; 3]) -> Sketch {
+    let mut sketch = Sketch::new();
+    let p = [(0.0, 0.0), (1.0, 0.0), (0.5, 1.0)].map(|(x, y)| sketch.add_point(Point2::new(x, y)));
+    (0..3).for_each(|i| {
+        sketch.add_line(p[i], p[(i + 1) % 3]);
+        sketch.add_constraint(Constraint::Distance(p[i], p[(i + 1) % 3], lengths[i]));
+    });
+    sketch
+}
+
+#[test]
+fn triangle_side_lengths() {
+    let lengths = [2.0, 1.5, 1.8];
+    let mut sketch = triangle(lengths);
+    let status = sketch.solve().unwrap();
+    // the rigid motions remain.
+    assert_eq!(
+        status,
+        SketchStatus {
+            dof: 3,
+            redundant: 0
+        }
+    );
+    let pts = sketch.points();
+    (0..3).for_each(|i| assert_near!(pts[i].distance(pts[(i + 1) % 3]), lengths[i]));
+}
+
+#[test]
+fn over_constrained() {
+    // redundant but consistent
+    let mut sketch = triangle([3.0, 4.0, 5.0]);
+    sketch.add_constraint(Constraint::Distance(1, 0, 3.0));
+    let status = sketch.solve().unwrap();
+    assert_eq!(
+        status,
+        SketchStatus {
+            dof: 3,
+            redundant: 1
+        }
+    );
+
+    // inconsistent
+    let mut sketch = triangle([3.0, 4.0, 5.0]);
+    sketch.add_constraint(Constraint::Distance(0, 1, 2.0));
+    let points = sketch.points().to_vec();
+    assert_eq!(sketch.solve().unwrap_err(), Error::InconsistentConstraints);
+    assert_eq!(sketch.points(), &points);
+
+    // The triangle inequality fails.
+    let mut sketch = triangle([1.0, 1.0, 3.0]);
+    assert_eq!(sketch.solve().unwrap_err(), Error::InconsistentConstraints);
+}
+
+#[test]
+fn rectangle() {
+    let mut sketch = Sketch::new();
+    let p = [(0.0, 0.0), (2.1, 0.2), (1.9, 1.1), (0.1, 0.9)]
+        .map(|(x, y)| sketch.add_point(Point2::new(x, y)));
+    let l = [0, 1, 2, 3].map(|i| sketch.add_line(p[i], p[(i + 1) % 4]));
+    sketch.add_constraint(Constraint::Horizontal(l[0]));
+    sketch.add_constraint(Constraint::Vertical(l[1]));
+    sketch.add_constraint(Constraint::Parallel(l[0], l[2]));
+    sketch.add_constraint(Constraint::Parallel(l[1], l[3]));
+    // under-constrained: translations, width, and height
+    let status = sketch.solve().unwrap();
+    assert_eq!(
+        status,
+        SketchStatus {
+            dof: 4,
+            redundant: 0
+        }
+    );
+
+    sketch.add_constraint(Constraint::Distance(p[0], p[1], 2.0));
+    sketch.add_constraint(Constraint::Distance(p[1], p[2], 1.0));
+    let status = sketch.solve().unwrap();
+    assert_eq!(
+        status,
+        SketchStatus {
+            dof: 2,
+            redundant: 0
+        }
+    );
+    let pts = sketch.points();
+    assert_near!(pts[2] - pts[0], Vector2::new(2.0, 1.0));
+    assert_near!(pts[3] - pts[0], Vector2::new(0.0, 1.0));
+}
+
+#[test]
+fn coincident_points() {
+    let mut sketch = Sketch::new();
+    let p = [(0.0, 0.0), (1.0, 0.0), (1.1, 0.1), (2.0, 1.0)]
+        .map(|(x, y)| sketch.add_point(Point2::new(x, y)));
+    sketch.add_constraint(Constraint::Coincident(p[1], p[2]));
+    sketch.add_constraint(Constraint::Distance(p[0], p[1], 1.0));
+    sketch.add_constraint(Constraint::Distance(p[2], p[3], 1.0));
+    sketch.solve().unwrap();
+    let pts = sketch.points();
+    assert_near!(pts[1], pts[2]);
+    assert_near!(pts[0].distance(pts[1]), 1.0);
+    assert_near!(pts[2].distance(pts[3]), 1.0);
+}
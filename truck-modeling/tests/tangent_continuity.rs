@@ -0,0 +1,46 @@
+use truck_modeling::*;
+
+#[test]
+fn line_into_arc() {
+    let v = builder::vertices([
+        (0.0, 0.0, 0.0),
+        (1.0, 0.0, 0.0),
+        (2.0, 1.0, 0.0),
+        (2.0, 2.0, 0.0),
+    ]);
+    let transit = Point3::new(1.0 + f64::sqrt(0.5), 1.0 - f64::sqrt(0.5), 0.0);
+    let arc: Edge = builder::circle_arc(&v[1], &v[2], transit);
+    let mut wire: Wire = vec![
+        builder::line(&v[0], &v[1]),
+        arc.clone(),
+        builder::line(&v[2], &v[3]),
+        builder::line(&v[3], &v[0]),
+    ]
+    .into();
+    assert_eq!(
+        wire.tangent_continuity(1.0e-3),
+        vec![true, true, false, false]
+    );
+
+    // the inverted edges
+    wire[1] = builder::circle_arc(&v[2], &v[1], transit).inverse();
+    wire[3] = builder::line(&v[0], &v[3]).inverse();
+    assert_eq!(
+        wire.tangent_continuity(1.0e-3),
+        vec![true, true, false, false]
+    );
+    let inverse = wire.inverse();
+    assert_eq!(
+        inverse.tangent_continuity(1.0e-3),
+        vec![false, true, true, false]
+    );
+
+    // The open wire does not wrap around.
+    wire.pop_back();
+    assert_eq!(wire.tangent_continuity(1.0e-3), vec![true, true]);
+
+    // the sharp corner at the junction
+    let v0 = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+    let wire: Wire = vec![builder::line(&v0, &v[1]), arc].into();
+    assert_eq!(wire.tangent_continuity(1.0e-3), vec![false]);
+}
@@ -0,0 +1,201 @@
+use crate::*;
+
+impl Default for Background {
+    #[inline(always)]
+    fn default() -> Self { Background::Solid(Color::BLACK) }
+}
+
+impl From<Color> for Background {
+    #[inline(always)]
+    fn from(color: Color) -> Self { Background::Solid(color) }
+}
+
+impl Background {
+    /// Returns the color representing the background.
+    ///
+    /// This color is given to the shaders as the background color in the scene status.
+    /// - `Solid`: the color itself,
+    /// - `Gradient`: the mean of the top and bottom colors,
+    /// - `Skybox`: `Color::BLACK`.
+    /// # Examples
+    /// ```
+    /// use truck_platform::{*, wgpu::Color};
+    /// let background = Background::Gradient(Color::WHITE, Color::BLACK);
+    /// assert_eq!(background.color(), Color { r: 0.5, g: 0.5, b: 0.5, a: 1.0 });
+    /// ```
+    pub fn color(&self) -> Color {
+        match self {
+            Background::Solid(color) => *color,
+            Background::Gradient(top, bottom) => Color {
+                r: (top.r + bottom.r) / 2.0,
+                g: (top.g + bottom.g) / 2.0,
+                b: (top.b + bottom.b) / 2.0,
+                a: (top.a + bottom.a) / 2.0,
+            },
+            Background::Skybox(_) => Color::BLACK,
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Zeroable, Pod)]
+struct BackgroundInfo {
+    top: [f32; 4],
+    bottom: [f32; 4],
+    ray_matrix: [[f32; 4]; 4],
+}
+
+/// The pipelines of the fullscreen pass drawing the background.
+#[derive(Debug)]
+pub(crate) struct BackgroundPipelines {
+    gradient_layout: BindGroupLayout,
+    gradient: RenderPipeline,
+    skybox_layout: BindGroupLayout,
+    skybox: RenderPipeline,
+    sampler: Sampler,
+}
+
+impl BackgroundPipelines {
+    pub(crate) fn new(device: &Device, scene_desc: &SceneDescriptor) -> Self {
+        let module = device.create_shader_module(ShaderModuleDescriptor {
+            source: ShaderSource::Wgsl(include_str!("shaders/background.wgsl").into()),
+            label: None,
+        });
+        let uniform = PreBindGroupLayoutEntry {
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Buffer {
+                ty: BufferBindingType::Uniform,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+        let texture = PreBindGroupLayoutEntry {
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Texture {
+                sample_type: TextureSampleType::Float { filterable: true },
+                view_dimension: TextureViewDimension::D2,
+                multisampled: false,
+            },
+            count: None,
+        };
+        let sampler = PreBindGroupLayoutEntry {
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Sampler(SamplerBindingType::Filtering),
+            count: None,
+        };
+        let gradient_layout = bind_group_util::create_bind_group_layout(device, [&uniform]);
+        let skybox_layout =
+            bind_group_util::create_bind_group_layout(device, [&uniform, &texture, &sampler]);
+        let pipeline = |layout: &BindGroupLayout, fragment_entry: &str| {
+            let pipeline_layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+                bind_group_layouts: &[layout],
+                push_constant_ranges: &[],
+                label: None,
+            });
+            // The background is behind all objects.
+            let depth_stencil = match scene_desc.backend_buffer.depth_test {
+                true => Some(DepthStencilState {
+                    format: TextureFormat::Depth32Float,
+                    depth_write_enabled: false,
+                    depth_compare: CompareFunction::Always,
+                    stencil: Default::default(),
+                    bias: Default::default(),
+                }),
+                false => None,
+            };
+            device.create_render_pipeline(&RenderPipelineDescriptor {
+                layout: Some(&pipeline_layout),
+                vertex: VertexState {
+                    module: &module,
+                    entry_point: Some("vs_main"),
+                    buffers: &[],
+                    compilation_options: Default::default(),
+                },
+                fragment: Some(FragmentState {
+                    module: &module,
+                    entry_point: Some(fragment_entry),
+                    targets: &[Some(ColorTargetState {
+                        format: scene_desc.render_texture.format,
+                        blend: Some(BlendState::REPLACE),
+                        write_mask: ColorWrites::ALL,
+                    })],
+                    compilation_options: Default::default(),
+                }),
+                primitive: Default::default(),
+                depth_stencil,
+                multisample: MultisampleState {
+                    count: scene_desc.backend_buffer.sample_count,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+                label: None,
+                multiview: None,
+                cache: None,
+            })
+        };
+        Self {
+            gradient: pipeline(&gradient_layout, "gradient_main"),
+            gradient_layout,
+            skybox: pipeline(&skybox_layout, "skybox_main"),
+            skybox_layout,
+            sampler: device.create_sampler(&SamplerDescriptor {
+                address_mode_u: AddressMode::Repeat,
+                mag_filter: FilterMode::Linear,
+                min_filter: FilterMode::Linear,
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Returns the pipeline and the bind group drawing the background,
+    /// or `None` if the background is only the clear color.
+    pub(crate) fn pipeline_and_bind_group(
+        &self,
+        device: &Device,
+        scene_desc: &SceneDescriptor,
+    ) -> Option<(&RenderPipeline, BindGroup)> {
+        let into_array = |color: &Color| [color.r, color.g, color.b, color.a].map(|x| x as f32);
+        let camera = &scene_desc.studio.camera;
+        let (width, height) = scene_desc.render_texture.canvas_size;
+        let as_rat = width as f64 / height as f64;
+        // the rotation of the camera without the translation
+        let mut rotation = camera.matrix;
+        rotation.w = Vector4::unit_w();
+        let ray_matrix = rotation * (camera.projection(as_rat) * camera.matrix).invert()?;
+        let (top, bottom) = match &scene_desc.studio.background {
+            Background::Solid(_) => return None,
+            Background::Gradient(top, bottom) => (into_array(top), into_array(bottom)),
+            Background::Skybox(_) => ([0.0; 4], [0.0; 4]),
+        };
+        let info = BackgroundInfo {
+            top,
+            bottom,
+            ray_matrix: ray_matrix.cast().unwrap().into(),
+        };
+        let buffer = BufferHandler::from_slice(&[info], device, BufferUsages::UNIFORM);
+        match &scene_desc.studio.background {
+            Background::Skybox(texture) => {
+                let view = texture.create_view(&Default::default());
+                let bind_group = bind_group_util::create_bind_group(
+                    device,
+                    &self.skybox_layout,
+                    [
+                        buffer.binding_resource(),
+                        BindingResource::TextureView(&view),
+                        BindingResource::Sampler(&self.sampler),
+                    ],
+                );
+                Some((&self.skybox, bind_group))
+            }
+            _ => {
+                let bind_group = bind_group_util::create_bind_group(
+                    device,
+                    &self.gradient_layout,
+                    [buffer.binding_resource()],
+                );
+                Some((&self.gradient, bind_group))
+            }
+        }
+    }
+}
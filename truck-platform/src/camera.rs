@@ -1,4 +1,7 @@
 use crate::*;
+use truck_base::{bounding_box::BoundingBox, tolerance::Origin};
+
+const FIT_MARGIN: f64 = 1.1;
 
 impl Ray {
     /// Returns the origin of the ray
@@ -169,6 +172,258 @@ impl Camera {
         }
     }
 
+    /// Creates a parallel camera looking at `center` from `eye`.
+    /// # Arguments
+    /// * `center`: the point at the center of the screen
+    /// * `eye`: the position of the camera
+    /// * `up`: the upward direction of the screen
+    /// * `half_extent`: half of the screen size, based on the vertical direction of the screen.
+    /// * `near_clip`: distance to the nearest face of the view volume
+    /// * `far_clip`: distance to the farthest face of the view volume
+    /// # Panics
+    /// Panics if `up` is parallel to the direction from `eye` to `center`.
+    /// # Examples
+    /// ```
+    /// use truck_base::{bounding_box::BoundingBox, cgmath64::*, tolerance::Tolerance};
+    /// use truck_platform::*;
+    /// // fits the view to the bounding box of the scene
+    /// let bdb = BoundingBox::from_iter([Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 1.0)]);
+    /// let center = bdb.center();
+    /// let eye = center + Vector3::new(1.0, 1.0, 1.0) * bdb.diameter();
+    /// let camera = Camera::orthographic(
+    ///     center,
+    ///     eye,
+    ///     Vector3::unit_y(),
+    ///     bdb.diameter() / 2.0,
+    ///     0.1,
+    ///     10.0,
+    /// );
+    /// assert!(camera.position().near(&eye));
+    /// assert!(camera.eye_direction().near(&-Vector3::new(1.0, 1.0, 1.0).normalize()));
+    /// assert_eq!(camera.projection_type(), ProjectionType::Parallel);
+    /// ```
+    #[inline(always)]
+    pub fn orthographic(
+        center: Point3,
+        eye: Point3,
+        up: Vector3,
+        half_extent: f64,
+        near_clip: f64,
+        far_clip: f64,
+    ) -> Camera {
+        let matrix = Matrix4::look_at_rh(eye, center, up).invert().unwrap();
+        Camera::parallel_camera(matrix, half_extent * 2.0, near_clip, far_clip)
+    }
+
+    /// Sets the perspective projection, keeping the camera matrix.
+    /// # Arguments
+    /// * `field_of_view`: FOV, based on the vertical direction of the screen.
+    /// * `near_clip`: distance to the nearest face of the view volume
+    /// * `far_clip`: distance to the farthest face of the view volume
+    /// # Details
+    /// The parallel camera is switched to the perspective one. The aspect ratio is not stored,
+    /// since it is given by the canvas size on rendering, cf. [`Camera::projection`].
+    /// # Panics
+    /// Panics unless `0 < field_of_view < PI` and `0 < near_clip < far_clip`.
+    /// # Examples
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use truck_base::{assert_near, cgmath64::*, tolerance::*};
+    /// use truck_platform::*;
+    /// let mut camera = Camera::parallel_camera(Matrix4::identity(), 2.0, 0.1, 10.0);
+    /// camera.set_perspective(Rad(PI / 3.0), 1.0e-2, 1.0e4);
+    /// assert_eq!(camera.projection_type(), ProjectionType::Perspective);
+    /// assert_near!(camera.field_of_view().unwrap().0, PI / 3.0);
+    /// assert_eq!(camera.screen_size(), None);
+    /// // the far clip is computed with the relative error about `far / near * f64::EPSILON`.
+    /// assert!((camera.near_clip() / 1.0e-2 - 1.0).abs() < 1.0e-8);
+    /// assert!((camera.far_clip() / 1.0e4 - 1.0).abs() < 1.0e-8);
+    /// ```
+    pub fn set_perspective<R: Into<Rad<f64>>>(
+        &mut self,
+        field_of_view: R,
+        near_clip: f64,
+        far_clip: f64,
+    ) {
+        *self = Camera::perspective_camera(self.matrix, field_of_view, near_clip, far_clip);
+    }
+
+    /// Sets the parallel projection, keeping the camera matrix.
+    /// # Arguments
+    /// * `screen_size`: screen size, based on the vertical direction of the screen.
+    /// * `near_clip`: distance to the nearest face of the view volume
+    /// * `far_clip`: distance to the farthest face of the view volume
+    /// # Details
+    /// The perspective camera is switched to the parallel one.
+    /// # Examples
+    /// ```
+    /// use truck_base::{assert_near, cgmath64::*, tolerance::*};
+    /// use truck_platform::*;
+    /// let mut camera = Camera::default();
+    /// camera.set_parallel(3.0, 0.5, 20.0);
+    /// assert_eq!(camera.projection_type(), ProjectionType::Parallel);
+    /// assert_eq!(camera.field_of_view(), None);
+    /// assert_near!(camera.screen_size().unwrap(), 3.0);
+    /// assert_near!(camera.near_clip(), 0.5);
+    /// assert_near!(camera.far_clip(), 20.0);
+    /// ```
+    pub fn set_parallel(&mut self, screen_size: f64, near_clip: f64, far_clip: f64) {
+        *self = Camera::parallel_camera(self.matrix, screen_size, near_clip, far_clip);
+    }
+
+    /// Returns the FOV based on the vertical direction of the screen,
+    /// or `None` if the camera is parallel.
+    /// # Examples
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use truck_base::{assert_near, tolerance::*};
+    /// use truck_platform::*;
+    /// // the FOV of the default camera is PI / 4.
+    /// assert_near!(Camera::default().field_of_view().unwrap().0, PI / 4.0);
+    /// ```
+    #[inline(always)]
+    pub fn field_of_view(&self) -> Option<Rad<f64>> {
+        match self.projection_type {
+            ProjectionType::Perspective => Some(Rad(2.0 * f64::atan(1.0 / self.projection[1][1]))),
+            ProjectionType::Parallel => None,
+        }
+    }
+
+    /// Returns the screen size based on the vertical direction of the screen,
+    /// or `None` if the camera is perspective.
+    #[inline(always)]
+    pub fn screen_size(&self) -> Option<f64> {
+        match self.projection_type {
+            ProjectionType::Perspective => None,
+            ProjectionType::Parallel => Some(2.0 / self.projection[1][1]),
+        }
+    }
+
+    /// Returns the distance to the nearest face of the view volume.
+    /// # Examples
+    /// ```
+    /// use truck_base::{assert_near, tolerance::*};
+    /// use truck_platform::*;
+    /// // the clip planes of the default camera
+    /// assert_near!(Camera::default().near_clip(), 0.1);
+    /// assert_near!(Camera::default().far_clip(), 10.0);
+    /// ```
+    #[inline(always)]
+    pub fn near_clip(&self) -> f64 {
+        let (a, b) = (self.projection[2][2], self.projection[3][2]);
+        match self.projection_type {
+            ProjectionType::Perspective => b / (a - 1.0),
+            ProjectionType::Parallel => b / a,
+        }
+    }
+
+    /// Returns the distance to the farthest face of the view volume.
+    #[inline(always)]
+    pub fn far_clip(&self) -> f64 {
+        let (a, b) = (self.projection[2][2], self.projection[3][2]);
+        match self.projection_type {
+            ProjectionType::Perspective => b / (a + 1.0),
+            ProjectionType::Parallel => (b - 1.0) / a,
+        }
+    }
+
+    /// Moves the camera so that `bbox` fills the view with a small margin, keeping the direction
+    /// of the camera and the field of view.
+    /// # Arguments
+    /// * `bbox`: the bounding box of the model
+    /// * `aspect`: the aspect ratio, x-resolution / y-resolution.
+    /// # Details
+    /// The center of the box is placed at the center of the screen. The perspective camera moves
+    /// along its eye direction, and the screen size of the parallel camera is changed. The clip
+    /// planes are also updated so that the view volume contains the whole box. Even if the box is
+    /// flat or a point, the camera is placed at a positive distance. An empty box is ignored.
+    /// # Examples
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use truck_base::{bounding_box::BoundingBox, cgmath64::*};
+    /// use truck_platform::*;
+    /// let matrix = Matrix4::look_at_rh(
+    ///     Point3::new(1.0, 1.0, 1.0),
+    ///     Point3::origin(),
+    ///     Vector3::new(0.0, 1.0, 0.0),
+    /// );
+    /// let mut camera =
+    ///     Camera::perspective_camera(matrix.invert().unwrap(), Rad(PI / 4.0), 0.1, 1.0);
+    /// let bbox = BoundingBox::from_iter([Point3::new(2.0, 2.0, 2.0), Point3::new(3.0, 3.0, 3.0)]);
+    /// camera.fit(&bbox, 1.5);
+    ///
+    /// // all the corners of the box are in the view volume.
+    /// let projection = camera.projection(1.5);
+    /// (0..8).for_each(|i| {
+    ///     let corner = Point3::new(
+    ///         2.0 + (i & 1) as f64,
+    ///         2.0 + ((i >> 1) & 1) as f64,
+    ///         2.0 + ((i >> 2) & 1) as f64,
+    ///     );
+    ///     let p = projection.transform_point(corner);
+    ///     assert!(p.x.abs() < 1.0 && p.y.abs() < 1.0 && p.z.abs() < 1.0);
+    /// });
+    /// // the center of the box is at the center of the screen.
+    /// let center = projection.transform_point(Point3::new(2.5, 2.5, 2.5));
+    /// assert!(center.x.abs() < 1.0e-6 && center.y.abs() < 1.0e-6);
+    /// ```
+    pub fn fit(&mut self, bbox: &BoundingBox<Point3>, aspect: f64) {
+        if bbox.is_empty() {
+            return;
+        }
+        let center = bbox.center();
+        let axes = [0, 1, 2].map(|i| self.matrix[i].truncate());
+        let (min, max) = (bbox.min(), bbox.max());
+        // (depth toward the camera, half extent on the screen) of the corners
+        let mut corners = (0..8)
+            .map(|i| {
+                let corner = Point3::new(
+                    if i & 1 == 0 { min.x } else { max.x },
+                    if i & 2 == 0 { min.y } else { max.y },
+                    if i & 4 == 0 { min.z } else { max.z },
+                );
+                let v = corner - center;
+                let extent = f64::max(v.dot(axes[1]).abs(), v.dot(axes[0]).abs() / aspect);
+                (v.dot(axes[2]), extent)
+            })
+            .collect::<Vec<_>>();
+        // A box seen as a point on the screen is framed by its diameter, or by the unit size.
+        if corners.iter().all(|(_, extent)| extent.so_small()) {
+            let diameter = bbox.diameter();
+            let extent = if diameter.so_small() {
+                1.0
+            } else {
+                diameter / 2.0
+            };
+            corners.iter_mut().for_each(|corner| corner.1 = extent);
+        }
+        let depths = corners.iter().map(|(depth, _)| *depth);
+        let z_min = depths.clone().fold(f64::INFINITY, f64::min);
+        let z_max = depths.fold(f64::NEG_INFINITY, f64::max);
+        let half_extent = corners
+            .iter()
+            .map(|(_, extent)| *extent)
+            .fold(0.0, f64::max);
+        let distance = match self.field_of_view() {
+            Some(fov) => {
+                let tan = f64::tan(fov.0 / 2.0) / FIT_MARGIN;
+                let distance = |(depth, extent): &(f64, f64)| depth + extent / tan;
+                corners
+                    .iter()
+                    .map(distance)
+                    .fold(f64::NEG_INFINITY, f64::max)
+            }
+            None => z_max + half_extent,
+        };
+        let near_clip = f64::max(distance - z_max, distance * 1.0e-2) / 2.0;
+        let far_clip = (distance - z_min) * 2.0;
+        self.matrix[3] = (center + axes[2] * distance).to_homogeneous();
+        match self.field_of_view() {
+            Some(fov) => self.set_perspective(fov, near_clip, far_clip),
+            None => self.set_parallel(half_extent * FIT_MARGIN * 2.0, near_clip, far_clip),
+        }
+    }
+
     /// Returns the projection matrix into the normalized view volume.
     /// # Arguments
     /// `as_rat`: the aspect ratio, x-resolution / y-resolution.
@@ -71,6 +71,7 @@ struct LightInfo {
     light_position: [f32; 4],
     light_color: [f32; 4],
     light_type: [u32; 4],
+    light_attenuation: [f32; 4],
 }
 
 #[repr(C)]
@@ -143,6 +144,11 @@ pub struct RenderObject {
     pipeline: Arc<RenderPipeline>,
     bind_group_layout: Arc<BindGroupLayout>,
     bind_group: Arc<BindGroup>,
+    overlay_index_buffer: Option<Arc<BufferHandler>>,
+    overlay_pipeline: Option<Arc<RenderPipeline>>,
+    picking_pipeline: Option<Arc<RenderPipeline>>,
+    gbuffer_pipeline: Option<Arc<RenderPipeline>>,
+    transparent_center: Option<Point3>,
     visible: bool,
 }
 
@@ -197,6 +203,13 @@ pub struct Light {
     pub color: Vector3,
     /// type of light source: point or uniform
     pub light_type: LightType,
+    /// scale of the color of light
+    pub intensity: f64,
+    /// the constant, linear and quadratic coefficients of the attenuation by the distance,
+    /// i.e. the light is attenuated by `1 / (c0 + c1 * d + c2 * d * d)` at the distance `d`.
+    ///
+    /// The uniform lights are not attenuated.
+    pub attenuation: [f64; 3],
 }
 
 /// Chain that holds [`Device`], [`Queue`] and [`SurfaceConfiguration`].
@@ -237,6 +250,22 @@ struct WindowHandler {
 #[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
 pub struct RenderID(usize);
 
+/// Background of the scene.
+///
+/// The backgrounds other than [`Background::Solid`] are drawn by a fullscreen pass before the objects.
+#[derive(Debug, Clone)]
+pub enum Background {
+    /// uniform color
+    Solid(Color),
+    /// vertical gradient from the top color to the bottom color
+    Gradient(Color, Color),
+    /// equirectangular environment texture, drawn with the rotation of the camera but without its translation.
+    ///
+    /// The texture must be a 2D texture with `TextureUsages::TEXTURE_BINDING`
+    /// whose format is filterable float, e.g. `TextureFormat::Rgba8Unorm`.
+    Skybox(Arc<Texture>),
+}
+
 /// Configuration for studio to shoot the scene.
 #[derive(Debug, Clone)]
 pub struct StudioConfig {
@@ -244,8 +273,8 @@ pub struct StudioConfig {
     pub camera: Camera,
     /// All lights in the scene. Default is `vec![Light::default()]`.
     pub lights: Vec<Light>,
-    /// background color. Default is `Color::BLACK`.
-    pub background: Color,
+    /// background of the scene. Default is `Background::Solid(Color::BLACK)`.
+    pub background: Background,
 }
 
 /// Configuration for buffer preparation
@@ -254,7 +283,27 @@ pub struct BackendBufferConfig {
     /// depth test flag. Default is `true`.
     pub depth_test: bool,
     /// sample count for anti-aliasing by MSAA. 1, 2, 4, 8, or 16. Default is `1`.
+    ///
+    /// If the adapter does not support the count, [`Scene`] falls back to the largest supported
+    /// count less than it with a warning. If the count is `1`, no multisampled buffer is created
+    /// and the objects are rendered directly into the target.
     pub sample_count: u32,
+    /// screen-space outlines drawn after the objects. Default is `None`, i.e. no outlines.
+    pub outline: Option<OutlineConfig>,
+}
+
+/// Configuration for the screen-space outlines on the silhouettes and the creases of objects.
+///
+/// The normals and the depths of the objects are rendered to a G-buffer by
+/// [`Rendered::gbuffer_pipeline`], and the outlines are detected by the Sobel filter on it.
+#[derive(Clone, Debug, Copy, PartialEq)]
+pub struct OutlineConfig {
+    /// color of the outlines. Default is `Color::BLACK`.
+    pub color: Color,
+    /// width of the outlines relative to the canvas height. Default is `0.003`.
+    ///
+    /// The outlines are at least one pixel wide, and have the same appearance in any resolution.
+    pub width: f64,
 }
 
 /// Configuration for rendering texture
@@ -266,6 +315,15 @@ pub struct RenderTextureConfig {
     pub format: TextureFormat,
 }
 
+/// A rectangle of the canvas in pixels, cf. [`Scene::render_viewport`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Viewport {
+    /// the top-left corner `(x, y)`
+    pub origin: (u32, u32),
+    /// the size `(width, height)`
+    pub size: (u32, u32),
+}
+
 /// Configures of [`Scene`](./struct.Scene.html).
 #[derive(Debug, Clone, Default)]
 pub struct SceneDescriptor {
@@ -297,8 +355,11 @@ pub struct Scene {
     device_handler: DeviceHandler,
     objects: SliceHashMap<RenderID, RenderObject>,
     bind_group_layout: BindGroupLayout,
-    forward_depth: Option<Texture>,
+    picking_bind_group_layout: BindGroupLayout,
+    forward_depth_view: Option<TextureView>,
     sampling_buffer: Option<Texture>,
+    background_pipelines: background::BackgroundPipelines,
+    outline_pass: Option<outline::OutlinePass>,
     scene_desc: SceneDescriptor,
     clock: TimeInstant,
 }
@@ -339,6 +400,95 @@ pub trait Rendered {
         layout: &PipelineLayout,
         scene_descriptor: &SceneDescriptor,
     ) -> Arc<RenderPipeline>;
+    /// Creates the index buffer drawn over the object by [`Rendered::overlay_pipeline`],
+    /// e.g. the edges of polygons.
+    ///
+    /// The overlay shares the vertex buffer and the bind group with the object.
+    /// The default implementation returns `None`.
+    #[inline(always)]
+    fn overlay_index_buffer(&self, _device_handler: &DeviceHandler) -> Option<Arc<BufferHandler>> {
+        None
+    }
+    /// Creates the render pipeline of the overlay, drawn just after the object.
+    ///
+    /// The pipeline layout is the same as the one of [`Rendered::pipeline`].
+    /// The overlay is not drawn if this method or [`Rendered::overlay_index_buffer`] returns `None`.
+    /// The default implementation returns `None`.
+    #[inline(always)]
+    fn overlay_pipeline(
+        &self,
+        _device_handler: &DeviceHandler,
+        _layout: &PipelineLayout,
+        _scene_descriptor: &SceneDescriptor,
+    ) -> Option<Arc<RenderPipeline>> {
+        None
+    }
+    /// Returns the center of the object in the world coordinate if the object is transparent.
+    ///
+    /// The transparent objects are drawn after all opaque objects, in the back-to-front order
+    /// of the depths of the centers. The pipelines of them should not write the depth.
+    /// The default implementation returns `None`, i.e. the object is opaque.
+    #[inline(always)]
+    fn transparent_center(&self) -> Option<Point3> { None }
+    /// Creates the render pipeline writing the index of the object for [`Scene::pick`].
+    ///
+    /// The color target is `TextureFormat::R32Uint` and its sample count is always `1`.
+    /// The index is given by the uniform buffer `u32` in `set = 2`.
+    /// Returns `None` if the object cannot be picked, e.g. transparent objects.
+    /// The default implementation returns `None`.
+    ///
+    /// # Shader Example
+    /// ```glsl
+    /// layout(set = 2, binding = 0) uniform PickingIndex {
+    ///     uint picking_index;
+    /// };
+    /// layout(location = 0) out uint color;
+    ///
+    /// void main() {
+    ///     color = picking_index;
+    /// }
+    /// ```
+    #[inline(always)]
+    fn picking_pipeline(
+        &self,
+        _device_handler: &DeviceHandler,
+        _layout: &PipelineLayout,
+        _scene_descriptor: &SceneDescriptor,
+    ) -> Option<Arc<RenderPipeline>> {
+        None
+    }
+    /// Creates the render pipeline writing the normal and the depth of the object to the
+    /// G-buffer, from which the outlines of [`OutlineConfig`] are detected.
+    ///
+    /// The color target is `TextureFormat::Rgba32Float` and its sample count is always `1`.
+    /// The depth stencil is `TextureFormat::Depth32Float` with the depth test.
+    /// The pipeline layout is the same as the one of [`Rendered::pipeline`].
+    /// The objects without G-buffer pipelines, e.g. transparent objects, have no outlines.
+    /// The default implementation returns `None`.
+    ///
+    /// # Shader Example
+    /// ```glsl
+    /// layout(location = 0) in vec3 position; // the position in the world coordinate
+    /// layout(location = 1) in vec3 normal;   // the normal in the world coordinate
+    /// layout(location = 0) out vec4 color;
+    ///
+    /// void main() {
+    ///     vec3 camera_position = camera_matrix[3].xyz;
+    ///     vec3 eye_direction = -camera_matrix[2].xyz;
+    ///     // the distance from the camera along the eye direction, which must be positive.
+    ///     float depth = dot(position - camera_position, eye_direction);
+    ///     color = vec4(normalize(normal), depth);
+    /// }
+    /// ```
+    #[inline(always)]
+    fn gbuffer_pipeline(
+        &self,
+        _device_handler: &DeviceHandler,
+        _layout: &PipelineLayout,
+        _scene_descriptor: &SceneDescriptor,
+    ) -> Option<Arc<RenderPipeline>> {
+        None
+    }
     #[doc(hidden)]
     fn render_object(&self, scene: &Scene) -> RenderObject {
         let (vertex_buffer, index_buffer) = self.vertex_buffer(scene.device_handler());
@@ -352,20 +502,37 @@ pub trait Rendered {
                 label: None,
             });
         let pipeline = self.pipeline(scene.device_handler(), &pipeline_layout, &scene.scene_desc);
+        let overlay_index_buffer = self.overlay_index_buffer(scene.device_handler());
+        let overlay_pipeline =
+            self.overlay_pipeline(scene.device_handler(), &pipeline_layout, &scene.scene_desc);
+        let picking_pipeline = self.picking_pipeline(
+            scene.device_handler(),
+            &scene.picking_pipeline_layout(&bind_group_layout),
+            &scene.scene_desc,
+        );
+        let gbuffer_pipeline =
+            self.gbuffer_pipeline(scene.device_handler(), &pipeline_layout, &scene.scene_desc);
         RenderObject {
             vertex_buffer,
             index_buffer,
             bind_group_layout,
             bind_group,
             pipeline,
+            overlay_index_buffer,
+            overlay_pipeline,
+            picking_pipeline,
+            gbuffer_pipeline,
+            transparent_center: self.transparent_center(),
             visible: true,
         }
     }
 }
 
+mod background;
 mod buffer_handler;
 mod camera;
 mod light;
+mod outline;
 #[doc(hidden)]
 pub mod rendered_macros;
 mod scene;
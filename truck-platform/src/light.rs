@@ -7,6 +7,12 @@ impl Light {
             light_position: self.position.to_homogeneous().cast().unwrap().into(),
             light_color: self.color.cast().unwrap().extend(1.0).into(),
             light_type: [self.light_type.into(), 0, 0, 0],
+            light_attenuation: [
+                self.attenuation[0] as f32,
+                self.attenuation[1] as f32,
+                self.attenuation[2] as f32,
+                self.intensity as f32,
+            ],
         }
     }
 
@@ -21,6 +27,7 @@ impl Light {
     ///     vec4 position;      // the position of light, position.w == 1.0
     ///     vec4 color;         // the color of light, color.w == 1.0
     ///     uvec4 light_type;   // Point => uvec4(0, 0, 0, 0), Uniform => uvec4(1, 0, 0, 0)
+    ///     vec4 attenuation;   // the coefficients of attenuation in xyz, and the intensity in w
     /// };
     /// ```
     #[inline(always)]
@@ -36,6 +43,8 @@ impl Default for Light {
             position: Point3::origin(),
             color: Vector3::new(1.0, 1.0, 1.0),
             light_type: LightType::Point,
+            intensity: 1.0,
+            attenuation: [1.0, 0.0, 0.0],
         }
     }
 }
@@ -0,0 +1,170 @@
+use crate::*;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Zeroable, Pod)]
+struct OutlineInfo {
+    color: [f32; 4],
+    offset: i32,
+    _padding: [i32; 3],
+}
+
+/// The G-buffer and the pipeline of the post-process drawing the outlines.
+#[derive(Debug)]
+pub(crate) struct OutlinePass {
+    gbuffer: Texture,
+    depth: Texture,
+    bind_group: BindGroup,
+    pipeline: RenderPipeline,
+}
+
+impl OutlinePass {
+    /// Returns `None` if [`BackendBufferConfig::outline`] is `None`.
+    pub(crate) fn new(device: &Device, scene_desc: &SceneDescriptor) -> Option<Self> {
+        let config = scene_desc.backend_buffer.outline?;
+        let (width, height) = scene_desc.render_texture.canvas_size;
+        let gbuffer = device.create_texture(&TextureDescriptor {
+            size: Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: TextureDimension::D2,
+            format: TextureFormat::Rgba32Float,
+            usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+            label: None,
+        });
+        let depth = SceneDescriptor::depth_texture(device, (width, height), 1);
+
+        // The outlines spread over both sides of the edges by the offset of the samples of the
+        // filter, which is in proportion to the canvas height.
+        let offset = f64::max((config.width * height as f64 / 2.0).round(), 1.0) as i32;
+        let color = config.color;
+        let info = OutlineInfo {
+            color: [color.r, color.g, color.b, color.a].map(|x| x as f32),
+            offset,
+            _padding: [0; 3],
+        };
+        let buffer = BufferHandler::from_slice(&[info], device, BufferUsages::UNIFORM);
+        let uniform = PreBindGroupLayoutEntry {
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Buffer {
+                ty: BufferBindingType::Uniform,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+        let texture = PreBindGroupLayoutEntry {
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Texture {
+                sample_type: TextureSampleType::Float { filterable: false },
+                view_dimension: TextureViewDimension::D2,
+                multisampled: false,
+            },
+            count: None,
+        };
+        let layout = bind_group_util::create_bind_group_layout(device, [&uniform, &texture]);
+        let view = gbuffer.create_view(&Default::default());
+        let bind_group = bind_group_util::create_bind_group(
+            device,
+            &layout,
+            [
+                buffer.binding_resource(),
+                BindingResource::TextureView(&view),
+            ],
+        );
+
+        let module = device.create_shader_module(ShaderModuleDescriptor {
+            source: ShaderSource::Wgsl(include_str!("shaders/outline.wgsl").into()),
+            label: None,
+        });
+        let pipeline_layout = device.create_pipeline_layout(&PipelineLayoutDescriptor {
+            bind_group_layouts: &[&layout],
+            push_constant_ranges: &[],
+            label: None,
+        });
+        // The outlines are drawn on the resolved texture, without depth and MSAA.
+        let pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
+            layout: Some(&pipeline_layout),
+            vertex: VertexState {
+                module: &module,
+                entry_point: Some("vs_main"),
+                buffers: &[],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(FragmentState {
+                module: &module,
+                entry_point: Some("fs_main"),
+                targets: &[Some(ColorTargetState {
+                    format: scene_desc.render_texture.format,
+                    blend: Some(BlendState::ALPHA_BLENDING),
+                    write_mask: ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: Default::default(),
+            depth_stencil: None,
+            multisample: Default::default(),
+            label: None,
+            multiview: None,
+            cache: None,
+        });
+        Some(Self {
+            gbuffer,
+            depth,
+            bind_group,
+            pipeline,
+        })
+    }
+
+    /// Renders the G-buffer of `objects`, and draws the outlines detected on it over `view`.
+    pub(crate) fn render<'a>(
+        &self,
+        encoder: &mut CommandEncoder,
+        view: &TextureView,
+        scene_bind_group: &BindGroup,
+        objects: impl IntoIterator<Item = (&'a RenderObject, &'a Arc<RenderPipeline>)>,
+    ) {
+        let gbuffer_view = self.gbuffer.create_view(&Default::default());
+        let depth_view = self.depth.create_view(&Default::default());
+        {
+            let mut rpass = encoder.begin_render_pass(&RenderPassDescriptor {
+                color_attachments: &[Some(RenderPassColorAttachment {
+                    view: &gbuffer_view,
+                    resolve_target: None,
+                    ops: Operations {
+                        load: LoadOp::Clear(Color::TRANSPARENT),
+                        store: StoreOp::Store,
+                    },
+                })],
+                depth_stencil_attachment: Some(Scene::depth_stencil_attachment_descriptor(
+                    &depth_view,
+                )),
+                ..Default::default()
+            });
+            rpass.set_bind_group(0, scene_bind_group, &[]);
+            for (object, pipeline) in objects {
+                rpass.set_pipeline(pipeline);
+                rpass.set_bind_group(1, Some(object.bind_group.as_ref()), &[]);
+                Scene::draw_object(&mut rpass, object);
+            }
+        }
+        let mut rpass = encoder.begin_render_pass(&RenderPassDescriptor {
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view,
+                resolve_target: None,
+                ops: Operations {
+                    load: LoadOp::Load,
+                    store: StoreOp::Store,
+                },
+            })],
+            ..Default::default()
+        });
+        rpass.set_pipeline(&self.pipeline);
+        rpass.set_bind_group(0, &self.bind_group, &[]);
+        rpass.draw(0..3, 0..1);
+    }
+}
@@ -1,13 +1,26 @@
 use crate::*;
+use background::BackgroundPipelines;
+use outline::OutlinePass;
+#[cfg(not(feature = "deterministic-ids"))]
 use std::sync::atomic::{AtomicUsize, Ordering};
 use winit::window::Window;
 
+#[cfg(not(feature = "deterministic-ids"))]
 static MAXID: AtomicUsize = AtomicUsize::new(0);
 
 impl RenderID {
     /// Generate the unique `RenderID`.
+    ///
+    /// With the feature `deterministic-ids`, the ID is given by the thread-local counter in
+    /// `truck_base::id`, which is reset by `truck_base::id::reset_id_counter`.
     #[inline(always)]
-    pub fn gen() -> Self { RenderID(MAXID.fetch_add(1, Ordering::SeqCst)) }
+    pub fn gen() -> Self {
+        #[cfg(not(feature = "deterministic-ids"))]
+        let id = MAXID.fetch_add(1, Ordering::SeqCst);
+        #[cfg(feature = "deterministic-ids")]
+        let id = truck_base::id::next_id();
+        RenderID(id)
+    }
 }
 
 async fn init_default_device(
@@ -99,7 +112,7 @@ impl Default for StudioConfig {
     #[inline(always)]
     fn default() -> StudioConfig {
         StudioConfig {
-            background: Color::BLACK,
+            background: Background::default(),
             camera: Camera::default(),
             lights: vec![Light::default()],
         }
@@ -112,6 +125,17 @@ impl Default for BackendBufferConfig {
         BackendBufferConfig {
             depth_test: true,
             sample_count: 1,
+            outline: None,
+        }
+    }
+}
+
+impl Default for OutlineConfig {
+    #[inline(always)]
+    fn default() -> OutlineConfig {
+        OutlineConfig {
+            color: Color::BLACK,
+            width: 0.003,
         }
     }
 }
@@ -172,6 +196,7 @@ impl SceneDescriptor {
     ///     vec4 position;      // the position of light, position.w == 1.0
     ///     vec4 color;         // the color of light, color.w == 1.0
     ///     uvec4 light_type;   // Point => uvec4(0, 0, 0, 0), Uniform => uvec4(1, 0, 0, 0)
+    ///     vec4 attenuation;   // the coefficients of attenuation in xyz, and the intensity in w
     /// };
     ///
     /// layout(set = 0, binding = 1) buffer Lights {
@@ -208,7 +233,7 @@ impl SceneDescriptor {
     }
 
     #[inline(always)]
-    fn depth_texture(device: &Device, size: (u32, u32), sample_count: u32) -> Texture {
+    pub(crate) fn depth_texture(device: &Device, size: (u32, u32), sample_count: u32) -> Texture {
         device.create_texture(&TextureDescriptor {
             size: Extent3d {
                 width: size.0,
@@ -219,12 +244,34 @@ impl SceneDescriptor {
             sample_count,
             dimension: TextureDimension::D2,
             format: TextureFormat::Depth32Float,
-            usage: TextureUsages::RENDER_ATTACHMENT,
+            usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING,
             view_formats: &[],
             label: None,
         })
     }
 
+    /// Replaces `sample_count` with the largest count not more than it which the adapter supports
+    /// for the render texture and the depth buffer, and warns if it is replaced.
+    fn validate_sample_count(&mut self, adapter: &Adapter) {
+        let requested = self.backend_buffer.sample_count;
+        let format = self.render_texture.format;
+        let color = adapter.get_texture_format_features(format).flags;
+        let depth = adapter
+            .get_texture_format_features(TextureFormat::Depth32Float)
+            .flags;
+        let supported = |count: &u32| {
+            color.sample_count_supported(*count)
+                && (!self.backend_buffer.depth_test || depth.sample_count_supported(*count))
+        };
+        let sample_count = (1..=requested).rev().find(supported).unwrap_or(1);
+        if sample_count != requested {
+            log::warn!(
+                "The sample count {requested} is not supported for {format:?}. {sample_count} is used instead."
+            );
+            self.backend_buffer.sample_count = sample_count;
+        }
+    }
+
     fn backend_buffers(&self, device: &Device) -> (Option<Texture>, Option<Texture>) {
         let forward_depth = if self.backend_buffer.depth_test {
             Some(Self::depth_texture(
@@ -267,9 +314,15 @@ impl DerefMut for SceneDescriptorMut<'_> {
 
 impl Drop for SceneDescriptorMut<'_> {
     fn drop(&mut self) {
+        let scene = &mut *self.0;
+        scene
+            .scene_desc
+            .validate_sample_count(scene.device_handler.adapter());
         let (forward_depth, sampling_buffer) = self.backend_buffers(self.0.device());
-        self.0.forward_depth = forward_depth;
+        self.0.forward_depth_view = forward_depth.map(|tex| tex.create_view(&Default::default()));
         self.0.sampling_buffer = sampling_buffer;
+        self.0.background_pipelines = BackgroundPipelines::new(self.0.device(), &self.0.scene_desc);
+        self.0.outline_pass = OutlinePass::new(self.0.device(), &self.0.scene_desc);
     }
 }
 
@@ -325,21 +378,61 @@ impl Scene {
         )
     }
 
+    #[inline(always)]
+    fn picking_bgl_entry() -> PreBindGroupLayoutEntry {
+        PreBindGroupLayoutEntry {
+            visibility: ShaderStages::VERTEX | ShaderStages::FRAGMENT,
+            ty: BindingType::Buffer {
+                ty: BufferBindingType::Uniform,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn picking_pipeline_layout(
+        &self,
+        object_layout: &BindGroupLayout,
+    ) -> PipelineLayout {
+        self.device()
+            .create_pipeline_layout(&PipelineLayoutDescriptor {
+                bind_group_layouts: &[
+                    &self.bind_group_layout,
+                    object_layout,
+                    &self.picking_bind_group_layout,
+                ],
+                push_constant_ranges: &[],
+                label: None,
+            })
+    }
+
     /// constructor
     // About `scene_desc`, entity is better than reference for the performance.
     // This is reference because only for as wgpu is.
     #[inline(always)]
     pub fn new(device_handler: DeviceHandler, scene_desc: &SceneDescriptor) -> Scene {
+        let mut scene_desc = scene_desc.clone();
+        scene_desc.validate_sample_count(device_handler.adapter());
         let device = device_handler.device();
         let (forward_depth, sampling_buffer) = scene_desc.backend_buffers(device);
+        let forward_depth_view = forward_depth.map(|tex| tex.create_view(&Default::default()));
         let bind_group_layout = Self::init_scene_bind_group_layout(device);
+        let picking_bind_group_layout =
+            bind_group_util::create_bind_group_layout(device, &[Self::picking_bgl_entry()]);
+        let background_pipelines = BackgroundPipelines::new(device, &scene_desc);
+        let outline_pass = OutlinePass::new(device, &scene_desc);
         Scene {
             objects: Default::default(),
             bind_group_layout,
-            forward_depth,
+            picking_bind_group_layout,
+            forward_depth_view,
             sampling_buffer,
+            background_pipelines,
+            outline_pass,
             clock: TimeInstant::now(),
-            scene_desc: scene_desc.clone(),
+            scene_desc,
             device_handler,
         }
     }
@@ -439,6 +532,7 @@ impl Scene {
     ///     vec4 position;      // the position of light, position.w == 1.0
     ///     vec4 color;         // the color of light, color.w == 1.0
     ///     uvec4 light_type;   // Point => uvec4(0, 0, 0, 0), Uniform => uvec4(1, 0, 0, 0)
+    ///     vec4 attenuation;   // the coefficients of attenuation in xyz, and the intensity in w
     /// };
     ///
     /// layout(set = 0, binding = 1) buffer Lights {
@@ -462,13 +556,17 @@ impl Scene {
     /// ```
     #[inline(always)]
     pub fn scene_status_buffer(&self) -> BufferHandler {
-        let bk = self.scene_desc.studio.background;
-        let size = self.scene_desc.render_texture.canvas_size;
+        self.create_scene_status_buffer(&self.scene_desc)
+    }
+
+    fn create_scene_status_buffer(&self, scene_desc: &SceneDescriptor) -> BufferHandler {
+        let bk = scene_desc.studio.background.color();
+        let size = scene_desc.render_texture.canvas_size;
         let scene_info = SceneInfo {
             background_color: [bk.r as f32, bk.g as f32, bk.b as f32, bk.a as f32],
             resolution: [size.0, size.1],
             time: self.elapsed().as_secs_f32(),
-            num_of_lights: self.scene_desc.studio.lights.len() as u32,
+            num_of_lights: scene_desc.studio.lights.len() as u32,
         };
         BufferHandler::from_slice(&[scene_info], self.device(), BufferUsages::UNIFORM)
     }
@@ -486,6 +584,7 @@ impl Scene {
     ///     vec4 position;      // the position of light, position.w == 1.0
     ///     vec4 color;         // the color of light, color.w == 1.0
     ///     uvec4 light_type;   // Point => uvec4(0, 0, 0, 0), Uniform => uvec4(1, 0, 0, 0)
+    ///     vec4 attenuation;   // the coefficients of attenuation in xyz, and the intensity in w
     /// };
     ///
     /// layout(set = 0, binding = 1) buffer Lights {
@@ -498,14 +597,17 @@ impl Scene {
     /// };
     /// ```
     #[inline(always)]
-    pub fn scene_bind_group(&self) -> BindGroup {
+    pub fn scene_bind_group(&self) -> BindGroup { self.create_scene_bind_group(&self.scene_desc) }
+
+    fn create_scene_bind_group(&self, scene_desc: &SceneDescriptor) -> BindGroup {
         bind_group_util::create_bind_group(
             self.device(),
             &self.bind_group_layout,
             vec![
-                self.camera_buffer().binding_resource(),
-                self.lights_buffer().binding_resource(),
-                self.scene_status_buffer().binding_resource(),
+                scene_desc.camera_buffer(self.device()).binding_resource(),
+                scene_desc.lights_buffer(self.device()).binding_resource(),
+                self.create_scene_status_buffer(scene_desc)
+                    .binding_resource(),
             ],
         )
     }
@@ -583,6 +685,8 @@ impl Scene {
                 let (vb, ib) = object.vertex_buffer(handler);
                 render_object.vertex_buffer = vb;
                 render_object.index_buffer = ib;
+                render_object.overlay_index_buffer = object.overlay_index_buffer(handler);
+                render_object.transparent_center = object.transparent_center();
                 true
             }
         }
@@ -612,6 +716,7 @@ impl Scene {
             Some(render_object) => {
                 let bind_group = object.bind_group(handler, &render_object.bind_group_layout);
                 render_object.bind_group = bind_group;
+                render_object.transparent_center = object.transparent_center();
                 true
             }
             _ => false,
@@ -649,6 +754,25 @@ impl Scene {
                 });
                 render_object.pipeline =
                     object.pipeline(handler, &pipeline_layout, &self.scene_desc);
+                // The overlay may be switched, e.g. by `PolygonState::show_edges`.
+                render_object.overlay_index_buffer = object.overlay_index_buffer(handler);
+                render_object.overlay_pipeline =
+                    object.overlay_pipeline(handler, &pipeline_layout, &self.scene_desc);
+                let picking_pipeline_layout =
+                    device.create_pipeline_layout(&PipelineLayoutDescriptor {
+                        bind_group_layouts: &[
+                            &self.bind_group_layout,
+                            &render_object.bind_group_layout,
+                            &self.picking_bind_group_layout,
+                        ],
+                        push_constant_ranges: &[],
+                        label: None,
+                    });
+                render_object.picking_pipeline =
+                    object.picking_pipeline(handler, &picking_pipeline_layout, &self.scene_desc);
+                render_object.gbuffer_pipeline =
+                    object.gbuffer_pipeline(handler, &pipeline_layout, &self.scene_desc);
+                render_object.transparent_center = object.transparent_center();
                 true
             }
             _ => false,
@@ -667,7 +791,7 @@ impl Scene {
         objects.into_iter().fold(true, closure)
     }
     #[inline(always)]
-    fn depth_stencil_attachment_descriptor(
+    pub(crate) fn depth_stencil_attachment_descriptor(
         depth_view: &TextureView,
     ) -> RenderPassDepthStencilAttachment<'_> {
         RenderPassDepthStencilAttachment {
@@ -681,71 +805,335 @@ impl Scene {
     }
 
     /// Renders the scene to `view`.
+    ///
+    /// The target is cleared by the background color, and then the draw order is as follows.
+    /// 1. the background, if it is not [`Background::Solid`],
+    /// 2. the opaque objects,
+    /// 3. the transparent objects from back to front, see [`Rendered::transparent_center`],
+    /// 4. the outlines by a post-process, if [`BackendBufferConfig::outline`] is set.
     pub fn render(&self, view: &TextureView) {
         let bind_group = self.scene_bind_group();
-        let depth_view = self
-            .forward_depth
-            .as_ref()
-            .map(|tex| tex.create_view(&Default::default()));
+        let mut encoder = self
+            .device()
+            .create_command_encoder(&CommandEncoderDescriptor { label: None });
+        self.encode_render_pass(&mut encoder, view, &self.scene_desc, &bind_group, None);
+        if let Some(outline_pass) = &self.outline_pass {
+            let objects = self
+                .objects
+                .as_slice()
+                .iter()
+                .filter(|(_, object)| object.visible)
+                .filter_map(|(_, object)| Some((object, object.gbuffer_pipeline.as_ref()?)));
+            outline_pass.render(&mut encoder, view, &bind_group, objects);
+        }
+        self.queue().submit(vec![encoder.finish()]);
+    }
+
+    /// Renders the scene through `camera` into the rectangle `viewport` of `view`, e.g. for the
+    /// split views of CAD applications.
+    ///
+    /// # Details
+    /// The render objects are shared with [`Scene::render`], and only the camera is replaced.
+    /// The aspect ratio of the projection is the one of `viewport`. The background is drawn and
+    /// the objects are clipped in `viewport`, and the pixels outside it are kept. The viewport and
+    /// the scissor rectangle are set only in the render pass of this method, so they do not affect
+    /// the other renderings. The depth buffer is cleared for each viewport.
+    /// The outlines of [`BackendBufferConfig::outline`] are not drawn.
+    ///
+    /// # Remarks
+    /// If MSAA is enabled, the whole multisampled buffer, which keeps the other viewports, is
+    /// resolved into `view`. Hence, all the viewports of a frame must be rendered into the same
+    /// `view` in succession.
+    ///
+    /// # Panics
+    /// Panics if `viewport` is empty or is not contained in the canvas.
+    pub fn render_viewport(&self, view: &TextureView, camera: &Camera, viewport: Viewport) {
+        let (width, height) = self.scene_desc.render_texture.canvas_size;
+        let Viewport { origin, size } = viewport;
+        assert!(
+            size.0 > 0 && size.1 > 0 && origin.0 + size.0 <= width && origin.1 + size.1 <= height,
+            "the viewport {viewport:?} is out of the canvas {:?}",
+            (width, height),
+        );
+        let mut scene_desc = self.scene_desc.clone();
+        scene_desc.studio.camera = *camera;
+        scene_desc.render_texture.canvas_size = size;
+        // The solid background is drawn since the clear color fills the whole texture.
+        if let Background::Solid(color) = scene_desc.studio.background {
+            scene_desc.studio.background = Background::Gradient(color, color);
+        }
+        let bind_group = self.create_scene_bind_group(&scene_desc);
+        let mut encoder = self
+            .device()
+            .create_command_encoder(&CommandEncoderDescriptor { label: None });
+        self.encode_render_pass(&mut encoder, view, &scene_desc, &bind_group, Some(viewport));
+        self.queue().submit(vec![encoder.finish()]);
+    }
+
+    /// Encodes the render pass drawing the background and the objects by `scene_desc`, whose
+    /// camera and canvas may differ from the ones of the scene.
+    ///
+    /// The color attachment is cleared if `viewport` is `None`, otherwise the pass is restricted
+    /// to `viewport`.
+    fn encode_render_pass(
+        &self,
+        encoder: &mut CommandEncoder,
+        view: &TextureView,
+        scene_desc: &SceneDescriptor,
+        bind_group: &BindGroup,
+        viewport: Option<Viewport>,
+    ) {
+        let background = self
+            .background_pipelines
+            .pipeline_and_bind_group(self.device(), scene_desc);
         let sampled_view = self
             .sampling_buffer
             .as_ref()
             .map(|tex| tex.create_view(&Default::default()));
-        let mut encoder = self
-            .device()
-            .create_command_encoder(&CommandEncoderDescriptor { label: None });
+        let (attachment, resolve_target) = match sampled_view.as_ref() {
+            Some(sampled_view) => (sampled_view, Some(view)),
+            None => (view, None),
+        };
+        let load = match viewport {
+            Some(_) => LoadOp::Load,
+            None => LoadOp::Clear(scene_desc.studio.background.color()),
+        };
+        let mut rpass = encoder.begin_render_pass(&RenderPassDescriptor {
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: attachment,
+                resolve_target,
+                ops: Operations {
+                    load,
+                    store: StoreOp::Store,
+                },
+            })],
+            depth_stencil_attachment: self
+                .forward_depth_view
+                .as_ref()
+                .map(Self::depth_stencil_attachment_descriptor),
+            ..Default::default()
+        });
+        if let Some(Viewport { origin, size }) = viewport {
+            let (x, y, w, h) = (origin.0, origin.1, size.0, size.1);
+            rpass.set_viewport(x as f32, y as f32, w as f32, h as f32, 0.0, 1.0);
+            rpass.set_scissor_rect(x, y, w, h);
+        }
+        if let Some((pipeline, background_bind_group)) = &background {
+            rpass.set_pipeline(pipeline);
+            rpass.set_bind_group(0, background_bind_group, &[]);
+            rpass.draw(0..3, 0..1);
+        }
+        rpass.set_bind_group(0, bind_group, &[]);
+        for object in self.sorted_objects(&scene_desc.studio.camera) {
+            rpass.set_pipeline(&object.pipeline);
+            rpass.set_bind_group(1, Some(object.bind_group.as_ref()), &[]);
+            Self::draw_object(&mut rpass, object);
+            if let (Some(index_buffer), Some(pipeline)) =
+                (&object.overlay_index_buffer, &object.overlay_pipeline)
+            {
+                rpass.set_pipeline(pipeline);
+                rpass.set_index_buffer(index_buffer.buffer.slice(..), IndexFormat::Uint32);
+                let index_size = index_buffer.size as u32 / size_of::<u32>() as u32;
+                rpass.draw_indexed(0..index_size, 0, 0..1);
+            }
+        }
+    }
+
+    /// Returns the visible objects, the opaque ones first and then the transparent ones sorted
+    /// from back to front.
+    fn sorted_objects(&self, camera: &Camera) -> Vec<&RenderObject> {
+        let (position, direction) = (camera.position(), camera.eye_direction());
+        let (mut opaque, mut transparent) = (Vec::new(), Vec::new());
+        self.objects
+            .as_slice()
+            .iter()
+            .filter(|(_, object)| object.visible)
+            .for_each(|(_, object)| match object.transparent_center {
+                Some(center) => transparent.push(((center - position).dot(direction), object)),
+                None => opaque.push(object),
+            });
+        transparent.sort_by(|(depth0, _), (depth1, _)| depth1.total_cmp(depth0));
+        opaque.extend(transparent.into_iter().map(|(_, object)| object));
+        opaque
+    }
+
+    /// Sets the vertex buffers of `object` and draws it.
+    pub(crate) fn draw_object(rpass: &mut RenderPass<'_>, object: &RenderObject) {
+        rpass.set_vertex_buffer(0, object.vertex_buffer.buffer.slice(..));
+        match object.index_buffer {
+            Some(ref index_buffer) => {
+                rpass.set_index_buffer(index_buffer.buffer.slice(..), IndexFormat::Uint32);
+                let index_size = index_buffer.size as u32 / size_of::<u32>() as u32;
+                rpass.draw_indexed(0..index_size, 0, 0..1);
+            }
+            None => rpass.draw(
+                0..(object.vertex_buffer.size / object.vertex_buffer.stride) as u32,
+                0..1,
+            ),
+        }
+    }
+
+    /// Returns the view of the depth texture written by [`Scene::render`], for the custom
+    /// post-processing, e.g. SSAO or DOF.
+    ///
+    /// # Details
+    /// The format is `TextureFormat::Depth32Float` with `TextureUsages::TEXTURE_BINDING`, and the
+    /// texture is multisampled if [`BackendBufferConfig::sample_count`] is more than one.
+    /// The depth is the one of the last rendering, and must not be sampled in the passes of
+    /// [`Scene::render`] since it is the depth attachment of them.
+    /// Returns `None` if [`BackendBufferConfig::depth_test`] is `false`.
+    ///
+    /// # Lifetime
+    /// The texture is recreated when the descriptor is changed by [`Scene::descriptor_mut`],
+    /// e.g. when [`WindowScene`] is resized in [`WindowScene::size_alignment`]. The bind groups
+    /// created from the old view keep referring to the old texture, so they must be recreated.
+    #[inline(always)]
+    pub fn depth_texture_view(&self) -> Option<&TextureView> { self.forward_depth_view.as_ref() }
+
+    /// Returns the render id of the object drawn at `ndc`, the normalized device coordinate
+    /// on the canvas.
+    ///
+    /// # Details
+    /// The indices of the visible objects are rendered by [`Rendered::picking_pipeline`] to an
+    /// offscreen `R32Uint` texture without MSAA, and the pixel under `ndc` is read back.
+    /// The objects without picking pipelines, e.g. transparent objects, are ignored.
+    ///
+    /// Returns `None` if there is no object at `ndc` or `ndc` is out of the canvas.
+    pub async fn pick(&self, ndc: (f32, f32)) -> Option<RenderID> {
+        let (device, queue) = (self.device(), self.queue());
+        let (width, height) = self.scene_desc.render_texture.canvas_size;
+        let x = (ndc.0 + 1.0) / 2.0 * width as f32;
+        let y = (1.0 - ndc.1) / 2.0 * height as f32;
+        if !(0.0..width as f32).contains(&x) || !(0.0..height as f32).contains(&y) {
+            return None;
+        }
+
+        let objects = self
+            .objects
+            .as_slice()
+            .iter()
+            .filter(|(_, object)| object.visible)
+            .filter_map(|(id, object)| Some((*id, object, object.picking_pipeline.as_ref()?)))
+            .collect::<Vec<_>>();
+        // The index `0` is reserved for the background.
+        let picking_bind_groups = (1..=objects.len() as u32)
+            .map(|index| {
+                let buffer = BufferHandler::from_slice(&[index], device, BufferUsages::UNIFORM);
+                bind_group_util::create_bind_group(
+                    device,
+                    &self.picking_bind_group_layout,
+                    [buffer.binding_resource()],
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let texture = device.create_texture(&TextureDescriptor {
+            size: Extent3d {
+                width,
+                height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: TextureDimension::D2,
+            format: TextureFormat::R32Uint,
+            usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::COPY_SRC,
+            view_formats: &[],
+            label: None,
+        });
+        let view = texture.create_view(&Default::default());
+        let depth_texture = SceneDescriptor::depth_texture(device, (width, height), 1);
+        let depth_view = depth_texture.create_view(&Default::default());
+        let bind_group = self.scene_bind_group();
+        let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor { label: None });
         {
-            let (attachment, resolve_target) = match sampled_view.as_ref() {
-                Some(sampled_view) => (sampled_view, Some(view)),
-                None => (view, None),
-            };
             let mut rpass = encoder.begin_render_pass(&RenderPassDescriptor {
                 color_attachments: &[Some(RenderPassColorAttachment {
-                    view: attachment,
-                    resolve_target,
+                    view: &view,
+                    resolve_target: None,
                     ops: Operations {
-                        load: LoadOp::Clear(self.scene_desc.studio.background),
+                        load: LoadOp::Clear(Color::TRANSPARENT),
                         store: StoreOp::Store,
                     },
                 })],
-                depth_stencil_attachment: depth_view
-                    .as_ref()
-                    .map(Self::depth_stencil_attachment_descriptor),
+                depth_stencil_attachment: Some(Self::depth_stencil_attachment_descriptor(
+                    &depth_view,
+                )),
                 ..Default::default()
             });
             rpass.set_bind_group(0, &bind_group, &[]);
-            for (_, object) in &self.objects {
-                if !object.visible {
-                    continue;
-                }
-                rpass.set_pipeline(&object.pipeline);
+            for ((_, object, pipeline), picking_bind_group) in
+                objects.iter().zip(&picking_bind_groups)
+            {
+                rpass.set_pipeline(pipeline);
                 rpass.set_bind_group(1, Some(object.bind_group.as_ref()), &[]);
-                rpass.set_vertex_buffer(0, object.vertex_buffer.buffer.slice(..));
-                match object.index_buffer {
-                    Some(ref index_buffer) => {
-                        rpass.set_index_buffer(index_buffer.buffer.slice(..), IndexFormat::Uint32);
-                        let index_size = index_buffer.size as u32 / size_of::<u32>() as u32;
-                        rpass.draw_indexed(0..index_size, 0, 0..1);
-                    }
-                    None => rpass.draw(
-                        0..(object.vertex_buffer.size / object.vertex_buffer.stride) as u32,
-                        0..1,
-                    ),
-                }
+                rpass.set_bind_group(2, picking_bind_group, &[]);
+                Self::draw_object(&mut rpass, object);
             }
         }
-        self.queue().submit(vec![encoder.finish()]);
+
+        // copies only the pixel under `ndc`.
+        let buffer = device.create_buffer(&BufferDescriptor {
+            label: None,
+            mapped_at_creation: false,
+            usage: BufferUsages::COPY_DST | BufferUsages::MAP_READ,
+            size: size_of::<u32>() as u64,
+        });
+        encoder.copy_texture_to_buffer(
+            ImageCopyTexture {
+                texture: &texture,
+                mip_level: 0,
+                origin: Origin3d {
+                    x: x as u32,
+                    y: y as u32,
+                    z: 0,
+                },
+                aspect: TextureAspect::All,
+            },
+            ImageCopyBuffer {
+                buffer: &buffer,
+                layout: ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: None,
+                    rows_per_image: None,
+                },
+            },
+            Extent3d {
+                width: 1,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+        );
+        queue.submit(Some(encoder.finish()));
+        let buffer_slice = buffer.slice(..);
+        let (sender, receiver) = futures_intrusive::channel::shared::oneshot_channel();
+        buffer_slice.map_async(MapMode::Read, move |v| sender.send(v).unwrap());
+        device.poll(Maintain::Wait);
+        let index = match receiver.receive().await {
+            Some(Ok(_)) => {
+                let range = buffer_slice.get_mapped_range();
+                u32::from_ne_bytes([range[0], range[1], range[2], range[3]])
+            }
+            Some(Err(e)) => panic!("{}", e),
+            None => panic!("Asynchronous processing fails"),
+        };
+        objects
+            .get((index as usize).checked_sub(1)?)
+            .map(|(id, _, _)| *id)
     }
 
-    /// Render image to buffer.
-    pub async fn render_to_buffer(&self) -> Vec<u8> {
-        let texture = self.compatible_texture();
-        let view = texture.create_view(&Default::default());
-        self.render(&view);
+    /// Copies `texture` of the canvas size into a vector, removing the paddings of the rows.
+    ///
+    /// The texture must have `TextureUsages::COPY_SRC` and a format of 4 bytes per pixel,
+    /// e.g. the one created by [`Scene::compatible_texture`].
+    pub async fn read_texture(&self, texture: &Texture) -> Vec<u8> {
         let (device, queue) = (self.device(), self.queue());
-
         let (width, height) = self.scene_desc.render_texture.canvas_size;
-        let size = (width * height * 4) as u64;
+        // `bytes_per_row` of the copy must be a multiple of `COPY_BYTES_PER_ROW_ALIGNMENT`.
+        let row_size = width * 4;
+        let padded_row_size =
+            row_size.div_ceil(COPY_BYTES_PER_ROW_ALIGNMENT) * COPY_BYTES_PER_ROW_ALIGNMENT;
+        let size = (padded_row_size * height) as u64;
         let buffer = device.create_buffer(&BufferDescriptor {
             label: None,
             mapped_at_creation: false,
@@ -755,7 +1143,7 @@ impl Scene {
         let mut encoder = device.create_command_encoder(&CommandEncoderDescriptor { label: None });
         encoder.copy_texture_to_buffer(
             ImageCopyTexture {
-                texture: &texture,
+                texture,
                 mip_level: 0,
                 origin: Origin3d::ZERO,
                 aspect: TextureAspect::All,
@@ -764,7 +1152,7 @@ impl Scene {
                 buffer: &buffer,
                 layout: ImageDataLayout {
                     offset: 0,
-                    bytes_per_row: Some(width * 4),
+                    bytes_per_row: Some(padded_row_size),
                     rows_per_image: Some(height),
                 },
             },
@@ -780,11 +1168,49 @@ impl Scene {
         buffer_slice.map_async(MapMode::Read, move |v| sender.send(v).unwrap());
         device.poll(Maintain::Wait);
         match receiver.receive().await {
-            Some(Ok(_)) => buffer_slice.get_mapped_range().iter().copied().collect(),
+            Some(Ok(_)) => buffer_slice
+                .get_mapped_range()
+                .chunks(padded_row_size as usize)
+                .flat_map(|row| &row[..row_size as usize])
+                .copied()
+                .collect(),
             Some(Err(e)) => panic!("{}", e),
             None => panic!("Asynchronous processing fails"),
         }
     }
+
+    /// Render image to buffer.
+    pub async fn render_to_buffer(&self) -> Vec<u8> {
+        let texture = self.compatible_texture();
+        let view = texture.create_view(&Default::default());
+        self.render(&view);
+        self.read_texture(&texture).await
+    }
+
+    /// Renders the scene to an offscreen texture of the size `(width, height)` and reads it back.
+    ///
+    /// # Details
+    /// The canvas size of the scene is changed only while rendering. If the sample count of the
+    /// scene is more than one, the multisampled image is resolved before reading back.
+    ///
+    /// # Panics
+    /// Panics if the format of the render texture is not one of `Rgba8Unorm`, `Rgba8UnormSrgb`,
+    /// `Bgra8Unorm` and `Bgra8UnormSrgb`.
+    pub async fn render_to_image(&mut self, width: u32, height: u32) -> image::RgbaImage {
+        let canvas_size = self.scene_desc.render_texture.canvas_size;
+        self.descriptor_mut().render_texture.canvas_size = (width, height);
+        let mut buffer = self.render_to_buffer().await;
+        let format = self.scene_desc.render_texture.format;
+        self.descriptor_mut().render_texture.canvas_size = canvas_size;
+        match format {
+            TextureFormat::Rgba8Unorm | TextureFormat::Rgba8UnormSrgb => {}
+            TextureFormat::Bgra8Unorm | TextureFormat::Bgra8UnormSrgb => {
+                buffer.chunks_mut(4).for_each(|pixel| pixel.swap(0, 2))
+            }
+            _ => panic!("unsupported texture format: {format:?}"),
+        }
+        image::RgbaImage::from_raw(width, height, buffer).unwrap()
+    }
 }
 
 impl WindowScene {
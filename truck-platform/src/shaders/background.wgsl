@@ -0,0 +1,47 @@
+struct BackgroundInfo {
+    top: vec4<f32>,
+    bottom: vec4<f32>,
+    // maps the normalized device coordinate to the direction of the ray
+    ray_matrix: mat4x4<f32>,
+}
+
+@group(0)
+@binding(0)
+var<uniform> info: BackgroundInfo;
+
+@group(0)
+@binding(1)
+var r_skybox: texture_2d<f32>;
+
+@group(0)
+@binding(2)
+var r_sampler: sampler;
+
+struct VertexOutput {
+    @builtin(position) gl_position: vec4<f32>,
+    @location(0) ndc: vec2<f32>,
+}
+
+// a triangle covering the whole screen
+@vertex
+fn vs_main(@builtin(vertex_index) idx: u32) -> VertexOutput {
+    let ndc = vec2<f32>(f32(idx & 1u) * 4.0 - 1.0, f32(idx >> 1u) * 4.0 - 1.0);
+    return VertexOutput(vec4<f32>(ndc, 1.0, 1.0), ndc);
+}
+
+@fragment
+fn gradient_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    return mix(info.bottom, info.top, (in.ndc.y + 1.0) / 2.0);
+}
+
+const PI: f32 = 3.14159265358979;
+
+@fragment
+fn skybox_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let p = info.ray_matrix * vec4<f32>(in.ndc, 1.0, 1.0);
+    let dir = normalize(p.xyz / p.w);
+    // equirectangular projection, whose center is the direction of -z.
+    let u = atan2(dir.x, -dir.z) / (2.0 * PI) + 0.5;
+    let v = acos(clamp(dir.y, -1.0, 1.0)) / PI;
+    return textureSample(r_skybox, r_sampler, vec2<f32>(u, v));
+}
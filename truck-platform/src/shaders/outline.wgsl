@@ -0,0 +1,60 @@
+struct OutlineInfo {
+    color: vec4<f32>,
+    // the distance in pixels between the center and the samples of the filter
+    offset: i32,
+}
+
+@group(0)
+@binding(0)
+var<uniform> info: OutlineInfo;
+
+// the normal in xyz and the depth in w, or zero for the background
+@group(0)
+@binding(1)
+var r_gbuffer: texture_2d<f32>;
+
+// the relative jump of the depths detected as the silhouettes of the overlapping objects
+const DEPTH_THRESHOLD: f32 = 0.1;
+// the jump of the normals detected as the creases
+const NORMAL_THRESHOLD: f32 = 0.8;
+
+// a triangle covering the whole screen
+@vertex
+fn vs_main(@builtin(vertex_index) idx: u32) -> @builtin(position) vec4<f32> {
+    let ndc = vec2<f32>(f32(idx & 1u) * 4.0 - 1.0, f32(idx >> 1u) * 4.0 - 1.0);
+    return vec4<f32>(ndc, 0.0, 1.0);
+}
+
+fn gbuffer(coord: vec2<i32>) -> vec4<f32> {
+    let size = vec2<i32>(textureDimensions(r_gbuffer));
+    return textureLoad(r_gbuffer, clamp(coord, vec2<i32>(0), size - 1), 0);
+}
+
+@fragment
+fn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    let center = vec2<i32>(position.xy);
+    // the Sobel filter
+    var grad_x = vec4<f32>(0.0);
+    var grad_y = vec4<f32>(0.0);
+    var covered = 0;
+    for (var i = -1; i <= 1; i = i + 1) {
+        for (var j = -1; j <= 1; j = j + 1) {
+            let texel = gbuffer(center + vec2<i32>(i, j) * info.offset);
+            grad_x = grad_x + f32(i * (2 - abs(j))) * texel;
+            grad_y = grad_y + f32(j * (2 - abs(i))) * texel;
+            if texel.w > 0.0 {
+                covered = covered + 1;
+            }
+        }
+    }
+    // the boundary between the objects and the background
+    let silhouette = 0 < covered && covered < 9;
+    let depth_jump = length(vec2<f32>(grad_x.w, grad_y.w));
+    let overlap = covered == 9 && depth_jump > DEPTH_THRESHOLD * gbuffer(center).w;
+    let normal_jump = sqrt(dot(grad_x.xyz, grad_x.xyz) + dot(grad_y.xyz, grad_y.xyz));
+    let crease = covered == 9 && normal_jump > NORMAL_THRESHOLD;
+    if !(silhouette || overlap || crease) {
+        discard;
+    }
+    return info.color;
+}
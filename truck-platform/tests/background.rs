@@ -0,0 +1,56 @@
+mod common;
+use common::Plane;
+use truck_platform::*;
+use wgpu::*;
+
+fn exec_gradient_test(backend: Backends, out_dir: &str) {
+    let out_dir = String::from(out_dir);
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let handler = common::init_device(backend);
+    let mut scene = Scene::new(handler, &Default::default());
+    scene.studio_config_mut().background = Background::Gradient(
+        Color {
+            r: 1.0,
+            g: 0.0,
+            b: 0.0,
+            a: 1.0,
+        },
+        Color {
+            r: 0.0,
+            g: 0.0,
+            b: 1.0,
+            a: 1.0,
+        },
+    );
+    let plane = new_plane!("shaders/trapezoid.wgsl", "vs_main", "fs_main");
+    scene.add_object(&plane);
+
+    for sample_count in [1, 4] {
+        scene.descriptor_mut().backend_buffer.sample_count = sample_count;
+        let (width, height) = scene.descriptor().render_texture.canvas_size;
+        let image = pollster::block_on(scene.render_to_image(width, height));
+        image
+            .save(format!("{out_dir}gradient-{sample_count}.png"))
+            .unwrap();
+
+        // The left side of the trapezoid is the background.
+        let column = (0..height - 16)
+            .map(|y| image.get_pixel(0, y).0)
+            .collect::<Vec<_>>();
+        assert!(column[0][0] > 250 && column[0][2] < 5, "{:?}", column[0]);
+        column.windows(2).for_each(|pixels| {
+            assert!(pixels[0][0] >= pixels[1][0], "{pixels:?}");
+            assert!(pixels[0][2] <= pixels[1][2], "{pixels:?}");
+        });
+        let last = column[column.len() - 1];
+        assert!(last[0] < 10 && last[2] > 245, "{last:?}");
+        // The object is drawn over the background.
+        assert_eq!(
+            image.get_pixel(width / 2, height / 2).0,
+            [255, 255, 255, 255]
+        );
+    }
+}
+
+#[test]
+fn gradient_test() { common::os_alt_exec_test(exec_gradient_test); }
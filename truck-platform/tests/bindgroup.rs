@@ -22,11 +22,15 @@ const POINT_LIGHT: Light = Light {
     position: Point3::new(0.1, 0.2, 0.3),
     color: Vector3::new(0.4, 0.5, 0.6),
     light_type: LightType::Point,
+    intensity: 0.7,
+    attenuation: [1.0, 0.1, 0.01],
 };
 const UNIFORM_LIGHT: Light = Light {
     position: Point3::new(1.1, 1.2, 1.3),
     color: Vector3::new(1.4, 1.5, 1.6),
     light_type: LightType::Uniform,
+    intensity: 2.0,
+    attenuation: [1.0, 0.0, 0.0],
 };
 
 fn save_buffer<P: AsRef<std::path::Path>>(path: P, vec: &[u8]) {
@@ -51,12 +55,12 @@ fn exec_bind_group_test(backend: Backends, out_dir: &str) {
         studio: StudioConfig {
             camera,
             lights,
-            background: Color {
+            background: Background::Solid(Color {
                 r: 0.1,
                 g: 0.2,
                 b: 0.3,
                 a: 0.4,
-            },
+            }),
         },
         render_texture: RenderTextureConfig {
             canvas_size: (PICTURE_WIDTH, PICTURE_HEIGHT),
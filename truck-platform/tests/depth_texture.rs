@@ -0,0 +1,155 @@
+mod common;
+use common::Plane;
+use std::sync::Arc;
+use truck_platform::*;
+use wgpu::*;
+
+/// The post-effect darkening the distant pixels by the depth of another scene.
+struct DepthFog<'a> {
+    color: &'a TextureView,
+    depth: &'a TextureView,
+    id: RenderID,
+}
+
+impl Rendered for DepthFog<'_> {
+    impl_render_id!(id);
+    fn vertex_buffer(
+        &self,
+        handler: &DeviceHandler,
+    ) -> (Arc<BufferHandler>, Option<Arc<BufferHandler>>) {
+        let vertex_buffer =
+            BufferHandler::from_slice(&[0, 1, 2, 2, 1, 3], handler.device(), BufferUsages::VERTEX);
+        (Arc::new(vertex_buffer), None)
+    }
+    fn bind_group_layout(&self, handler: &DeviceHandler) -> Arc<BindGroupLayout> {
+        let texture = |sample_type| PreBindGroupLayoutEntry {
+            visibility: ShaderStages::FRAGMENT,
+            ty: BindingType::Texture {
+                sample_type,
+                view_dimension: TextureViewDimension::D2,
+                multisampled: false,
+            },
+            count: None,
+        };
+        Arc::new(bind_group_util::create_bind_group_layout(
+            handler.device(),
+            &[
+                texture(TextureSampleType::Float { filterable: false }),
+                texture(TextureSampleType::Depth),
+            ],
+        ))
+    }
+    fn bind_group(&self, handler: &DeviceHandler, layout: &BindGroupLayout) -> Arc<BindGroup> {
+        Arc::new(bind_group_util::create_bind_group(
+            handler.device(),
+            layout,
+            [
+                BindingResource::TextureView(self.color),
+                BindingResource::TextureView(self.depth),
+            ],
+        ))
+    }
+    fn pipeline(
+        &self,
+        handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        scene_desc: &SceneDescriptor,
+    ) -> Arc<RenderPipeline> {
+        let device = handler.device();
+        let module = device.create_shader_module(ShaderModuleDescriptor {
+            label: None,
+            source: ShaderSource::Wgsl(include_str!("shaders/depth-fog.wgsl").into()),
+        });
+        Arc::new(device.create_render_pipeline(&RenderPipelineDescriptor {
+            layout: Some(layout),
+            vertex: VertexState {
+                module: &module,
+                entry_point: Some("vs_main"),
+                buffers: &[VertexBufferLayout {
+                    array_stride: std::mem::size_of::<u32>() as BufferAddress,
+                    step_mode: VertexStepMode::Vertex,
+                    attributes: &[VertexAttribute {
+                        format: VertexFormat::Uint32,
+                        offset: 0,
+                        shader_location: 0,
+                    }],
+                }],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(FragmentState {
+                module: &module,
+                entry_point: Some("fs_main"),
+                targets: &[Some(ColorTargetState {
+                    format: scene_desc.render_texture.format,
+                    blend: Some(BlendState::REPLACE),
+                    write_mask: ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: Default::default(),
+            depth_stencil: None,
+            multisample: Default::default(),
+            label: None,
+            multiview: None,
+            cache: None,
+        }))
+    }
+}
+
+fn exec_depth_texture_test(backend: Backends, out_dir: &str) {
+    let out_dir = String::from(out_dir);
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let handler = common::init_device(backend);
+    let mut scene = Scene::new(handler.clone(), &Default::default());
+    let plane = new_plane!("shaders/tilted.wgsl", "vs_main", "fs_main");
+    scene.add_object(&plane);
+    let (width, height) = scene.descriptor().render_texture.canvas_size;
+    let texture = handler.device().create_texture(&TextureDescriptor {
+        label: None,
+        size: Extent3d {
+            width,
+            height,
+            depth_or_array_layers: 1,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: TextureDimension::D2,
+        format: scene.descriptor().render_texture.format,
+        usage: TextureUsages::RENDER_ATTACHMENT | TextureUsages::TEXTURE_BINDING,
+        view_formats: &[],
+    });
+    let color = texture.create_view(&Default::default());
+    scene.render(&color);
+
+    // The post-effect is drawn by another scene without depth test.
+    let mut post = Scene::new(
+        handler,
+        &SceneDescriptor {
+            backend_buffer: BackendBufferConfig {
+                depth_test: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    );
+    assert!(post.depth_texture_view().is_none());
+    let fog = DepthFog {
+        color: &color,
+        depth: scene.depth_texture_view().unwrap(),
+        id: RenderID::gen(),
+    };
+    post.add_object(&fog);
+    let image = pollster::block_on(post.render_to_image(width, height));
+    image.save(format!("{out_dir}depth-fog.png")).unwrap();
+
+    // The plane is darker in the distance, i.e. on the right side.
+    let row = (0..width)
+        .map(|x| image.get_pixel(x, height / 2).0[0])
+        .collect::<Vec<_>>();
+    assert!(row.windows(2).all(|pair| pair[0] >= pair[1]), "{row:?}");
+    assert!(u8::abs_diff(row[0], 242) <= 2, "{}", row[0]);
+    assert!(row[width as usize - 1] < 30, "{}", row[width as usize - 1]);
+}
+
+#[test]
+fn depth_texture_test() { common::os_alt_exec_test(exec_depth_texture_test); }
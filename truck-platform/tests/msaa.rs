@@ -46,3 +46,50 @@ fn exec_msaa_test(backend: Backends, out_dir: &str) {
 
 #[test]
 fn msaa_test() { common::os_alt_exec_test(exec_msaa_test); }
+
+fn exec_unsupported_sample_count_test(backend: Backends, out_dir: &str) {
+    let out_dir = String::from(out_dir);
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let handler = common::init_device(backend);
+    let features = |format| handler.adapter().get_texture_format_features(format).flags;
+    let (color, depth) = (
+        features(TextureFormat::Rgba8Unorm),
+        features(TextureFormat::Depth32Float),
+    );
+    let supported =
+        |count: u32| color.sample_count_supported(count) && depth.sample_count_supported(count);
+    let mut scene = Scene::new(
+        handler,
+        &SceneDescriptor {
+            backend_buffer: BackendBufferConfig {
+                sample_count: 64,
+                ..Default::default()
+            },
+            render_texture: RenderTextureConfig {
+                canvas_size: (PICTURE_WIDTH, PICTURE_HEIGHT),
+                format: TextureFormat::Rgba8Unorm,
+            },
+            ..Default::default()
+        },
+    );
+    let plane = new_plane!("shaders/trapezoid.wgsl", "vs_main", "fs_main");
+    // Each count falls back to the largest supported count not more than it.
+    for requested in [64, 16, 3, 0] {
+        scene.descriptor_mut().backend_buffer.sample_count = requested;
+        let sample_count = scene.descriptor().backend_buffer.sample_count;
+        assert!(
+            0 < sample_count && sample_count <= u32::max(requested, 1),
+            "{requested} {sample_count}"
+        );
+        assert!(supported(sample_count));
+        assert!((sample_count + 1..=requested).all(|count| !supported(count)));
+        let buffer = common::render_one(&mut scene, &plane);
+        save_buffer(
+            out_dir.clone() + &format!("sample_count_{requested}.png"),
+            &buffer,
+        );
+    }
+}
+
+#[test]
+fn unsupported_sample_count_test() { common::os_alt_exec_test(exec_unsupported_sample_count_test); }
@@ -0,0 +1,34 @@
+mod common;
+use common::Plane;
+use truck_platform::*;
+use wgpu::*;
+
+fn exec_readback_test(backend: Backends, out_dir: &str) {
+    let out_dir = String::from(out_dir);
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let handler = common::init_device(backend);
+    let mut scene = Scene::new(handler, &Default::default());
+    let plane = new_plane!("shaders/trapezoid.wgsl", "vs_main", "fs_main");
+    scene.add_object(&plane);
+
+    for sample_count in [1, 4] {
+        scene.descriptor_mut().backend_buffer.sample_count = sample_count;
+        // The row of 300 pixels is not aligned to `COPY_BYTES_PER_ROW_ALIGNMENT`.
+        for size in [(512, 512), (300, 200)] {
+            let image = pollster::block_on(scene.render_to_image(size.0, size.1));
+            assert_eq!(image.dimensions(), size);
+            assert_eq!(scene.descriptor().render_texture.canvas_size, (1024, 768));
+            image
+                .save(format!("{out_dir}readback-{sample_count}-{}.png", size.0))
+                .unwrap();
+
+            scene.descriptor_mut().render_texture.canvas_size = size;
+            let buffer = pollster::block_on(scene.render_to_buffer());
+            scene.descriptor_mut().render_texture.canvas_size = (1024, 768);
+            assert!(common::same_buffer(image.as_raw(), &buffer));
+        }
+    }
+}
+
+#[test]
+fn readback_test() { common::os_alt_exec_test(exec_readback_test); }
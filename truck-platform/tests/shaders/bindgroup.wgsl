@@ -7,6 +7,7 @@ struct Light {
     position: vec4<f32>,
     color: vec4<f32>,
     light_type: vec4<u32>,
+    attenuation: vec4<f32>,
 }
 
 struct Lights {
@@ -45,9 +46,11 @@ const acp3: vec4<f32> = vec4<f32>(19.301563694896643, -28.84390979125007, -0.878
 const alp0: vec4<f32> = vec4<f32>(0.1, 0.2, 0.3, 1.0);
 const alc0: vec4<f32> = vec4<f32>(0.4, 0.5, 0.6, 1.0);
 const alt0: vec4<u32> = vec4<u32>(0u, 0u, 0u, 0u);
+const ala0: vec4<f32> = vec4<f32>(1.0, 0.1, 0.01, 0.7);
 const alp1: vec4<f32> = vec4<f32>(1.1, 1.2, 1.3, 1.0);
 const alc1: vec4<f32> = vec4<f32>(1.4, 1.5, 1.6, 1.0);
 const alt1: vec4<u32> = vec4<u32>(1u, 0u, 0u, 0u);
+const ala1: vec4<f32> = vec4<f32>(1.0, 0.0, 0.0, 2.0);
 const asnl: u32 = 2u;
 const abk: vec4<f32> = vec4<f32>(0.1, 0.2, 0.3, 0.4);
 const arsl: vec2<u32> = vec2<u32>(256u, 256u);
@@ -84,12 +87,16 @@ fn vs_main(@location(0) idx: u32) -> @builtin(position) vec4<f32> {
         return vec4<f32>(0.0, 0.0, 0.0, 1.0);
     } else if (any(lights.lights[0].light_type != alt0)) {
         return vec4<f32>(0.0, 0.0, 0.0, 1.0);
+    } else if (distance(lights.lights[0].attenuation, ala0) > EPS) {
+        return vec4<f32>(0.0, 0.0, 0.0, 1.0);
     } else if (distance(lights.lights[1].position, alp1) > EPS) {
         return vec4<f32>(0.0, 0.0, 0.0, 1.0);
     } else if (distance(lights.lights[1].color, alc1) > EPS) {
         return vec4<f32>(0.0, 0.0, 0.0, 1.0);
     } else if (any(lights.lights[1].light_type != alt1)) {
         return vec4<f32>(0.0, 0.0, 0.0, 1.0);
+    } else if (distance(lights.lights[1].attenuation, ala1) > EPS) {
+        return vec4<f32>(0.0, 0.0, 0.0, 1.0);
     } else if (info.nlights != asnl) {
         return vec4<f32>(0.0, 0.0, 0.0, 1.0);
     } else if (distance(info.bk_color, abk) > EPS) {
@@ -125,12 +132,16 @@ fn fs_main() -> @location(0) vec4<f32> {
         return vec4<f32>(0.3, 0.3, 0.3, 1.0);
     } else if (any(lights.lights[0].light_type != alt0)) {
         return vec4<f32>(0.4, 0.4, 0.4, 1.0);
+    } else if (distance(lights.lights[0].attenuation, ala0) > EPS) {
+        return vec4<f32>(0.4, 0.4, 0.4, 1.0);
     } else if (distance(lights.lights[1].position, alp1) > EPS) {
         return vec4<f32>(0.5, 0.5, 0.5, 1.0);
     } else if (distance(lights.lights[1].color, alc1) > EPS) {
         return vec4<f32>(0.6, 0.6, 0.6, 1.0);
     } else if (any(lights.lights[1].light_type != alt1)) {
         return vec4<f32>(0.7, 0.7, 0.7, 1.0);
+    } else if (distance(lights.lights[1].attenuation, ala1) > EPS) {
+        return vec4<f32>(0.7, 0.7, 0.7, 1.0);
     } else if (info.nlights != asnl) {
         return vec4<f32>(0.8, 0.8, 0.8, 1.0);
     } else if (distance(info.bk_color, abk) > EPS) {
@@ -166,12 +177,16 @@ fn fs_main_anti() -> @location(0) vec4<f32> {
         return vec4<f32>(0.3, 0.3, 0.3, 1.0);
     } else if (any(lights.lights[0].light_type != alt0)) {
         return vec4<f32>(0.4, 0.4, 0.4, 1.0);
+    } else if (distance(lights.lights[0].attenuation, ala0) > EPS) {
+        return vec4<f32>(0.4, 0.4, 0.4, 1.0);
     } else if (distance(lights.lights[1].position, alp1) > EPS) {
         return vec4<f32>(0.5, 0.5, 0.5, 1.0);
     } else if (distance(lights.lights[1].color, alc1) > EPS) {
         return vec4<f32>(0.6, 0.6, 0.6, 1.0);
     } else if (any(lights.lights[1].light_type == alt1)) {
         return vec4<f32>(0.7, 0.7, 0.7, 1.0);
+    } else if (distance(lights.lights[1].attenuation, ala1) > EPS) {
+        return vec4<f32>(0.7, 0.7, 0.7, 1.0);
     } else if (info.nlights != asnl) {
         return vec4<f32>(0.8, 0.8, 0.8, 1.0);
     } else if (distance(info.bk_color, abk) > EPS) {
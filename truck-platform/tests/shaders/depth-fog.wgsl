@@ -0,0 +1,26 @@
+@group(1)
+@binding(0)
+var r_color: texture_2d<f32>;
+
+@group(1)
+@binding(1)
+var r_depth: texture_depth_2d;
+
+@vertex
+fn vs_main(@location(0) idx: u32) -> @builtin(position) vec4<f32> {
+    var vertex: array<vec2<f32>, 4>;
+    vertex[0] = vec2<f32>(-1.0, -1.0);
+    vertex[1] = vec2<f32>(1.0, -1.0);
+    vertex[2] = vec2<f32>(-1.0, 1.0);
+    vertex[3] = vec2<f32>(1.0, 1.0);
+    return vec4<f32>(vertex[idx], 0.0, 1.0);
+}
+
+// darkens the distant pixels
+@fragment
+fn fs_main(@builtin(position) position: vec4<f32>) -> @location(0) vec4<f32> {
+    let coord = vec2<i32>(position.xy);
+    let color = textureLoad(r_color, coord, 0);
+    let depth = textureLoad(r_depth, coord, 0);
+    return vec4<f32>(color.rgb * (1.0 - depth), color.a);
+}
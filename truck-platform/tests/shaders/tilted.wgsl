@@ -0,0 +1,16 @@
+// a plane covering the screen, whose depth increases from the left to the right
+@vertex
+fn vs_main(@location(0) idx: u32) -> @builtin(position) vec4<f32> {
+    var vertex: array<vec2<f32>, 4>;
+    vertex[0] = vec2<f32>(-1.0, -1.0);
+    vertex[1] = vec2<f32>(1.0, -1.0);
+    vertex[2] = vec2<f32>(-1.0, 1.0);
+    vertex[3] = vec2<f32>(1.0, 1.0);
+    let depth = 0.05 + (vertex[idx].x + 1.0) * 0.45;
+    return vec4<f32>(vertex[idx], depth, 1.0);
+}
+
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+    return vec4<f32>(1.0, 1.0, 1.0, 1.0);
+}
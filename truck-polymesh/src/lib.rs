@@ -109,6 +109,8 @@ pub mod faces;
 mod meshing_shape;
 /// wavefront obj I/O
 pub mod obj;
+/// PLY I/O
+pub mod ply;
 /// Defines [`PolygonMeshEditor`](./polygon_mesh/struct.PolygonMeshEditor.html).
 pub mod polygon_mesh;
 /// Defines generalized polyline curve.
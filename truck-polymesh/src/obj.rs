@@ -53,6 +53,60 @@ pub fn write<W: Write>(mesh: &PolygonMesh, writer: W) -> Result<()> {
     sub_write(mesh, &mut BufWriter::new(writer))
 }
 
+/// Writes obj data with the material and group assignments of faces to output stream
+///
+/// # Details
+/// The faces are written in the order of [`Faces::face_iter`], with `o`, `g` and `usemtl`
+/// statements at which the assignments change. The names of the material libraries are written
+/// by `mtllib` statements at the top.
+///
+/// # Errors
+/// Returns an error with [`std::io::ErrorKind::InvalidInput`] if the length of
+/// `groups.face_groups` is not the number of faces or some faces refer to absent groups.
+pub fn write_with_groups<W: Write>(
+    mesh: &PolygonMesh,
+    groups: &ObjGroups,
+    writer: W,
+) -> Result<()> {
+    let is_valid = groups.face_groups.len() == mesh.faces().len()
+        && groups.face_groups.iter().all(|i| *i < groups.groups.len());
+    if !is_valid {
+        let error = "the face groups are incompatible with the faces";
+        return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, error).into());
+    }
+    let mut writer = BufWriter::new(writer);
+    for mtllib in &groups.mtllibs {
+        writer.write_fmt(format_args!("mtllib {mtllib}\n"))?;
+    }
+    write3vec(&mut writer, mesh.positions(), "v")?;
+    write2vec(&mut writer, mesh.uv_coords(), "vt")?;
+    write3vec(&mut writer, mesh.normals(), "vn")?;
+    let default = ObjGroup::default();
+    let mut current = &default;
+    for (face, i) in mesh.face_iter().zip(&groups.face_groups) {
+        let group = &groups.groups[*i];
+        let statements = [
+            ("o", &current.object, &group.object),
+            ("g", &current.group, &group.group),
+            ("usemtl", &current.material, &group.material),
+        ];
+        for (prefix, old, new) in statements {
+            if old != new {
+                let name = new.as_deref().unwrap_or_default();
+                writer.write_fmt(format_args!("{prefix} {name}\n"))?;
+            }
+        }
+        current = group;
+        writer.write_all(b"f")?;
+        for v in face {
+            writer.write_all(b" ")?;
+            v.write(&mut writer)?;
+        }
+        writer.write_all(b"\n")?;
+    }
+    Ok(())
+}
+
 /// Writes obj data to output stream
 pub fn write_vec<W: Write>(mesh: &[PolygonMesh], writer: W) -> Result<()> {
     let mut writer = BufWriter::new(writer);
@@ -122,12 +176,76 @@ fn sub_write<W: Write>(mesh: &PolygonMesh, writer: &mut BufWriter<W>) -> Result<
     mesh.faces.write(writer)
 }
 
+/// The object, group and material assigned to faces by `o`, `g` and `usemtl` statements
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ObjGroup {
+    /// the name of the object
+    pub object: Option<String>,
+    /// the name of the group
+    pub group: Option<String>,
+    /// the name of the material
+    pub material: Option<String>,
+}
+
+/// The material and group assignments of faces, the side table of [`PolygonMesh`] in obj files
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ObjGroups {
+    /// the names of the material libraries given by `mtllib` statements
+    pub mtllibs: Vec<String>,
+    /// the assignments used by the faces
+    pub groups: Vec<ObjGroup>,
+    /// the indices of the assignments in `groups`, in the order of [`Faces::face_iter`]
+    pub face_groups: Vec<usize>,
+}
+
 /// Reads mesh data from wavefront obj file.
-pub fn read<R: Read>(reader: R) -> Result<PolygonMesh> {
+pub fn read<R: Read>(reader: R) -> Result<PolygonMesh> { read_with_groups(reader).map(|x| x.0) }
+
+/// Reads mesh data with the material and group assignments of faces from wavefront obj file.
+///
+/// # Details
+/// The faces before any `o`, `g` and `usemtl` statements, e.g. the faces with no material, belong
+/// to the default group [`ObjGroup::default()`]. The statements without names reset the names to `None`.
+///
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let obj = b"mtllib cube.mtl
+/// v 0.0 0.0 0.0
+/// v 1.0 0.0 0.0
+/// v 0.0 1.0 0.0
+/// v 1.0 1.0 0.0
+/// f 1 2 3
+/// usemtl red
+/// f 2 4 3
+/// usemtl blue
+/// f 1 2 4 3
+/// ";
+/// let (mesh, groups) = obj::read_with_groups(obj.as_slice()).unwrap();
+/// assert_eq!(groups.mtllibs, vec!["cube.mtl".to_string()]);
+/// let materials = groups
+///     .face_groups
+///     .iter()
+///     .map(|i| groups.groups[*i].material.as_deref())
+///     .collect::<Vec<_>>();
+/// assert_eq!(materials, vec![None, Some("red"), Some("blue")]);
+///
+/// let mut obj = Vec::new();
+/// obj::write_with_groups(&mesh, &groups, &mut obj).unwrap();
+/// let (mesh0, groups0) = obj::read_with_groups(obj.as_slice()).unwrap();
+/// assert_eq!(mesh0, mesh);
+/// assert_eq!(groups0, groups);
+/// ```
+pub fn read_with_groups<R: Read>(reader: R) -> Result<(PolygonMesh, ObjGroups)> {
     let mut positions = Vec::new();
     let mut uv_coords = Vec::new();
     let mut normals = Vec::new();
     let mut faces = Faces::default();
+    let mut mtllibs = Vec::new();
+    let mut groups = Vec::<ObjGroup>::new();
+    let mut current = ObjGroup::default();
+    // the groups of triangles, quadrangles and the other faces
+    let mut face_groups: [Vec<usize>; 3] = Default::default();
     let reader = BufReader::new(reader);
     for line in reader.lines().map(|s| s.unwrap()) {
         let mut args = line.split_whitespace();
@@ -146,6 +264,14 @@ pub fn read<R: Read>(reader: R) -> Result<PolygonMesh> {
                 let y = args.next().unwrap().parse::<f64>()?;
                 let z = args.next().unwrap().parse::<f64>()?;
                 normals.push(Vector3::new(x, y, z));
+            } else if first_str == "o" {
+                current.object = statement_name(args);
+            } else if first_str == "g" {
+                current.group = statement_name(args);
+            } else if first_str == "usemtl" {
+                current.material = statement_name(args);
+            } else if first_str == "mtllib" {
+                mtllibs.extend(args.map(String::from));
             } else if first_str == "f" {
                 let mut face = Vec::new();
                 for vert_str in args {
@@ -171,16 +297,40 @@ pub fn read<R: Read>(reader: R) -> Result<PolygonMesh> {
                     };
                     face.push(vert);
                 }
+                if face.len() >= 3 {
+                    let idx = match groups.iter().position(|group| group == &current) {
+                        Some(idx) => idx,
+                        None => {
+                            groups.push(current.clone());
+                            groups.len() - 1
+                        }
+                    };
+                    face_groups[usize::min(face.len(), 5) - 3].push(idx);
+                }
                 faces.push(face);
             }
         }
     }
-    PolygonMesh::try_new(
+    let mesh = PolygonMesh::try_new(
         StandardAttributes {
             positions,
             uv_coords,
             normals,
         },
         faces,
-    )
+    )?;
+    let groups = ObjGroups {
+        mtllibs,
+        groups,
+        face_groups: face_groups.concat(),
+    };
+    Ok((mesh, groups))
+}
+
+fn statement_name<'a>(args: impl Iterator<Item = &'a str>) -> Option<String> {
+    let name = args.collect::<Vec<_>>().join(" ");
+    match name.is_empty() {
+        true => None,
+        false => Some(name),
+    }
 }
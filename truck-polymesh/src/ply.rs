@@ -0,0 +1,480 @@
+use crate::*;
+use rustc_hash::FxHashMap as HashMap;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::str::SplitAsciiWhitespace;
+
+type Vertex = StandardVertex;
+type Result<T> = std::result::Result<T, errors::Error>;
+
+fn invalid_data(message: &'static str) -> errors::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, message).into()
+}
+
+/// PLY format.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlyFormat {
+    /// ASCII format.
+    Ascii,
+    /// Binary little endian format.
+    BinaryLittleEndian,
+}
+
+/// Writes PLY data to output stream.
+///
+/// # Details
+/// In PLY files, the positions, the normals, and the texture coordinates of a vertex share
+/// their index. If each vertex of the faces refers to the attributes with the same index as its
+/// position, the attributes are written as they are, including the positions which are not
+/// referred by any faces. Otherwise, the vertices are expanded to the distinct tuples of
+/// attributes, and the missing attributes are filled with zeros.
+///
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let positions = vec![
+///     Point3::new(0.0, 0.0, 0.0),
+///     Point3::new(1.0, 0.0, 0.0),
+///     Point3::new(0.0, 1.0, 0.0),
+///     Point3::new(0.0, 0.0, 1.0),
+/// ];
+/// let faces = Faces::from_iter(&[[0, 2, 1], [0, 1, 3], [1, 2, 3], [2, 0, 3]]);
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions,
+///         ..Default::default()
+///     },
+///     faces,
+/// );
+///
+/// let mut bytes = Vec::new();
+/// ply::write(&mesh, &mut bytes, ply::PlyFormat::BinaryLittleEndian).unwrap();
+/// let read = ply::read(bytes.as_slice()).unwrap();
+/// assert_eq!(mesh, read);
+/// ```
+pub fn write<W: Write>(mesh: &PolygonMesh, writer: W, format: PlyFormat) -> Result<()> {
+    let (attributes, faces) = ply_vertices(mesh);
+    let mut writer = BufWriter::new(writer);
+    write_header(&mut writer, &attributes, &faces, format)?;
+    let StandardAttributes {
+        positions,
+        uv_coords,
+        normals,
+    } = &attributes;
+    let mut vertex_iter = positions.iter().enumerate().map(|(i, p)| {
+        let mut vertex = vec![p.x, p.y, p.z];
+        if let Some(n) = normals.get(i) {
+            vertex.extend([n.x, n.y, n.z]);
+        }
+        if let Some(uv) = uv_coords.get(i) {
+            vertex.extend([uv.x, uv.y]);
+        }
+        vertex
+    });
+    match format {
+        PlyFormat::Ascii => {
+            vertex_iter.try_for_each(|vertex| {
+                let line = vertex.iter().map(f64::to_string).collect::<Vec<_>>();
+                writer.write_fmt(format_args!("{}\n", line.join(" ")))
+            })?;
+            faces.iter().try_for_each(|face| {
+                writer.write_fmt(format_args!("{}", face.len()))?;
+                face.iter()
+                    .try_for_each(|i| writer.write_fmt(format_args!(" {i}")))?;
+                writer.write_all(b"\n")
+            })?;
+        }
+        PlyFormat::BinaryLittleEndian => {
+            vertex_iter.try_for_each(|vertex| {
+                vertex
+                    .iter()
+                    .try_for_each(|x| writer.write_all(&x.to_le_bytes()))
+            })?;
+            let long_face = faces.iter().any(|face| face.len() > u8::MAX as usize);
+            faces.iter().try_for_each(|face| {
+                match long_face {
+                    true => writer.write_all(&(face.len() as u32).to_le_bytes())?,
+                    false => writer.write_all(&[face.len() as u8])?,
+                }
+                face.iter()
+                    .try_for_each(|i| writer.write_all(&(*i as u32).to_le_bytes()))
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the attributes and the faces whose vertices refer to the attributes by the same index.
+fn ply_vertices(mesh: &PolygonMesh) -> (StandardAttributes, Vec<Vec<usize>>) {
+    let len = mesh.positions().len();
+    let has_uv = !mesh.uv_coords().is_empty();
+    let has_normal = !mesh.normals().is_empty();
+    let aligned = (!has_uv || mesh.uv_coords().len() == len)
+        && (!has_normal || mesh.normals().len() == len)
+        && mesh
+            .face_iter()
+            .flatten()
+            .all(|v| v.uv == has_uv.then_some(v.pos) && v.nor == has_normal.then_some(v.pos));
+    if aligned {
+        let faces = mesh
+            .face_iter()
+            .map(|face| face.iter().map(|v| v.pos).collect())
+            .collect();
+        return (mesh.attributes().clone(), faces);
+    }
+
+    let has_uv = mesh.face_iter().flatten().any(|v| v.uv.is_some());
+    let has_normal = mesh.face_iter().flatten().any(|v| v.nor.is_some());
+    let mut attributes = StandardAttributes::default();
+    let mut map = HashMap::<Vertex, usize>::default();
+    let faces = mesh
+        .face_iter()
+        .map(|face| {
+            face.iter()
+                .map(|v| {
+                    *map.entry(*v).or_insert_with(|| {
+                        attributes.positions.push(mesh.positions()[v.pos]);
+                        if has_uv {
+                            let uv = v.uv.map(|i| mesh.uv_coords()[i]);
+                            attributes.uv_coords.push(uv.unwrap_or_else(Vector2::zero));
+                        }
+                        if has_normal {
+                            let nor = v.nor.map(|i| mesh.normals()[i]);
+                            attributes.normals.push(nor.unwrap_or_else(Vector3::zero));
+                        }
+                        attributes.positions.len() - 1
+                    })
+                })
+                .collect()
+        })
+        .collect();
+    (attributes, faces)
+}
+
+fn write_header<W: Write>(
+    writer: &mut W,
+    attributes: &StandardAttributes,
+    faces: &[Vec<usize>],
+    format: PlyFormat,
+) -> Result<()> {
+    let format = match format {
+        PlyFormat::Ascii => "ascii",
+        PlyFormat::BinaryLittleEndian => "binary_little_endian",
+    };
+    writer.write_fmt(format_args!("ply\nformat {format} 1.0\n"))?;
+    writer.write_all(b"comment generated by truck-polymesh\n")?;
+    let len = attributes.positions.len();
+    writer.write_fmt(format_args!("element vertex {len}\n"))?;
+    writer.write_all(b"property double x\nproperty double y\nproperty double z\n")?;
+    if !attributes.normals.is_empty() {
+        writer.write_all(b"property double nx\nproperty double ny\nproperty double nz\n")?;
+    }
+    if !attributes.uv_coords.is_empty() {
+        writer.write_all(b"property double s\nproperty double t\n")?;
+    }
+    let count_type = match faces.iter().any(|face| face.len() > u8::MAX as usize) {
+        true => "uint",
+        false => "uchar",
+    };
+    writer.write_fmt(format_args!("element face {}\n", faces.len()))?;
+    writer.write_fmt(format_args!(
+        "property list {count_type} uint vertex_indices\n"
+    ))?;
+    writer.write_all(b"end_header\n")?;
+    Ok(())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    Ascii,
+    BinaryLittleEndian,
+    BinaryBigEndian,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ScalarType {
+    Char,
+    UChar,
+    Short,
+    UShort,
+    Int,
+    UInt,
+    Float,
+    Double,
+}
+
+impl ScalarType {
+    fn parse(name: &str) -> Result<Self> {
+        match name {
+            "char" | "int8" => Ok(Self::Char),
+            "uchar" | "uint8" => Ok(Self::UChar),
+            "short" | "int16" => Ok(Self::Short),
+            "ushort" | "uint16" => Ok(Self::UShort),
+            "int" | "int32" => Ok(Self::Int),
+            "uint" | "uint32" => Ok(Self::UInt),
+            "float" | "float32" => Ok(Self::Float),
+            "double" | "float64" => Ok(Self::Double),
+            _ => Err(invalid_data("unknown scalar type of PLY")),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Property {
+    Scalar(ScalarType, String),
+    List(ScalarType, ScalarType, String),
+}
+
+impl Property {
+    fn name(&self) -> &str {
+        match self {
+            Property::Scalar(_, name) => name,
+            Property::List(_, _, name) => name,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Element {
+    name: String,
+    count: usize,
+    properties: Vec<Property>,
+}
+
+fn read_header<R: BufRead>(reader: &mut R) -> Result<(Format, Vec<Element>)> {
+    let mut line = String::new();
+    let mut next_line = |line: &mut String| -> Result<()> {
+        line.clear();
+        match reader.read_line(line)? {
+            0 => Err(invalid_data("unexpected end of PLY header")),
+            _ => Ok(()),
+        }
+    };
+    next_line(&mut line)?;
+    if line.trim() != "ply" {
+        return Err(invalid_data("not a PLY file"));
+    }
+    let mut format = None;
+    let mut elements = Vec::<Element>::new();
+    loop {
+        next_line(&mut line)?;
+        let args = line.split_whitespace().collect::<Vec<_>>();
+        match args.as_slice() {
+            ["format", "ascii", _] => format = Some(Format::Ascii),
+            ["format", "binary_little_endian", _] => format = Some(Format::BinaryLittleEndian),
+            ["format", "binary_big_endian", _] => format = Some(Format::BinaryBigEndian),
+            ["element", name, count] => elements.push(Element {
+                name: name.to_string(),
+                count: count.parse()?,
+                properties: Vec::new(),
+            }),
+            ["property", "list", count_type, item_type, name] => {
+                let element = elements
+                    .last_mut()
+                    .ok_or_else(|| invalid_data("property without element"))?;
+                element.properties.push(Property::List(
+                    ScalarType::parse(count_type)?,
+                    ScalarType::parse(item_type)?,
+                    name.to_string(),
+                ));
+            }
+            ["property", scalar_type, name] => {
+                let element = elements
+                    .last_mut()
+                    .ok_or_else(|| invalid_data("property without element"))?;
+                element.properties.push(Property::Scalar(
+                    ScalarType::parse(scalar_type)?,
+                    name.to_string(),
+                ));
+            }
+            ["end_header"] => break,
+            ["comment", ..] | ["obj_info", ..] | [] => {}
+            _ => return Err(invalid_data("syntax error in PLY header")),
+        }
+    }
+    let format = format.ok_or_else(|| invalid_data("no format in PLY header"))?;
+    Ok((format, elements))
+}
+
+/// reads scalar values from the body of PLY
+trait ScalarReader {
+    fn read_scalar(&mut self, scalar_type: ScalarType) -> Result<f64>;
+    fn read_index(&mut self, scalar_type: ScalarType) -> Result<usize> {
+        let x = self.read_scalar(scalar_type)?;
+        match x >= 0.0 && x.fract() == 0.0 {
+            true => Ok(x as usize),
+            false => Err(invalid_data("invalid index in PLY")),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct AsciiBody<'a>(SplitAsciiWhitespace<'a>);
+
+impl ScalarReader for AsciiBody<'_> {
+    fn read_scalar(&mut self, _: ScalarType) -> Result<f64> {
+        let token = self
+            .0
+            .next()
+            .ok_or_else(|| invalid_data("unexpected end of PLY"))?;
+        Ok(token.parse::<f64>()?)
+    }
+}
+
+#[derive(Debug)]
+struct BinaryBody<R> {
+    reader: R,
+    big_endian: bool,
+}
+
+macro_rules! read_binary {
+    ($body: expr, $type: ty) => {{
+        let mut bytes = [0; size_of::<$type>()];
+        $body.reader.read_exact(&mut bytes)?;
+        match $body.big_endian {
+            true => f64::from(<$type>::from_be_bytes(bytes)),
+            false => f64::from(<$type>::from_le_bytes(bytes)),
+        }
+    }};
+}
+
+impl<R: Read> ScalarReader for BinaryBody<R> {
+    fn read_scalar(&mut self, scalar_type: ScalarType) -> Result<f64> {
+        Ok(match scalar_type {
+            ScalarType::Char => read_binary!(self, i8),
+            ScalarType::UChar => read_binary!(self, u8),
+            ScalarType::Short => read_binary!(self, i16),
+            ScalarType::UShort => read_binary!(self, u16),
+            ScalarType::Int => read_binary!(self, i32),
+            ScalarType::UInt => read_binary!(self, u32),
+            ScalarType::Float => read_binary!(self, f32),
+            ScalarType::Double => read_binary!(self, f64),
+        })
+    }
+}
+
+fn read_body<B: ScalarReader>(elements: &[Element], body: &mut B) -> Result<PolygonMesh> {
+    let mut attributes = StandardAttributes::default();
+    let mut faces = Vec::<Vec<usize>>::new();
+    let mut has_uv = false;
+    let mut has_normal = false;
+    for element in elements {
+        let names = element
+            .properties
+            .iter()
+            .map(Property::name)
+            .collect::<Vec<_>>();
+        let has = |keys: &[&str]| keys.iter().all(|key| names.contains(key));
+        if element.name == "vertex" {
+            has_normal = has(&["nx", "ny", "nz"]);
+            has_uv = has(&["s", "t"]) || has(&["u", "v"]) || has(&["texture_u", "texture_v"]);
+        }
+        for _ in 0..element.count {
+            let mut position = Point3::origin();
+            let mut normal = Vector3::zero();
+            let mut uv = Vector2::zero();
+            for property in &element.properties {
+                match property {
+                    Property::Scalar(scalar_type, name) => {
+                        let x = body.read_scalar(*scalar_type)?;
+                        match name.as_str() {
+                            "x" => position.x = x,
+                            "y" => position.y = x,
+                            "z" => position.z = x,
+                            "nx" => normal.x = x,
+                            "ny" => normal.y = x,
+                            "nz" => normal.z = x,
+                            "s" | "u" | "texture_u" => uv.x = x,
+                            "t" | "v" | "texture_v" => uv.y = x,
+                            // the other properties, e.g. colors, are skipped.
+                            _ => {}
+                        }
+                    }
+                    Property::List(count_type, item_type, name) => {
+                        let count = body.read_index(*count_type)?;
+                        let list = (0..count)
+                            .map(|_| body.read_index(*item_type))
+                            .collect::<Result<Vec<_>>>()?;
+                        let is_face = element.name == "face"
+                            && (name == "vertex_indices" || name == "vertex_index");
+                        if is_face {
+                            faces.push(list);
+                        }
+                    }
+                }
+            }
+            if element.name == "vertex" {
+                attributes.positions.push(position);
+                if has_normal {
+                    attributes.normals.push(normal);
+                }
+                if has_uv {
+                    attributes.uv_coords.push(uv);
+                }
+            }
+        }
+    }
+    let faces = faces
+        .into_iter()
+        .map(|face| {
+            face.into_iter()
+                .map(|pos| Vertex {
+                    pos,
+                    uv: has_uv.then_some(pos),
+                    nor: has_normal.then_some(pos),
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    PolygonMesh::try_new(attributes, faces)
+}
+
+/// Reads mesh data from PLY file.
+///
+/// The ASCII, binary little endian, and binary big endian formats are supported.
+/// The properties `x`, `y`, `z`, `nx`, `ny`, `nz`, `s`, and `t` of the element `vertex` and
+/// the list `vertex_indices` of the element `face` are read, and the other properties and
+/// elements, e.g. the colors of vertices, are skipped.
+///
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// let data = b"ply
+/// format ascii 1.0
+/// element vertex 5
+/// property float x
+/// property float y
+/// property float z
+/// property uchar red
+/// property uchar green
+/// property uchar blue
+/// element face 1
+/// property list uchar int vertex_indices
+/// end_header
+/// 0 0 0 255 0 0
+/// 1 0 0 0 255 0
+/// 1 1 0 0 0 255
+/// 0.5 1.5 0 255 255 0
+/// 0 1 0 0 255 255
+/// 5 0 1 2 3 4
+/// ";
+/// let mesh = ply::read(data.as_slice()).unwrap();
+/// assert_eq!(mesh.positions().len(), 5);
+/// assert_eq!(mesh.positions()[3], Point3::new(0.5, 1.5, 0.0));
+/// assert_eq!(mesh.faces().len(), 1);
+/// assert_eq!(mesh.face_iter().next().unwrap().len(), 5);
+/// ```
+pub fn read<R: Read>(reader: R) -> Result<PolygonMesh> {
+    let mut reader = BufReader::new(reader);
+    let (format, elements) = read_header(&mut reader)?;
+    match format {
+        Format::Ascii => {
+            let mut string = String::new();
+            reader.read_to_string(&mut string)?;
+            read_body(&elements, &mut AsciiBody(string.split_ascii_whitespace()))
+        }
+        Format::BinaryLittleEndian | Format::BinaryBigEndian => {
+            let big_endian = format == Format::BinaryBigEndian;
+            read_body(&elements, &mut BinaryBody { reader, big_endian })
+        }
+    }
+}
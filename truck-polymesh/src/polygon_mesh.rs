@@ -126,6 +126,62 @@ impl PolygonMesh {
     /// Creates the bounding box of the polygon mesh.
     #[inline(always)]
     pub fn bounding_box(&self) -> BoundingBox<Point3> { self.positions().iter().collect() }
+    /// Returns the surface area. The quadrangles and n-gons are fan-triangulated.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// // the unit cube
+    /// let positions = vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 1.0, 0.0),
+    ///     Point3::new(0.0, 1.0, 0.0),
+    ///     Point3::new(0.0, 0.0, 1.0),
+    ///     Point3::new(1.0, 0.0, 1.0),
+    ///     Point3::new(1.0, 1.0, 1.0),
+    ///     Point3::new(0.0, 1.0, 1.0),
+    /// ];
+    /// let faces = Faces::from_iter(&[
+    ///     [0, 3, 2, 1],
+    ///     [0, 1, 5, 4],
+    ///     [1, 2, 6, 5],
+    ///     [2, 3, 7, 6],
+    ///     [3, 0, 4, 7],
+    ///     [4, 5, 6, 7],
+    /// ]);
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     faces,
+    /// );
+    /// assert_near!(mesh.surface_area(), 6.0);
+    /// assert_near!(mesh.centroid(), Point3::new(0.5, 0.5, 0.5));
+    /// ```
+    #[inline(always)]
+    pub fn surface_area(&self) -> f64 { self.weighted_triangles().map(|(area, _)| area).sum() }
+    /// Returns the area-weighted centroid of the surface.
+    ///
+    /// If the surface area is zero, the components of the result are NaN.
+    /// See [`PolygonMesh::surface_area`] for an example.
+    pub fn centroid(&self) -> Point3 {
+        let (area, moment) = self
+            .weighted_triangles()
+            .fold((0.0, Vector3::zero()), |(area, moment), (a, center)| {
+                (area + a, moment + center.to_vec() * a)
+            });
+        Point3::from_vec(moment / area)
+    }
+    /// the areas and the centroids of triangles
+    fn weighted_triangles(&self) -> impl Iterator<Item = (f64, Point3)> + '_ {
+        self.faces().triangle_iter().map(|face| {
+            let [p, q, r] = face.map(|v| self.positions()[v.pos]);
+            let area = (q - p).cross(r - p).magnitude() / 2.0;
+            (area, Point3::centroid(&[p, q, r]))
+        })
+    }
     /// Returns a polygon mesh with only positions information.
     #[inline(always)]
     pub fn to_positions_mesh(&self) -> PolygonMesh<usize, Vec<Point3>> {
@@ -154,6 +210,77 @@ impl PolygonMesh {
             },
         }
     }
+    /// Samples points on the surface, where the number of points on each triangle is proportional to its area.
+    ///
+    /// # Details
+    /// The number of all points is the surface area times `density`, rounded down.
+    /// The points on each triangle are placed by a low-discrepancy sequence, and hence the result is deterministic.
+    /// The degenerate triangles are skipped.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_polymesh::*;
+    /// // the unit cube
+    /// let positions = vec![
+    ///     Point3::new(0.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 0.0, 0.0),
+    ///     Point3::new(1.0, 1.0, 0.0),
+    ///     Point3::new(0.0, 1.0, 0.0),
+    ///     Point3::new(0.0, 0.0, 1.0),
+    ///     Point3::new(1.0, 0.0, 1.0),
+    ///     Point3::new(1.0, 1.0, 1.0),
+    ///     Point3::new(0.0, 1.0, 1.0),
+    /// ];
+    /// let faces = Faces::from_iter(&[
+    ///     [0, 3, 2, 1],
+    ///     [0, 1, 5, 4],
+    ///     [1, 2, 6, 5],
+    ///     [2, 3, 7, 6],
+    ///     [3, 0, 4, 7],
+    ///     [4, 5, 6, 7],
+    /// ]);
+    /// let mesh = PolygonMesh::new(
+    ///     StandardAttributes {
+    ///         positions,
+    ///         ..Default::default()
+    ///     },
+    ///     faces,
+    /// );
+    ///
+    /// let points = mesh.sample_surface(100.0);
+    /// assert_eq!(points.len(), 600);
+    /// // the points on the bottom face
+    /// assert_eq!(points.iter().filter(|p| p.z.so_small()).count(), 100);
+    /// ```
+    pub fn sample_surface(&self, density: f64) -> Vec<Point3> {
+        // the additive recurrence by the plastic number
+        const ALPHA: [f64; 2] = [0.7548776662466927, 0.5698402909980532];
+        let mut remainder = 0.0;
+        self.faces()
+            .triangle_iter()
+            .flat_map(|face| {
+                let [p, q, r] = face.map(|v| self.positions()[v.pos]);
+                let area = (q - p).cross(r - p).magnitude() / 2.0;
+                let n = match area < TOLERANCE2 {
+                    true => 0,
+                    false => {
+                        let expected = area * density + remainder;
+                        remainder = expected.fract();
+                        expected as usize
+                    }
+                };
+                (1..=n).map(move |k| {
+                    let s = (0.5 + ALPHA[0] * k as f64).fract();
+                    let t = (0.5 + ALPHA[1] * k as f64).fract();
+                    let (s, t) = match s + t > 1.0 {
+                        true => (1.0 - s, 1.0 - t),
+                        false => (s, t),
+                    };
+                    p + (q - p) * s + (r - p) * t
+                })
+            })
+            .collect()
+    }
 }
 
 impl Invertible for PolygonMesh {
@@ -200,9 +200,95 @@ fn write_ascii<I: IntoStlIterator, W: Write>(iter: I, writer: &mut W) -> Result<
 /// Writes binary STL data.
 #[inline(always)]
 fn write_binary<I: IntoStlIterator, W: Write>(iter: I, writer: &mut W) -> Result<()> {
+    write_binary_with_header(iter, writer, &[0u8; 80])
+}
+
+/// Options for writing binary STL.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StlOptions {
+    /// the 80 bytes header of the binary STL.
+    pub header: [u8; 80],
+    /// the scaling factor of the coordinates, e.g. `1.0 / 25.4` for converting millimeters to inches.
+    pub scale: f64,
+}
+
+impl Default for StlOptions {
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            header: [0; 80],
+            scale: 1.0,
+        }
+    }
+}
+
+/// Writes binary STL data of the mesh with the specified header and scaling factor.
+///
+/// If all vertices of a triangle have normals, the normal of the facet is the average of them.
+/// Otherwise, the normal of the facet is computed by the positions.
+///
+/// # Examples
+/// ```
+/// use truck_polymesh::*;
+/// use stl::StlOptions;
+/// let mesh = PolygonMesh::new(
+///     StandardAttributes {
+///         positions: vec![
+///             Point3::new(0.0, 0.0, 0.0),
+///             Point3::new(25.4, 0.0, 0.0),
+///             Point3::new(0.0, 25.4, 0.0),
+///         ],
+///         ..Default::default()
+///     },
+///     Faces::from_iter(&[[0, 1, 2]]),
+/// );
+/// let mut header = [b' '; 80];
+/// header[..5].copy_from_slice(b"truck");
+/// let options = StlOptions {
+///     header,
+///     scale: 1.0 / 25.4,
+/// };
+///
+/// let mut bytes = Vec::new();
+/// stl::write_binary_with_options(&mesh, &mut bytes, options).unwrap();
+/// assert_eq!(&bytes[..80], &header);
+/// let faces = stl::StlReader::new(bytes.as_slice(), stl::StlType::Binary)
+///     .unwrap()
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+/// assert_eq!(faces[0].normal, [0.0, 0.0, 1.0]);
+/// assert_eq!(faces[0].vertices, [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]);
+/// ```
+pub fn write_binary_with_options<W: Write>(
+    mesh: &PolygonMesh,
+    writer: &mut W,
+    options: StlOptions,
+) -> Result<()> {
+    let StlOptions { header, scale } = options;
+    let (positions, normals) = (mesh.positions(), mesh.normals());
+    let faces = mesh.faces().triangle_iter().map(|face| {
+        let p = array![i => positions[face[i].pos]; 3];
+        let normal = face
+            .iter()
+            .try_fold(Vector3::zero(), |sum, v| Some(sum + normals[v.nor?]))
+            .filter(|n| !n.so_small())
+            .unwrap_or_else(|| (p[1] - p[0]).cross(p[2] - p[0]))
+            .normalize();
+        let normal = normal.cast().unwrap().into();
+        let vertices = array![i => (p[i] * scale).cast().unwrap().into(); 3];
+        StlFace { normal, vertices }
+    });
+    write_binary_with_header(faces, writer, &header)
+}
+
+fn write_binary_with_header<I: IntoStlIterator, W: Write>(
+    iter: I,
+    writer: &mut W,
+    header: &[u8; 80],
+) -> Result<()> {
     let mut iter = iter.into_iter();
     let len = iter.len() as u32;
-    writer.write_all(&[0u8; 80])?;
+    writer.write_all(header)?;
     writer.write_all(&len.to_le_bytes())?;
     iter.try_for_each(|face| {
         writer.write_all(bytemuck::cast_slice(&[face]))?;
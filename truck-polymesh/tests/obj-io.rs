@@ -270,3 +270,64 @@ fn incomplete_obj_oi_test() {
     let read_mesh = obj::read(AsRef::<[u8]>::as_ref(&gened_obj)).unwrap();
     assert_eq!(mesh, read_mesh);
 }
+
+#[test]
+fn material_groups_obj_ioi_test() {
+    // The quadrangle comes before the triangles, and the last triangle has no material.
+    let obj = b"mtllib materials.mtl
+v 0.0 0.0 0.0
+v 1.0 0.0 0.0
+v 1.0 1.0 0.0
+v 0.0 1.0 0.0
+v 0.5 0.5 1.0
+o pyramid
+g base
+usemtl red
+f 1 4 3 2
+g side
+usemtl blue
+f 1 2 5
+f 2 3 5
+usemtl red
+f 3 4 5
+usemtl
+f 4 1 5
+";
+    let (mesh, groups) = obj::read_with_groups(obj.as_slice()).unwrap();
+    assert_eq!(groups.mtllibs, vec!["materials.mtl".to_string()]);
+    let materials = |mesh: &PolygonMesh, groups: &obj::ObjGroups| {
+        mesh.face_iter()
+            .zip(&groups.face_groups)
+            .map(|(face, i)| {
+                let group = &groups.groups[*i];
+                (face.len(), group.group.clone(), group.material.clone())
+            })
+            .collect::<Vec<_>>()
+    };
+    let name = |s: &str| Some(s.to_string());
+    let answer = vec![
+        (3, name("side"), name("blue")),
+        (3, name("side"), name("blue")),
+        (3, name("side"), name("red")),
+        (3, name("side"), None),
+        (4, name("base"), name("red")),
+    ];
+    assert_eq!(materials(&mesh, &groups), answer);
+    assert!(groups.groups.iter().all(|g| g.object == name("pyramid")));
+
+    let mut gened_obj: Vec<u8> = Vec::new();
+    obj::write_with_groups(&mesh, &groups, &mut gened_obj).unwrap();
+    let (mesh0, groups0) = obj::read_with_groups(gened_obj.as_slice()).unwrap();
+    assert_eq!(mesh0, mesh);
+    assert_eq!(groups0.mtllibs, groups.mtllibs);
+    assert_eq!(materials(&mesh0, &groups0), answer);
+
+    // the faces without any assignments belong to the default group.
+    let obj = b"v 0.0 0.0 0.0\nv 1.0 0.0 0.0\nv 0.0 1.0 0.0\nf 1 2 3\n";
+    let (_, groups) = obj::read_with_groups(obj.as_slice()).unwrap();
+    assert_eq!(groups.groups, vec![obj::ObjGroup::default()]);
+    assert_eq!(groups.face_groups, vec![0]);
+
+    let invalid = obj::ObjGroups::default();
+    assert!(obj::write_with_groups(&mesh, &invalid, &mut gened_obj).is_err());
+}
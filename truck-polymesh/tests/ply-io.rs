@@ -0,0 +1,139 @@
+use ply::PlyFormat;
+use truck_polymesh::*;
+
+fn pentagonal_pyramid() -> PolygonMesh {
+    let positions = (0..5)
+        .map(|i| {
+            let t = 2.0 * std::f64::consts::PI * i as f64 / 5.0;
+            Point3::new(f64::cos(t), f64::sin(t), 0.0)
+        })
+        .chain([Point3::new(0.0, 0.0, 1.0)])
+        .collect::<Vec<_>>();
+    let normals = positions
+        .iter()
+        .map(|p| (p.to_vec() - Vector3::new(0.0, 0.0, 0.3)).normalize())
+        .collect();
+    let uv_coords = positions.iter().map(|p| Vector2::new(p.x, p.y)).collect();
+    let vertex = |i: usize| StandardVertex {
+        pos: i,
+        uv: Some(i),
+        nor: Some(i),
+    };
+    let mut faces = Faces::from_iter((0..5).map(|i| [vertex((i + 1) % 5), vertex(i), vertex(5)]));
+    faces.push([4, 3, 2, 1, 0].map(vertex));
+    PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            uv_coords,
+            normals,
+        },
+        faces,
+    )
+}
+
+#[test]
+fn ply_round_trip() {
+    let mesh = pentagonal_pyramid();
+    for format in [PlyFormat::Ascii, PlyFormat::BinaryLittleEndian] {
+        let mut bytes = Vec::new();
+        ply::write(&mesh, &mut bytes, format).unwrap();
+        let read = ply::read(bytes.as_slice()).unwrap();
+        assert_eq!(mesh, read, "{format:?}");
+    }
+}
+
+#[test]
+fn ply_expanded_attributes() {
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(1.0, 1.0, 0.0),
+                Point3::new(0.0, 1.0, 0.0),
+            ],
+            normals: vec![Vector3::unit_z(), -Vector3::unit_z()],
+            ..Default::default()
+        },
+        Faces::from_iter(&[
+            [(0, None, Some(0)), (1, None, Some(0)), (2, None, Some(0))],
+            [(0, None, Some(1)), (3, None, Some(1)), (2, None, Some(1))],
+        ]),
+    );
+    let mut bytes = Vec::new();
+    ply::write(&mesh, &mut bytes, PlyFormat::Ascii).unwrap();
+    let read = ply::read(bytes.as_slice()).unwrap();
+    assert_eq!(read.positions().len(), 6);
+    assert_eq!(read.normals().len(), 6);
+    mesh.face_iter()
+        .zip(read.face_iter())
+        .for_each(|(face0, face1)| {
+            face0.iter().zip(face1).for_each(|(v0, v1)| {
+                assert_eq!(mesh.positions()[v0.pos], read.positions()[v1.pos]);
+                assert_eq!(
+                    mesh.normals()[v0.nor.unwrap()],
+                    read.normals()[v1.nor.unwrap()]
+                );
+            })
+        });
+}
+
+#[test]
+fn ply_point_cloud() {
+    let positions = (0..100)
+        .map(|i| Point3::new(i as f64, f64::sin(i as f64), f64::cos(i as f64)))
+        .collect::<Vec<_>>();
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions,
+            ..Default::default()
+        },
+        Faces::default(),
+    );
+    let mut bytes = Vec::new();
+    ply::write(&mesh, &mut bytes, PlyFormat::BinaryLittleEndian).unwrap();
+    assert_eq!(mesh, ply::read(bytes.as_slice()).unwrap());
+}
+
+#[test]
+fn ply_big_endian_with_colors() {
+    let mut bytes = b"ply
+format binary_big_endian 1.0
+comment colored triangle
+element vertex 3
+property float x
+property float y
+property float z
+property uchar red
+property uchar green
+property uchar blue
+element face 1
+property list uchar int vertex_indices
+end_header
+"
+    .to_vec();
+    for (p, color) in [
+        ([0.0f32, 0.0, 0.0], 255),
+        ([1.0, 0.0, 0.0], 0),
+        ([0.0, 1.0, 0.0], 128),
+    ] {
+        p.iter().for_each(|x| bytes.extend(x.to_be_bytes()));
+        bytes.extend([color; 3]);
+    }
+    bytes.push(3);
+    [0i32, 1, 2]
+        .iter()
+        .for_each(|i| bytes.extend(i.to_be_bytes()));
+
+    let mesh = ply::read(bytes.as_slice()).unwrap();
+    assert_eq!(
+        mesh.positions(),
+        &[
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(0.0, 1.0, 0.0),
+        ]
+    );
+    assert!(mesh.normals().is_empty());
+    assert_eq!(mesh.faces().len(), 1);
+}
@@ -85,3 +85,51 @@ fn through_polymesh() {
         assert!(f32::abs(face0.normal[2] - face1.normal[2]) < 5.0e-4);
     }
 }
+
+#[test]
+fn binary_with_options() {
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(2.0, 0.0, 0.0),
+                Point3::new(2.0, 2.0, 0.0),
+                Point3::new(0.0, 2.0, 0.0),
+            ],
+            normals: vec![Vector3::new(0.0, 1.0, 1.0), Vector3::new(0.0, -1.0, 1.0)],
+            ..Default::default()
+        },
+        Faces::from_iter(&[
+            [(0, None, Some(0)), (1, None, Some(1)), (2, None, Some(0))],
+            [(0, None, None), (2, None, None), (3, None, None)],
+        ]),
+    );
+    let mut header = [0u8; 80];
+    header[..13].copy_from_slice(b"signature 1.0");
+    let options = stl::StlOptions { header, scale: 0.5 };
+    let mut bytes = Vec::new();
+    stl::write_binary_with_options(&mesh, &mut bytes, options).unwrap();
+    assert_eq!(&bytes[..80], &header);
+
+    let faces = StlReader::new(bytes.as_slice(), StlType::Binary)
+        .unwrap()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    // the average of the normals of the vertices
+    let normal: [f32; 3] = Vector3::new(0.0, 1.0, 3.0)
+        .normalize()
+        .cast()
+        .unwrap()
+        .into();
+    assert_eq!(faces[0].normal, normal);
+    assert_eq!(
+        faces[0].vertices,
+        [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 1.0, 0.0]]
+    );
+    // the normal computed from the positions
+    assert_eq!(faces[1].normal, [0.0, 0.0, 1.0]);
+    assert_eq!(
+        faces[1].vertices,
+        [[0.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0]]
+    );
+}
@@ -103,6 +103,7 @@ impl App for MyApp {
                     position: Point3::new(0.5, 2.0, 0.5),
                     color: Vector3::new(1.0, 1.0, 1.0),
                     light_type: LightType::Point,
+                    ..Default::default()
                 }],
                 ..Default::default()
             },
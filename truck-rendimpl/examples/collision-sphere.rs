@@ -49,6 +49,7 @@ impl App for MyApp {
                     position: Point3::new(2.0, 2.0, 2.0),
                     color: Vector3::new(1.0, 1.0, 1.0),
                     light_type: LightType::Point,
+                    ..Default::default()
                 }],
                 ..Default::default()
             },
@@ -46,29 +46,33 @@ impl App for MyApp {
                         position: Point3::new(-a, -a, b),
                         color: Vector3::new(0.5, 0.5, 0.5),
                         light_type: LightType::Point,
+                        ..Default::default()
                     },
                     Light {
                         position: Point3::new(-a, a, b),
                         color: Vector3::new(0.5, 0.5, 0.5),
                         light_type: LightType::Point,
+                        ..Default::default()
                     },
                     Light {
                         position: Point3::new(a, -a, b),
                         color: Vector3::new(0.5, 0.5, 0.5),
                         light_type: LightType::Point,
+                        ..Default::default()
                     },
                     Light {
                         position: Point3::new(a, a, b),
                         color: Vector3::new(0.5, 0.5, 0.5),
                         light_type: LightType::Point,
+                        ..Default::default()
                     },
                 ],
-                background: wgpu::Color {
+                background: Background::Solid(wgpu::Color {
                     r: BACKGROUND[0],
                     g: BACKGROUND[1],
                     b: BACKGROUND[2],
                     a: BACKGROUND[3],
-                },
+                }),
             },
             backend_buffer: BackendBufferConfig {
                 sample_count,
@@ -128,6 +128,7 @@ impl App for MyRender {
                     position: Point3::new(0.0, 20.0, 0.0),
                     color: Vector3::new(1.0, 1.0, 1.0) * 1.5,
                     light_type: LightType::Point,
+                    ..Default::default()
                 }],
                 ..Default::default()
             },
@@ -262,6 +263,7 @@ impl App for MyRender {
                             position: vec,
                             color: Vector3::new(1.0, 1.0, 1.0),
                             light_type: LightType::Uniform,
+                            ..Default::default()
                         }
                     }
                     LightType::Uniform => {
@@ -270,6 +272,7 @@ impl App for MyRender {
                             position,
                             color: Vector3::new(1.0, 1.0, 1.0),
                             light_type: LightType::Point,
+                            ..Default::default()
                         }
                     }
                 };
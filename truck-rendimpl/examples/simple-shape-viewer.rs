@@ -161,12 +161,13 @@ impl App for MyApp {
         let sample_count = 4;
         let scene_desc = WindowSceneDescriptor {
             studio: StudioConfig {
-                background: Color::BLACK,
+                background: Background::Solid(Color::BLACK),
                 camera: MyApp::create_camera(),
                 lights: vec![Light {
                     position: Point3::new(1.0, 1.0, 1.0),
                     color: Vector3::new(1.0, 1.0, 1.0),
                     light_type: LightType::Point,
+                    ..Default::default()
                 }],
             },
             backend_buffer: BackendBufferConfig {
@@ -297,6 +298,7 @@ impl App for MyApp {
                             position: vec,
                             color: Vector3::new(1.0, 1.0, 1.0),
                             light_type: LightType::Uniform,
+                            ..Default::default()
                         }
                     }
                     LightType::Uniform => {
@@ -305,6 +307,7 @@ impl App for MyApp {
                             position,
                             color: Vector3::new(1.0, 1.0, 1.0),
                             light_type: LightType::Point,
+                            ..Default::default()
                         }
                     }
                 };
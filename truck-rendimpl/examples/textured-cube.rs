@@ -59,6 +59,7 @@ impl App for MyApp {
                     position: Point3::new(1.0, 1.0, 1.0),
                     color: Vector3::new(1.0, 1.0, 1.0),
                     light_type: LightType::Point,
+                    ..Default::default()
                 }],
                 ..Default::default()
             },
@@ -82,6 +83,7 @@ impl App for MyApp {
             },
             texture: Some(std::sync::Arc::new(texture)),
             backface_culling: true,
+            ..Default::default()
         };
         let mesh = Self::create_cube().triangulation(0.05).to_polygon();
         let shape: PolygonInstance = scene.instance_creator().create_instance(&mesh, &state);
@@ -201,6 +203,7 @@ impl App for MyApp {
                             position,
                             color: Vector3::new(1.0, 1.0, 1.0),
                             light_type: LightType::Uniform,
+                            ..Default::default()
                         }
                     }
                     LightType::Uniform => {
@@ -209,6 +212,7 @@ impl App for MyApp {
                             position,
                             color: Vector3::new(1.0, 1.0, 1.0),
                             light_type: LightType::Point,
+                            ..Default::default()
                         }
                     }
                 }
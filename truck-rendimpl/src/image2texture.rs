@@ -1,16 +1,85 @@
 use super::*;
 use image::*;
 
+/// Configures of the textures created by [`from_bytes`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TextureConfig {
+    /// If this parameter is true, the texels are regarded as sRGB and are converted into linear
+    /// colors by the sampler. Default is `false`.
+    pub srgb: bool,
+    /// If this parameter is true, the full chain of mipmaps is generated. Default is `false`.
+    pub mipmaps: bool,
+}
+
 /// Utility for creating `Texture` from `DynamicImage`
 #[inline(always)]
 pub fn image2texture(device_handler: &DeviceHandler, image: &DynamicImage) -> Texture {
     let buffer = image.to_rgba8();
-    imagebuffer2texture(device_handler, &buffer, TextureFormat::Rgba8Unorm)
+    imagebuffer2texture(device_handler, &[buffer], TextureFormat::Rgba8Unorm)
+}
+
+/// Creates `Texture` by decoding the encoded image, e.g. PNG or JPEG, in `bytes`.
+///
+/// # Details
+/// If `format_hint` is `None`, the format is guessed from the magic bytes. The size of the image
+/// need not be a power of two. The size of each mipmap is the half of the previous one, rounded
+/// down, and the mipmaps are generated until both the width and the height are one.
+///
+/// # Examples
+/// ```
+/// use std::io::Cursor;
+/// use truck_platform::*;
+/// use truck_rendimpl::{image2texture::{self, TextureConfig}, *};
+/// use image::{ImageFormat, RgbaImage};
+///
+/// let handler = pollster::block_on(DeviceHandler::default_device());
+/// let mut png = Vec::new();
+/// RgbaImage::new(300, 200)
+///     .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+///     .unwrap();
+///
+/// let config = TextureConfig {
+///     srgb: true,
+///     mipmaps: true,
+/// };
+/// let texture = image2texture::from_bytes(&handler, &png, None, config).unwrap();
+/// assert_eq!((texture.width(), texture.height()), (300, 200));
+/// assert_eq!(texture.format(), wgpu::TextureFormat::Rgba8UnormSrgb);
+/// // 300, 150, 75, 37, 18, 9, 4, 2, 1
+/// assert_eq!(texture.mip_level_count(), 9);
+/// ```
+pub fn from_bytes(
+    device_handler: &DeviceHandler,
+    bytes: &[u8],
+    format_hint: Option<ImageFormat>,
+    config: TextureConfig,
+) -> ImageResult<Texture> {
+    let image = match format_hint {
+        Some(format) => load_from_memory_with_format(bytes, format)?,
+        None => load_from_memory(bytes)?,
+    };
+    let buffer = image.to_rgba8();
+    let mut levels = vec![buffer];
+    while config.mipmaps {
+        let last = &levels[levels.len() - 1];
+        let (width, height) = (last.width(), last.height());
+        if width == 1 && height == 1 {
+            break;
+        }
+        let (width, height) = (u32::max(width / 2, 1), u32::max(height / 2, 1));
+        levels.push(imageops::resize(last, width, height, imageops::Triangle));
+    }
+    let format = match config.srgb {
+        true => TextureFormat::Rgba8UnormSrgb,
+        false => TextureFormat::Rgba8Unorm,
+    };
+    Ok(imagebuffer2texture(device_handler, &levels, format))
 }
 
+/// Creates `Texture` whose mipmaps are `levels`.
 fn imagebuffer2texture<P, Container>(
     device_handler: &DeviceHandler,
-    image_buffer: &ImageBuffer<P, Container>,
+    levels: &[ImageBuffer<P, Container>],
     format: TextureFormat,
 ) -> Texture
 where
@@ -19,35 +88,41 @@ where
     Container: std::ops::Deref<Target = [P::Subpixel]>,
 {
     let (device, queue) = (device_handler.device(), device_handler.queue());
-    let size = Extent3d {
+    let extent = |image_buffer: &ImageBuffer<P, Container>| Extent3d {
         width: image_buffer.width(),
         height: image_buffer.height(),
         depth_or_array_layers: 1,
     };
     let texture = device.create_texture(&TextureDescriptor {
         label: None,
-        size,
-        mip_level_count: 1,
+        size: extent(&levels[0]),
+        mip_level_count: levels.len() as u32,
         sample_count: 1,
         dimension: TextureDimension::D2,
         format,
         view_formats: &[],
         usage: TextureUsages::TEXTURE_BINDING | TextureUsages::COPY_DST,
     });
-    queue.write_texture(
-        ImageCopyTexture {
-            texture: &texture,
-            mip_level: 0,
-            origin: Origin3d::ZERO,
-            aspect: TextureAspect::All,
-        },
-        bytemuck::cast_slice(image_buffer),
-        ImageDataLayout {
-            offset: 0,
-            bytes_per_row: Some(size.width * size_of::<P>() as u32),
-            rows_per_image: Some(size.height),
-        },
-        size,
-    );
+    levels
+        .iter()
+        .enumerate()
+        .for_each(|(mip_level, image_buffer)| {
+            let size = extent(image_buffer);
+            queue.write_texture(
+                ImageCopyTexture {
+                    texture: &texture,
+                    mip_level: mip_level as u32,
+                    origin: Origin3d::ZERO,
+                    aspect: TextureAspect::All,
+                },
+                bytemuck::cast_slice(image_buffer),
+                ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(size.width * size_of::<P>() as u32),
+                    rows_per_image: Some(size.height),
+                },
+                size,
+            );
+        });
     texture
 }
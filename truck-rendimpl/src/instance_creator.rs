@@ -86,6 +86,44 @@ impl WireShaders {
     }
 }
 
+impl PointShaders {
+    /// Constructor
+    /// # Parameters
+    /// - `vertex_module`: vertex shader module
+    /// - `vertex_entry`: entry point of vertex shader module
+    /// - `fragment_module`: fragment shader module
+    /// - `fragment_entry`: entry point of fragment shader module
+    #[inline(always)]
+    pub const fn new(
+        vertex_module: Arc<ShaderModule>,
+        vertex_entry: &'static str,
+        fragment_module: Arc<ShaderModule>,
+        fragment_entry: &'static str,
+    ) -> Self {
+        Self {
+            vertex_module,
+            vertex_entry,
+            fragment_module,
+            fragment_entry,
+        }
+    }
+
+    /// Creates default point cloud shaders
+    #[inline(always)]
+    fn default(device: &Device) -> Self {
+        let shader_module = Arc::new(device.create_shader_module(ShaderModuleDescriptor {
+            source: ShaderSource::Wgsl(include_str!("shaders/point.wgsl").into()),
+            label: None,
+        }));
+        Self::new(
+            Arc::clone(&shader_module),
+            "vs_main",
+            shader_module,
+            "fs_main",
+        )
+    }
+}
+
 impl CreatorCreator for DeviceHandler {
     #[inline(always)]
     fn instance_creator(&self) -> InstanceCreator {
@@ -93,6 +131,7 @@ impl CreatorCreator for DeviceHandler {
             handler: self.clone(),
             polygon_shaders: PolygonShaders::default(self.device()),
             wire_shaders: WireShaders::default(self.device()),
+            point_shaders: PointShaders::default(self.device()),
         }
     }
 }
@@ -66,6 +66,8 @@ impl Default for PolygonState {
             material: Default::default(),
             texture: None,
             backface_culling: true,
+            show_edges: false,
+            edge_color: Vector4::new(0.0, 0.0, 0.0, 1.0),
         }
     }
 }
@@ -55,6 +55,11 @@ pub struct PolygonState {
     pub texture: Option<Arc<Texture>>,
     /// If this parameter is true, the backface culling will be activated.
     pub backface_culling: bool,
+    /// If this parameter is true, the edges of the polygons are drawn over the faces.
+    /// Default is `false`.
+    pub show_edges: bool,
+    /// color of the edges drawn by `show_edges`. Default is black.
+    pub edge_color: Vector4,
 }
 
 /// Configures of `WireFrameInstance`.
@@ -66,6 +71,28 @@ pub struct WireFrameState {
     pub color: Vector4,
 }
 
+/// Configures of `WireFrameInstance` created from the edges of the boundary representation.
+///
+/// The curves of the edges are sampled into polylines by [`ParameterDivision1D`].
+#[derive(Clone, Debug)]
+pub struct ShapeWireFrameState {
+    /// the state of the created instance
+    pub wireframe_state: WireFrameState,
+    /// the tolerance of sampling the curves. Default is `0.005`.
+    pub polyline_precision: f64,
+}
+
+/// Configures of `PointCloudInstance`.
+#[derive(Clone, Debug)]
+pub struct PointCloudState {
+    /// instance matrix
+    pub matrix: Matrix4,
+    /// color of instance, multiplied by the colors of points. Default is white.
+    pub color: Vector4,
+    /// diameter of points in pixels. Default is `4.0`.
+    pub point_size: f64,
+}
+
 /// shaders for rendering polygons
 #[derive(Debug, Clone)]
 pub struct PolygonShaders {
@@ -86,6 +113,15 @@ pub struct WireShaders {
     fragment_entry: &'static str,
 }
 
+/// shaders for rendering point clouds
+#[derive(Debug, Clone)]
+pub struct PointShaders {
+    vertex_module: Arc<ShaderModule>,
+    vertex_entry: &'static str,
+    fragment_module: Arc<ShaderModule>,
+    fragment_entry: &'static str,
+}
+
 /// Instance of polygon
 ///
 /// One can duplicate polygons with different postures and materials
@@ -97,6 +133,9 @@ pub struct WireShaders {
 #[derive(Debug)]
 pub struct PolygonInstance {
     polygon: (Arc<BufferHandler>, Arc<BufferHandler>),
+    // the center of the bounding box of the vertices, for sorting transparent instances.
+    center: Point3,
+    edges: Arc<BufferHandler>,
     state: PolygonState,
     shaders: PolygonShaders,
     id: RenderID,
@@ -112,12 +151,24 @@ pub struct WireFrameInstance {
     id: RenderID,
 }
 
+/// Point cloud rendering
+///
+/// Each point is drawn as a round sprite whose size is constant in the screen space.
+#[derive(Debug)]
+pub struct PointCloudInstance {
+    points: (Arc<BufferHandler>, Arc<BufferHandler>),
+    state: PointCloudState,
+    shaders: PointShaders,
+    id: RenderID,
+}
+
 /// Constroctor for instances
 #[derive(Debug, Clone)]
 pub struct InstanceCreator {
     handler: DeviceHandler,
     polygon_shaders: PolygonShaders,
     wire_shaders: WireShaders,
+    point_shaders: PointShaders,
 }
 
 /// for creating `InstanceCreator`
@@ -162,10 +213,21 @@ struct AttrVertex {
     pub normal: [f32; 3],
 }
 
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Zeroable, Pod)]
+struct PointVertex {
+    pub position: [f32; 3],
+    pub corner: [f32; 2],
+    pub color: [f32; 4],
+}
+
 /// utility for creating `Texture`
 pub mod image2texture;
 mod instance_creator;
 mod instance_descriptor;
+mod picking;
+mod pointcloud_instance;
 mod polygon_instance;
 mod polyrend;
+mod shaperend;
 mod wireframe_instance;
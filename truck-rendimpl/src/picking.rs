@@ -0,0 +1,56 @@
+use crate::*;
+
+/// Creates the pipeline for [`Rendered::picking_pipeline`].
+///
+/// The vertex buffer must have the position `[f32; 3]` at the top of each vertex.
+pub(crate) fn create_picking_pipeline(
+    device: &Device,
+    layout: &PipelineLayout,
+    array_stride: BufferAddress,
+    primitive: PrimitiveState,
+) -> Arc<RenderPipeline> {
+    let module = device.create_shader_module(ShaderModuleDescriptor {
+        source: ShaderSource::Wgsl(include_str!("shaders/picking.wgsl").into()),
+        label: None,
+    });
+    let pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
+        layout: Some(layout),
+        vertex: VertexState {
+            module: &module,
+            entry_point: Some("vs_main"),
+            buffers: &[VertexBufferLayout {
+                array_stride,
+                step_mode: VertexStepMode::Vertex,
+                attributes: &[VertexAttribute {
+                    format: VertexFormat::Float32x3,
+                    offset: 0,
+                    shader_location: 0,
+                }],
+            }],
+            compilation_options: Default::default(),
+        },
+        fragment: Some(FragmentState {
+            module: &module,
+            entry_point: Some("fs_main"),
+            targets: &[Some(ColorTargetState {
+                format: TextureFormat::R32Uint,
+                blend: None,
+                write_mask: ColorWrites::ALL,
+            })],
+            compilation_options: Default::default(),
+        }),
+        primitive,
+        depth_stencil: Some(DepthStencilState {
+            format: TextureFormat::Depth32Float,
+            depth_write_enabled: true,
+            depth_compare: CompareFunction::Less,
+            stencil: Default::default(),
+            bias: Default::default(),
+        }),
+        multisample: MultisampleState::default(),
+        label: None,
+        multiview: None,
+        cache: None,
+    });
+    Arc::new(pipeline)
+}
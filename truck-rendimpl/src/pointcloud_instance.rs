@@ -0,0 +1,205 @@
+use crate::*;
+
+impl Default for PointCloudState {
+    #[inline(always)]
+    fn default() -> PointCloudState {
+        PointCloudState {
+            matrix: Matrix4::identity(),
+            color: Vector4::new(1.0, 1.0, 1.0, 1.0),
+            point_size: 4.0,
+        }
+    }
+}
+
+impl PointCloudInstance {
+    /// Clone the instance as another drawn element.
+    #[inline(always)]
+    pub fn clone_instance(&self) -> Self {
+        Self {
+            points: self.points.clone(),
+            state: self.state.clone(),
+            shaders: self.shaders.clone(),
+            id: RenderID::gen(),
+        }
+    }
+    /// Returns the point cloud state
+    #[inline(always)]
+    pub const fn instance_state(&self) -> &PointCloudState { &self.state }
+    /// Returns the mutable reference to point cloud state
+    #[inline(always)]
+    pub fn instance_state_mut(&mut self) -> &mut PointCloudState { &mut self.state }
+    /// swap vertex buffers and index buffers
+    #[inline(always)]
+    pub fn swap_vertex(&mut self, other: &mut PointCloudInstance) {
+        std::mem::swap(&mut self.points, &mut other.points);
+    }
+
+    /// Creates the instance from the points and their colors.
+    pub(crate) fn from_colored_points(
+        points: impl ExactSizeIterator<Item = (Point3, Vector4)>,
+        handler: &DeviceHandler,
+        shaders: &PointShaders,
+        state: &PointCloudState,
+    ) -> Self {
+        const CORNERS: [[f32; 2]; 4] = [[-1.0, -1.0], [1.0, -1.0], [1.0, 1.0], [-1.0, 1.0]];
+        let device = handler.device();
+        let len = points.len();
+        // Each point is expanded to the quadrangle of the sprite.
+        let vertices = points
+            .flat_map(|(point, color)| {
+                CORNERS.map(|corner| PointVertex {
+                    position: point.cast().unwrap().into(),
+                    corner,
+                    color: color.cast().unwrap().into(),
+                })
+            })
+            .collect::<Vec<_>>();
+        let indices = (0..len as u32)
+            .flat_map(|i| [0, 1, 2, 0, 2, 3].map(|j| 4 * i + j))
+            .collect::<Vec<_>>();
+        let vb = BufferHandler::from_slice(&vertices, device, BufferUsages::VERTEX);
+        let ib = BufferHandler::from_slice(&indices, device, BufferUsages::INDEX);
+        PointCloudInstance {
+            points: (Arc::new(vb), Arc::new(ib)),
+            state: state.clone(),
+            shaders: shaders.clone(),
+            id: RenderID::gen(),
+        }
+    }
+}
+
+impl Instance for PointCloudInstance {
+    type Shaders = PointShaders;
+    fn standard_shaders(creator: &InstanceCreator) -> PointShaders { creator.point_shaders.clone() }
+}
+
+impl Rendered for PointCloudInstance {
+    impl_render_id!(id);
+    fn vertex_buffer(&self, _: &DeviceHandler) -> (Arc<BufferHandler>, Option<Arc<BufferHandler>>) {
+        (self.points.0.clone(), Some(self.points.1.clone()))
+    }
+    fn bind_group_layout(&self, handler: &DeviceHandler) -> Arc<BindGroupLayout> {
+        Arc::new(bind_group_util::create_bind_group_layout(
+            handler.device(),
+            &[
+                // matrix
+                PreBindGroupLayoutEntry {
+                    visibility: ShaderStages::VERTEX,
+                    ty: BindingType::Buffer {
+                        ty: BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // color and point size
+                PreBindGroupLayoutEntry {
+                    visibility: ShaderStages::VERTEX,
+                    ty: BindingType::Buffer {
+                        ty: BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+            ],
+        ))
+    }
+    fn bind_group(&self, handler: &DeviceHandler, layout: &BindGroupLayout) -> Arc<BindGroup> {
+        let device = handler.device();
+        let matrix_data: [[f32; 4]; 4] = self.state.matrix.cast::<f32>().unwrap().into();
+        let matrix_buffer = BufferHandler::from_slice(&matrix_data, device, BufferUsages::UNIFORM);
+        let color = self.state.color;
+        let info_data: [f32; 8] = [
+            color[0] as f32,
+            color[1] as f32,
+            color[2] as f32,
+            color[3] as f32,
+            self.state.point_size as f32,
+            0.0,
+            0.0,
+            0.0,
+        ];
+        let info_buffer = BufferHandler::from_slice(&info_data, device, BufferUsages::UNIFORM);
+        Arc::new(bind_group_util::create_bind_group(
+            device,
+            layout,
+            vec![
+                matrix_buffer.binding_resource(),
+                info_buffer.binding_resource(),
+            ],
+        ))
+    }
+    fn pipeline(
+        &self,
+        handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        scene_desc: &SceneDescriptor,
+    ) -> Arc<RenderPipeline> {
+        let device = handler.device();
+        let depth_stencil = match scene_desc.backend_buffer.depth_test {
+            true => Some(DepthStencilState {
+                format: TextureFormat::Depth32Float,
+                depth_write_enabled: true,
+                depth_compare: CompareFunction::Less,
+                stencil: Default::default(),
+                bias: Default::default(),
+            }),
+            false => None,
+        };
+        let sample_count = scene_desc.backend_buffer.sample_count;
+        let pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
+            layout: Some(layout),
+            vertex: VertexState {
+                module: &self.shaders.vertex_module,
+                entry_point: Some(self.shaders.vertex_entry),
+                buffers: &[VertexBufferLayout {
+                    array_stride: size_of::<PointVertex>() as BufferAddress,
+                    step_mode: VertexStepMode::Vertex,
+                    attributes: &[
+                        VertexAttribute {
+                            format: VertexFormat::Float32x3,
+                            offset: 0,
+                            shader_location: 0,
+                        },
+                        VertexAttribute {
+                            format: VertexFormat::Float32x2,
+                            offset: 3 * 4,
+                            shader_location: 1,
+                        },
+                        VertexAttribute {
+                            format: VertexFormat::Float32x4,
+                            offset: 3 * 4 + 2 * 4,
+                            shader_location: 2,
+                        },
+                    ],
+                }],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(FragmentState {
+                module: &self.shaders.fragment_module,
+                entry_point: Some(self.shaders.fragment_entry),
+                targets: &[Some(ColorTargetState {
+                    format: scene_desc.render_texture.format,
+                    blend: Some(BlendState::REPLACE),
+                    write_mask: ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: PrimitiveState {
+                topology: PrimitiveTopology::TriangleList,
+                ..Default::default()
+            },
+            depth_stencil,
+            multisample: MultisampleState {
+                count: sample_count,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            label: None,
+            multiview: None,
+            cache: None,
+        });
+        Arc::new(pipeline)
+    }
+}
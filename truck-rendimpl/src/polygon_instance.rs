@@ -6,6 +6,8 @@ impl PolygonInstance {
     pub fn clone_instance(&self) -> PolygonInstance {
         PolygonInstance {
             polygon: self.polygon.clone(),
+            center: self.center,
+            edges: self.edges.clone(),
             state: self.state.clone(),
             shaders: self.shaders.clone(),
             id: RenderID::gen(),
@@ -22,6 +24,8 @@ impl PolygonInstance {
     #[inline(always)]
     pub fn swap_vertex(&mut self, other: &mut PolygonInstance) {
         std::mem::swap(&mut self.polygon, &mut other.polygon);
+        std::mem::swap(&mut self.center, &mut other.center);
+        std::mem::swap(&mut self.edges, &mut other.edges);
     }
 
     #[inline(always)]
@@ -119,6 +123,8 @@ impl Rendered for PolygonInstance {
             true => Some(Face::Back),
             false => None,
         };
+        // transparent instances are drawn from back to front without writing depth.
+        let depth_write_enabled = !self.state.material.alpha_blend;
         let blend = match self.state.material.alpha_blend {
             true => Some(BlendState::ALPHA_BLENDING),
             false => Some(BlendState::REPLACE),
@@ -126,7 +132,7 @@ impl Rendered for PolygonInstance {
         let depth_stencil = match scene_desc.backend_buffer.depth_test {
             true => Some(DepthStencilState {
                 format: TextureFormat::Depth32Float,
-                depth_write_enabled: true,
+                depth_write_enabled,
                 depth_compare: CompareFunction::Less,
                 stencil: Default::default(),
                 bias: Default::default(),
@@ -191,4 +197,199 @@ impl Rendered for PolygonInstance {
         });
         Arc::new(pipeline)
     }
+    #[inline(always)]
+    fn overlay_index_buffer(&self, _: &DeviceHandler) -> Option<Arc<BufferHandler>> {
+        match self.state.show_edges {
+            true => Some(self.edges.clone()),
+            false => None,
+        }
+    }
+    fn overlay_pipeline(
+        &self,
+        device_handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        scene_desc: &SceneDescriptor,
+    ) -> Option<Arc<RenderPipeline>> {
+        if !self.state.show_edges {
+            return None;
+        }
+        let device = device_handler.device();
+        let module = device.create_shader_module(ShaderModuleDescriptor {
+            source: ShaderSource::Wgsl(include_str!("shaders/edge.wgsl").into()),
+            label: None,
+        });
+        let color = self.state.edge_color;
+        let constants: std::collections::HashMap<_, _> = [
+            ("edge_color_r".to_string(), color[0]),
+            ("edge_color_g".to_string(), color[1]),
+            ("edge_color_b".to_string(), color[2]),
+            ("edge_color_a".to_string(), color[3]),
+        ]
+        .into_iter()
+        .collect();
+        let depth_stencil = match scene_desc.backend_buffer.depth_test {
+            true => Some(DepthStencilState {
+                format: TextureFormat::Depth32Float,
+                depth_write_enabled: !self.state.material.alpha_blend,
+                depth_compare: CompareFunction::Less,
+                stencil: Default::default(),
+                bias: Default::default(),
+            }),
+            false => None,
+        };
+        let sample_count = scene_desc.backend_buffer.sample_count;
+        let pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
+            layout: Some(layout),
+            vertex: VertexState {
+                module: &module,
+                entry_point: Some("vs_main"),
+                buffers: &[VertexBufferLayout {
+                    array_stride: size_of::<AttrVertex>() as BufferAddress,
+                    step_mode: VertexStepMode::Vertex,
+                    attributes: &[VertexAttribute {
+                        format: VertexFormat::Float32x3,
+                        offset: 0,
+                        shader_location: 0,
+                    }],
+                }],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(FragmentState {
+                module: &module,
+                entry_point: Some("fs_main"),
+                targets: &[Some(ColorTargetState {
+                    format: scene_desc.render_texture.format,
+                    blend: Some(BlendState::ALPHA_BLENDING),
+                    write_mask: ColorWrites::ALL,
+                })],
+                compilation_options: PipelineCompilationOptions {
+                    constants: &constants,
+                    ..Default::default()
+                },
+            }),
+            primitive: PrimitiveState {
+                topology: PrimitiveTopology::LineList,
+                ..Default::default()
+            },
+            depth_stencil,
+            multisample: MultisampleState {
+                count: sample_count,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            },
+            label: None,
+            multiview: None,
+            cache: None,
+        });
+        Some(Arc::new(pipeline))
+    }
+    #[inline(always)]
+    fn transparent_center(&self) -> Option<Point3> {
+        match self.state.material.alpha_blend {
+            true => Some(self.state.matrix.transform_point(self.center)),
+            false => None,
+        }
+    }
+    #[inline(always)]
+    fn picking_pipeline(
+        &self,
+        device_handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        _: &SceneDescriptor,
+    ) -> Option<Arc<RenderPipeline>> {
+        // transparent instances cannot be picked.
+        if self.state.material.alpha_blend {
+            return None;
+        }
+        let cull_mode = match self.state.backface_culling {
+            true => Some(Face::Back),
+            false => None,
+        };
+        let primitive = PrimitiveState {
+            topology: PrimitiveTopology::TriangleList,
+            front_face: FrontFace::Ccw,
+            cull_mode,
+            polygon_mode: PolygonMode::Fill,
+            ..Default::default()
+        };
+        Some(picking::create_picking_pipeline(
+            device_handler.device(),
+            layout,
+            size_of::<AttrVertex>() as BufferAddress,
+            primitive,
+        ))
+    }
+    fn gbuffer_pipeline(
+        &self,
+        device_handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        _: &SceneDescriptor,
+    ) -> Option<Arc<RenderPipeline>> {
+        // transparent instances have no outlines.
+        if self.state.material.alpha_blend {
+            return None;
+        }
+        let device = device_handler.device();
+        let module = device.create_shader_module(ShaderModuleDescriptor {
+            source: ShaderSource::Wgsl(include_str!("shaders/gbuffer.wgsl").into()),
+            label: None,
+        });
+        let cull_mode = match self.state.backface_culling {
+            true => Some(Face::Back),
+            false => None,
+        };
+        let pipeline = device.create_render_pipeline(&RenderPipelineDescriptor {
+            layout: Some(layout),
+            vertex: VertexState {
+                module: &module,
+                entry_point: Some("vs_main"),
+                buffers: &[VertexBufferLayout {
+                    array_stride: size_of::<AttrVertex>() as BufferAddress,
+                    step_mode: VertexStepMode::Vertex,
+                    attributes: &[
+                        VertexAttribute {
+                            format: VertexFormat::Float32x3,
+                            offset: 0,
+                            shader_location: 0,
+                        },
+                        VertexAttribute {
+                            format: VertexFormat::Float32x3,
+                            offset: 2 * 4 + 3 * 4,
+                            shader_location: 1,
+                        },
+                    ],
+                }],
+                compilation_options: Default::default(),
+            },
+            fragment: Some(FragmentState {
+                module: &module,
+                entry_point: Some("fs_main"),
+                targets: &[Some(ColorTargetState {
+                    format: TextureFormat::Rgba32Float,
+                    blend: None,
+                    write_mask: ColorWrites::ALL,
+                })],
+                compilation_options: Default::default(),
+            }),
+            primitive: PrimitiveState {
+                topology: PrimitiveTopology::TriangleList,
+                front_face: FrontFace::Ccw,
+                cull_mode,
+                polygon_mode: PolygonMode::Fill,
+                ..Default::default()
+            },
+            depth_stencil: Some(DepthStencilState {
+                format: TextureFormat::Depth32Float,
+                depth_write_enabled: true,
+                depth_compare: CompareFunction::Less,
+                stencil: Default::default(),
+                bias: Default::default(),
+            }),
+            multisample: MultisampleState::default(),
+            label: None,
+            multiview: None,
+            cache: None,
+        });
+        Some(Arc::new(pipeline))
+    }
 }
@@ -1,6 +1,48 @@
 use crate::*;
 use itertools::*;
 
+fn expanded_mesh(mesh: &PolygonMesh) -> PolygonMesh<usize, Vec<AttrVertex>> {
+    mesh.expands(|attr| AttrVertex {
+        position: attr.position.cast().unwrap().into(),
+        uv_coord: attr
+            .uv_coord
+            .and_then(|v| Some(v.cast()?.into()))
+            .unwrap_or([0.0, 0.0]),
+        normal: attr
+            .normal
+            .and_then(|v| Some(v.cast()?.into()))
+            .unwrap_or([0.0, 0.0, 0.0]),
+    })
+}
+
+fn expanded_buffers(
+    expanded: &PolygonMesh<usize, Vec<AttrVertex>>,
+    vertex_usage: BufferUsages,
+    index_usage: BufferUsages,
+    device: &Device,
+) -> (BufferHandler, BufferHandler) {
+    let indices = expanded
+        .faces()
+        .triangle_iter()
+        .flatten()
+        .map(|x| x as u32)
+        .collect::<Vec<_>>();
+    (
+        BufferHandler::from_slice(expanded.attributes(), device, vertex_usage),
+        BufferHandler::from_slice(&indices, device, index_usage),
+    )
+}
+
+/// Creates the index buffer of the line list, removing the duplicated edges.
+fn edge_buffer(edges: impl Iterator<Item = [usize; 2]>, device: &Device) -> BufferHandler {
+    let indices = edges
+        .map(|[a, b]| [usize::min(a, b) as u32, usize::max(a, b) as u32])
+        .unique()
+        .flatten()
+        .collect::<Vec<_>>();
+    BufferHandler::from_slice(&indices, device, BufferUsages::INDEX)
+}
+
 impl CreateBuffers for PolygonMesh {
     #[inline(always)]
     fn buffers(
@@ -9,27 +51,7 @@ impl CreateBuffers for PolygonMesh {
         index_usage: BufferUsages,
         device: &Device,
     ) -> (BufferHandler, BufferHandler) {
-        let expanded = self.expands(|attr| AttrVertex {
-            position: attr.position.cast().unwrap().into(),
-            uv_coord: attr
-                .uv_coord
-                .and_then(|v| Some(v.cast()?.into()))
-                .unwrap_or([0.0, 0.0]),
-            normal: attr
-                .normal
-                .and_then(|v| Some(v.cast()?.into()))
-                .unwrap_or([0.0, 0.0, 0.0]),
-        });
-        let indices = expanded
-            .faces()
-            .triangle_iter()
-            .flatten()
-            .map(|x| x as u32)
-            .collect::<Vec<_>>();
-        (
-            BufferHandler::from_slice(expanded.attributes(), device, vertex_usage),
-            BufferHandler::from_slice(&indices, device, index_usage),
-        )
+        expanded_buffers(&expanded_mesh(self), vertex_usage, index_usage, device)
     }
 }
 
@@ -49,9 +71,18 @@ impl ToInstance<PolygonInstance> for PolygonMesh {
         shaders: &PolygonShaders,
         state: &PolygonState,
     ) -> PolygonInstance {
-        let (vb, ib) = self.buffers(BufferUsages::VERTEX, BufferUsages::INDEX, handler.device());
+        let device = handler.device();
+        let expanded = expanded_mesh(self);
+        let (vb, ib) =
+            expanded_buffers(&expanded, BufferUsages::VERTEX, BufferUsages::INDEX, device);
+        let edges = expanded
+            .faces()
+            .face_iter()
+            .flat_map(|face| face.iter().circular_tuple_windows().map(|(a, b)| [*a, *b]));
         PolygonInstance {
             polygon: (Arc::new(vb), Arc::new(ib)),
+            center: self.bounding_box().center(),
+            edges: Arc::new(edge_buffer(edges, device)),
             state: state.clone(),
             shaders: shaders.clone(),
             id: RenderID::gen(),
@@ -141,9 +172,23 @@ impl ToInstance<PolygonInstance> for StructuredMesh {
         shaders: &PolygonShaders,
         state: &PolygonState,
     ) -> PolygonInstance {
-        let (vb, ib) = self.buffers(BufferUsages::VERTEX, BufferUsages::INDEX, handler.device());
+        let device = handler.device();
+        let (vb, ib) = self.buffers(BufferUsages::VERTEX, BufferUsages::INDEX, device);
+        let (m, n) = (self.positions().len(), self.positions()[0].len());
+        let edges = iproduct!(0..m, 0..n).flat_map(|(i, j)| {
+            let u_edge = (j > 0).then(|| [i * n + j - 1, i * n + j]);
+            let v_edge = (i > 0).then(|| [(i - 1) * n + j, i * n + j]);
+            u_edge.into_iter().chain(v_edge)
+        });
         PolygonInstance {
             polygon: (Arc::new(vb), Arc::new(ib)),
+            center: self
+                .positions()
+                .iter()
+                .flatten()
+                .collect::<BoundingBox<_>>()
+                .center(),
+            edges: Arc::new(edge_buffer(edges, device)),
             state: state.clone(),
             shaders: shaders.clone(),
             id: RenderID::gen(),
@@ -255,3 +300,30 @@ impl ToInstance<WireFrameInstance> for Vec<PolylineCurve<Point3>> {
         }
     }
 }
+
+impl ToInstance<PointCloudInstance> for Vec<Point3> {
+    type State = PointCloudState;
+    fn to_instance(
+        &self,
+        handler: &DeviceHandler,
+        shaders: &PointShaders,
+        state: &PointCloudState,
+    ) -> PointCloudInstance {
+        let white = Vector4::new(1.0, 1.0, 1.0, 1.0);
+        let points = self.iter().map(|p| (*p, white));
+        PointCloudInstance::from_colored_points(points, handler, shaders, state)
+    }
+}
+
+impl ToInstance<PointCloudInstance> for Vec<(Point3, Vector4)> {
+    type State = PointCloudState;
+    fn to_instance(
+        &self,
+        handler: &DeviceHandler,
+        shaders: &PointShaders,
+        state: &PointCloudState,
+    ) -> PointCloudInstance {
+        let points = self.iter().copied();
+        PointCloudInstance::from_colored_points(points, handler, shaders, state)
+    }
+}
@@ -0,0 +1,38 @@
+struct Camera {
+    _matrix: mat4x4<f32>,
+    projection: mat4x4<f32>,
+}
+
+@group(0)
+@binding(0)
+var<uniform> camera: Camera;
+
+struct ModelMatrix {
+    model_matrix: mat4x4<f32>,
+}
+
+@group(1)
+@binding(0)
+var<uniform> model_matrix: ModelMatrix;
+
+override edge_color_r: f32;
+override edge_color_g: f32;
+override edge_color_b: f32;
+override edge_color_a: f32;
+
+// the offset of the depth in the normalized device coordinate
+const DEPTH_BIAS: f32 = 1.0e-4;
+
+@vertex
+fn vs_main(@location(0) position: vec3<f32>) -> @builtin(position) vec4<f32> {
+    var res: vec4<f32> = camera.projection * model_matrix.model_matrix * vec4<f32>(position, 1.0);
+    // The offset in the view space grows with the distance from the camera,
+    // since the normalized depth of the perspective projection gets coarse.
+    res.z = res.z - DEPTH_BIAS * res.w;
+    return res;
+}
+
+@fragment
+fn fs_main() -> @location(0) vec4<f32> {
+    return vec4<f32>(edge_color_r, edge_color_g, edge_color_b, edge_color_a);
+}
@@ -0,0 +1,46 @@
+struct Camera {
+    camera_matrix: mat4x4<f32>,
+    projection: mat4x4<f32>,
+}
+
+@group(0)
+@binding(0)
+var<uniform> camera: Camera;
+
+struct ModelMatrix {
+    model_matrix: mat4x4<f32>,
+}
+
+@group(1)
+@binding(0)
+var<uniform> model_matrix: ModelMatrix;
+
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) normal: vec3<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) gl_position: vec4<f32>,
+    @location(0) position: vec3<f32>,
+    @location(1) normal: vec3<f32>,
+}
+
+@vertex
+fn vs_main(in: VertexInput) -> VertexOutput {
+    let world_position = model_matrix.model_matrix * vec4<f32>(in.position, 1.0);
+    let world_normal = model_matrix.model_matrix * vec4<f32>(in.normal, 0.0);
+    return VertexOutput(
+        camera.projection * world_position,
+        world_position.xyz,
+        world_normal.xyz
+    );
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let camera_position = camera.camera_matrix[3].xyz;
+    let eye_direction = -camera.camera_matrix[2].xyz;
+    let depth = dot(in.position - camera_position, eye_direction);
+    return vec4<f32>(normalize(in.normal), depth);
+}
@@ -5,6 +5,7 @@ struct Light {
     position: vec4<f32>,
     color: vec4<f32>,
     light_type: vec4<u32>,
+    attenuation: vec4<f32>,
 }
 
 struct Material {
@@ -25,9 +26,20 @@ fn light_direction(light: Light, position: vec3<f32>) -> vec3<f32> {
     return res;
 }
 
+// The intensity of the light attenuated by the distance. Uniform lights are not attenuated.
+fn light_intensity(light: Light, position: vec3<f32>) -> f32 {
+    if (light.light_type[0] != 0u) {
+        return light.attenuation.w;
+    }
+    let dist = distance(light.position.xyz, position);
+    let coef = light.attenuation.xyz;
+    return light.attenuation.w / (coef.x + coef.y * dist + coef.z * dist * dist);
+}
+
 fn irradiance(light: Light, position: vec3<f32>, normal: vec3<f32>) -> vec3<f32> {
     let light_dir = light_direction(light, position);
-    return light.color.xyz * clamp(dot(light_dir, normal), 0.0, 1.0);
+    let intensity = light_intensity(light, position);
+    return light.color.xyz * intensity * clamp(dot(light_dir, normal), 0.0, 1.0);
 }
 
 fn diffuse_brdf(material: Material) -> vec3<f32> {
@@ -0,0 +1,30 @@
+struct Camera {
+    _matrix: mat4x4<f32>,
+    projection: mat4x4<f32>,
+}
+
+@group(0)
+@binding(0)
+var<uniform> camera: Camera;
+
+struct ModelMatrix {
+    model_matrix: mat4x4<f32>,
+}
+
+@group(1)
+@binding(0)
+var<uniform> model_matrix: ModelMatrix;
+
+@group(2)
+@binding(0)
+var<uniform> picking_index: u32;
+
+@vertex
+fn vs_main(@location(0) position: vec3<f32>) -> @builtin(position) vec4<f32> {
+    return camera.projection * model_matrix.model_matrix * vec4<f32>(position, 1.0);
+}
+
+@fragment
+fn fs_main() -> @location(0) u32 {
+    return picking_index;
+}
@@ -0,0 +1,69 @@
+struct VertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) corner: vec2<f32>,
+    @location(2) color: vec4<f32>,
+}
+
+struct Camera {
+    _matrix: mat4x4<f32>,
+    projection: mat4x4<f32>,
+}
+
+@group(0)
+@binding(0)
+var<uniform> camera: Camera;
+
+struct SceneInfo {
+    bk_color: vec4<f32>,
+    resolution: vec2<u32>,
+    time: f32,
+    nlights: u32,
+}
+
+@group(0)
+@binding(2)
+var<uniform> info: SceneInfo;
+
+struct ModelMatrix {
+    model_matrix: mat4x4<f32>,
+}
+
+@group(1)
+@binding(0)
+var<uniform> model_matrix: ModelMatrix;
+
+struct PointInfo {
+    color: vec4<f32>,
+    point_size: f32,
+}
+
+@group(1)
+@binding(1)
+var<uniform> point_info: PointInfo;
+
+struct VertexOutput {
+    @builtin(position) gl_position: vec4<f32>,
+    @location(0) corner: vec2<f32>,
+    @location(1) color: vec4<f32>,
+}
+
+@vertex
+fn vs_main(in: VertexInput) -> VertexOutput {
+    let position = camera.projection * model_matrix.model_matrix * vec4<f32>(in.position, 1.0);
+    // The sprite is expanded in the normalized device coordinate, and hence
+    // its size is constant in the screen space.
+    let offset = in.corner * point_info.point_size / vec2<f32>(info.resolution);
+    return VertexOutput(
+        position + vec4<f32>(offset * position.w, 0.0, 0.0),
+        in.corner,
+        in.color * point_info.color,
+    );
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    if dot(in.corner, in.corner) > 1.0 {
+        discard;
+    }
+    return in.color;
+}
@@ -0,0 +1,54 @@
+use crate::*;
+use truck_topology::{Edge, Wire};
+
+/// Samples the oriented curve of `edge` into a polyline whose ends are the points of the vertices.
+fn edge_polyline<C>(edge: &Edge<Point3, C>, tol: f64) -> PolylineCurve<Point3>
+where C: BoundedCurve + ParameterDivision1D<Point = Point3> + Clone {
+    let curve = edge.curve();
+    let (_, mut points) = curve.parameter_division(curve.range_tuple(), tol);
+    if !edge.orientation() {
+        points.reverse();
+    }
+    // The ends are just the vertices, so the strips of closed edges and closed wires are closed.
+    let last = points.len() - 1;
+    points[0] = edge.front().point();
+    points[last] = edge.back().point();
+    PolylineCurve(points)
+}
+
+impl<C> ToInstance<WireFrameInstance> for Edge<Point3, C>
+where C: BoundedCurve + ParameterDivision1D<Point = Point3> + Clone
+{
+    type State = ShapeWireFrameState;
+    #[inline(always)]
+    fn to_instance(
+        &self,
+        handler: &DeviceHandler,
+        shaders: &WireShaders,
+        state: &ShapeWireFrameState,
+    ) -> WireFrameInstance {
+        edge_polyline(self, state.polyline_precision).to_instance(
+            handler,
+            shaders,
+            &state.wireframe_state,
+        )
+    }
+}
+
+impl<C> ToInstance<WireFrameInstance> for Wire<Point3, C>
+where C: BoundedCurve + ParameterDivision1D<Point = Point3> + Clone
+{
+    type State = ShapeWireFrameState;
+    #[inline(always)]
+    fn to_instance(
+        &self,
+        handler: &DeviceHandler,
+        shaders: &WireShaders,
+        state: &ShapeWireFrameState,
+    ) -> WireFrameInstance {
+        self.edge_iter()
+            .map(|edge| edge_polyline(edge, state.polyline_precision))
+            .collect::<Vec<_>>()
+            .to_instance(handler, shaders, &state.wireframe_state)
+    }
+}
@@ -10,6 +10,16 @@ impl Default for WireFrameState {
     }
 }
 
+impl Default for ShapeWireFrameState {
+    #[inline(always)]
+    fn default() -> ShapeWireFrameState {
+        ShapeWireFrameState {
+            wireframe_state: Default::default(),
+            polyline_precision: 0.005,
+        }
+    }
+}
+
 impl WireFrameInstance {
     /// Clone the instance as another drawn element.
     #[inline(always)]
@@ -144,6 +154,24 @@ impl Rendered for WireFrameInstance {
         });
         Arc::new(pipeline)
     }
+    #[inline(always)]
+    fn picking_pipeline(
+        &self,
+        handler: &DeviceHandler,
+        layout: &PipelineLayout,
+        _: &SceneDescriptor,
+    ) -> Option<Arc<RenderPipeline>> {
+        let primitive = PrimitiveState {
+            topology: PrimitiveTopology::LineList,
+            ..Default::default()
+        };
+        Some(picking::create_picking_pipeline(
+            handler.device(),
+            layout,
+            size_of::<[f32; 3]>() as BufferAddress,
+            primitive,
+        ))
+    }
 }
 
 impl ToInstance<WireFrameInstance> for Vec<(Point3, Point3)> {
@@ -81,6 +81,7 @@ fn nontex_inst_state() -> PolygonState {
         },
         texture: None,
         backface_culling: true,
+        ..Default::default()
     }
 }
 
@@ -41,6 +41,7 @@ fn test_scene(backend: Backends) -> Scene {
                     position: Point3::new(-3.0, 4.0, -2.0),
                     color: Vector3::new(1.0, 1.0, 1.0),
                     light_type: LightType::Point,
+                    ..Default::default()
                 }],
                 ..Default::default()
             },
@@ -168,3 +169,829 @@ fn exec_tex_render_test(backend: Backends, out_dir: &str) {
 
 #[test]
 fn tex_render_test() { common::os_alt_exec_test(exec_tex_render_test) }
+
+fn exec_tex_from_bytes_test(backend: Backends, out_dir: &str) {
+    use image2texture::TextureConfig;
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    let creator = scene.instance_creator();
+    let image = Arc::new(generate_texture(&mut scene, out_dir.clone()));
+    let answer = tex_polygon(&mut scene, &creator, &image);
+    let mut png = Vec::new();
+    image
+        .write_to(&mut std::io::Cursor::new(&mut png), image::ImageFormat::Png)
+        .unwrap();
+
+    let mut render_bytes = |filename: &str, config: TextureConfig| {
+        let texture = image2texture::from_bytes(
+            scene.device_handler(),
+            &png,
+            Some(image::ImageFormat::Png),
+            config,
+        )
+        .unwrap();
+        let cube: PolygonInstance = creator.create_instance(
+            &obj::read(CUBE_OBJ).unwrap(),
+            &PolygonState {
+                texture: Some(Arc::new(texture)),
+                ..Default::default()
+            },
+        );
+        let buffer = common::render_one(&mut scene, &cube);
+        common::save_buffer(out_dir.clone() + filename, &buffer, PICTURE_SIZE);
+        buffer
+    };
+    // The decoded texture is the same as the original one.
+    let linear = render_bytes("tex-from-bytes.png", Default::default());
+    let diff = common::count_difference(&answer, &linear);
+    assert!(diff < 10, "{diff} pixel difference");
+    // The sRGB texels are darkened by the conversion into linear colors.
+    let srgb = TextureConfig {
+        srgb: true,
+        ..Default::default()
+    };
+    let srgb = render_bytes("tex-from-bytes-srgb.png", srgb);
+    let count = |pred: fn(&u8, &u8) -> bool| {
+        linear
+            .iter()
+            .zip(&srgb)
+            .filter(|(linear, srgb)| pred(linear, srgb))
+            .count()
+    };
+    let (darker, brighter) = (count(|l, s| l > s), count(|l, s| l < s));
+    assert!(darker > 1000 && brighter < 10, "{darker} {brighter}");
+    // The mipmaps of the non-power-of-two texture keep the smooth gradation.
+    let mipmaps = TextureConfig {
+        mipmaps: true,
+        ..Default::default()
+    };
+    let mipmapped = render_bytes("tex-from-bytes-mipmaps.png", mipmaps);
+    let diff = common::count_difference(&answer, &mipmapped);
+    assert!(diff < answer.len() / 100, "{diff} pixel difference");
+}
+
+#[test]
+fn tex_from_bytes_test() { common::os_alt_exec_test(exec_tex_from_bytes_test) }
+
+/// Returns the bounding box of the pixels which are not the background.
+fn foreground_range(buffer: &[u8], size: (u32, u32)) -> ([u32; 2], [u32; 2]) {
+    buffer
+        .chunks(4)
+        .enumerate()
+        .filter(|(_, pixel)| pixel[..3] != [0, 0, 0])
+        .fold(([u32::MAX; 2], [0; 2]), |(min, max), (i, _)| {
+            let (x, y) = (i as u32 % size.0, i as u32 / size.0);
+            (
+                [min[0].min(x), min[1].min(y)],
+                [max[0].max(x), max[1].max(y)],
+            )
+        })
+}
+
+fn exec_ortho_render_test(backend: Backends, out_dir: &str) {
+    use truck_meshalgo::prelude::*;
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    scene.studio_config_mut().camera = Camera::orthographic(
+        Point3::new(0.5, 0.5, 0.5),
+        Point3::new(0.5, 0.5, 3.0),
+        Vector3::unit_y(),
+        1.0,
+        0.1,
+        100.0,
+    );
+    scene.studio_config_mut().lights[0].position = Point3::new(0.5, 0.5, 3.0);
+    let creator = scene.instance_creator();
+
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let mesh = cube.triangulation(0.01).to_polygon();
+    let instance: PolygonInstance = creator.create_instance(&mesh, &Default::default());
+    let buffer = common::render_one(&mut scene, &instance);
+    common::save_buffer(out_dir + "ortho-cube.png", &buffer, PICTURE_SIZE);
+
+    // The front and back faces are projected onto the same square, whose side is
+    // the half of the screen height.
+    let (min, max) = foreground_range(&buffer, PICTURE_SIZE);
+    let side = PICTURE_SIZE.1 / 2;
+    assert!(
+        u32::abs_diff(max[0] - min[0] + 1, side) <= 2,
+        "{min:?} {max:?}"
+    );
+    assert!(
+        u32::abs_diff(max[1] - min[1] + 1, side) <= 2,
+        "{min:?} {max:?}"
+    );
+    let center = (PICTURE_SIZE.0 / 2, PICTURE_SIZE.1 / 2);
+    assert!(
+        u32::abs_diff(min[0] + side / 2, center.0) <= 2,
+        "{min:?} {max:?}"
+    );
+    assert!(
+        u32::abs_diff(min[1] + side / 2, center.1) <= 2,
+        "{min:?} {max:?}"
+    );
+}
+
+#[test]
+fn ortho_render_test() { common::os_alt_exec_test(exec_ortho_render_test) }
+
+fn exec_light_attenuation_test(backend: Backends, out_dir: &str) {
+    use truck_meshalgo::prelude::*;
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    scene.studio_config_mut().camera = Camera::orthographic(
+        Point3::origin(),
+        Point3::new(0.0, 0.0, 5.0),
+        Vector3::unit_y(),
+        1.0,
+        0.1,
+        100.0,
+    );
+    let creator = scene.instance_creator();
+
+    let v = builder::vertex(Point3::new(-1.0, -1.0, 0.0));
+    let e = builder::tsweep(&v, Vector3::unit_x() * 2.0);
+    let square: Face = builder::tsweep(&e, Vector3::unit_y() * 2.0);
+    let mesh = Shell::from(vec![square]).triangulation(0.01).to_polygon();
+    // Only the diffuse reflection, which is proportional to the irradiance.
+    let state = PolygonState {
+        material: Material {
+            reflectance: 0.0,
+            ambient_ratio: 0.0,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let instance: PolygonInstance = creator.create_instance(&mesh, &state);
+    let mut render = |intensity: f64, attenuation: [f64; 3], filename: &str| {
+        scene.studio_config_mut().lights = vec![Light {
+            position: Point3::new(-1.0, 0.0, 1.0),
+            intensity,
+            attenuation,
+            ..Default::default()
+        }];
+        let buffer = common::render_one(&mut scene, &instance);
+        common::save_buffer(out_dir.clone() + filename, &buffer, PICTURE_SIZE);
+        // the red components of the pixels on the plane at x = -0.75 and x = 0.75
+        let pixel = |x: f64| {
+            let px = (PICTURE_SIZE.0 as f64 / 2.0 + x * PICTURE_SIZE.1 as f64 / 2.0) as u32;
+            let py = PICTURE_SIZE.1 / 2;
+            buffer[4 * (py * PICTURE_SIZE.0 + px) as usize] as f64
+        };
+        (pixel(-0.75), pixel(0.75))
+    };
+    let (near0, far0) = render(1.0, [1.0, 0.0, 0.0], "default-light.png");
+    let (near1, far1) = render(1.0, [1.0, 0.0, 1.0], "attenuated-light.png");
+    let (near2, far2) = render(0.5, [1.0, 0.0, 0.0], "half-intensity-light.png");
+
+    // The attenuated light falls off with distance.
+    assert!(near1 < near0 && far1 < far0);
+    assert!(far1 / near1 < far0 / near0);
+    // The output is gamma corrected by `pow(x, 0.4545)`.
+    let ratio = f64::powf(0.5, 0.4545);
+    assert!(f64::abs(near2 - near0 * ratio) < 3.0, "{near2} {near0}");
+    assert!(f64::abs(far2 - far0 * ratio) < 3.0, "{far2} {far0}");
+}
+
+#[test]
+fn light_attenuation_test() { common::os_alt_exec_test(exec_light_attenuation_test) }
+
+fn exec_pick_test(backend: Backends, _: &str) {
+    use truck_meshalgo::prelude::*;
+    let mut scene = test_scene(backend);
+    scene.studio_config_mut().camera = Camera::orthographic(
+        Point3::origin(),
+        Point3::new(0.0, 0.0, 5.0),
+        Vector3::unit_y(),
+        2.0,
+        0.1,
+        100.0,
+    );
+    let creator = scene.instance_creator();
+
+    let v = builder::vertex(Point3::new(-0.5, -0.5, -0.5));
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let mesh = cube.triangulation(0.01).to_polygon();
+    let state = |x: f64| PolygonState {
+        matrix: Matrix4::from_translation(Vector3::new(x, 0.0, 0.0)),
+        ..Default::default()
+    };
+    let left: PolygonInstance = creator.create_instance(&mesh, &state(-1.0));
+    let right: PolygonInstance = creator.create_instance(&mesh, &state(1.0));
+    scene.add_object(&left);
+    scene.add_object(&right);
+
+    // The centers of the cubes are projected onto `(±1.0 / 2.0 / aspect, 0.0)`.
+    let aspect = PICTURE_SIZE.0 as f32 / PICTURE_SIZE.1 as f32;
+    let x = 0.5 / aspect;
+    let pick = |ndc| pollster::block_on(scene.pick(ndc));
+    assert_eq!(pick((-x, 0.0)), Some(left.render_id()));
+    assert_eq!(pick((x, 0.0)), Some(right.render_id()));
+    assert_eq!(pick((0.0, 0.0)), None);
+    assert_eq!(pick((x, 0.5)), None);
+
+    // invisible objects cannot be picked.
+    scene.set_visibility(&right, false);
+    assert_eq!(pollster::block_on(scene.pick((x, 0.0))), None);
+}
+
+#[test]
+fn pick_test() { common::os_alt_exec_test(exec_pick_test) }
+
+fn exec_transparency_test(backend: Backends, out_dir: &str) {
+    use truck_meshalgo::prelude::*;
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    scene.studio_config_mut().camera = Camera::orthographic(
+        Point3::origin(),
+        Point3::new(0.0, 0.0, 5.0),
+        Vector3::unit_y(),
+        2.0,
+        0.1,
+        100.0,
+    );
+    scene.studio_config_mut().lights[0].position = Point3::new(0.0, 0.0, 5.0);
+    let creator = scene.instance_creator();
+
+    let v = builder::vertex(Point3::new(-1.0, -1.0, 0.0));
+    let e = builder::tsweep(&v, Vector3::unit_x() * 1.5);
+    let face = builder::tsweep(&e, Vector3::unit_y() * 1.5);
+    let mesh = Shell::from(vec![face]).triangulation(0.01).to_polygon();
+    let state = |translation: Vector3, albedo: Vector4| PolygonState {
+        matrix: Matrix4::from_translation(translation),
+        material: Material {
+            albedo,
+            alpha_blend: true,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    // red in front, blue behind
+    let front: PolygonInstance = creator.create_instance(
+        &mesh,
+        &state(
+            Vector3::new(0.5, 0.5, 0.5),
+            Vector4::new(1.0, 0.0, 0.0, 0.5),
+        ),
+    );
+    let back: PolygonInstance = creator.create_instance(
+        &mesh,
+        &state(Vector3::zero(), Vector4::new(0.0, 0.0, 1.0, 0.5)),
+    );
+
+    scene.add_object(&front);
+    scene.add_object(&back);
+    let buffer0 = pollster::block_on(scene.render_to_buffer());
+    common::save_buffer(
+        out_dir.clone() + "transparency0.png",
+        &buffer0,
+        PICTURE_SIZE,
+    );
+    scene.clear_objects();
+    scene.add_object(&back);
+    scene.add_object(&front);
+    let buffer1 = pollster::block_on(scene.render_to_buffer());
+    common::save_buffer(out_dir + "transparency1.png", &buffer1, PICTURE_SIZE);
+    assert_eq!(common::count_difference(&buffer0, &buffer1), 0);
+
+    // The red quad is composited over the blue one at the center of the canvas.
+    let (width, height) = PICTURE_SIZE;
+    let idx = ((height / 2 * width + width / 2) * 4) as usize;
+    let pixel = &buffer0[idx..idx + 4];
+    assert!(pixel[0] > pixel[2], "{pixel:?}");
+    assert!(pixel[2] > 0, "{pixel:?}");
+}
+
+#[test]
+fn transparency_test() { common::os_alt_exec_test(exec_transparency_test) }
+
+fn exec_show_edges_test(backend: Backends, out_dir: &str) {
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    scene.studio_config_mut().camera = Camera::orthographic(
+        Point3::origin(),
+        Point3::new(0.0, 0.0, 5.0),
+        Vector3::unit_y(),
+        1.0,
+        0.1,
+        100.0,
+    );
+    scene.studio_config_mut().lights[0].position = Point3::new(0.0, 0.0, 5.0);
+    scene.studio_config_mut().background = Background::Solid(Color::WHITE);
+    let creator = scene.instance_creator();
+
+    // a quadrangle whose side is the half of the screen height
+    let mesh = PolygonMesh::new(
+        StandardAttributes {
+            positions: vec![
+                Point3::new(-0.5, -0.5, 0.0),
+                Point3::new(0.5, -0.5, 0.0),
+                Point3::new(0.5, 0.5, 0.0),
+                Point3::new(-0.5, 0.5, 0.0),
+            ],
+            normals: vec![Vector3::unit_z()],
+            ..Default::default()
+        },
+        Faces::from_iter([[
+            (0, None, Some(0)),
+            (1, None, Some(0)),
+            (2, None, Some(0)),
+            (3, None, Some(0)),
+        ]]),
+    );
+    let mut instance: PolygonInstance = creator.create_instance(&mesh, &Default::default());
+    let (width, height) = PICTURE_SIZE;
+    let pixel = |buffer: &[u8], x: u32| {
+        let idx = ((height / 2 * width + x) * 4) as usize;
+        [buffer[idx], buffer[idx + 1], buffer[idx + 2]]
+    };
+    let is_dark = |[r, g, b]: [u8; 3]| r < 50 && g < 50 && b < 50;
+    let edge_range = width / 2 - height / 4 - 2..=width / 2 - height / 4 + 2;
+
+    let buffer = common::render_one(&mut scene, &instance);
+    common::save_buffer(out_dir.clone() + "without-edges.png", &buffer, PICTURE_SIZE);
+    assert!(edge_range.clone().all(|x| !is_dark(pixel(&buffer, x))));
+
+    instance.instance_state_mut().show_edges = true;
+    let buffer = common::render_one(&mut scene, &instance);
+    common::save_buffer(out_dir + "with-edges.png", &buffer, PICTURE_SIZE);
+    assert!(edge_range.clone().any(|x| is_dark(pixel(&buffer, x))));
+    // The interior of the face is not covered by the edges.
+    assert!(!is_dark(pixel(&buffer, width / 2)));
+
+    // The edges are switched on the object in the scene by updating its pipeline.
+    instance.instance_state_mut().show_edges = false;
+    scene.add_object(&instance);
+    let buffer = pollster::block_on(scene.render_to_buffer());
+    assert!(edge_range.clone().all(|x| !is_dark(pixel(&buffer, x))));
+    instance.instance_state_mut().show_edges = true;
+    scene.update_pipeline(&instance);
+    let buffer = pollster::block_on(scene.render_to_buffer());
+    assert!(edge_range.clone().any(|x| is_dark(pixel(&buffer, x))));
+}
+
+#[test]
+fn show_edges_test() { common::os_alt_exec_test(exec_show_edges_test) }
+
+fn exec_point_cloud_test(backend: Backends, out_dir: &str) {
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    let creator = scene.instance_creator();
+    let count_foreground = |buffer: &[u8]| buffer.chunks(4).filter(|p| p[0] > 128).count();
+    let camera = |distance: f64| {
+        Camera::perspective_camera(
+            Matrix4::look_at_rh(
+                Point3::new(0.0, 0.0, distance),
+                Point3::origin(),
+                Vector3::unit_y(),
+            )
+            .invert()
+            .unwrap(),
+            Rad(std::f64::consts::PI / 4.0),
+            0.1,
+            100.0,
+        )
+    };
+
+    // The size of a sprite does not depend on the distance from the camera.
+    let state = PointCloudState {
+        point_size: 16.0,
+        ..Default::default()
+    };
+    let point: PointCloudInstance = creator.create_instance(&vec![Point3::origin()], &state);
+    scene.studio_config_mut().camera = camera(2.0);
+    let near = common::render_one(&mut scene, &point);
+    scene.studio_config_mut().camera = camera(8.0);
+    let far = common::render_one(&mut scene, &point);
+    let (near, far) = (count_foreground(&near), count_foreground(&far));
+    // the area of the circle of diameter 16
+    assert!(f64::abs(near as f64 - 201.0) < 30.0, "{near}");
+    assert!(usize::abs_diff(near, far) < 10, "{near} {far}");
+
+    // 100k points, drawn as discrete dots.
+    let points = (0..400)
+        .flat_map(|i| (0..250).map(move |j| (i, j)))
+        .map(|(i, j)| {
+            let point = Point3::new(i as f64 / 100.0 - 2.0, j as f64 / 100.0 - 1.25, 0.0);
+            (
+                point,
+                Vector4::new(1.0, i as f64 / 400.0, j as f64 / 250.0, 1.0),
+            )
+        })
+        .collect::<Vec<_>>();
+    let state = PointCloudState {
+        point_size: 2.0,
+        ..Default::default()
+    };
+    let cloud: PointCloudInstance = creator.create_instance(&points, &state);
+    scene.studio_config_mut().camera = camera(2.5);
+    let buffer = common::render_one(&mut scene, &cloud);
+    common::save_buffer(out_dir + "point-cloud.png", &buffer, PICTURE_SIZE);
+    let foreground = count_foreground(&buffer);
+    assert!(foreground > 0);
+    assert!(foreground < (PICTURE_SIZE.0 * PICTURE_SIZE.1) as usize / 2);
+}
+
+#[test]
+fn point_cloud_test() { common::os_alt_exec_test(exec_point_cloud_test) }
+
+fn exec_camera_fit_test(backend: Backends, out_dir: &str) {
+    use truck_meshalgo::prelude::*;
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    let creator = scene.instance_creator();
+
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let mesh = cube.triangulation(0.01).to_polygon();
+    let instance: PolygonInstance = creator.create_instance(&mesh, &Default::default());
+
+    let aspect = PICTURE_SIZE.0 as f64 / PICTURE_SIZE.1 as f64;
+    let matrix = Matrix4::look_at_rh(
+        Point3::new(10.0, 6.0, 8.0),
+        Point3::new(-3.0, 0.0, 1.0),
+        Vector3::unit_y(),
+    )
+    .invert()
+    .unwrap();
+    let cameras = [
+        (
+            "fit-perspective.png",
+            Camera::perspective_camera(matrix, Rad(std::f64::consts::PI / 4.0), 0.1, 1.0),
+        ),
+        (
+            "fit-parallel.png",
+            Camera::parallel_camera(matrix, 20.0, 0.1, 1.0),
+        ),
+    ];
+    for (filename, mut camera) in cameras {
+        camera.fit(&mesh.bounding_box(), aspect);
+        scene.studio_config_mut().camera = camera;
+        scene.studio_config_mut().lights[0].position = camera.position();
+        let buffer = common::render_one(&mut scene, &instance);
+        common::save_buffer(out_dir.clone() + filename, &buffer, PICTURE_SIZE);
+
+        // The cube is fully in the frame, fills it, and is roughly centered.
+        let (min, max) = foreground_range(&buffer, PICTURE_SIZE);
+        assert!(min[0] > 0 && min[1] > 0, "{filename}: {min:?} {max:?}");
+        assert!(
+            max[0] < PICTURE_SIZE.0 - 1 && max[1] < PICTURE_SIZE.1 - 1,
+            "{filename}: {min:?} {max:?}"
+        );
+        let (width, height) = (max[0] - min[0] + 1, max[1] - min[1] + 1);
+        assert!(
+            width > PICTURE_SIZE.0 / 2 || height > PICTURE_SIZE.1 / 2,
+            "{filename}: {min:?} {max:?}"
+        );
+        let center = ((min[0] + max[0]) / 2, (min[1] + max[1]) / 2);
+        assert!(
+            u32::abs_diff(center.0, PICTURE_SIZE.0 / 2) < PICTURE_SIZE.1 / 8,
+            "{filename}: {min:?} {max:?}"
+        );
+        assert!(
+            u32::abs_diff(center.1, PICTURE_SIZE.1 / 2) < PICTURE_SIZE.1 / 8,
+            "{filename}: {min:?} {max:?}"
+        );
+    }
+}
+
+#[test]
+fn camera_fit_test() { common::os_alt_exec_test(exec_camera_fit_test) }
+
+fn exec_outline_test(backend: Backends, out_dir: &str) {
+    use truck_meshalgo::prelude::*;
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    let center = Point3::new(0.5, 0.5, 0.5);
+    let eye = center + Vector3::new(3.0, 3.0, 3.0);
+    let camera = Camera::orthographic(center, eye, Vector3::unit_y(), 1.0, 0.1, 100.0);
+    scene.studio_config_mut().camera = camera;
+    scene.studio_config_mut().lights[0].position = eye;
+    scene.studio_config_mut().background = Background::Solid(Color::WHITE);
+    let creator = scene.instance_creator();
+
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let mesh = cube.triangulation(0.01).to_polygon();
+    let instance: PolygonInstance = creator.create_instance(&mesh, &Default::default());
+    scene.add_object(&instance);
+
+    let plain = pollster::block_on(scene.render_to_buffer());
+    common::save_buffer(out_dir.clone() + "outline-none.png", &plain, PICTURE_SIZE);
+    scene.descriptor_mut().backend_buffer.outline = Some(OutlineConfig {
+        width: 0.01,
+        ..Default::default()
+    });
+    let outlined = pollster::block_on(scene.render_to_buffer());
+    common::save_buffer(out_dir.clone() + "outline.png", &outlined, PICTURE_SIZE);
+
+    let aspect = PICTURE_SIZE.0 as f64 / PICTURE_SIZE.1 as f64;
+    let is_dark = |buffer: &[u8], point: Point3| {
+        let ndc = camera.projection(aspect).transform_point(point);
+        let x = ((ndc.x + 1.0) / 2.0 * PICTURE_SIZE.0 as f64) as usize;
+        let y = ((1.0 - ndc.y) / 2.0 * PICTURE_SIZE.1 as f64) as usize;
+        let idx = (y * PICTURE_SIZE.0 as usize + x) * 4;
+        buffer[idx..idx + 3].iter().all(|c| *c < 64)
+    };
+    // The creases between the three visible faces, and the silhouette of the hexagon.
+    let edges = [
+        Point3::new(1.0, 1.0, 0.5),
+        Point3::new(1.0, 0.5, 1.0),
+        Point3::new(0.5, 1.0, 1.0),
+        Point3::new(1.0, 0.5, 0.0),
+        Point3::new(0.0, 0.5, 1.0),
+        Point3::new(0.5, 0.0, 1.0),
+    ];
+    for point in edges {
+        assert!(!is_dark(&plain, point), "{point:?}");
+        assert!(is_dark(&outlined, point), "{point:?}");
+    }
+    let faces = [
+        Point3::new(1.0, 0.5, 0.5),
+        Point3::new(0.5, 1.0, 0.5),
+        Point3::new(0.5, 0.5, 1.0),
+    ];
+    for point in faces {
+        assert!(!is_dark(&plain, point), "{point:?}");
+        assert!(!is_dark(&outlined, point), "{point:?}");
+    }
+
+    // The width of the outlines is in proportion to the resolution.
+    let count_dark = |image: image::RgbaImage| {
+        image
+            .pixels()
+            .filter(|pixel| pixel.0[..3].iter().all(|c| *c < 64))
+            .count()
+    };
+    let (width, height) = PICTURE_SIZE;
+    let small = count_dark(pollster::block_on(
+        scene.render_to_image(width / 2, height / 2),
+    ));
+    let large = count_dark(pollster::block_on(scene.render_to_image(width, height)));
+    let ratio = large as f64 / small as f64;
+    assert!(f64::abs(ratio - 4.0) < 0.8, "{small} {large}");
+
+    // The outlines are toggled by the descriptor.
+    scene.descriptor_mut().backend_buffer.outline = None;
+    let buffer = pollster::block_on(scene.render_to_buffer());
+    assert!(common::same_buffer(&buffer, &plain));
+}
+
+#[test]
+fn outline_test() { common::os_alt_exec_test(exec_outline_test) }
+
+fn exec_clip_range_test(backend: Backends, out_dir: &str) {
+    use truck_meshalgo::prelude::*;
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    let fov = Rad(std::f64::consts::PI / 4.0);
+    let camera = Camera::perspective_camera(Matrix4::identity(), fov, 0.1, 100.0);
+    scene.studio_config_mut().camera = camera;
+    scene.studio_config_mut().lights[0].position = Point3::origin();
+    let creator = scene.instance_creator();
+
+    // the squares facing the camera at the origin
+    let square = |origin: Point3, size: f64, albedo: Vector4| {
+        let v = builder::vertex(origin);
+        let e = builder::tsweep(&v, Vector3::unit_x() * size);
+        let face = builder::tsweep(&e, Vector3::unit_y() * size);
+        let mesh = Shell::from(vec![face])
+            .triangulation(size / 100.0)
+            .to_polygon();
+        let state = PolygonState {
+            material: Material {
+                albedo,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        creator.create_instance(&mesh, &state)
+    };
+    // The scene spans from 5.0e-2 to 1.0e3.
+    let red = Vector4::new(1.0, 0.0, 0.0, 1.0);
+    let green = Vector4::new(0.0, 1.0, 0.0, 1.0);
+    let blue = Vector4::new(0.0, 0.0, 1.0, 1.0);
+    let back: PolygonInstance = square(Point3::new(-500.0, -500.0, -1000.0), 1000.0, red);
+    let front: PolygonInstance = square(Point3::new(-500.0, -500.0, -900.0), 1000.0, green);
+    let near: PolygonInstance = square(Point3::new(5.0e-3, -5.0e-3, -5.0e-2), 1.0e-2, blue);
+    scene.add_object(&back);
+    scene.add_object(&front);
+    scene.add_object(&near);
+
+    let aspect = PICTURE_SIZE.0 as f64 / PICTURE_SIZE.1 as f64;
+    let pixel = |buffer: &[u8], camera: &Camera, point: Point3| {
+        let ndc = camera.projection(aspect).transform_point(point);
+        let x = ((ndc.x + 1.0) / 2.0 * PICTURE_SIZE.0 as f64) as usize;
+        let y = ((1.0 - ndc.y) / 2.0 * PICTURE_SIZE.1 as f64) as usize;
+        let idx = (y * PICTURE_SIZE.0 as usize + x) * 4;
+        [buffer[idx], buffer[idx + 1], buffer[idx + 2]]
+    };
+    let (far_point, near_point) = (Point3::new(0.0, 0.0, -900.0), Point3::new(0.01, 0.0, -0.05));
+
+    // The default clip planes cut off both of the far squares and the near square.
+    let buffer = pollster::block_on(scene.render_to_buffer());
+    common::save_buffer(out_dir.clone() + "clip-default.png", &buffer, PICTURE_SIZE);
+    assert_eq!(pixel(&buffer, &camera, far_point), [0, 0, 0]);
+    assert_eq!(pixel(&buffer, &camera, near_point), [0, 0, 0]);
+
+    // The clip planes covering the scene show all squares in the right order.
+    let mut camera = camera;
+    camera.set_perspective(fov, 1.0e-2, 1.0e4);
+    scene.studio_config_mut().camera = camera;
+    let buffer = pollster::block_on(scene.render_to_buffer());
+    common::save_buffer(out_dir + "clip-wide.png", &buffer, PICTURE_SIZE);
+    let [r, g, b] = pixel(&buffer, &camera, far_point);
+    assert!(g > 128 && r < 32 && b < 32, "{:?}", [r, g, b]);
+    let [r, g, b] = pixel(&buffer, &camera, near_point);
+    assert!(b > 128 && r < 32 && g < 32, "{:?}", [r, g, b]);
+    // the front square hides the back one everywhere.
+    let reddish = buffer.chunks(4).filter(|pixel| pixel[0] > 32).count();
+    assert_eq!(reddish, 0);
+}
+
+#[test]
+fn clip_range_test() { common::os_alt_exec_test(exec_clip_range_test) }
+
+fn exec_viewport_test(backend: Backends, out_dir: &str) {
+    use truck_meshalgo::prelude::*;
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    let creator = scene.instance_creator();
+
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let mesh = cube.triangulation(0.01).to_polygon();
+    let instance: PolygonInstance = creator.create_instance(&mesh, &Default::default());
+    scene.add_object(&instance);
+
+    // the front, top, side and perspective views of the quad view
+    let (width, height) = (PICTURE_SIZE.0 / 2, PICTURE_SIZE.1 / 2);
+    let aspect = width as f64 / height as f64;
+    let center = Point3::new(0.5, 0.5, 0.5);
+    let views = [
+        (Vector3::unit_z(), Vector3::unit_y(), false),
+        (Vector3::unit_y(), -Vector3::unit_z(), false),
+        (Vector3::unit_x(), Vector3::unit_y(), false),
+        (Vector3::new(1.0, 1.5, 2.0), Vector3::unit_y(), true),
+    ];
+    let cameras = views.map(|(dir, up, perspective)| {
+        let eye = center + dir * 5.0;
+        let matrix = Matrix4::look_at_rh(eye, center, up).invert().unwrap();
+        let mut camera = match perspective {
+            true => Camera::perspective_camera(matrix, Rad(std::f64::consts::PI / 4.0), 0.1, 1.0),
+            false => Camera::parallel_camera(matrix, 1.0, 0.1, 1.0),
+        };
+        camera.fit(&mesh.bounding_box(), aspect);
+        camera
+    });
+    scene.studio_config_mut().lights = cameras
+        .iter()
+        .map(|camera| Light {
+            position: camera.position(),
+            color: Vector3::new(1.0, 1.0, 1.0),
+            light_type: LightType::Point,
+            ..Default::default()
+        })
+        .collect();
+    let viewports = [(0, 0), (width, 0), (0, height), (width, height)].map(|origin| Viewport {
+        origin,
+        size: (width, height),
+    });
+
+    let texture = scene.compatible_texture();
+    let view = texture.create_view(&Default::default());
+    cameras
+        .iter()
+        .zip(viewports)
+        .for_each(|(camera, viewport)| scene.render_viewport(&view, camera, viewport));
+    let buffer = pollster::block_on(scene.read_texture(&texture));
+    common::save_buffer(out_dir.clone() + "viewport.png", &buffer, PICTURE_SIZE);
+
+    // Each quadrant has its own cube in its center.
+    for (i, Viewport { origin, size }) in viewports.into_iter().enumerate() {
+        let quadrant = buffer
+            .chunks(PICTURE_SIZE.0 as usize * 4)
+            .skip(origin.1 as usize)
+            .take(size.1 as usize)
+            .flat_map(|row| {
+                let start = origin.0 as usize * 4;
+                &row[start..start + size.0 as usize * 4]
+            })
+            .copied()
+            .collect::<Vec<_>>();
+        let (min, max) = foreground_range(&quadrant, size);
+        assert!(min[0] > 0 && min[1] > 0, "{i}: {min:?} {max:?}");
+        assert!(
+            max[0] < size.0 - 1 && max[1] < size.1 - 1,
+            "{i}: {min:?} {max:?}"
+        );
+        let center = ((min[0] + max[0]) / 2, (min[1] + max[1]) / 2);
+        assert!(
+            u32::abs_diff(center.0, size.0 / 2) < size.1 / 8,
+            "{i}: {min:?} {max:?}"
+        );
+        assert!(
+            u32::abs_diff(center.1, size.1 / 2) < size.1 / 8,
+            "{i}: {min:?} {max:?}"
+        );
+    }
+}
+
+#[test]
+fn viewport_test() { common::os_alt_exec_test(exec_viewport_test) }
+
+fn exec_shape_wireframe_test(backend: Backends, out_dir: &str) {
+    use truck_meshalgo::prelude::*;
+    let out_dir = out_dir.to_string();
+    std::fs::create_dir_all(&out_dir).unwrap();
+    let mut scene = test_scene(backend);
+    let center = Point3::new(0.5, 0.5, 0.5);
+    let eye = center + Vector3::new(3.0, 2.0, 4.0);
+    let camera = Camera::orthographic(center, eye, Vector3::unit_y(), 2.0, 0.1, 100.0);
+    scene.studio_config_mut().camera = camera;
+    scene.studio_config_mut().lights[0].position = eye;
+    let creator = scene.instance_creator();
+
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let mesh = cube.triangulation(0.01).to_polygon();
+    let shaded: PolygonInstance = creator.create_instance(&mesh, &Default::default());
+    let edges: Wire = cube.edge_iter().collect();
+    let wireframe: WireFrameInstance = creator.create_instance(&edges, &Default::default());
+
+    let shaded_buffer = common::render_one(&mut scene, &shaded);
+    let wire_buffer = common::render_one(&mut scene, &wireframe);
+    common::save_buffer(
+        out_dir.clone() + "shape-wireframe.png",
+        &wire_buffer,
+        PICTURE_SIZE,
+    );
+
+    // The silhouette of the wireframe is the one of the shaded cube.
+    let (shaded_min, shaded_max) = foreground_range(&shaded_buffer, PICTURE_SIZE);
+    let (wire_min, wire_max) = foreground_range(&wire_buffer, PICTURE_SIZE);
+    for i in 0..2 {
+        assert!(
+            u32::abs_diff(shaded_min[i], wire_min[i]) <= 2
+                && u32::abs_diff(shaded_max[i], wire_max[i]) <= 2,
+            "{shaded_min:?} {shaded_max:?} {wire_min:?} {wire_max:?}"
+        );
+    }
+    // The lines are drawn on the edges, and not on the faces.
+    let aspect = PICTURE_SIZE.0 as f64 / PICTURE_SIZE.1 as f64;
+    let is_drawn = |point: Point3| {
+        let ndc = camera.projection(aspect).transform_point(point);
+        let x = ((ndc.x + 1.0) / 2.0 * PICTURE_SIZE.0 as f64) as usize;
+        let y = ((1.0 - ndc.y) / 2.0 * PICTURE_SIZE.1 as f64) as usize;
+        let range = |c: usize| c.saturating_sub(1)..=c + 1;
+        range(y).any(|y| {
+            range(x).any(|x| {
+                let idx = (y * PICTURE_SIZE.0 as usize + x) * 4;
+                wire_buffer[idx..idx + 3] != [0, 0, 0]
+            })
+        })
+    };
+    let edges = [
+        Point3::new(1.0, 1.0, 0.5),
+        Point3::new(1.0, 0.5, 1.0),
+        Point3::new(0.5, 1.0, 1.0),
+        Point3::new(0.5, 0.0, 1.0),
+    ];
+    edges
+        .into_iter()
+        .for_each(|point| assert!(is_drawn(point), "{point:?}"));
+    let faces = [
+        Point3::new(1.0, 0.5, 0.5),
+        Point3::new(0.5, 1.0, 0.5),
+        Point3::new(0.5, 0.5, 1.0),
+    ];
+    faces
+        .into_iter()
+        .for_each(|point| assert!(!is_drawn(point), "{point:?}"));
+}
+
+#[test]
+fn shape_wireframe_test() { common::os_alt_exec_test(exec_shape_wireframe_test) }
@@ -36,15 +36,25 @@ fn irradiance_test() -> bool {
     light.position = vec4<f32>(-1.0, 0.0, 1.0, 1.0);
     light.color = vec4<f32>(0.01, 0.1, 1.0, 1.0);
     light.light_type = vec4<u32>(0u);
+    light.attenuation = vec4<f32>(1.0, 0.0, 0.0, 1.0);
     result = irradiance(light, position, normal);
     answer = vec3<f32>(0.01, 0.1, 1.0) / sqrt(5.0);
     if (distance(result, answer) > EPS) {
         return false;
     }
 
+    // attenuated point light
+    light.attenuation = vec4<f32>(1.0, 0.0, 1.0, 2.0);
+    result = irradiance(light, position, normal);
+    answer = vec3<f32>(0.01, 0.1, 1.0) / sqrt(5.0) / 3.0;
+    if (distance(result, answer) > EPS) {
+        return false;
+    }
+
     // uniform light
     light.position = light.position / sqrt(2.0);
     light.light_type[0] = 1u;
+    light.attenuation = vec4<f32>(1.0, 0.0, 0.0, 1.0);
     result = irradiance(light, position, normal);
     answer = vec3<f32>(0.01, 0.1, 1.0) / sqrt(2.0);
     if (distance(result, answer) > EPS) {
@@ -132,6 +142,7 @@ fn microfacet_color_test() -> bool {
     light.position = vec4<f32>(-1.0, 0.0, 1.0, 1.0);
     light.color = vec4<f32>(0.1, 0.2, 0.3, 1.0);
     light.light_type[0] = 0u;
+    light.attenuation = vec4<f32>(1.0, 0.0, 0.0, 1.0);
     let camera_dir = vec3<f32>(1.0, 0.0, 1.0) / sqrt(2.0);
     var material: Material;
     material.albedo = vec4<f32>(0.01, 0.1, 1.0, 1.0);
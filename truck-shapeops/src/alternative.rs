@@ -88,6 +88,8 @@ where
     S1: ParametricSurface3D,
 {
     derive_method!(normal, Vector3, u: f64, v: f64);
+    derive_method!(gaussian_curvature, f64, u: f64, v: f64);
+    derive_method!(mean_curvature, f64, u: f64, v: f64);
 }
 
 impl<C0, C1> Cut for Alternative<C0, C1>
@@ -0,0 +1,213 @@
+use crate::*;
+use truck_meshalgo::prelude::*;
+use truck_topology::*;
+
+/// The clearance between two solids, returned by [`min_distance`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Clearance {
+    /// The minimum distance between the solids, which is zero if they touch or interfere.
+    pub distance: f64,
+    /// The witness points on the boundaries of the first and the second solids, respectively.
+    /// If the solids interfere, both points are the same point in the common part.
+    pub points: (Point3, Point3),
+    /// Whether the interiors of the solids overlap.
+    pub interfering: bool,
+}
+
+/// Returns the minimum distance between two solids with the witness points.
+///
+/// # Details
+/// The distance is measured between the tessellations with tolerance `tol`, hence the error is
+/// bounded by `tol`. The pairs of faces and then of triangles are culled by their bounding boxes,
+/// and the remaining pairs of triangles are compared exactly.
+///
+/// The solids interfere if their boundaries cross each other transversally or if one solid is
+/// contained in the other. In that case, the distance is zero and `interfering` is `true`. The
+/// solids which only touch each other, e.g. two boxes sharing a part of their faces, are not
+/// interfering.
+///
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+/// let moved = builder::translated(&cube, Vector3::new(3.0, 0.0, 0.0));
+///
+/// let clearance = truck_shapeops::min_distance(&cube, &moved, 0.01);
+/// assert_near!(clearance.distance, 2.0);
+/// assert_near!(clearance.points.0.x, 1.0);
+/// assert_near!(clearance.points.1.x, 3.0);
+/// assert!(!clearance.interfering);
+///
+/// let moved = builder::translated(&cube, Vector3::new(0.5, 0.5, 0.5));
+/// let clearance = truck_shapeops::min_distance(&cube, &moved, 0.01);
+/// assert_eq!(clearance.distance, 0.0);
+/// assert!(clearance.interfering);
+/// ```
+pub fn min_distance<C, S>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Clearance
+where
+    C: ShapeOpsCurve<S>,
+    S: ShapeOpsSurface,
+{
+    let (meshed0, meshed1) = (solid0.triangulation(tol), solid1.triangulation(tol));
+    let (poly0, poly1) = (meshed0.to_polygon(), meshed1.to_polygon());
+    let interfering = |point: Point3| Clearance {
+        distance: 0.0,
+        points: (point, point),
+        interfering: true,
+    };
+
+    let face_meshes = |meshed: &Solid<_, _, Option<PolygonMesh>>| {
+        meshed
+            .face_iter()
+            .filter_map(|face| face.surface())
+            .map(|mesh| (mesh.bounding_box(), triangles(&mesh)))
+            .collect::<Vec<_>>()
+    };
+    let (meshes0, meshes1) = (face_meshes(&meshed0), face_meshes(&meshed1));
+    let mut pairs = meshes0
+        .iter()
+        .flat_map(|(bdb0, tris0)| {
+            meshes1
+                .iter()
+                .map(move |(bdb1, tris1)| (box_distance(*bdb0, *bdb1), bdb1, tris0, tris1))
+        })
+        .collect::<Vec<_>>();
+    pairs.sort_by(|(d0, ..), (d1, ..)| d0.total_cmp(d1));
+    let mut nearest = (f64::INFINITY, Point3::origin(), Point3::origin());
+    // The pairs whose bounding boxes are not nearer than the current nearest are skipped, except
+    // for the pairs touching each other, which may cross each other.
+    let skipped = |lower_bound: f64, nearest: f64| lower_bound > 0.0 && lower_bound >= nearest;
+    for (lower_bound, bdb1, tris0, tris1) in pairs {
+        if skipped(lower_bound, nearest.0) {
+            break;
+        }
+        for (tbdb0, tri0) in tris0 {
+            if skipped(box_distance(*tbdb0, *bdb1), nearest.0) {
+                continue;
+            }
+            for (tbdb1, tri1) in tris1 {
+                let lower_bound = box_distance(*tbdb0, *tbdb1);
+                if skipped(lower_bound, nearest.0) {
+                    continue;
+                }
+                if lower_bound == 0.0 && straddles(*tri0, *tri1) && straddles(*tri1, *tri0) {
+                    if let Some((point, _)) = collide_triangles(*tri0, *tri1) {
+                        return interfering(point);
+                    }
+                }
+                let candidate = triangle_distance(*tri0, *tri1);
+                if candidate.0 < nearest.0 {
+                    nearest = candidate;
+                }
+            }
+        }
+    }
+    let (distance, p, q) = nearest;
+
+    // The vertex farthest from the witness point is away from the contact, so it decides the
+    // containment even if the solids touch each other.
+    let contained = |outer: &PolygonMesh, inner: &PolygonMesh, witness: Point3| {
+        let compare =
+            |a: &&Point3, b: &&Point3| a.distance2(witness).total_cmp(&b.distance2(witness));
+        let farthest = inner.positions().iter().max_by(compare)?;
+        Some(*farthest).filter(|point| outer.inside(*point))
+    };
+    match contained(&poly0, &poly1, q).or_else(|| contained(&poly1, &poly0, p)) {
+        Some(point) => interfering(point),
+        None => Clearance {
+            distance,
+            points: (p, q),
+            interfering: false,
+        },
+    }
+}
+
+/// Returns the distance between two bounding boxes, which is zero if they overlap.
+fn box_distance(bdb0: BoundingBox<Point3>, bdb1: BoundingBox<Point3>) -> f64 {
+    let gap = |i: usize| f64::max(bdb0.min()[i] - bdb1.max()[i], bdb1.min()[i] - bdb0.max()[i]);
+    Vector3::new(gap(0), gap(1), gap(2))
+        .map(|x| f64::max(x, 0.0))
+        .magnitude()
+}
+
+/// Returns the triangles of the mesh with their bounding boxes.
+fn triangles(mesh: &PolygonMesh) -> Vec<(BoundingBox<Point3>, [Point3; 3])> {
+    let positions = mesh.positions();
+    mesh.faces()
+        .triangle_iter()
+        .map(|tri| {
+            let tri = tri.map(|v| positions[v.pos]);
+            (tri.iter().collect(), tri)
+        })
+        .collect()
+}
+
+/// Returns whether `tri` has the vertices on both sides of the plane of `other`.
+///
+/// The triangles which only touch each other, e.g. at an edge or on the same plane, do not
+/// straddle each other.
+fn straddles(tri: [Point3; 3], [a, b, c]: [Point3; 3]) -> bool {
+    let n = (b - a).cross(c - a).normalize();
+    let dists = tri.map(|p| (p - a).dot(n));
+    dists.iter().any(|d| *d > TOLERANCE) && dists.iter().any(|d| *d < -TOLERANCE)
+}
+
+/// Returns the distance between two triangles not crossing each other and the nearest points on
+/// each triangle, which are attained by a pair of a vertex and a triangle or of two edges.
+fn triangle_distance(tri0: [Point3; 3], tri1: [Point3; 3]) -> (f64, Point3, Point3) {
+    let edges = |tri: [Point3; 3]| [0, 1, 2].map(|i| (tri[i], tri[(i + 1) % 3]));
+    let nearest_on_triangle = |p: Point3, tri: [Point3; 3]| {
+        let [a, b, c] = barycentric_nearest(p, tri);
+        Point3::from_vec(tri[0].to_vec() * a + tri[1].to_vec() * b + tri[2].to_vec() * c)
+    };
+    let vertices0 = tri0.into_iter().map(|p| (p, nearest_on_triangle(p, tri1)));
+    let vertices1 = tri1.into_iter().map(|q| (nearest_on_triangle(q, tri0), q));
+    let segments = edges(tri0).into_iter().flat_map(|(p0, p1)| {
+        edges(tri1)
+            .into_iter()
+            .map(move |(q0, q1)| nearest_on_segments(p0, p1, q0, q1))
+    });
+    let (p, q) = vertices0
+        .chain(vertices1)
+        .chain(segments)
+        .min_by(|(p0, q0), (p1, q1)| p0.distance2(*q0).total_cmp(&p1.distance2(*q1)))
+        .expect("there are some candidates.");
+    (p.distance(q), p, q)
+}
+
+/// Returns the nearest points on the segments `p0p1` and `q0q1`.
+fn nearest_on_segments(p0: Point3, p1: Point3, q0: Point3, q1: Point3) -> (Point3, Point3) {
+    let (dp, dq, r) = (p1 - p0, q1 - q0, p0 - q0);
+    let (a, e, f) = (dp.magnitude2(), dq.magnitude2(), dq.dot(r));
+    let (s, t) = match (a.so_small2(), e.so_small2()) {
+        (true, true) => (0.0, 0.0),
+        (true, false) => (0.0, f64::clamp(f / e, 0.0, 1.0)),
+        (false, true) => (f64::clamp(-dp.dot(r) / a, 0.0, 1.0), 0.0),
+        (false, false) => {
+            let (b, c) = (dp.dot(dq), dp.dot(r));
+            let denom = a * e - b * b;
+            // parallel segments have no unique nearest points, so start from the end point.
+            let s = match denom > 0.0 {
+                true => f64::clamp((b * f - c * e) / denom, 0.0, 1.0),
+                false => 0.0,
+            };
+            let t = (b * s + f) / e;
+            match (t < 0.0, t > 1.0) {
+                (true, _) => (f64::clamp(-c / a, 0.0, 1.0), 0.0),
+                (_, true) => (f64::clamp((b - c) / a, 0.0, 1.0), 1.0),
+                _ => (s, t),
+            }
+        }
+    };
+    (p0 + dp * s, q0 + dq * t)
+}
+
+#[cfg(test)]
+mod tests;
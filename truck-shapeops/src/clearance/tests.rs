@@ -0,0 +1,57 @@
+use truck_modeling::*;
+
+fn unit_cube() -> Solid {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    builder::tsweep(&f, Vector3::unit_z())
+}
+
+#[test]
+fn separated_boxes() {
+    let cube = unit_cube();
+    let moved = builder::translated(&cube, Vector3::new(0.0, 0.0, 3.0));
+    let clearance = crate::min_distance(&cube, &moved, 0.01);
+    assert_near!(clearance.distance, 2.0);
+    // the witness points are on the facing faces.
+    assert_near!(clearance.points.0.z, 1.0);
+    assert_near!(clearance.points.1.z, 3.0);
+    assert!(!clearance.interfering);
+
+    // the nearest points are the corners.
+    let moved = builder::translated(&cube, Vector3::new(2.0, 2.0, 2.0));
+    let clearance = crate::min_distance(&cube, &moved, 0.01);
+    assert_near!(clearance.distance, f64::sqrt(3.0));
+    assert_near!(clearance.points.0, Point3::new(1.0, 1.0, 1.0));
+    assert_near!(clearance.points.1, Point3::new(2.0, 2.0, 2.0));
+}
+
+#[test]
+fn touching_boxes() {
+    let cube = unit_cube();
+    let moved = builder::translated(&cube, Vector3::new(1.0, 0.5, 0.0));
+    let clearance = crate::min_distance(&cube, &moved, 0.01);
+    assert_eq!(clearance.distance, 0.0);
+    assert!(!clearance.interfering);
+}
+
+#[test]
+fn interfering_boxes() {
+    let cube = unit_cube();
+    let moved = builder::translated(&cube, Vector3::new(0.5, 0.2, -0.3));
+    let clearance = crate::min_distance(&cube, &moved, 0.01);
+    assert_eq!(clearance.distance, 0.0);
+    assert!(clearance.interfering);
+
+    // the small box in the large box
+    let small = builder::scaled(
+        &cube,
+        Point3::new(0.5, 0.5, 0.5),
+        Vector3::new(0.5, 0.5, 0.5),
+    );
+    let clearance = crate::min_distance(&cube, &small, 0.01);
+    assert_eq!(clearance.distance, 0.0);
+    assert!(clearance.interfering);
+    let clearance = crate::min_distance(&small, &cube, 0.01);
+    assert!(clearance.interfering);
+}
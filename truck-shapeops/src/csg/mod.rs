@@ -0,0 +1,150 @@
+use crate::*;
+use truck_meshalgo::prelude::*;
+use truck_topology::*;
+
+/// A tree of boolean operations between solids, which is evaluated at once by [`Csg::evaluate`].
+///
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use truck_shapeops::Csg;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+/// let moved0 = builder::translated(&cube, Vector3::new(0.5, 0.5, 0.5));
+/// let moved1 = builder::translated(&cube, Vector3::new(-0.7, 0.3, 0.2));
+///
+/// // (cube ∪ moved0) − moved1
+/// let tree = Csg::Diff(
+///     Box::new(Csg::Union(
+///         Box::new(Csg::Leaf(cube.clone())),
+///         Box::new(Csg::Leaf(moved0)),
+///     )),
+///     Box::new(Csg::Leaf(moved1)),
+/// );
+/// let solid = tree.evaluate(0.05).unwrap();
+/// assert_eq!(solid.boundaries().len(), 1);
+///
+/// // the difference from itself is the empty solid.
+/// let tree = Csg::Diff(Box::new(Csg::Leaf(cube.clone())), Box::new(Csg::Leaf(cube)));
+/// assert!(tree.evaluate(0.05).unwrap().boundaries().is_empty());
+/// ```
+#[derive(Clone, Debug)]
+pub enum Csg<C, S> {
+    /// a solid
+    Leaf(Solid<Point3, C, S>),
+    /// the union of two trees
+    Union(Box<Csg<C, S>>, Box<Csg<C, S>>),
+    /// the difference of the second tree from the first one
+    Diff(Box<Csg<C, S>>, Box<Csg<C, S>>),
+    /// the intersection of two trees
+    Intersect(Box<Csg<C, S>>, Box<Csg<C, S>>),
+}
+
+impl<C, S> From<Solid<Point3, C, S>> for Csg<C, S> {
+    #[inline(always)]
+    fn from(solid: Solid<Point3, C, S>) -> Self { Csg::Leaf(solid) }
+}
+
+impl<C: ShapeOpsCurve<S>, S: ShapeOpsSurface> Csg<C, S> {
+    /// Evaluates the tree by folding the boolean operations from the leaves.
+    ///
+    /// # Details
+    /// The result may be the empty solid, which has no boundary shells, e.g. the difference of a
+    /// solid from itself or the intersection of the separated solids. The empty solids propagate
+    /// as the empty sets. The operations between the solids whose bounding boxes do not overlap
+    /// are evaluated without computing the intersections of the boundaries.
+    ///
+    /// Returns `None` if some boolean operation fails.
+    pub fn evaluate(&self, tol: f64) -> Option<Solid<Point3, C, S>> {
+        match self {
+            Csg::Leaf(solid) => Some(solid.clone()),
+            Csg::Union(csg0, csg1) => union(&csg0.evaluate(tol)?, &csg1.evaluate(tol)?, tol),
+            Csg::Diff(csg0, csg1) => difference(&csg0.evaluate(tol)?, &csg1.evaluate(tol)?, tol),
+            Csg::Intersect(csg0, csg1) => {
+                intersection(&csg0.evaluate(tol)?, &csg1.evaluate(tol)?, tol)
+            }
+        }
+    }
+}
+
+/// The trivial cases of the boolean operations, i.e. the solids are empty, the same, or separated.
+enum Trivial {
+    Empty0,
+    Empty1,
+    Same,
+    Separated,
+}
+
+fn trivial<C, S>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Option<Trivial>
+where
+    C: ShapeOpsCurve<S>,
+    S: ShapeOpsSurface,
+{
+    if solid0.boundaries().is_empty() {
+        Some(Trivial::Empty0)
+    } else if solid1.boundaries().is_empty() {
+        Some(Trivial::Empty1)
+    } else if solid0 == solid1 {
+        Some(Trivial::Same)
+    } else {
+        let bdb =
+            |solid: &Solid<Point3, C, S>| solid.triangulation(tol).to_polygon().bounding_box();
+        let separated = (bdb(solid0) ^ bdb(solid1)).is_empty();
+        Some(Trivial::Separated).filter(|_| separated)
+    }
+}
+
+fn union<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Option<Solid<Point3, C, S>> {
+    match trivial(solid0, solid1, tol) {
+        Some(Trivial::Empty0) => Some(solid1.clone()),
+        Some(Trivial::Empty1 | Trivial::Same) => Some(solid0.clone()),
+        Some(Trivial::Separated) => {
+            let mut boundaries = solid0.boundaries().clone();
+            boundaries.extend(solid1.boundaries().iter().cloned());
+            Some(Solid::new(boundaries))
+        }
+        None => or(solid0, solid1, tol),
+    }
+}
+
+fn difference<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Option<Solid<Point3, C, S>> {
+    match trivial(solid0, solid1, tol) {
+        Some(Trivial::Empty0 | Trivial::Empty1 | Trivial::Separated) => Some(solid0.clone()),
+        Some(Trivial::Same) => Some(Solid::new(Vec::new())),
+        None => {
+            let mut not1 = solid1.clone();
+            not1.not();
+            and(solid0, &not1, tol)
+        }
+    }
+}
+
+fn intersection<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Option<Solid<Point3, C, S>> {
+    match trivial(solid0, solid1, tol) {
+        Some(Trivial::Empty0 | Trivial::Same) => Some(solid0.clone()),
+        Some(Trivial::Empty1) => Some(solid1.clone()),
+        Some(Trivial::Separated) => Some(Solid::new(Vec::new())),
+        None => and(solid0, solid1, tol),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,59 @@
+use super::Csg;
+use truck_meshalgo::prelude::*;
+use truck_modeling::*;
+
+fn unit_cube() -> Solid {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    builder::tsweep(&f, Vector3::unit_z())
+}
+
+fn leaf(solid: &Solid) -> Box<Csg<Curve, Surface>> { Box::new(Csg::Leaf(solid.clone())) }
+
+#[test]
+fn union_difference() {
+    let a = unit_cube();
+    let b = builder::translated(&a, Vector3::new(0.5, 0.5, 0.5));
+    let c = builder::translated(&a, Vector3::new(-0.7, 0.3, 0.2));
+    let tree = Csg::Diff(Box::new(Csg::Union(leaf(&a), leaf(&b))), leaf(&c));
+    let evaluated = tree.evaluate(0.05).unwrap();
+
+    let mut not_c = c.clone();
+    not_c.not();
+    let union = crate::or(&a, &b, 0.05).unwrap();
+    let manual = crate::and(&union, &not_c, 0.05).unwrap();
+    assert_eq!(evaluated.boundaries().len(), manual.boundaries().len());
+    assert_eq!(evaluated.face_iter().count(), manual.face_iter().count());
+    assert_near!(evaluated.volume(0.05), manual.volume(0.05));
+    // (2 - 0.5^3) - 0.3 * 0.7 * 0.8
+    assert_near!(evaluated.volume(0.05), 1.707);
+}
+
+#[test]
+fn separated_solids() {
+    let a = unit_cube();
+    let b = builder::translated(&a, Vector3::new(3.0, 0.0, 0.0));
+    let union = Csg::Union(leaf(&a), leaf(&b)).evaluate(0.05).unwrap();
+    assert_eq!(union.boundaries().len(), 2);
+    assert_near!(union.volume(0.05), 2.0);
+    let intersection = Csg::Intersect(leaf(&a), leaf(&b)).evaluate(0.05).unwrap();
+    assert!(intersection.boundaries().is_empty());
+    let difference = Csg::Diff(leaf(&a), leaf(&b)).evaluate(0.05).unwrap();
+    assert_eq!(difference, a);
+}
+
+#[test]
+fn empty_propagation() {
+    let a = unit_cube();
+    let b = builder::translated(&a, Vector3::new(0.5, 0.5, 0.5));
+    let empty = || Box::new(Csg::Diff(leaf(&a), leaf(&a)));
+    let union = Csg::Union(empty(), leaf(&b)).evaluate(0.05).unwrap();
+    assert_eq!(union, b);
+    let intersection = Csg::Intersect(leaf(&b), empty()).evaluate(0.05).unwrap();
+    assert!(intersection.boundaries().is_empty());
+    let difference = Csg::Diff(empty(), leaf(&b)).evaluate(0.05).unwrap();
+    assert!(difference.boundaries().is_empty());
+    let difference = Csg::Diff(leaf(&b), empty()).evaluate(0.05).unwrap();
+    assert_eq!(difference, b);
+}
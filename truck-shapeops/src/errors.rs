@@ -0,0 +1,23 @@
+use thiserror::Error;
+
+/// Causes of the failures of the boolean operations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum ShapeOpsError {
+    /// The intersection curves of the boundaries could not be traced on the surfaces,
+    /// or they do not divide the faces consistently.
+    #[error("The intersection curves of the boundaries could not be traced.")]
+    NoIntersection,
+    /// The boundaries touch each other with the parallel tangent planes, e.g. two cubes sharing a face.
+    #[error("The boundaries of the solids are in tangential contact.")]
+    TangentialContact,
+    /// The resulting faces do not form closed manifold shells.
+    #[error("The result is not a closed manifold solid.")]
+    NonManifoldResult,
+    /// The geometry could not be approximated within the tolerance, e.g. the triangulations of
+    /// the faces or the approximations of the intersection curves failed.
+    #[error("The geometry could not be approximated within the tolerance.")]
+    ToleranceTooTight,
+    /// The progress callback requested to abort the operation.
+    #[error("The operation was cancelled by the progress callback.")]
+    Cancelled,
+}
@@ -0,0 +1,558 @@
+use super::{circle_arc_by_three_points, rolling_ball_fillet_surface};
+use crate::{
+    offset::{common_offset_point, spherical_polygon},
+    ShapeOpsCurve, ShapeOpsSurface,
+};
+use algo::curve::{presearch_closest_point, search_closest_parameter};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use truck_geometry::prelude::*;
+use truck_topology::*;
+
+const FILLET_DIVISION: usize = 5;
+const CROSSING_DIVISION: usize = 16;
+const TRIALS: usize = 100;
+
+/// Fillets `edges` of `solid` by the rolling ball with `radius`.
+///
+/// # Details
+/// Each filleted edge must be a convex edge between two faces, and each end vertex of the
+/// filleted edges must be shared by exactly three edges. The fillets are ended at the vertices
+/// according to the number of the filleted edges sharing them:
+/// - one: the fillet is cut by the third face,
+/// - two: the fillets are joined by the sphere touching the three faces, and the remaining edge
+///   is cut by the plane through the center of the sphere and its feet on the faces of the edge,
+/// - three: the corner is rounded by the sphere touching the three faces.
+///
+/// Returns `None` if some edge is not in `solid`, the edges do not satisfy the above conditions,
+/// or the fillets do not fit in the faces.
+///
+/// # Examples
+/// ```
+/// use truck_meshalgo::prelude::*;
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// // the edges sharing the vertex at (1, 1, 1)
+/// let corner = Point3::new(1.0, 1.0, 1.0);
+/// let edges = cube
+///     .edge_iter()
+///     .filter(|edge| edge.front().point().near(&corner) || edge.back().point().near(&corner))
+///     .map(|edge| edge.id())
+///     .collect::<Vec<_>>();
+///
+/// let filleted = truck_shapeops::fillet_edges(&cube, &edges, 0.2, 1.0e-4).unwrap();
+/// let shell = &filleted.boundaries()[0];
+/// // 6 faces, 3 fillets and the spherical corner
+/// assert_eq!(shell.len(), 10);
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// ```
+pub fn fillet_edges<C, S>(
+    solid: &Solid<Point3, C, S>,
+    edges: &[EdgeID<C>],
+    radius: f64,
+    tol: f64,
+) -> Option<Solid<Point3, C, S>>
+where
+    C: ShapeOpsCurve<S> + From<NurbsCurve<Vector4>>,
+    S: ShapeOpsSurface + From<NurbsSurface<Vector4>>,
+{
+    nonpositive_tolerance!(tol);
+    if radius < tol {
+        return None;
+    }
+    let ids = edges.iter().copied().collect::<HashSet<_>>();
+    if !ids
+        .iter()
+        .all(|id| solid.edge_iter().any(|edge| edge.id() == *id))
+    {
+        return None;
+    }
+    let boundaries = solid
+        .boundaries()
+        .iter()
+        .map(|shell| fillet_shell(shell, &ids, radius, tol))
+        .collect::<Option<Vec<_>>>()?;
+    Solid::try_new(boundaries).ok()
+}
+
+/// the parameter on a curve and the vertex at which the curve is cut
+type CutPoint = (f64, Vertex<Point3>);
+
+/// The fillet along an edge.
+struct Fillet<C> {
+    /// the edge oriented as in the first adjacent face
+    edge: Edge<Point3, C>,
+    /// the indices of the adjacent faces
+    faces: [usize; 2],
+    /// the fillet surface, whose parameter `u` runs from the first face to the second face
+    surface: NurbsSurface<Vector4>,
+    /// the curves along which the fillet surface touches the adjacent faces
+    contacts: [NurbsCurve<Vector4>; 2],
+    /// the parameters on the contacts and the vertices at the front and the back of the edge
+    ends: [[Option<CutPoint>; 2]; 2],
+    /// the edges from the first contact to the second contact at the front and the back of the edge
+    connectors: [Option<Edge<Point3, C>>; 2],
+}
+
+impl<C> Fillet<C> {
+    /// Returns the fillet along the convex `edge` between `faces`.
+    fn new<S>(
+        edge: &Edge<Point3, C>,
+        faces: &[(usize, bool)],
+        surfaces: &[S],
+        radius: f64,
+    ) -> Option<Self>
+    where
+        C: ShapeOpsCurve<S>,
+        S: ShapeOpsSurface,
+    {
+        let [(a, orientation), (b, _)] = faces[..] else {
+            return None;
+        };
+        if a == b {
+            return None;
+        }
+        let edge = match orientation {
+            true => edge.clone(),
+            false => edge.inverse(),
+        };
+        let curve = edge.oriented_curve();
+        let (t0, t1) = curve.range_tuple();
+        let t = (t0 + t1) / 2.0;
+        let normal = |surface: &S| {
+            let (u, v) = surface.search_nearest_parameter(curve.subs(t), None, TRIALS)?;
+            Some(surface.normal(u, v))
+        };
+        // The edge is convex if the second face is on the back side of the first face.
+        let (n0, n1) = (normal(&surfaces[a])?, normal(&surfaces[b])?);
+        if n1.dot(n0.cross(curve.der(t))) >= 0.0 {
+            return None;
+        }
+        let surface = rolling_ball_fillet_surface(
+            &surfaces[a],
+            &surfaces[b],
+            &curve,
+            FILLET_DIVISION,
+            |_| radius,
+            true,
+        )?;
+        let contacts = [
+            surface.column_curve(0),
+            surface.column_curve(surface.control_points().len() - 1),
+        ];
+        Some(Self {
+            edge,
+            faces: [a, b],
+            surface,
+            contacts,
+            ends: Default::default(),
+            connectors: [None, None],
+        })
+    }
+
+    /// Returns the index of the end of the edge at `vertex`.
+    fn end(&self, vertex: &Vertex<Point3>) -> usize {
+        match self.edge.front() == vertex {
+            true => 0,
+            false => 1,
+        }
+    }
+
+    /// Returns the side of the fillet on the `face`th face.
+    fn side(&self, face: usize) -> Option<usize> { self.faces.iter().position(|i| *i == face) }
+}
+
+/// Returns the parameters of the points where the curves meet within `tol`.
+fn meeting_parameters(
+    curve0: &(impl ParametricCurve3D + BoundedCurve),
+    curve1: &(impl ParametricCurve3D + BoundedCurve),
+    tol: f64,
+) -> Option<(f64, f64)> {
+    let ranges = (curve0.range_tuple(), curve1.range_tuple());
+    let hint = presearch_closest_point(curve0, curve1, ranges, 10);
+    let (t0, t1) = search_closest_parameter(curve0, curve1, hint, TRIALS)?;
+    let in_range = |t: f64, (s0, s1): (f64, f64)| s0 - TOLERANCE <= t && t <= s1 + TOLERANCE;
+    let is_met = curve0.subs(t0).distance(curve1.subs(t1)) < tol;
+    match is_met && in_range(t0, ranges.0) && in_range(t1, ranges.1) {
+        true => Some((t0, t1)),
+        false => None,
+    }
+}
+
+/// Returns the part of `curve` on the parameter range `(t0, t1)`.
+fn sub_curve<C: Cut>(mut curve: C, (t0, t1): (f64, f64)) -> C {
+    let (s0, s1) = curve.range_tuple();
+    if t1 < s1 {
+        curve.cut(t1);
+    }
+    if s0 < t0 {
+        curve = curve.cut(t0);
+    }
+    curve
+}
+
+/// Returns the intersection curve of the surfaces led by `leader`.
+fn intersection_curve<C, S>(
+    surface0: S,
+    surface1: S,
+    leader: &(impl ParametricCurve3D + BoundedCurve),
+    tol: f64,
+) -> Option<C>
+where
+    C: ShapeOpsCurve<S>,
+    S: ShapeOpsSurface,
+{
+    let bsp = BSplineCurve::quadratic_approximation(leader, leader.range_tuple(), tol, TRIALS)?;
+    let curve = IntersectionCurve::new(surface0, surface1, bsp);
+    let bsp = BSplineCurve::quadratic_approximation(&curve, curve.range_tuple(), tol, TRIALS)?;
+    let (surface0, surface1, _) = curve.destruct();
+    Some(IntersectionCurve::new(surface0, surface1, bsp).into())
+}
+
+/// Returns the arc of the great circle of the sphere from `v0` to `v1`.
+fn great_arc(
+    center: Point3,
+    radius: f64,
+    v0: &Vertex<Point3>,
+    v1: &Vertex<Point3>,
+) -> NurbsCurve<Vector4> {
+    let (p, q) = (v0.point(), v1.point());
+    let transit = center + ((p - center) + (q - center)).normalize() * radius;
+    circle_arc_by_three_points(p.to_homogeneous(), q.to_homogeneous(), transit)
+}
+
+/// Returns the parameter of the point where `curve` crosses the plane through `origin` with `normal`.
+fn plane_crossing(
+    curve: &(impl ParametricCurve3D + BoundedCurve),
+    (origin, normal): (Point3, Vector3),
+) -> Option<f64> {
+    let height = |t: f64| (curve.subs(t) - origin).dot(normal);
+    let (t0, t1) = curve.range_tuple();
+    let (mut t0, mut t1) = (0..CROSSING_DIVISION)
+        .map(|i| {
+            let a = i as f64 / CROSSING_DIVISION as f64;
+            let b = (i + 1) as f64 / CROSSING_DIVISION as f64;
+            (t0 + (t1 - t0) * a, t0 + (t1 - t0) * b)
+        })
+        .find(|(s0, s1)| height(*s0) * height(*s1) <= 0.0)?;
+    for _ in 0..TRIALS {
+        let t = (t0 + t1) / 2.0;
+        match height(t0) * height(t) <= 0.0 {
+            true => t1 = t,
+            false => t0 = t,
+        }
+    }
+    Some((t0 + t1) / 2.0)
+}
+
+/// Returns the plane spanned by `axes` from `origin`, whose parameter range covers the
+/// parallelogram spanned by `axes` and the point `q` on the plane with a margin.
+fn covering_plane(origin: Point3, (e0, e1): (Vector3, Vector3), q: Point3) -> Plane {
+    let gram = Matrix2::new(e0.dot(e0), e0.dot(e1), e0.dot(e1), e1.dot(e1));
+    let d = q - origin;
+    let st = gram.invert().map_or(Vector2::zero(), |inv| {
+        inv * Vector2::new(e0.dot(d), e1.dot(d))
+    });
+    let min = f64::min(0.0, f64::min(st.x, st.y)) - 0.5;
+    let max = f64::max(1.0, f64::max(st.x, st.y)) + 0.5;
+    let o = origin + (e0 + e1) * min;
+    Plane::new(o, o + e0 * (max - min), o + e1 * (max - min))
+}
+
+fn fillet_shell<C, S>(
+    shell: &Shell<Point3, C, S>,
+    ids: &HashSet<EdgeID<C>>,
+    radius: f64,
+    tol: f64,
+) -> Option<Shell<Point3, C, S>>
+where
+    C: ShapeOpsCurve<S> + From<NurbsCurve<Vector4>>,
+    S: ShapeOpsSurface + From<NurbsSurface<Vector4>>,
+{
+    let surfaces = shell
+        .face_iter()
+        .map(Face::oriented_surface)
+        .collect::<Vec<_>>();
+    // the faces adjacent to the edges with the orientations of the edges in the faces
+    let mut edge_faces = HashMap::<EdgeID<C>, (Edge<Point3, C>, Vec<(usize, bool)>)>::default();
+    shell.face_iter().enumerate().for_each(|(i, face)| {
+        face.edge_iter().for_each(|edge| {
+            let entry = edge_faces.entry(edge.id());
+            entry
+                .or_insert_with(|| (edge.absolute_clone(), Vec::new()))
+                .1
+                .push((i, edge.orientation()));
+        })
+    });
+    let mut vertex_edges = HashMap::<VertexID<Point3>, (Vertex<Point3>, Vec<EdgeID<C>>)>::default();
+    edge_faces.iter().for_each(|(id, (edge, _))| {
+        [edge.front(), edge.back()].into_iter().for_each(|v| {
+            let entry = vertex_edges.entry(v.id());
+            entry.or_insert_with(|| (v.clone(), Vec::new())).1.push(*id);
+        })
+    });
+
+    let mut fillets = edge_faces
+        .iter()
+        .filter(|(id, _)| ids.contains(id))
+        .map(|(id, (edge, faces))| Some((*id, Fillet::new(edge, faces, &surfaces, radius)?)))
+        .collect::<Option<HashMap<_, _>>>()?;
+    if fillets.is_empty() {
+        return Some(shell.clone());
+    }
+
+    // the parameters and the vertices where the sharp edges are cut at their front and back
+    let mut cuts = HashMap::<EdgeID<C>, [Option<CutPoint>; 2]>::default();
+    // the connectors on the faces which are not adjacent to the filleted edges
+    let mut side_connectors = Vec::<Edge<Point3, C>>::new();
+    let mut corner_faces = Vec::<Face<Point3, C, S>>::new();
+    let corners = fillets
+        .values()
+        .flat_map(|fillet| [fillet.edge.front().id(), fillet.edge.back().id()])
+        .collect::<HashSet<_>>();
+    for (vertex, edges) in corners.iter().map(|id| &vertex_edges[id]) {
+        let faces = edges
+            .iter()
+            .flat_map(|id| edge_faces[id].1.iter().map(|(i, _)| *i))
+            .collect::<HashSet<_>>();
+        if edges.len() != 3 || faces.len() != 3 {
+            return None;
+        }
+        let (filleted, sharp): (Vec<_>, Vec<_>) = edges
+            .iter()
+            .copied()
+            .partition(|id| fillets.contains_key(id));
+        let mut cut = |id: EdgeID<C>, end: CutPoint| {
+            let edge = &edge_faces[&id].0;
+            let idx = match edge.front() == vertex {
+                true => 0,
+                false => 1,
+            };
+            cuts.entry(id).or_default()[idx] = Some(end);
+        };
+        match filleted[..] {
+            // The fillet is cut by the third face.
+            [id] => {
+                let fillet = fillets.get_mut(&id)?;
+                let end = fillet.end(vertex);
+                for side in 0..2 {
+                    let face = fillet.faces[side];
+                    let sharp_id = *sharp
+                        .iter()
+                        .find(|id| edge_faces[*id].1.iter().any(|(i, _)| *i == face))?;
+                    let curve = edge_faces[&sharp_id].0.curve();
+                    let (t, s) = meeting_parameters(&fillet.contacts[side], &curve, tol)?;
+                    let v = Vertex::new(curve.subs(s));
+                    fillet.ends[end][side] = Some((t, v.clone()));
+                    cut(sharp_id, (s, v));
+                }
+                let third = *faces.iter().find(|i| !fillet.faces.contains(i))?;
+                let (t0, v0) = fillet.ends[end][0].clone()?;
+                let (t1, v1) = fillet.ends[end][1].clone()?;
+                let line = Line(Point2::new(0.0, t0), Point2::new(1.0, t1));
+                let leader = PCurve::new(line, &fillet.surface);
+                let fillet_surface = S::from(fillet.surface.clone());
+                let curve =
+                    intersection_curve(fillet_surface, surfaces[third].clone(), &leader, tol)?;
+                let connector = Edge::new(&v0, &v1, curve);
+                fillet.connectors[end] = Some(connector.clone());
+                side_connectors.push(connector);
+            }
+            // The fillets meet at the sphere touching the three faces.
+            _ => {
+                let offsets = faces
+                    .iter()
+                    .map(|i| (&surfaces[*i], -radius))
+                    .collect::<Vec<_>>();
+                let center = common_offset_point(&offsets, vertex.point(), None, tol)?;
+                let feet = faces
+                    .iter()
+                    .map(|i| {
+                        let (u, v) = surfaces[*i].search_nearest_parameter(center, None, TRIALS)?;
+                        Some((*i, Vertex::new(surfaces[*i].subs(u, v))))
+                    })
+                    .collect::<Option<HashMap<_, _>>>()?;
+                let mut arcs = filleted
+                    .iter()
+                    .map(|id| {
+                        let fillet = fillets.get_mut(id)?;
+                        let end = fillet.end(vertex);
+                        let (v0, v1) = (&feet[&fillet.faces[0]], &feet[&fillet.faces[1]]);
+                        for (side, v) in [v0, v1].into_iter().enumerate() {
+                            let contact = &fillet.contacts[side];
+                            let t = contact.search_nearest_parameter(v.point(), None, TRIALS)?;
+                            fillet.ends[end][side] = Some((t, v.clone()));
+                        }
+                        let connector = Edge::new(v0, v1, great_arc(center, radius, v0, v1).into());
+                        fillet.connectors[end] = Some(connector.clone());
+                        Some(connector)
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                // The sharp edge is cut by the plane of the great circle through the feet on
+                // its faces, and the gap between the sphere and the edge is filled by the plane.
+                if let [id0, id1] = filleted[..] {
+                    let (fillet0, fillet1) = (&fillets[&id0], &fillets[&id1]);
+                    let shared = *fillet0.faces.iter().find(|i| fillet1.faces.contains(i))?;
+                    let a = *fillet0.faces.iter().find(|i| **i != shared)?;
+                    let b = *fillet1.faces.iter().find(|i| **i != shared)?;
+                    let (v0, v1) = (&feet[&a], &feet[&b]);
+                    let arc = Edge::new(v0, v1, great_arc(center, radius, v0, v1).into());
+                    let (e0, e1) = (v0.point() - center, v1.point() - center);
+                    let normal = e0.cross(e1);
+                    let curve = edge_faces[&sharp[0]].0.curve();
+                    let s = plane_crossing(&curve, (center, normal))?;
+                    let q = Vertex::new(curve.subs(s));
+                    cut(sharp[0], (s, q.clone()));
+
+                    let plane = covering_plane(center, (e0, e1), q.point());
+                    let shelf = S::from(NurbsSurface::from(BSplineSurface::from(plane)));
+                    let mut side_connector =
+                        |v0: &Vertex<Point3>, v1: &Vertex<Point3>, i: usize| {
+                            let leader = Line(v0.point(), v1.point());
+                            let curve = intersection_curve(
+                                shelf.clone(),
+                                surfaces[i].clone(),
+                                &leader,
+                                tol,
+                            )?;
+                            let connector = Edge::new(v0, v1, curve);
+                            side_connectors.push(connector.clone());
+                            Some(connector)
+                        };
+                    let wire = Wire::from(vec![
+                        side_connector(v0, &q, a)?,
+                        side_connector(&q, v1, b)?,
+                        arc.inverse(),
+                    ]);
+                    let mut face = Face::try_new(vec![wire], shelf).ok()?;
+                    if normal.dot(feet[&shared].point() - center) < 0.0 {
+                        face.invert();
+                    }
+                    corner_faces.push(face);
+                    arcs.push(arc);
+                }
+                let (wire, surface) = spherical_polygon(arcs, center, radius)?;
+                let face = Face::try_new(vec![wire], surface.into()).ok()?;
+                corner_faces.push(face);
+            }
+        }
+    }
+
+    // the edges along the contacts, oriented as the filleted edges in the first faces
+    let contact_edges = fillets
+        .iter()
+        .map(|(id, fillet)| {
+            let contact_edge = |side: usize| {
+                let (t0, v0) = fillet.ends[0][side].clone()?;
+                let (t1, v1) = fillet.ends[1][side].clone()?;
+                match t0 < t1 {
+                    true => {
+                        let curve = sub_curve(fillet.contacts[side].clone(), (t0, t1));
+                        Some(Edge::new(&v0, &v1, curve.into()))
+                    }
+                    false => None,
+                }
+            };
+            Some((*id, [contact_edge(0)?, contact_edge(1)?]))
+        })
+        .collect::<Option<HashMap<_, _>>>()?;
+    // the sharp edges shortened to the contacts
+    let cut_edges = cuts
+        .into_iter()
+        .map(|(id, [front, back])| {
+            let edge = &edge_faces[&id].0;
+            let curve = edge.curve();
+            let (t0, t1) = curve.range_tuple();
+            let (t0, v0) = front.unwrap_or_else(|| (t0, edge.front().clone()));
+            let (t1, v1) = back.unwrap_or_else(|| (t1, edge.back().clone()));
+            match t0 < t1 {
+                true => Some((id, Edge::new(&v0, &v1, sub_curve(curve, (t0, t1))))),
+                false => None,
+            }
+        })
+        .collect::<Option<HashMap<_, _>>>()?;
+    let connector = |v0: &Vertex<Point3>, v1: &Vertex<Point3>| {
+        side_connectors.iter().find_map(|edge| match edge.ends() {
+            (front, back) if front == v0 && back == v1 => Some(edge.clone()),
+            (front, back) if front == v1 && back == v0 => Some(edge.inverse()),
+            _ => None,
+        })
+    };
+
+    // The boundaries of the faces are replaced by the new edges, and the gaps are filled by the connectors.
+    let faces = shell
+        .face_iter()
+        .enumerate()
+        .map(|(i, face)| {
+            let is_changed = face.edge_iter().any(|edge| {
+                contact_edges.contains_key(&edge.id()) || cut_edges.contains_key(&edge.id())
+            });
+            if !is_changed {
+                return Some(face.clone());
+            }
+            let boundaries = face
+                .boundaries()
+                .into_iter()
+                .map(|wire| {
+                    let mut edges = Vec::<Edge<Point3, C>>::new();
+                    for edge in wire.iter() {
+                        let new_edge = if let Some(fillet) = fillets.get(&edge.id()) {
+                            let contact = &contact_edges[&edge.id()][fillet.side(i)?];
+                            match edge.orientation() == fillet.edge.orientation() {
+                                true => contact.clone(),
+                                false => contact.inverse(),
+                            }
+                        } else if let Some(cut_edge) = cut_edges.get(&edge.id()) {
+                            match edge.orientation() {
+                                true => cut_edge.clone(),
+                                false => cut_edge.inverse(),
+                            }
+                        } else {
+                            edge.clone()
+                        };
+                        if let Some(back) = edges.last().map(|edge| edge.back().clone()) {
+                            if &back != new_edge.front() {
+                                edges.push(connector(&back, new_edge.front())?);
+                            }
+                        }
+                        edges.push(new_edge);
+                    }
+                    let front = edges.first()?.front().clone();
+                    let back = edges.last()?.back().clone();
+                    if back != front {
+                        edges.push(connector(&back, &front)?);
+                    }
+                    Some(Wire::from(edges))
+                })
+                .collect::<Option<Vec<_>>>()?;
+            Face::try_new(boundaries, face.oriented_surface()).ok()
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    // the fillet faces, whose boundaries are counterclockwise in the parameter spaces
+    let fillet_faces = fillets
+        .into_iter()
+        .map(|(id, fillet)| {
+            let [contact0, contact1] = &contact_edges[&id];
+            let [connector0, connector1] = fillet.connectors;
+            let wire = Wire::from(vec![
+                contact0.inverse(),
+                connector0?,
+                contact1.clone(),
+                connector1?.inverse(),
+            ]);
+            Face::try_new(vec![wire], fillet.surface.into()).ok()
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(
+        faces
+            .into_iter()
+            .chain(fillet_faces)
+            .chain(corner_faces)
+            .collect(),
+    )
+}
@@ -15,6 +15,7 @@ use truck_meshalgo::prelude::*;
 
 type PCurveLns = PCurve<Line<Point2>, NurbsSurface<Vector4>>;
 
+/// Curves of the solids filleted by [`fillet_edges`].
 #[allow(clippy::enum_variant_names)]
 #[derive(
     Clone,
@@ -28,27 +29,35 @@ type PCurveLns = PCurve<Line<Point2>, NurbsSurface<Vector4>>;
     SearchParameterD1,
     SearchNearestParameterD1,
 )]
-enum Curve {
+pub enum FilletedCurve {
+    /// NURBS curve
     NurbsCurve(NurbsCurve<Vector4>),
+    /// curve on the fillet surface
     PCurve(PCurveLns),
+    /// intersection curve of a fillet surface and a side face
     IntersectionCurve(
         IntersectionCurve<PCurveLns, Box<NurbsSurface<Vector4>>, Box<NurbsSurface<Vector4>>>,
     ),
 }
 
-truck_topology::prelude!(Point3, Curve, NurbsSurface<Vector4>);
+truck_topology::prelude!(Point3, FilletedCurve, NurbsSurface<Vector4>);
+
+mod edges;
+pub use edges::fillet_edges;
+
+const BEZIER_DEGREE: usize = 4;
 
 pub trait FilletCurve: ParametricCurve3D + BoundedCurve + ParameterDivision1D {}
 impl<C: ParametricCurve3D + BoundedCurve + ParameterDivision1D> FilletCurve for C {}
 
 trait NotStrictlyCut: Sized {
-    fn pre_cut(&self, vertex: &Vertex, curve: Curve, t: f64) -> (Self, Self);
+    fn pre_cut(&self, vertex: &Vertex, curve: FilletedCurve, t: f64) -> (Self, Self);
     fn not_strictly_cut(&self, vertex: &Vertex) -> Option<(Self, Self)>;
     fn not_strictly_cut_with_parameter(&self, vertex: &Vertex, t: f64) -> Option<(Self, Self)>;
 }
 
 impl NotStrictlyCut for Edge {
-    fn pre_cut(&self, vertex: &Vertex, mut curve0: Curve, t: f64) -> (Self, Self) {
+    fn pre_cut(&self, vertex: &Vertex, mut curve0: FilletedCurve, t: f64) -> (Self, Self) {
         let curve1 = curve0.cut(t);
         let mut edge0 = Edge::new(self.absolute_front(), vertex, curve0);
         let mut edge1 = Edge::new(vertex, self.absolute_back(), curve1);
@@ -213,12 +222,13 @@ fn interpole_bezier(points: &[Vector4]) -> BSplineCurve<Vector4> {
 
 fn composite_line_bezier(
     line: Line<Point2>,
-    surface: &NurbsSurface<Vector4>,
+    surface: &impl ParametricSurface3D,
 ) -> BSplineCurve<Vector4> {
-    let curve = PCurve::new(line, surface.non_rationalized());
-    let degree = surface.udegree() + surface.vdegree();
-    let points = (0..=degree)
-        .map(|i| curve.subs(i as f64 / degree as f64))
+    let points = (0..=BEZIER_DEGREE)
+        .map(|i| {
+            let p = line.subs(i as f64 / BEZIER_DEGREE as f64);
+            surface.subs(p.x, p.y).to_homogeneous()
+        })
         .collect::<Vec<_>>();
     interpole_bezier(&points)
 }
@@ -319,9 +329,9 @@ impl RelaySphere {
     }
 }
 
-fn relay_spheres(
-    surface0: &NurbsSurface<Vector4>,
-    surface1: &NurbsSurface<Vector4>,
+fn relay_spheres<S: ParametricSurface3D + SearchParameter<D2, Point = Point3>>(
+    surface0: &S,
+    surface1: &S,
     curve: &impl FilletCurve,
     division: usize,
     radius: impl Fn(f64) -> f64,
@@ -353,8 +363,8 @@ fn almost_fillet_patch(rs0: RelaySphere, rs1: RelaySphere) -> BSplineSurface<Vec
 
 fn expand_fillet(
     relay_spheres: &[RelaySphere],
-    surface0: &NurbsSurface<Vector4>,
-    surface1: &NurbsSurface<Vector4>,
+    surface0: &impl ParametricSurface3D,
+    surface1: &impl ParametricSurface3D,
 ) -> NurbsSurface<Vector4> {
     const LEN: usize = number_of_cpts_of_unit_circle();
     let mut collectors = vec![CurveCollector::<BSplineCurve<_>>::Singleton; LEN];
@@ -411,9 +421,9 @@ fn expand_fillet(
 }
 
 // Orientation of `curve` should be compatible with orientation of `surface0`.
-fn rolling_ball_fillet_surface(
-    surface0: &NurbsSurface<Vector4>,
-    surface1: &NurbsSurface<Vector4>,
+fn rolling_ball_fillet_surface<S: ParametricSurface3D + SearchParameter<D2, Point = Point3>>(
+    surface0: &S,
+    surface1: &S,
     curve: &impl FilletCurve,
     division: usize,
     radius: impl Fn(f64) -> f64,
@@ -586,7 +596,7 @@ fn create_new_side(
         })
         .collect();
     let side_surface = Box::new(side.oriented_surface());
-    let Curve::PCurve(fillet_curve) = fillet_edge.curve() else {
+    let FilletedCurve::PCurve(fillet_curve) = fillet_edge.curve() else {
         return None;
     };
     let fillet_surface = Box::new(fillet_curve.surface().clone());
@@ -740,7 +750,7 @@ fn concat_fillet_surface(surfaces: &[NurbsSurface<Vector4>]) -> NurbsSurface<Vec
     NurbsSurface::new(BSplineSurface::new((uknot_vec, vknot_vec), control_points))
 }
 
-fn create_free_edge(curve: Curve) -> Edge {
+fn create_free_edge(curve: FilletedCurve) -> Edge {
     let v0 = Vertex::new(curve.front());
     let v1 = Vertex::new(curve.back());
     Edge::new(&v0, &v1, curve)
@@ -979,7 +989,7 @@ fn fillet_along_wire(
 fn create_fillet_surface() {
     use truck_meshalgo::prelude::*;
     #[rustfmt::skip]
-    let surface0 = BSplineSurface::new(
+    let surface0: NurbsSurface<Vector4> = BSplineSurface::new(
         (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2)),
         vec![
             vec![Point3::new(0.2, 0.0, 0.0), Point3::new(0.0, 0.5, 0.0), Point3::new(-0.2, 1.0, 0.0)],
@@ -989,7 +999,7 @@ fn create_fillet_surface() {
     )
     .into();
     #[rustfmt::skip]
-    let surface1 = BSplineSurface::new(
+    let surface1: NurbsSurface<Vector4> = BSplineSurface::new(
         (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2)),
         vec![
             vec![Point3::new(0.2, 0.0, 0.0),  Point3::new(0.0, 0.0, -0.5), Point3::new(-0.2, 0.0, -1.0)],
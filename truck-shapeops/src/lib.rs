@@ -13,9 +13,23 @@
     unused_qualifications
 )]
 
+mod errors;
+pub use errors::ShapeOpsError;
 mod healing;
 pub use healing::{RobustSplitClosedEdgesAndFaces, SplitClosedEdgesAndFaces};
 mod transversal;
-pub use transversal::{and, or, ShapeOpsCurve, ShapeOpsSurface};
+pub use transversal::{
+    and, and_with_progress, and_with_report, or, or_with_progress, or_with_report, xor,
+    ShapeOpsCurve, ShapeOpsSurface,
+};
+mod clearance;
+pub use clearance::{min_distance, Clearance};
+mod csg;
+pub use csg::Csg;
+mod section;
+pub use section::{section, split_by_plane};
+mod offset;
+pub use offset::offset_solid;
 mod alternative;
 mod fillet;
+pub use fillet::fillet_edges;
@@ -0,0 +1,673 @@
+use crate::*;
+use rustc_hash::FxHashMap as HashMap;
+use truck_geometry::prelude::*;
+use truck_meshalgo::prelude::*;
+use truck_topology::*;
+
+const TRIALS: usize = 100;
+
+/// Offsets the boundaries of `solid` by `distance` along the normals of the faces.
+///
+/// # Details
+/// Each face is moved along its outward normal by `distance`: the positive distance grows the solid
+/// and the negative one shrinks it. The offset surfaces and curves are approximated by quadratic
+/// B-splines within `tol`, while the arcs blending them, described below, are exactly the rational
+/// quadratic curves.
+///
+/// The offset faces separated at a sharp edge, e.g. at a convex edge of the grown solid, are blended
+/// by the arcs around the edge, and the corner surrounded by such edges is rounded by a sphere.
+/// The offset faces overlapping at a sharp edge, e.g. at a concave edge of the grown solid, are
+/// trimmed at their intersection, and the corner surrounded by such edges stays sharp.
+///
+/// Returns `None` if
+/// - some corner is surrounded by both separated and overlapping offset faces,
+/// - the offset surfaces or edges fold back, e.g. the solid is shrunk by more than the radii of
+///   curvature or by more than its thickness,
+/// - or the offset geometry cannot be approximated within `tol`.
+///
+/// # Examples
+/// ```
+/// use std::f64::consts::PI;
+/// use truck_meshalgo::prelude::*;
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+/// let wire: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_x(), Rad(PI));
+/// let shell = builder::cone(&wire, Vector3::unit_y(), Rad(7.0));
+/// let sphere = Solid::new(vec![shell]);
+///
+/// let offset = truck_shapeops::offset_solid(&sphere, 0.2, 0.01).unwrap();
+/// let shell = &offset.boundaries()[0];
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// shell.vertex_iter().for_each(|v| {
+///     let radius = v.point().to_vec().magnitude();
+///     assert!(f64::abs(radius - 1.2) < 0.01);
+/// });
+///
+/// // The sphere vanishes before shrinking by 1.5.
+/// assert!(truck_shapeops::offset_solid(&sphere, -1.5, 0.01).is_none());
+///
+/// // The edges and the corners of the grown cube are rounded.
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+/// let offset = truck_shapeops::offset_solid(&cube, 0.1, 0.01).unwrap();
+/// // 6 faces, 12 edges and 8 corners
+/// assert_eq!(offset.boundaries()[0].len(), 26);
+/// ```
+pub fn offset_solid<C, S>(
+    solid: &Solid<Point3, C, S>,
+    distance: f64,
+    tol: f64,
+) -> Option<Solid<Point3, C, S>>
+where
+    C: ShapeOpsCurve<S> + From<NurbsCurve<Vector4>>,
+    S: ShapeOpsSurface + From<NurbsSurface<Vector4>>,
+{
+    nonpositive_tolerance!(tol);
+    let boundaries = solid
+        .boundaries()
+        .iter()
+        .map(|shell| offset_shell(shell, distance, tol))
+        .collect::<Option<Vec<_>>>()?;
+    Solid::try_new(boundaries).ok()
+}
+
+/// Interpolates the points `f(t)` by a quadratic B-spline curve within `tol`.
+fn approximate_curve(
+    f: impl Fn(f64) -> Option<Point3>,
+    (t0, t1): (f64, f64),
+    tol: f64,
+) -> Option<BSplineCurve<Point3>> {
+    for n in 0..TRIALS {
+        let mut knot_vec = KnotVec::uniform_knot(2, n + 1);
+        knot_vec.transform(t1 - t0, t0);
+        let len = n + 2;
+        let param = |x: f64| t0 + (t1 - t0) * x / len as f64;
+        let parameter_points = (0..=len)
+            .map(|i| Some((param(i as f64), f(param(i as f64))?)))
+            .collect::<Option<Vec<_>>>()?;
+        let bsp = BSplineCurve::try_interpole(knot_vec, parameter_points).ok()?;
+        let is_approx = (0..len)
+            .map(|i| param(i as f64 + 0.5))
+            .map(|t| Some(f(t)?.distance2(bsp.subs(t)) < tol * tol))
+            .collect::<Option<Vec<_>>>()?;
+        if is_approx.into_iter().all(|x| x) {
+            return Some(bsp);
+        }
+    }
+    None
+}
+
+/// Interpolates the points `f(u, v)` by a quadratic B-spline surface within `tol`.
+fn approximate_surface(
+    f: impl Fn(f64, f64) -> Option<Point3>,
+    ((u0, u1), (v0, v1)): ((f64, f64), (f64, f64)),
+    tol: f64,
+) -> Option<BSplineSurface<Point3>> {
+    for n in 0..TRIALS {
+        let knot_vec = |(t0, t1): (f64, f64)| {
+            let mut knot_vec = KnotVec::uniform_knot(2, n + 1);
+            knot_vec.transform(t1 - t0, t0);
+            knot_vec
+        };
+        let (uknot_vec, vknot_vec) = (knot_vec((u0, u1)), knot_vec((v0, v1)));
+        let len = n + 2;
+        let uparam = |x: f64| u0 + (u1 - u0) * x / len as f64;
+        let vparam = |x: f64| v0 + (v1 - v0) * x / len as f64;
+        // interpolates in the u-direction first, and then interpolates the control points in the v-direction.
+        let rows = (0..=len)
+            .map(|j| {
+                let v = vparam(j as f64);
+                let points = (0..=len)
+                    .map(|i| Some((uparam(i as f64), f(uparam(i as f64), v)?)))
+                    .collect::<Option<Vec<_>>>()?;
+                let bsp = BSplineCurve::try_interpole(uknot_vec.clone(), points).ok()?;
+                Some(bsp.destruct().1)
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let control_points = (0..=len)
+            .map(|i| {
+                let points = (0..=len)
+                    .map(|j| (vparam(j as f64), rows[j][i]))
+                    .collect::<Vec<_>>();
+                let bsp = BSplineCurve::try_interpole(vknot_vec.clone(), points).ok()?;
+                Some(bsp.destruct().1)
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let bsp = BSplineSurface::new((uknot_vec, vknot_vec), control_points);
+        let is_approx = (0..len)
+            .flat_map(|i| (0..len).map(move |j| (uparam(i as f64 + 0.5), vparam(j as f64 + 0.5))))
+            .map(|(u, v)| Some(f(u, v)?.distance2(bsp.subs(u, v)) < tol * tol))
+            .collect::<Option<Vec<_>>>()?;
+        if is_approx.into_iter().all(|x| x) {
+            return Some(bsp);
+        }
+    }
+    None
+}
+
+/// Interpolates the arcs given by the homogeneous control points `f(v)` by the rational surface
+/// whose `u`-curves are the arcs, within `tol`.
+fn approximate_arcs(
+    f: impl Fn(f64) -> Option<[Vector4; 3]>,
+    (t0, t1): (f64, f64),
+    tol: f64,
+) -> Option<NurbsSurface<Vector4>> {
+    let arc = |control_points: [Vector4; 3]| {
+        NurbsCurve::new(BSplineCurve::new(
+            KnotVec::bezier_knot(2),
+            control_points.to_vec(),
+        ))
+    };
+    for n in 0..TRIALS {
+        let mut knot_vec = KnotVec::uniform_knot(2, n + 1);
+        knot_vec.transform(t1 - t0, t0);
+        let len = n + 2;
+        let param = |x: f64| t0 + (t1 - t0) * x / len as f64;
+        let arcs = (0..=len)
+            .map(|i| Some((param(i as f64), f(param(i as f64))?)))
+            .collect::<Option<Vec<_>>>()?;
+        let control_points = (0..3)
+            .map(|k| {
+                let points = arcs.iter().map(|(t, arc)| (*t, arc[k])).collect::<Vec<_>>();
+                let bsp = BSplineCurve::try_interpole(knot_vec.clone(), points).ok()?;
+                Some(bsp.destruct().1)
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let knot_vecs = (KnotVec::bezier_knot(2), knot_vec);
+        let surface = NurbsSurface::new(BSplineSurface::new(knot_vecs, control_points));
+        let is_approx = (0..len)
+            .map(|i| param(i as f64 + 0.5))
+            .map(|t| {
+                let arc = arc(f(t)?);
+                let is_near = |s: f64| arc.subs(s).distance2(surface.subs(s, t)) < tol * tol;
+                Some([0.0, 0.5, 1.0].into_iter().all(is_near))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        if is_approx.into_iter().all(|x| x) {
+            return Some(surface);
+        }
+    }
+    None
+}
+
+/// The offset of an edge, decided by the offset directions of the adjacent faces.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum EdgeKind {
+    /// The adjacent faces meet tangentially, and hence the offset faces are connected.
+    Smooth,
+    /// The offset faces are separated, and blended by the arcs around the edge.
+    Gap,
+    /// The offset faces overlap, and are trimmed at their intersection.
+    Overlap,
+}
+
+/// Returns the point whose signed distances from the surfaces are the offset distances, searched
+/// from `start` by the Gauss-Newton method. The point is restricted on `plane` if it is given.
+pub(super) fn common_offset_point<S: ShapeOpsSurface>(
+    offsets: &[(&S, f64)],
+    start: Point3,
+    plane: Option<(Point3, Vector3)>,
+    tol: f64,
+) -> Option<Point3> {
+    let mut x = start;
+    let mut hints = vec![None; offsets.len()];
+    for _ in 0..TRIALS {
+        // the gradients and the residues of the conditions
+        let rows = offsets
+            .iter()
+            .zip(&mut hints)
+            .map(|((surface, distance), hint)| {
+                let (u, v) = surface.search_nearest_parameter(x, *hint, TRIALS)?;
+                *hint = Some((u, v));
+                let n = surface.normal(u, v);
+                Some((n, (x - surface.subs(u, v)).dot(n) - distance))
+            })
+            .chain(plane.map(|(origin, n)| Some((n, (x - origin).dot(n)))))
+            .collect::<Option<Vec<_>>>()?;
+        // The damping fixes the point in the directions which are not restricted.
+        let init = (Matrix3::identity() * TOLERANCE, Vector3::zero());
+        let (mat, vec) = rows.iter().fold(init, |(mat, vec), &(n, f)| {
+            (
+                mat + Matrix3::from_cols(n * n.x, n * n.y, n * n.z),
+                vec + n * f,
+            )
+        });
+        let dx = mat.invert()? * vec;
+        x -= dx;
+        if dx.so_small() {
+            return match rows.iter().all(|(_, f)| f.abs() < tol) {
+                true => Some(x),
+                false => None,
+            };
+        }
+    }
+    None
+}
+
+/// Returns the homogeneous control points of the rational quadratic Bézier arc around `center`
+/// from `p` to `q`, which are at the same distance from `center`.
+fn arc_control_points(center: Point3, p: Point3, q: Point3) -> Option<[Vector4; 3]> {
+    let (a, b) = (p - center, q - center);
+    let cos = a.dot(b) / a.magnitude2();
+    // The arc is degenerate or not less than a semicircle.
+    if cos >= 1.0 - TOLERANCE || cos <= TOLERANCE - 1.0 {
+        return None;
+    }
+    // the intersection of the tangent lines at the ends, weighted by the cosine of the half angle
+    let transit = center + (a + b) / (1.0 + cos);
+    let weight = f64::sqrt((1.0 + cos) / 2.0);
+    Some([
+        p.to_homogeneous(),
+        transit.to_homogeneous() * weight,
+        q.to_homogeneous(),
+    ])
+}
+
+/// Returns the arc around `center` from `a` to `b`, which is shared by the faces around the corner.
+fn corner_arc<C: From<NurbsCurve<Vector4>>>(
+    arcs: &mut Vec<Edge<Point3, C>>,
+    center: Point3,
+    (a, b): (&Vertex<Point3>, &Vertex<Point3>),
+) -> Option<Edge<Point3, C>> {
+    let is_arc = |edge: &Edge<Point3, C>, a: &Vertex<Point3>, b: &Vertex<Point3>| {
+        edge.front().id() == a.id() && edge.back().id() == b.id()
+    };
+    if let Some(arc) = arcs.iter().find(|arc| is_arc(arc, a, b)) {
+        return Some(arc.clone());
+    } else if let Some(arc) = arcs.iter().find(|arc| is_arc(arc, b, a)) {
+        return Some(arc.inverse());
+    }
+    let control_points = arc_control_points(center, a.point(), b.point())?;
+    let bsp = BSplineCurve::new(KnotVec::bezier_knot(2), control_points.to_vec());
+    let arc = Edge::new(a, b, NurbsCurve::new(bsp).into());
+    arcs.push(arc.clone());
+    Some(arc)
+}
+
+/// Creates the face whose boundary is counterclockwise in the parameter space of `surface`, and
+/// inverts it if the normal at `(u, v)` is not along `outward`.
+fn oriented_face<C, S>(
+    wire: Wire<Point3, C>,
+    surface: NurbsSurface<Vector4>,
+    (u, v): (f64, f64),
+    outward: Vector3,
+) -> Option<Face<Point3, C, S>>
+where
+    S: From<NurbsSurface<Vector4>>,
+{
+    let is_inverted = surface.normal(u, v).dot(outward) < 0.0;
+    let mut face = Face::try_new(vec![wire], surface.into()).ok()?;
+    if is_inverted {
+        face.invert();
+    }
+    Some(face)
+}
+
+/// Returns the boundary of the spherical polygon around `center` bounded by the arcs, and the
+/// sphere whose parameter space the boundary runs counterclockwise in. The normal of the sphere
+/// is directed to the outside.
+pub(super) fn spherical_polygon<C>(
+    mut arcs: Vec<Edge<Point3, C>>,
+    center: Point3,
+    radius: f64,
+) -> Option<(Wire<Point3, C>, NurbsSurface<Vector4>)> {
+    let mut edges = vec![arcs.pop()?];
+    while let Some(last) = edges.last().map(|edge| edge.back().id()) {
+        let Some(idx) = arcs
+            .iter()
+            .position(|arc| arc.front().id() == last || arc.back().id() == last)
+        else {
+            break;
+        };
+        let arc = arcs.swap_remove(idx);
+        match arc.front().id() == last {
+            true => edges.push(arc),
+            false => edges.push(arc.inverse()),
+        }
+    }
+    let mut wire = Wire::from(edges);
+    // The sphere is parametrized by the stereographic projection from the antipode of the middle
+    // of the vertices, which is the rational surface of degree two containing the arcs strictly.
+    let dirs = wire
+        .vertex_iter()
+        .map(|v| (v.point() - center).normalize())
+        .collect::<Vec<_>>();
+    let axis = dirs
+        .iter()
+        .fold(Vector3::zero(), |sum, dir| sum + dir)
+        .normalize();
+    let u_axis = (dirs[0] - axis * dirs[0].dot(axis)).normalize();
+    let v_axis = axis.cross(u_axis);
+    let projected = dirs
+        .iter()
+        .map(|dir| match dir.dot(axis) > -0.5 {
+            true => Some(Point2::new(dir.dot(u_axis), dir.dot(v_axis)) / (1.0 + dir.dot(axis))),
+            false => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let area = (0..projected.len()).fold(0.0, |area, i| {
+        let (p, q) = (projected[i], projected[(i + 1) % projected.len()]);
+        area + p.x * q.y - p.y * q.x
+    });
+    if area < 0.0 {
+        wire.invert();
+    }
+    let bdb = BoundingBox::from_iter(&projected);
+    let (min, max) = (bdb.min(), bdb.max());
+    let margin = min.distance(max) * 0.05;
+    let (u0, u1) = (min.x - margin, max.x + margin);
+    let (v0, v1) = (min.y - margin, max.y + margin);
+    let sphere = |u: f64, v: f64| {
+        let w = 1.0 + u * u + v * v;
+        let dir = axis * (1.0 - u * u - v * v) + u_axis * (2.0 * u) + v_axis * (2.0 * v);
+        (center.to_vec() * w + dir * radius).extend(w)
+    };
+    // the Bézier control points of the quadratic polynomial from the values at the ends and the middle
+    let bezier = |f0: Vector4, f: Vector4, f1: Vector4| [f0, f * 2.0 - (f0 + f1) / 2.0, f1];
+    let (us, vs) = ([u0, (u0 + u1) / 2.0, u1], [v0, (v0 + v1) / 2.0, v1]);
+    let rows = us.map(|u| bezier(sphere(u, vs[0]), sphere(u, vs[1]), sphere(u, vs[2])));
+    let columns = [0, 1, 2].map(|j| bezier(rows[0][j], rows[1][j], rows[2][j]));
+    let control_points = (0..3)
+        .map(|i| columns.iter().map(|column| column[i]).collect())
+        .collect();
+    let knot_vec = |(t0, t1): (f64, f64)| {
+        let mut knot_vec = KnotVec::bezier_knot(2);
+        knot_vec.transform(t1 - t0, t0);
+        knot_vec
+    };
+    let knot_vecs = (knot_vec((u0, u1)), knot_vec((v0, v1)));
+    let surface = NurbsSurface::new(BSplineSurface::new(knot_vecs, control_points));
+    Some((wire, surface))
+}
+
+/// Returns the spherical face around `center` bounded by the arcs, which rounds a corner.
+fn corner_face<C, S>(
+    arcs: Vec<Edge<Point3, C>>,
+    center: Point3,
+    distance: f64,
+) -> Option<Face<Point3, C, S>>
+where
+    S: From<NurbsSurface<Vector4>>,
+{
+    let (wire, surface) = spherical_polygon(arcs, center, distance.abs())?;
+    let mut face = Face::try_new(vec![wire], surface.into()).ok()?;
+    // The sphere is inside of the shrunk solid.
+    if distance < 0.0 {
+        face.invert();
+    }
+    Some(face)
+}
+
+fn offset_shell<C, S>(
+    shell: &Shell<Point3, C, S>,
+    distance: f64,
+    tol: f64,
+) -> Option<Shell<Point3, C, S>>
+where
+    C: ShapeOpsCurve<S> + From<NurbsCurve<Vector4>>,
+    S: ShapeOpsSurface + From<NurbsSurface<Vector4>>,
+{
+    let surfaces = shell.face_iter().map(Face::surface).collect::<Vec<_>>();
+    let signs = shell
+        .face_iter()
+        .map(|face| match face.orientation() {
+            true => 1.0,
+            false => -1.0,
+        })
+        .collect::<Vec<_>>();
+    // the offset distances along the normals of the non-oriented surfaces
+    let distances = signs.iter().map(|sign| sign * distance).collect::<Vec<_>>();
+    // the outward normal of the `i`th face at the point on it
+    let normal = |i: usize, pt: Point3| {
+        let (u, v) = surfaces[i]
+            .search_parameter(pt, None, TRIALS)
+            .or_else(|| surfaces[i].search_nearest_parameter(pt, None, TRIALS))?;
+        Some(surfaces[i].normal(u, v) * signs[i])
+    };
+
+    // the faces adjacent to the edges with the orientations of the edges in the faces
+    let mut edge_faces = HashMap::<EdgeID<C>, (Edge<Point3, C>, Vec<(usize, bool)>)>::default();
+    let mut vertex_faces = HashMap::<VertexID<Point3>, (Vertex<Point3>, Vec<usize>)>::default();
+    shell.face_iter().enumerate().for_each(|(i, face)| {
+        face.edge_iter().for_each(|edge| {
+            let entry = edge_faces.entry(edge.id());
+            entry
+                .or_insert_with(|| (edge.absolute_clone(), Vec::new()))
+                .1
+                .push((i, edge.orientation()));
+            [edge.front(), edge.back()].into_iter().for_each(|v| {
+                let entry = vertex_faces.entry(v.id());
+                let faces = &mut entry.or_insert_with(|| (v.clone(), Vec::new())).1;
+                if !faces.contains(&i) {
+                    faces.push(i);
+                }
+            });
+        })
+    });
+
+    const SAMPLES: usize = 4;
+    let kinds = edge_faces
+        .iter()
+        .map(|(id, (edge, faces))| {
+            let curve = edge.curve();
+            let (t0, t1) = curve.range_tuple();
+            let mut is_sharp = false;
+            let mut convexity = 0.0;
+            for k in 0..=SAMPLES {
+                let t = t0 + (t1 - t0) * k as f64 / SAMPLES as f64;
+                let pt = curve.subs(t);
+                let normals = faces
+                    .iter()
+                    .map(|(i, _)| normal(*i, pt))
+                    .collect::<Option<Vec<_>>>()?;
+                is_sharp |= normals
+                    .iter()
+                    .any(|n| (n - normals[0]).magnitude() * distance.abs() >= tol);
+                if let [(_, orientation), _] = faces[..] {
+                    // The edge is convex if the other face is on the back side of the first face.
+                    let tangent = curve.der(t) * if orientation { 1.0 } else { -1.0 };
+                    let x = normals[1].dot(normals[0].cross(tangent));
+                    if x.abs() > f64::abs(convexity) {
+                        convexity = x;
+                    }
+                }
+            }
+            let kind = match (is_sharp, faces.len()) {
+                (false, _) => EdgeKind::Smooth,
+                (true, 2) if (convexity < 0.0) == (distance > 0.0) => EdgeKind::Gap,
+                (true, 2) => EdgeKind::Overlap,
+                _ => return None,
+            };
+            Some((*id, kind))
+        })
+        .collect::<Option<HashMap<_, _>>>()?;
+
+    let mut vertex_kinds = HashMap::<VertexID<Point3>, EdgeKind>::default();
+    for (id, (edge, _)) in &edge_faces {
+        for v in [edge.front(), edge.back()] {
+            let kind = vertex_kinds.entry(v.id()).or_insert(EdgeKind::Smooth);
+            match (*kind, kinds[id]) {
+                (_, EdgeKind::Smooth) => {}
+                (EdgeKind::Smooth, new_kind) => *kind = new_kind,
+                (old_kind, new_kind) if old_kind == new_kind => {}
+                // the corners with both arcs and intersections are not supported.
+                _ => return None,
+            }
+        }
+    }
+
+    // the corners of the offset faces, whose vertices are shared if they coincide
+    let mut corners = HashMap::<(VertexID<Point3>, usize), Vertex<Point3>>::default();
+    for (id, (v, faces)) in &vertex_faces {
+        let pt = v.point();
+        let points = match vertex_kinds[id] {
+            EdgeKind::Overlap => {
+                let offsets = faces
+                    .iter()
+                    .map(|i| (&surfaces[*i], distances[*i]))
+                    .collect::<Vec<_>>();
+                vec![common_offset_point(&offsets, pt, None, tol)?; faces.len()]
+            }
+            _ => faces
+                .iter()
+                .map(|i| Some(pt + normal(*i, pt)? * distance))
+                .collect::<Option<Vec<_>>>()?,
+        };
+        let mut new_vertices = Vec::<Vertex<Point3>>::new();
+        faces.iter().zip(points).for_each(|(i, x)| {
+            let new_vertex = match new_vertices.iter().find(|w| w.point().distance(x) < tol) {
+                Some(w) => w.clone(),
+                None => {
+                    new_vertices.push(Vertex::new(x));
+                    new_vertices[new_vertices.len() - 1].clone()
+                }
+            };
+            corners.insert((*id, *i), new_vertex);
+        });
+    }
+    let corner = |v: &Vertex<Point3>, i: usize| &corners[&(v.id(), i)];
+
+    // the edges of the offset faces, keyed by the original edges and the faces
+    let mut new_edges = HashMap::<(EdgeID<C>, usize), Edge<Point3, C>>::default();
+    let mut arcs = HashMap::<VertexID<Point3>, Vec<Edge<Point3, C>>>::default();
+    let mut blends = Vec::<Face<Point3, C, S>>::new();
+    for (id, (edge, faces)) in &edge_faces {
+        let curve = edge.curve();
+        let (v0, v1) = (edge.front(), edge.back());
+        let i = faces[0].0;
+        let offset = |i: usize, t: f64| {
+            let pt = curve.subs(t);
+            Some(pt + normal(i, pt)? * distance)
+        };
+        if kinds[id] == EdgeKind::Gap {
+            let j = faces[1].0;
+            // The blend is swept by the arcs from the offset of the `i`th face to the `j`th face.
+            let arc = |t: f64| arc_control_points(curve.subs(t), offset(i, t)?, offset(j, t)?);
+            let surface = approximate_arcs(arc, curve.range_tuple(), tol)?;
+            let offset_edge = |k: usize, i: usize| {
+                let curve = surface.column_curve(k);
+                Edge::new(corner(v0, i), corner(v1, i), curve.into())
+            };
+            let (edge_i, edge_j) = (offset_edge(0, i), offset_edge(2, j));
+            let mut arc = |v: &Vertex<Point3>| {
+                let around = arcs.entry(v.id()).or_default();
+                corner_arc(around, v.point(), (corner(v, i), corner(v, j)))
+            };
+            let (arc0, arc1) = (arc(v0)?, arc(v1)?);
+            let wire = Wire::from(vec![arc0, edge_j.clone(), arc1.inverse(), edge_i.inverse()]);
+            let (t0, t1) = curve.range_tuple();
+            let t = (t0 + t1) / 2.0;
+            let pt = curve.subs(t);
+            let outward = normal(i, pt)? + normal(j, pt)?;
+            blends.push(oriented_face(wire, surface, (0.5, t), outward)?);
+            new_edges.insert((*id, i), edge_i);
+            new_edges.insert((*id, j), edge_j);
+            continue;
+        }
+
+        let (w0, w1) = (corner(v0, i), corner(v1, i));
+        if faces
+            .iter()
+            .any(|(j, _)| corner(v0, *j).id() != w0.id() || corner(v1, *j).id() != w1.id())
+        {
+            return None;
+        }
+        // The edge is trimmed if the corner is not the offset of the end.
+        let trimmed = |t: f64, x: Point3| match offset(i, t)?.distance(x) < tol {
+            true => Some(t),
+            false => curve.search_nearest_parameter(x, Some(t), TRIALS),
+        };
+        let (t0, t1) = curve.range_tuple();
+        let range = (trimmed(t0, w0.point())?, trimmed(t1, w1.point())?);
+        // The offset edge is folded back.
+        if range.0 >= range.1 {
+            return None;
+        }
+        let bsp = match kinds[id] {
+            EdgeKind::Overlap => {
+                let j = faces[1].0;
+                let offsets = [(&surfaces[i], distances[i]), (&surfaces[j], distances[j])];
+                let intersection = |t: f64| {
+                    let pt = curve.subs(t);
+                    let plane = (pt, curve.der(t).normalize());
+                    common_offset_point(&offsets, pt, Some(plane), tol)
+                };
+                approximate_curve(intersection, range, tol)?
+            }
+            _ => approximate_curve(|t| offset(i, t), range, tol)?,
+        };
+        let new_edge = Edge::new(w0, w1, NurbsCurve::from(bsp).into());
+        faces.iter().for_each(|(j, _)| {
+            new_edges.insert((*id, *j), new_edge.clone());
+        });
+    }
+    let new_wire = |i: usize, wire: &Wire<Point3, C>| {
+        wire.edge_iter()
+            .map(|edge| match edge.orientation() {
+                true => new_edges[&(edge.id(), i)].clone(),
+                false => new_edges[&(edge.id(), i)].inverse(),
+            })
+            .collect::<Wire<Point3, C>>()
+    };
+
+    // The corners surrounded by three or more arcs are rounded by the spheres.
+    let corner_faces = arcs
+        .into_iter()
+        .filter(|(_, arcs)| arcs.len() > 2)
+        .map(|(id, arcs)| corner_face(arcs, vertex_faces[&id].0.point(), distance))
+        .collect::<Option<Vec<Face<Point3, C, S>>>>()?;
+
+    let poly_shell = shell.triangulation(tol);
+    let offset_faces = shell
+        .face_iter()
+        .zip(poly_shell.face_iter())
+        .enumerate()
+        .map(|(i, (face, poly_face))| {
+            let (surface, distance) = (&surfaces[i], distances[i]);
+            // the parameter domain of the face
+            let bdb = BoundingBox::from_iter(poly_face.surface()?.uv_coords());
+            let (min, max) = (bdb.min(), bdb.max());
+            let offset =
+                |u: f64, v: f64| Some(surface.subs(u, v) + surface.normal(u, v) * distance);
+            let ranges = ((min.x, max.x), (min.y, max.y));
+            let bsp = approximate_surface(offset, ranges, tol)?;
+            // The offset surface folds back if the normal or the derivatives are flipped. The
+            // normal is kept if both derivatives are flipped, e.g. shrinking a sphere past its center.
+            let is_folded = (0..8).any(|k| {
+                (0..8).any(|l| {
+                    let u = min.x + (max.x - min.x) * (k as f64 + 0.5) / 8.0;
+                    let v = min.y + (max.y - min.y) * (l as f64 + 0.5) / 8.0;
+                    let is_flipped = |a: Vector3, b: Vector3| a.dot(b) <= 0.0;
+                    is_flipped(bsp.normal(u, v), surface.normal(u, v))
+                        || is_flipped(bsp.uder(u, v), surface.uder(u, v))
+                        || is_flipped(bsp.vder(u, v), surface.vder(u, v))
+                })
+            });
+            if is_folded {
+                return None;
+            }
+            let boundaries = face
+                .absolute_boundaries()
+                .iter()
+                .map(|wire| new_wire(i, wire))
+                .collect();
+            let mut new_face = Face::try_new(boundaries, NurbsSurface::from(bsp).into()).ok()?;
+            if !face.orientation() {
+                new_face.invert();
+            }
+            Some(new_face)
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some(
+        offset_faces
+            .into_iter()
+            .chain(blends)
+            .chain(corner_faces)
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests;
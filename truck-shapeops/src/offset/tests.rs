@@ -0,0 +1,68 @@
+use std::f64::consts::PI;
+use truck_meshalgo::prelude::*;
+use truck_modeling::*;
+
+#[test]
+fn offset_sphere() {
+    let v = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+    let wire: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_x(), Rad(PI));
+    let shell = builder::cone(&wire, Vector3::unit_y(), Rad(7.0));
+    let sphere = Solid::new(vec![shell]);
+
+    for (distance, radius) in [(0.2, 1.2), (-0.3, 0.7)] {
+        let offset = crate::offset_solid(&sphere, distance, 0.01).unwrap();
+        assert_eq!(offset.boundaries().len(), 1);
+        assert_eq!(
+            offset.boundaries()[0].shell_condition(),
+            ShellCondition::Closed
+        );
+        let poly = offset.triangulation(0.01).to_polygon();
+        poly.positions().iter().for_each(|pt| {
+            let r = pt.to_vec().magnitude();
+            assert!(f64::abs(r - radius) < 0.01, "{distance} {r}");
+        });
+    }
+
+    // shrinking past the center
+    assert!(crate::offset_solid(&sphere, -1.5, 0.01).is_none());
+}
+
+#[test]
+fn offset_cube() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+
+    // The edges are blended by the arcs and the corners are rounded by the spheres.
+    let offset = crate::offset_solid(&cube, 0.1, 0.01).unwrap();
+    let shell = &offset.boundaries()[0];
+    assert_eq!(shell.len(), 6 + 12 + 8);
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+    let poly = offset.triangulation(0.01).to_polygon();
+    poly.positions().iter().for_each(|pt| {
+        let gap = pt.to_vec().map(|x| f64::max(f64::max(-x, x - 1.0), 0.0));
+        assert!(f64::abs(gap.magnitude() - 0.1) < 0.01, "{pt:?}");
+    });
+    let volume = offset.volume(0.01);
+    let rounded = 1.0 + 6.0 * 0.1 + 3.0 * PI * 0.1 * 0.1 + 4.0 / 3.0 * PI * 0.1 * 0.1 * 0.1;
+    assert!(f64::abs(volume - rounded) < 0.01, "{volume}");
+
+    // The faces are trimmed at their intersections and the corners stay sharp.
+    let offset = crate::offset_solid(&cube, -0.1, 0.01).unwrap();
+    let shell = &offset.boundaries()[0];
+    assert_eq!(shell.len(), 6);
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+    let poly = offset.triangulation(0.01).to_polygon();
+    poly.positions().iter().for_each(|pt| {
+        let dist = pt.to_vec().map(|x| f64::abs(x - 0.5));
+        assert!(
+            f64::abs(dist.x.max(dist.y).max(dist.z) - 0.4) < 0.01,
+            "{pt:?}"
+        );
+    });
+    assert!(f64::abs(offset.volume(0.01) - 0.8 * 0.8 * 0.8) < 0.01);
+
+    // shrinking past the center
+    assert!(crate::offset_solid(&cube, -0.6, 0.01).is_none());
+}
@@ -0,0 +1,299 @@
+use crate::*;
+use truck_geometry::prelude::*;
+use truck_meshalgo::prelude::*;
+use truck_topology::*;
+
+/// Returns a cuboid whose top face is on `plane` and which contains the part of `solid` below `plane`.
+///
+/// Returns `None` if the solid does not cross the plane.
+fn half_space<C, S>(
+    solid: &Solid<Point3, C, S>,
+    plane: &Plane,
+    tol: f64,
+) -> Option<Solid<Point3, C, S>>
+where
+    C: ShapeOpsCurve<S> + From<Line<Point3>>,
+    S: ShapeOpsSurface + From<Plane>,
+{
+    let bdb = solid.triangulation(tol).to_polygon().bounding_box();
+    let (center, r) = (bdb.center(), bdb.diameter());
+    let (u, v, n) = (
+        plane.u_axis().normalize(),
+        plane.normal().cross(plane.u_axis()).normalize(),
+        plane.normal(),
+    );
+    let dist = (center - plane.origin()).dot(n);
+    if dist.abs() > r / 2.0 + tol {
+        return None;
+    }
+    let c = center - n * dist;
+    let top_pts = [(-r, -r), (r, -r), (r, r), (-r, r)].map(|(x, y)| c + u * x + v * y);
+    let bottom_pts = top_pts.map(|p| p - n * 2.0 * r);
+    let top = top_pts.map(Vertex::new);
+    let bottom = bottom_pts.map(Vertex::new);
+    let line = |v0: &Vertex<Point3>, v1: &Vertex<Point3>| -> Edge<Point3, C> {
+        Edge::new(v0, v1, Line(v0.point(), v1.point()).into())
+    };
+    let top_edges: [_; 4] = std::array::from_fn(|i| line(&top[i], &top[(i + 1) % 4]));
+    let bottom_edges: [_; 4] = std::array::from_fn(|i| line(&bottom[i], &bottom[(i + 1) % 4]));
+    let vertical_edges: [_; 4] = std::array::from_fn(|i| line(&bottom[i], &top[i]));
+    let mut shell: Shell<Point3, C, S> = (0..4)
+        .map(|i| {
+            let j = (i + 1) % 4;
+            let wire = Wire::from(vec![
+                bottom_edges[i].clone(),
+                vertical_edges[j].clone(),
+                top_edges[i].inverse(),
+                vertical_edges[i].inverse(),
+            ]);
+            let surface = Plane::new(bottom_pts[i], bottom_pts[j], top_pts[i]);
+            Face::new(vec![wire], surface.into())
+        })
+        .collect();
+    let top_wire = Wire::from_iter(&top_edges);
+    let top_surface = Plane::new(top_pts[0], top_pts[1], top_pts[3]);
+    shell.push(Face::new(vec![top_wire], top_surface.into()));
+    let bottom_wire = Wire::from_iter(bottom_edges.iter().rev().map(Edge::inverse));
+    let bottom_surface = Plane::new(bottom_pts[0], bottom_pts[3], bottom_pts[1]);
+    shell.push(Face::new(vec![bottom_wire], bottom_surface.into()));
+    Some(Solid::new(vec![shell]))
+}
+
+/// Returns whether all the points of `mesh` are on `plane`.
+fn is_on_plane(mesh: &PolygonMesh, plane: &Plane, tol: f64) -> bool {
+    let (o, n) = (plane.origin(), plane.normal());
+    mesh.positions().iter().all(|p| (p - o).dot(n).abs() < tol)
+}
+
+/// Returns the signed area of the polyline loop `wire` with respect to the normal of `plane`.
+fn signed_area(wire: &Wire<Point3, PolylineCurve<Point3>>, plane: &Plane) -> f64 {
+    let (o, n) = (plane.origin(), plane.normal());
+    wire.edge_iter()
+        .map(|edge| {
+            let curve = edge.oriented_curve();
+            curve
+                .windows(2)
+                .map(|p| (p[0] - o).cross(p[1] - o).dot(n) / 2.0)
+                .sum::<f64>()
+        })
+        .sum()
+}
+
+/// Returns the boundaries of the faces of `solid` lying on `plane` with their signed areas.
+///
+/// The boundaries of each face are inverted if the face is clockwise with respect to the normal
+/// of `plane`, so that the outer boundaries are counter-clockwise and the holes are clockwise.
+fn boundaries_on_plane<C, S>(
+    solid: &Solid<Point3, C, S>,
+    plane: &Plane,
+    tol: f64,
+) -> Vec<(Wire<Point3, C>, f64)>
+where
+    C: ShapeOpsCurve<S>,
+    S: ShapeOpsSurface,
+{
+    let meshed = solid.triangulation(tol);
+    solid
+        .face_iter()
+        .zip(meshed.face_iter())
+        .filter(|(_, meshed_face)| {
+            matches!(meshed_face.surface(), Some(mesh) if is_on_plane(&mesh, plane, tol))
+        })
+        .flat_map(|(face, meshed_face)| {
+            let areas = meshed_face
+                .boundaries()
+                .iter()
+                .map(|wire| signed_area(wire, plane))
+                .collect::<Vec<_>>();
+            let clockwise = areas.iter().sum::<f64>() < 0.0;
+            face.boundaries()
+                .into_iter()
+                .zip(areas)
+                .map(move |(mut wire, area)| match clockwise {
+                    true => {
+                        wire.invert();
+                        (wire, -area)
+                    }
+                    false => (wire, area),
+                })
+        })
+        .collect()
+}
+
+/// Returns whether the oriented loops `(wire0, area0)` and `(wire1, area1)` are the same.
+fn same_loop<C>(
+    (wire0, area0): &(Wire<Point3, C>, f64),
+    (wire1, area1): &(Wire<Point3, C>, f64),
+    tol: f64,
+) -> bool {
+    wire0.len() == wire1.len()
+        && area0 * area1 > 0.0
+        && wire0.vertex_iter().all(|v| {
+            wire1
+                .vertex_iter()
+                .any(|w| v.point().distance(w.point()) < tol)
+        })
+}
+
+/// Returns the boundaries of the faces of the part of `solid` below `plane` lying on `plane`.
+///
+/// Returns `None` if the set operation fails.
+fn crossing_boundaries<C, S>(
+    solid: &Solid<Point3, C, S>,
+    plane: &Plane,
+    tol: f64,
+) -> Option<Vec<(Wire<Point3, C>, f64)>>
+where
+    C: ShapeOpsCurve<S> + From<Line<Point3>>,
+    S: ShapeOpsSurface + From<Plane>,
+{
+    let Some(half_space) = half_space(solid, plane, tol) else {
+        return Some(Vec::new());
+    };
+    let lower = and(solid, &half_space, tol)?;
+    Some(boundaries_on_plane(&lower, plane, tol))
+}
+
+/// Returns the crossing loops of `solid` by the plane translated by `offset` along its normal,
+/// moved back onto `plane`.
+fn shifted_crossing_boundaries<C, S>(
+    solid: &Solid<Point3, C, S>,
+    plane: &Plane,
+    offset: f64,
+    tol: f64,
+) -> Option<Vec<(Wire<Point3, C>, f64)>>
+where
+    C: ShapeOpsCurve<S> + From<Line<Point3>> + Transformed<Matrix4>,
+    S: ShapeOpsSurface + From<Plane>,
+{
+    let n = plane.normal();
+    let shifted = plane.transformed(Matrix4::from_translation(n * offset));
+    let mat = Matrix4::from_translation(-n * offset);
+    let boundaries = crossing_boundaries(solid, &shifted, tol)?;
+    let boundaries = boundaries
+        .into_iter()
+        .map(|(wire, area)| {
+            let wire = wire.mapped(|p| mat.transform_point(*p), |c| c.transformed(mat));
+            (wire, area)
+        })
+        .collect();
+    Some(boundaries)
+}
+
+/// Returns the section of `solid` by `plane`.
+///
+/// # Details
+/// The section is the boundary of the intersection of the solid and the plane. Each closed loop of the section
+/// is returned as a separate wire, oriented counter-clockwise with respect to the normal of the plane, except
+/// that the wires bounding holes of the section are clockwise.
+///
+/// If some faces of the solid lie on the plane, the boundaries of these faces are returned together with
+/// the loops on which the rest of the solid crosses the plane, in the same orientation. Since the set
+/// operations fail for the faces in tangential contact, the crossing loops are calculated on the plane
+/// translated by `2.0 * tol` in this case, and moved back onto the plane.
+/// If the plane does not cross the solid, the returned vector is empty.
+///
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// let plane = Plane::new(
+///     Point3::new(0.0, 0.0, 0.5),
+///     Point3::new(1.0, 0.0, 0.5),
+///     Point3::new(0.0, 1.0, 0.5),
+/// );
+/// let wires = truck_shapeops::section(&cube, &plane, 0.05);
+/// assert_eq!(wires.len(), 1);
+/// assert_eq!(wires[0].len(), 4);
+/// assert!(wires[0].is_closed());
+/// ```
+pub fn section<C, S>(solid: &Solid<Point3, C, S>, plane: &Plane, tol: f64) -> Vec<Wire<Point3, C>>
+where
+    C: ShapeOpsCurve<S> + From<Line<Point3>> + Transformed<Matrix4>,
+    S: ShapeOpsSurface + From<Plane>, {
+    let tangent = boundaries_on_plane(solid, plane, tol);
+    let crossing = match tangent.is_empty() {
+        true => crossing_boundaries(solid, plane, tol),
+        false => shifted_crossing_boundaries(solid, plane, 2.0 * tol, tol)
+            .or_else(|| shifted_crossing_boundaries(solid, plane, -2.0 * tol, tol)),
+    }
+    .unwrap_or_default();
+    // The faces of the lower part on the plane include the tangent faces below the plane.
+    let crossing = crossing
+        .into_iter()
+        .filter(|wire| !tangent.iter().any(|t| same_loop(t, wire, tol)))
+        .collect::<Vec<_>>();
+    tangent
+        .into_iter()
+        .chain(crossing)
+        .map(|(wire, _)| wire)
+        .collect()
+}
+
+/// Splits `solid` by `plane` into two closed solids.
+///
+/// # Details
+/// Returns the part on the back side of the plane and the part on the front side, with respect to
+/// the normal of the plane. Both parts are capped by the planar faces on the section.
+///
+/// If the whole solid is on one side of the plane, including the case where the plane is tangent to
+/// the solid, returns `(Some(solid.clone()), None)`. Returns `(None, None)` if the set operation fails.
+///
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// let plane = Plane::new(
+///     Point3::new(0.0, 0.0, 2.0),
+///     Point3::new(1.0, 0.0, 2.0),
+///     Point3::new(0.0, 1.0, 2.0),
+/// );
+/// let (lower, upper) = truck_shapeops::split_by_plane(&cube, &plane, 0.05);
+/// assert_eq!(lower.unwrap().face_iter().count(), 6);
+/// assert!(upper.is_none());
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn split_by_plane<C, S>(
+    solid: &Solid<Point3, C, S>,
+    plane: &Plane,
+    tol: f64,
+) -> (Option<Solid<Point3, C, S>>, Option<Solid<Point3, C, S>>)
+where
+    C: ShapeOpsCurve<S> + From<Line<Point3>>,
+    S: ShapeOpsSurface + From<Plane>,
+{
+    let (o, n) = (plane.origin(), plane.normal());
+    let polygon = solid.triangulation(tol).to_polygon();
+    let (min, max) = polygon
+        .positions()
+        .iter()
+        .map(|p| (p - o).dot(n))
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), x| {
+            (f64::min(min, x), f64::max(max, x))
+        });
+    if max < tol || min > -tol {
+        return (Some(solid.clone()), None);
+    }
+    let Some(lower_half) = half_space(solid, plane, tol) else {
+        return (Some(solid.clone()), None);
+    };
+    let mut upper_half = lower_half.clone();
+    upper_half.not();
+    let lower = and(solid, &lower_half, tol).filter(|solid| !solid.boundaries().is_empty());
+    let upper = and(solid, &upper_half, tol).filter(|solid| !solid.boundaries().is_empty());
+    match (lower, upper) {
+        (Some(lower), Some(upper)) => (Some(lower), Some(upper)),
+        _ => (None, None),
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,143 @@
+use std::f64::consts::PI;
+use truck_meshalgo::prelude::*;
+use truck_modeling::*;
+
+#[test]
+fn sphere_section() {
+    let v = builder::vertex(Point3::new(0.0, 1.0, 0.0));
+    let wire: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_x(), Rad(PI));
+    let shell = builder::cone(&wire, Vector3::unit_y(), Rad(7.0));
+    let sphere = Solid::new(vec![shell]);
+
+    let plane = Plane::new(
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 0.0, -1.0),
+    );
+    let wires = crate::section(&sphere, &plane, 0.01);
+    assert_eq!(wires.len(), 1);
+    assert!(wires[0].is_closed());
+    wires[0].edge_iter().for_each(|edge| {
+        let curve = edge.curve();
+        let (_, pts) = curve.parameter_division(curve.range_tuple(), 0.01);
+        pts.into_iter().for_each(|pt| {
+            assert!(f64::abs(pt.y) < 0.01, "{pt:?}");
+            assert!(f64::abs(pt.to_vec().magnitude() - 1.0) < 0.01, "{pt:?}");
+        });
+    });
+
+    // the plane does not cross the sphere
+    let plane = Plane::new(
+        Point3::new(0.0, 2.0, 0.0),
+        Point3::new(1.0, 2.0, 0.0),
+        Point3::new(0.0, 2.0, -1.0),
+    );
+    assert!(crate::section(&sphere, &plane, 0.01).is_empty());
+}
+
+#[test]
+fn tangent_section() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+
+    let plane = Plane::new(
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(0.0, 1.0, 1.0),
+    );
+    let wires = crate::section(&cube, &plane, 0.01);
+    assert_eq!(wires.len(), 1);
+    assert_eq!(wires[0].len(), 4);
+    wires[0]
+        .vertex_iter()
+        .for_each(|v| assert_near!(v.point().z, 1.0));
+    assert_near!(signed_area(&wires[0], Vector3::unit_z()), 1.0);
+}
+
+/// Returns the signed area of the polygon given by the vertices of `wire`.
+fn signed_area(wire: &Wire, normal: Vector3) -> f64 {
+    let pts = wire.vertex_iter().map(|v| v.point()).collect::<Vec<_>>();
+    let o = pts[0];
+    pts.windows(2)
+        .map(|p| (p[0] - o).cross(p[1] - o).dot(normal) / 2.0)
+        .sum()
+}
+
+#[test]
+fn tangent_and_crossing_section() {
+    // a step whose lower top face is on the plane and whose upper part crosses the plane
+    let v = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(2.0, 0.0, 0.0),
+        Point3::new(2.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 2.0),
+        Point3::new(0.0, 0.0, 2.0),
+    ]
+    .map(builder::vertex);
+    let wire: Wire = (0..6)
+        .map(|i| builder::line(&v[i], &v[(i + 1) % 6]))
+        .collect();
+    let face: Face = builder::try_attach_plane(vec![wire]).unwrap();
+    let normal = face.oriented_surface().normal(0.0, 0.0);
+    let step: Solid = builder::tsweep(&face, normal);
+
+    let plane = Plane::new(
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(0.0, 1.0, 1.0),
+    );
+    let wires = crate::section(&step, &plane, 0.01);
+    assert_eq!(wires.len(), 2);
+    wires.iter().for_each(|wire| {
+        assert_eq!(wire.len(), 4);
+        assert!(wire.is_closed());
+        wire.vertex_iter()
+            .for_each(|v| assert_near!(v.point().z, 1.0));
+        // counter-clockwise with respect to the normal of the plane
+        assert_near!(signed_area(wire, Vector3::unit_z()), 1.0);
+    });
+}
+
+#[test]
+fn split_cube() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+
+    let plane = Plane::new(
+        Point3::new(0.0, 0.0, 0.5),
+        Point3::new(1.0, 0.0, 0.5),
+        Point3::new(0.0, 1.0, 0.5),
+    );
+    let (lower, upper) = crate::split_by_plane(&cube, &plane, 0.05);
+    let (lower, upper) = (lower.unwrap(), upper.unwrap());
+    [&lower, &upper].into_iter().for_each(|solid| {
+        assert_eq!(solid.boundaries().len(), 1);
+        assert_eq!(
+            solid.boundaries()[0].shell_condition(),
+            ShellCondition::Closed
+        );
+    });
+    lower
+        .vertex_iter()
+        .for_each(|v| assert!(v.point().z < 0.5 + TOLERANCE));
+    upper
+        .vertex_iter()
+        .for_each(|v| assert!(v.point().z > 0.5 - TOLERANCE));
+    assert_near!(lower.volume(0.01), 0.5);
+    assert_near!(lower.volume(0.01) + upper.volume(0.01), 1.0);
+
+    // the plane tangent to the cube
+    let plane = Plane::new(
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(1.0, 0.0, 1.0),
+        Point3::new(0.0, 1.0, 1.0),
+    );
+    let (lower, upper) = crate::split_by_plane(&cube, &plane, 0.05);
+    assert_eq!(lower.unwrap().face_iter().count(), 6);
+    assert!(upper.is_none());
+}
@@ -1,6 +1,8 @@
-use crate::alternative::Alternative;
+use crate::{alternative::Alternative, ShapeOpsError};
 
 use super::*;
+use itertools::Itertools;
+use std::result::Result;
 use truck_geometry::prelude::*;
 use truck_meshalgo::prelude::*;
 use truck_topology::*;
@@ -72,108 +74,393 @@ fn altshell_to_shell<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     )
 }
 
-fn process_one_pair_of_shells<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+/// Faces of two shells divided by the intersection curves, classified by their positions.
+struct ClassifiedFaces<C, S> {
+    /// faces of the first shell inside the second one
+    and0: AltCurveShell<C, S>,
+    /// faces of the first shell outside the second one
+    or0: AltCurveShell<C, S>,
+    /// faces of the second shell inside the first one
+    and1: AltCurveShell<C, S>,
+    /// faces of the second shell outside the first one
+    or1: AltCurveShell<C, S>,
+}
+
+fn classify_unknown_faces<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    unknown: AltCurveShell<C, S>,
+    and: &mut AltCurveShell<C, S>,
+    or: &mut AltCurveShell<C, S>,
+    poly_shell: &Shell<Point3, PolylineCurve<Point3>, Option<PolygonMesh>>,
+) -> Option<()> {
+    unknown.into_iter().try_for_each(|face| {
+        let pt = face.boundaries()[0].vertex_iter().next().unwrap().point();
+        let dir = hash::take_one_unit(pt);
+        let count = poly_shell.iter().try_fold(0, |count, face| {
+            let poly = face.surface()?;
+            Some(count + poly.signed_crossing_faces(pt, dir))
+        })?;
+        if count >= 1 {
+            and.push(face);
+        } else {
+            or.push(face);
+        }
+        Some(())
+    })
+}
+
+/// Whether the boundaries touch each other with parallel tangent planes, e.g. two cubes sharing a face.
+fn is_tangential<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    shell0: &Shell<Point3, C, S>,
+    shell1: &Shell<Point3, C, S>,
+    tol: f64,
+) -> bool {
+    let poly_shell0 = shell0.triangulation(tol);
+    let poly_shell1 = shell1.triangulation(tol);
+    let faces0 = shell0.face_iter().zip(poly_shell0.face_iter());
+    let faces1 = shell1.face_iter().zip(poly_shell1.face_iter());
+    faces0.cartesian_product(faces1.collect::<Vec<_>>()).any(
+        |((face0, poly_face0), (face1, poly_face1))| {
+            let (Some(poly0), Some(poly1)) = (poly_face0.surface(), poly_face1.surface()) else {
+                return false;
+            };
+            if !poly1.collide_with_neighborhood_of(poly0.positions(), tol) {
+                return false;
+            }
+            let (surface0, surface1) = (face0.surface(), face1.surface());
+            poly0.positions().iter().any(|pt| {
+                let Some((u1, v1)) = surface1.search_nearest_parameter(*pt, None, 10) else {
+                    return false;
+                };
+                let Some((u0, v0)) = surface0.search_parameter(*pt, None, 10) else {
+                    return false;
+                };
+                let (n0, n1) = (surface0.normal(u0, v0), surface1.normal(u1, v1));
+                surface1.subs(u1, v1).distance(*pt) < tol && n0.cross(n1).magnitude() < tol
+            })
+        },
+    )
+}
+
+/// Returns [`ShapeOpsError::TangentialContact`] if the boundaries touch each other tangentially,
+/// otherwise `error`.
+fn diagnose<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    shell0: &Shell<Point3, C, S>,
+    shell1: &Shell<Point3, C, S>,
+    tol: f64,
+    error: ShapeOpsError,
+) -> ShapeOpsError {
+    match is_tangential(shell0, shell1, tol) {
+        true => ShapeOpsError::TangentialContact,
+        false => error,
+    }
+}
+
+/// The number of the stages reported by [`Progress`] for each pair of shells.
+const STAGES_PER_PAIR: usize = 5;
+
+/// Reports the progress of the stages to the callback, and checks the cancellation.
+struct Progress<'a> {
+    callback: Option<&'a dyn Fn(f32) -> bool>,
+    done: usize,
+    total: usize,
+}
+
+impl<'a> Progress<'a> {
+    /// Reports the start of `total` stages.
+    fn start(
+        callback: Option<&'a dyn Fn(f32) -> bool>,
+        total: usize,
+    ) -> Result<Self, ShapeOpsError> {
+        let progress = Self {
+            callback,
+            done: 0,
+            total,
+        };
+        progress.report()?;
+        Ok(progress)
+    }
+
+    /// Progress without callback.
+    fn none() -> Self {
+        Self {
+            callback: None,
+            done: 0,
+            total: 1,
+        }
+    }
+
+    /// Reports the end of a stage.
+    fn step(&mut self) -> Result<(), ShapeOpsError> {
+        self.done = usize::min(self.done + 1, self.total);
+        self.report()
+    }
+
+    fn report(&self) -> Result<(), ShapeOpsError> {
+        match self.callback {
+            Some(callback) if !callback(self.done as f32 / self.total as f32) => {
+                Err(ShapeOpsError::Cancelled)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+fn classify_faces<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     shell0: &Shell<Point3, C, S>,
     shell1: &Shell<Point3, C, S>,
     tol: f64,
-) -> Option<[Shell<Point3, C, S>; 2]> {
+    progress: &mut Progress<'_>,
+) -> Result<ClassifiedFaces<C, S>, ShapeOpsError> {
     nonpositive_tolerance!(tol);
     let poly_shell0 = shell0.triangulation(tol);
     let poly_shell1 = shell1.triangulation(tol);
+    progress.step()?;
     let altshell0: AltCurveShell<C, S> =
         shell0.mapped(|x| *x, |c| Alternative::FirstType(c.clone()), Clone::clone);
     let altshell1: AltCurveShell<C, S> =
         shell1.mapped(|x| *x, |c| Alternative::FirstType(c.clone()), Clone::clone);
+    let no_intersection = || diagnose(shell0, shell1, tol, ShapeOpsError::NoIntersection);
     let loops_store::LoopsStoreQuadruple {
         geom_loops_store0: loops_store0,
         geom_loops_store1: loops_store1,
         ..
-    } = loops_store::create_loops_stores(&altshell0, &poly_shell0, &altshell1, &poly_shell1)?;
-    let mut cls0 = divide_face::divide_faces(&altshell0, &loops_store0, tol)?;
+    } = loops_store::create_loops_stores(&altshell0, &poly_shell0, &altshell1, &poly_shell1)
+        .ok_or_else(no_intersection)?;
+    progress.step()?;
+    let mut cls0 =
+        divide_face::divide_faces(&altshell0, &loops_store0, tol).ok_or_else(no_intersection)?;
     cls0.integrate_by_component();
-    let mut cls1 = divide_face::divide_faces(&altshell1, &loops_store1, tol)?;
+    let mut cls1 =
+        divide_face::divide_faces(&altshell1, &loops_store1, tol).ok_or_else(no_intersection)?;
     cls1.integrate_by_component();
+    progress.step()?;
     let [mut and0, mut or0, unknown0] = cls0.and_or_unknown();
-    unknown0.into_iter().try_for_each(|face| {
-        let pt = face.boundaries()[0].vertex_iter().next().unwrap().point();
-        let dir = hash::take_one_unit(pt);
-        let count = poly_shell1.iter().try_fold(0, |count, face| {
-            let poly = face.surface()?;
-            Some(count + poly.signed_crossing_faces(pt, dir))
-        })?;
-        if count >= 1 {
-            and0.push(face);
-        } else {
-            or0.push(face);
-        }
-        Some(())
-    })?;
+    classify_unknown_faces(unknown0, &mut and0, &mut or0, &poly_shell1)
+        .ok_or(ShapeOpsError::ToleranceTooTight)?;
     let [mut and1, mut or1, unknown1] = cls1.and_or_unknown();
-    unknown1.into_iter().try_for_each(|face| {
-        let pt = face.boundaries()[0].vertex_iter().next().unwrap().point();
-        let dir = hash::take_one_unit(pt);
-        let count = poly_shell0.iter().try_fold(0, |count, face| {
-            let poly = face.surface()?;
-            Some(count + poly.signed_crossing_faces(pt, dir))
-        })?;
-        if count >= 1 {
-            and1.push(face);
-        } else {
-            or1.push(face);
-        }
-        Some(())
-    })?;
+    classify_unknown_faces(unknown1, &mut and1, &mut or1, &poly_shell0)
+        .ok_or(ShapeOpsError::ToleranceTooTight)?;
+    progress.step()?;
+    Ok(ClassifiedFaces {
+        and0,
+        or0,
+        and1,
+        or1,
+    })
+}
+
+fn process_one_pair_of_shells<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    shell0: &Shell<Point3, C, S>,
+    shell1: &Shell<Point3, C, S>,
+    tol: f64,
+    progress: &mut Progress<'_>,
+) -> Result<[Shell<Point3, C, S>; 2], ShapeOpsError> {
+    let ClassifiedFaces {
+        mut and0,
+        mut or0,
+        mut and1,
+        mut or1,
+    } = classify_faces(shell0, shell1, tol, progress)?;
     and0.append(&mut and1);
     or0.append(&mut or1);
-    Some([
-        altshell_to_shell(&and0, tol)?,
-        altshell_to_shell(&or0, tol)?,
-    ])
+    let and_shell = altshell_to_shell(&and0, tol).ok_or(ShapeOpsError::ToleranceTooTight)?;
+    let or_shell = altshell_to_shell(&or0, tol).ok_or(ShapeOpsError::ToleranceTooTight)?;
+    progress.step()?;
+    Ok([and_shell, or_shell])
 }
 
-/// AND operation between two solids.
-pub fn and<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+/// Processes the shells of two solids in turn, and returns the connected components of the
+/// `I`th result of [`process_one_pair_of_shells`], i.e. AND for `I == 0` and OR for `I == 1`.
+fn integrate_solids<C: ShapeOpsCurve<S>, S: ShapeOpsSurface, const I: usize>(
     solid0: &Solid<Point3, C, S>,
     solid1: &Solid<Point3, C, S>,
     tol: f64,
-) -> Option<Solid<Point3, C, S>> {
+    callback: Option<&dyn Fn(f32) -> bool>,
+) -> Result<Solid<Point3, C, S>, ShapeOpsError> {
+    let pairs = solid0.boundaries().len() + solid1.boundaries().len() - 1;
+    let mut progress = Progress::start(callback, pairs * STAGES_PER_PAIR)?;
     let mut iter0 = solid0.boundaries().iter();
     let mut iter1 = solid1.boundaries().iter();
     let shell0 = iter0.next().unwrap();
     let shell1 = iter1.next().unwrap();
-    let [mut and_shell, _] = process_one_pair_of_shells(shell0, shell1, tol)?;
-    for shell in iter0 {
-        let [res, _] = process_one_pair_of_shells(&and_shell, shell, tol)?;
-        and_shell = res;
-    }
-    for shell in iter1 {
-        let [res, _] = process_one_pair_of_shells(&and_shell, shell, tol)?;
-        and_shell = res;
+    let res = process_one_pair_of_shells(shell0, shell1, tol, &mut progress)?;
+    let mut shell = res.into_iter().nth(I).unwrap();
+    for other in iter0.chain(iter1) {
+        let res = process_one_pair_of_shells(&shell, other, tol, &mut progress)?;
+        shell = res.into_iter().nth(I).unwrap();
     }
-    let boundaries = and_shell.connected_components();
-    Some(Solid::new(boundaries))
+    let boundaries = shell.connected_components();
+    Solid::try_new(boundaries)
+        .map_err(|_| diagnose(shell0, shell1, tol, ShapeOpsError::NonManifoldResult))
+}
+
+/// AND operation between two solids.
+#[inline(always)]
+pub fn and<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Option<Solid<Point3, C, S>> {
+    and_with_report(solid0, solid1, tol).ok()
+}
+
+/// AND operation between two solids, reporting the cause of the failure.
+///
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use truck_shapeops::ShapeOpsError;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube0: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// // the cubes share a face.
+/// let cube1 = builder::translated(&cube0, Vector3::unit_x());
+/// let result = truck_shapeops::and_with_report(&cube0, &cube1, 0.05);
+/// assert_eq!(result.unwrap_err(), ShapeOpsError::TangentialContact);
+/// ```
+pub fn and_with_report<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Result<Solid<Point3, C, S>, ShapeOpsError> {
+    integrate_solids::<C, S, 0>(solid0, solid1, tol, None)
+}
+
+/// AND operation between two solids, reporting the progress to `progress`.
+///
+/// `progress` is called with the fraction of the finished stages, which increases monotonically
+/// from `0.0` to `1.0`. If `progress` returns `false`, the operation is aborted as soon as
+/// the current stage ends, and [`ShapeOpsError::Cancelled`] is returned. The other failures are
+/// reported as in [`and_with_report`]. The input solids are not changed in any case.
+pub fn and_with_progress<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+    progress: &dyn Fn(f32) -> bool,
+) -> Result<Solid<Point3, C, S>, ShapeOpsError> {
+    integrate_solids::<C, S, 0>(solid0, solid1, tol, Some(progress))
 }
 
 /// OR operation between two solids.
+#[inline(always)]
 pub fn or<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     solid0: &Solid<Point3, C, S>,
     solid1: &Solid<Point3, C, S>,
     tol: f64,
 ) -> Option<Solid<Point3, C, S>> {
-    let mut iter0 = solid0.boundaries().iter();
-    let mut iter1 = solid1.boundaries().iter();
-    let shell0 = iter0.next().unwrap();
-    let shell1 = iter1.next().unwrap();
-    let [_, mut or_shell] = process_one_pair_of_shells(shell0, shell1, tol)?;
-    for shell in iter0 {
-        let [_, res] = process_one_pair_of_shells(&or_shell, shell, tol)?;
-        or_shell = res;
-    }
-    for shell in iter1 {
-        let [_, res] = process_one_pair_of_shells(&or_shell, shell, tol)?;
-        or_shell = res;
-    }
-    let boundaries = or_shell.connected_components();
+    or_with_report(solid0, solid1, tol).ok()
+}
+
+/// OR operation between two solids, reporting the cause of the failure.
+///
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use truck_shapeops::ShapeOpsError;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube0: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// // the cubes share a face.
+/// let cube1 = builder::translated(&cube0, Vector3::unit_x());
+/// let result = truck_shapeops::or_with_report(&cube0, &cube1, 0.05);
+/// assert_eq!(result.unwrap_err(), ShapeOpsError::TangentialContact);
+///
+/// // the cubes overlap.
+/// let cube2 = builder::translated(&cube0, Vector3::new(0.5, 0.5, 0.5));
+/// let or = truck_shapeops::or_with_report(&cube0, &cube2, 0.05).unwrap();
+/// assert_eq!(or.boundaries().len(), 1);
+/// ```
+pub fn or_with_report<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Result<Solid<Point3, C, S>, ShapeOpsError> {
+    integrate_solids::<C, S, 1>(solid0, solid1, tol, None)
+}
+
+/// OR operation between two solids, reporting the progress to `progress`.
+///
+/// cf. [`and_with_progress`]
+///
+/// # Examples
+/// ```
+/// use std::cell::RefCell;
+/// use truck_modeling::*;
+/// use truck_shapeops::ShapeOpsError;
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube0: Solid = builder::tsweep(&f, Vector3::unit_z());
+/// let cube1 = builder::translated(&cube0, Vector3::new(0.5, 0.5, 0.5));
+///
+/// let reported = RefCell::new(Vec::new());
+/// let progress = |x: f32| {
+///     reported.borrow_mut().push(x);
+///     true
+/// };
+/// let or = truck_shapeops::or_with_progress(&cube0, &cube1, 0.05, &progress).unwrap();
+/// assert_eq!(or.boundaries().len(), 1);
+/// let reported = reported.into_inner();
+/// assert!(reported.windows(2).all(|x| x[0] <= x[1]));
+/// assert_eq!(reported.last(), Some(&1.0));
+///
+/// // cancel at the first report
+/// let cancel = |_: f32| false;
+/// let result = truck_shapeops::or_with_progress(&cube0, &cube1, 0.05, &cancel);
+/// assert_eq!(result.unwrap_err(), ShapeOpsError::Cancelled);
+/// ```
+pub fn or_with_progress<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+    progress: &dyn Fn(f32) -> bool,
+) -> Result<Solid<Point3, C, S>, ShapeOpsError> {
+    integrate_solids::<C, S, 1>(solid0, solid1, tol, Some(progress))
+}
+
+/// XOR operation, the symmetric difference, between two solids.
+///
+/// # Details
+/// The result consists of the difference `solid0 - solid1` and the difference `solid1 - solid0`,
+/// which share the intersection curves of the boundaries. If the solids do not overlap, the result
+/// is the solid having both boundaries.
+///
+/// The intersection of the boundaries is calculated only once if both solids have only one boundary shell.
+pub fn xor<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Option<Solid<Point3, C, S>> {
+    let (diff0, diff1) = match (
+        solid0.boundaries().as_slice(),
+        solid1.boundaries().as_slice(),
+    ) {
+        ([shell0], [shell1]) => {
+            let ClassifiedFaces {
+                and0,
+                mut or0,
+                and1,
+                mut or1,
+            } = classify_faces(shell0, shell1, tol, &mut Progress::none()).ok()?;
+            or0.extend(and1.face_iter().map(Face::inverse));
+            or1.extend(and0.face_iter().map(Face::inverse));
+            (altshell_to_shell(&or0, tol)?, altshell_to_shell(&or1, tol)?)
+        }
+        _ => {
+            let (mut not0, mut not1) = (solid0.clone(), solid1.clone());
+            not0.not();
+            not1.not();
+            let mut boundaries = and(solid0, &not1, tol)?.into_boundaries();
+            boundaries.extend(and(solid1, &not0, tol)?.into_boundaries());
+            return Some(Solid::new(boundaries));
+        }
+    };
+    let mut boundaries = diff0.connected_components();
+    boundaries.extend(diff1.connected_components());
     Some(Solid::new(boundaries))
 }
 
@@ -19,3 +19,68 @@ fn punched_cube() {
     let file = std::fs::File::create("punched-cube.obj").unwrap();
     obj::write(&poly, file).unwrap();
 }
+
+#[test]
+fn xor_cubes() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube0: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let cube1 = builder::translated(&cube0, Vector3::new(0.5, 0.5, 0.5));
+
+    let xor = crate::xor(&cube0, &cube1, 0.05).unwrap();
+    // two L-shaped lobes touching at the boundary of the overlap
+    assert_eq!(xor.boundaries().len(), 2);
+    assert_near!(xor.volume(0.01), 2.0 - 0.25);
+
+    // non-overlapping solids
+    let cube2 = builder::translated(&cube0, Vector3::new(2.0, 0.0, 0.0));
+    let xor = crate::xor(&cube0, &cube2, 0.05).unwrap();
+    assert_eq!(xor.boundaries().len(), 2);
+    assert_near!(xor.volume(0.01), 2.0);
+}
+
+#[test]
+fn touching_cubes() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube0: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let cube1 = builder::translated(&cube0, Vector3::unit_x());
+
+    let res = crate::or_with_report(&cube0, &cube1, 0.05);
+    assert_eq!(res.unwrap_err(), crate::ShapeOpsError::TangentialContact);
+    let res = crate::and_with_report(&cube0, &cube1, 0.05);
+    assert_eq!(res.unwrap_err(), crate::ShapeOpsError::TangentialContact);
+    assert!(crate::or(&cube0, &cube1, 0.05).is_none());
+}
+
+#[test]
+fn cancelled_and() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube0: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let cube1 = builder::translated(&cube0, Vector3::new(0.5, 0.5, 0.5));
+
+    let reported = std::cell::RefCell::new(Vec::new());
+    let progress = |x: f32| {
+        reported.borrow_mut().push(x);
+        true
+    };
+    let and = crate::and_with_progress(&cube0, &cube1, 0.05, &progress).unwrap();
+    assert_near!(and.volume(0.01), 0.125);
+    assert_eq!(reported.take(), vec![0.0, 0.2, 0.4, 0.6, 0.8, 1.0]);
+
+    // The operation stops at the first report after the cancellation.
+    let progress = |x: f32| {
+        reported.borrow_mut().push(x);
+        x < 0.5
+    };
+    let res = crate::and_with_progress(&cube0, &cube1, 0.05, &progress);
+    assert_eq!(res.unwrap_err(), crate::ShapeOpsError::Cancelled);
+    assert_eq!(reported.take(), vec![0.0, 0.2, 0.4, 0.6]);
+    // The inputs are not changed.
+    assert_near!(cube0.volume(0.01), 1.0);
+    assert_near!(cube1.volume(0.01), 1.0);
+}
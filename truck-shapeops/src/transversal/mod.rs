@@ -4,4 +4,7 @@ mod integrate;
 mod intersection_curve;
 mod loops_store;
 mod polyline_construction;
-pub use integrate::{and, or, ShapeOpsCurve, ShapeOpsSurface};
+pub use integrate::{
+    and, and_with_progress, and_with_report, or, or_with_progress, or_with_report, xor,
+    ShapeOpsCurve, ShapeOpsSurface,
+};
@@ -0,0 +1,142 @@
+use std::f64::consts::PI;
+use truck_meshalgo::prelude::*;
+use truck_modeling::*;
+use truck_shapeops::fillet_edges;
+
+fn unit_cube() -> Solid {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    builder::tsweep(&f, Vector3::unit_z())
+}
+
+fn edge_ids_by_midpoints(solid: &Solid, midpoints: &[[f64; 3]]) -> Vec<EdgeID> {
+    midpoints
+        .iter()
+        .map(|midpoint| {
+            let midpoint = Point3::from(*midpoint);
+            solid
+                .edge_iter()
+                .find(|edge| {
+                    let (p, q) = (edge.front().point(), edge.back().point());
+                    p.midpoint(q).near(&midpoint)
+                })
+                .unwrap()
+                .id()
+        })
+        .collect()
+}
+
+/// Returns whether `solid` has a face on the sphere with `center` and `radius`.
+fn has_spherical_face(solid: &Solid, center: Point3, radius: f64) -> bool {
+    let meshed = solid.triangulation(0.001);
+    let is_spherical = meshed.face_iter().any(|face| {
+        let mesh = face.surface().unwrap();
+        mesh.positions()
+            .iter()
+            .all(|p| f64::abs(p.distance(center) - radius) < 1.0e-3)
+    });
+    is_spherical
+}
+
+fn assert_filleted(solid: &Solid, faces: usize, volume: f64) {
+    let shell = &solid.boundaries()[0];
+    assert_eq!(shell.len(), faces);
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+    let result = solid.volume(0.001);
+    assert!(f64::abs(result - volume) < 1.0e-3, "{result} {volume}");
+}
+
+#[test]
+fn fillet_one_edge() {
+    let cube = unit_cube();
+    let edges = edge_ids_by_midpoints(&cube, &[[0.5, 0.0, 1.0]]);
+    let radius = 0.3;
+    let solid = fillet_edges(&cube, &edges, radius, 1.0e-4).unwrap();
+    let volume = 1.0 - (1.0 - PI / 4.0) * radius * radius;
+    assert_filleted(&solid, 7, volume);
+}
+
+#[test]
+fn fillet_adjacent_edges() {
+    let cube = unit_cube();
+    let edges = edge_ids_by_midpoints(&cube, &[[0.5, 0.0, 1.0], [1.0, 0.5, 1.0]]);
+    let radius = 0.2;
+    let solid = fillet_edges(&cube, &edges, radius, 1.0e-4).unwrap();
+    // The eighth of the ball remains at the shared vertex.
+    let volume = 1.0
+        - 2.0 * (1.0 - PI / 4.0) * radius * radius * (1.0 - radius)
+        - (1.0 - PI / 6.0) * radius * radius * radius;
+    // 6 faces, 2 fillets, the spherical corner and the plane filling the gap to the sharp edge
+    assert_filleted(&solid, 10, volume);
+    let center = Point3::new(1.0 - radius, radius, 1.0 - radius);
+    assert!(has_spherical_face(&solid, center, radius));
+}
+
+#[test]
+fn fillet_closed_loop() {
+    let cube = unit_cube();
+    let midpoints = [
+        [0.5, 0.0, 1.0],
+        [1.0, 0.5, 1.0],
+        [0.5, 1.0, 1.0],
+        [0.0, 0.5, 1.0],
+    ];
+    let edges = edge_ids_by_midpoints(&cube, &midpoints);
+    let radius = 0.2;
+    let solid = fillet_edges(&cube, &edges, radius, 1.0e-4).unwrap();
+    let volume = 1.0
+        - 4.0 * (1.0 - PI / 4.0) * radius * radius * (1.0 - 2.0 * radius)
+        - 4.0 * (1.0 - PI / 6.0) * radius * radius * radius;
+    // 6 faces, 4 fillets, and the spherical corners with the planes at 4 vertices
+    assert_filleted(&solid, 18, volume);
+}
+
+#[test]
+fn fillet_corner() {
+    let cube = unit_cube();
+    let midpoints = [[1.0, 1.0, 0.5], [1.0, 0.5, 1.0], [0.5, 1.0, 1.0]];
+    let edges = edge_ids_by_midpoints(&cube, &midpoints);
+    let radius = 0.2;
+    let solid = fillet_edges(&cube, &edges, radius, 1.0e-4).unwrap();
+    // The eighth of the ball remains at the corner.
+    let volume = 1.0
+        - 3.0 * (1.0 - PI / 4.0) * radius * radius * (1.0 - radius)
+        - (1.0 - PI / 6.0) * radius * radius * radius;
+    assert_filleted(&solid, 10, volume);
+}
+
+#[test]
+fn fillet_all_edges() {
+    let cube = unit_cube();
+    let edges = cube.edge_iter().map(|edge| edge.id()).collect::<Vec<_>>();
+    let radius = 0.1;
+    let solid = fillet_edges(&cube, &edges, radius, 1.0e-4).unwrap();
+    let volume = 1.0
+        - 12.0 * (1.0 - PI / 4.0) * radius * radius * (1.0 - 2.0 * radius)
+        - 8.0 * (1.0 - PI / 6.0) * radius * radius * radius;
+    // 6 faces, 12 fillets and 8 corners
+    assert_filleted(&solid, 26, volume);
+}
+
+#[test]
+fn filleted_cube_is_closed() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x() * 2.0);
+    let f = builder::tsweep(&e, Vector3::unit_y() * 2.0);
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z() * 2.0);
+    let edge_id = cube.edge_iter().next().unwrap().id();
+    let solid = fillet_edges(&cube, &[edge_id], 1.0, 1.0e-4).unwrap();
+    let mut poly = solid.triangulation(0.01).to_polygon();
+    poly.put_together_same_attrs(TOLERANCE * 2.0)
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    assert_eq!(poly.shell_condition(), ShellCondition::Closed);
+}
+
+#[test]
+fn fillet_too_large() {
+    let cube = unit_cube();
+    let edges = edge_ids_by_midpoints(&cube, &[[0.5, 0.0, 1.0]]);
+    assert!(fillet_edges(&cube, &edges, 1.5, 1.0e-4).is_none());
+}
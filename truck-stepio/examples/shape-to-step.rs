@@ -33,7 +33,7 @@ fn main() {
     let step_string = out::CompleteStepDisplay::new(
         out::StepModel::from(&compressed),
         out::StepHeaderDescriptor {
-            organization_system: "shape-to-step".to_owned(),
+            originating_system: Some("shape-to-step".to_owned()),
             ..Default::default()
         },
     )
@@ -4,14 +4,17 @@
 pub use ruststep;
 
 use ruststep::{
-    ast::{DataSection, EntityInstance, Name, Parameter, SubSuperRecord},
+    ast::{DataSection, EntityInstance, Exchange, Name, Parameter, SubSuperRecord},
     primitive::Logical,
     tables::{EntityTable, IntoOwned, PlaceHolder},
     Holder,
 };
 use serde::{Deserialize, Serialize};
 use std::result::Result;
-use std::{collections::HashMap, f64::consts::PI};
+use std::{
+    collections::{HashMap, HashSet},
+    f64::consts::PI,
+};
 use truck_geometry::prelude as truck;
 use truck_topology::compress::*;
 
@@ -70,6 +73,13 @@ pub struct Table {
     pub oriented_face: HashMap<u64, OrientedFaceHolder>,
     pub shell: HashMap<u64, ShellHolder>,
     pub oriented_shell: HashMap<u64, OrientedShellHolder>,
+    pub manifold_solid_brep: HashMap<u64, ManifoldSolidBrepHolder>,
+    pub brep_with_voids: HashMap<u64, BrepWithVoidsHolder>,
+
+    // presentation
+    pub styled_item: HashMap<u64, StyledItemHolder>,
+    pub presentation_style: HashMap<u64, PresentationStyleHolder>,
+    pub colour_rgb: HashMap<u64, ColourRgbHolder>,
 
     // others
     pub definitional_representation: HashMap<u64, DefinitionalRepresentationHolder>,
@@ -156,6 +166,10 @@ impl Table {
                     self.surface_curve
                         .insert(*id, Deserialize::deserialize(&record.parameter)?);
                 }
+                "INTERSECTION_CURVE" => {
+                    self.surface_curve
+                        .insert(*id, Deserialize::deserialize(&record.parameter)?);
+                }
                 "PLANE" => {
                     self.plane.insert(*id, Deserialize::deserialize(record)?);
                 }
@@ -292,6 +306,77 @@ impl Table {
                         }
                     }
                 }
+                "MANIFOLD_SOLID_BREP" => {
+                    self.manifold_solid_brep
+                        .insert(*id, Deserialize::deserialize(record)?);
+                }
+                "BREP_WITH_VOIDS" => {
+                    self.brep_with_voids
+                        .insert(*id, Deserialize::deserialize(record)?);
+                }
+                "STYLED_ITEM" | "OVER_RIDING_STYLED_ITEM" => {
+                    if let Parameter::List(params) = &record.parameter {
+                        if params.len() >= 3 {
+                            self.styled_item.insert(
+                                *id,
+                                StyledItemHolder {
+                                    label: Deserialize::deserialize(&params[0])?,
+                                    styles: Deserialize::deserialize(&params[1])?,
+                                    item: Deserialize::deserialize(&params[2])?,
+                                },
+                            );
+                        }
+                    }
+                }
+                "PRESENTATION_STYLE_ASSIGNMENT"
+                | "PRESENTATION_STYLE_BY_CONTEXT"
+                | "SURFACE_STYLE_USAGE"
+                | "SURFACE_SIDE_STYLE"
+                | "SURFACE_STYLE_FILL_AREA"
+                | "FILL_AREA_STYLE"
+                | "FILL_AREA_STYLE_COLOUR"
+                | "SURFACE_STYLE_RENDERING"
+                | "SURFACE_STYLE_RENDERING_WITH_PROPERTIES" => {
+                    let mut references = Vec::new();
+                    collect_references(&record.parameter, &mut references);
+                    self.presentation_style.insert(
+                        *id,
+                        PresentationStyleHolder {
+                            name: record.name.clone(),
+                            references,
+                        },
+                    );
+                }
+                "COLOUR_RGB" => {
+                    self.colour_rgb
+                        .insert(*id, Deserialize::deserialize(record)?);
+                }
+                "DRAUGHTING_PRE_DEFINED_COLOUR" => {
+                    if let Parameter::List(params) = &record.parameter {
+                        if let Some(label) = params.first() {
+                            let label: String = Deserialize::deserialize(label)?;
+                            let (red, green, blue) = match label.as_str() {
+                                "red" => (1.0, 0.0, 0.0),
+                                "green" => (0.0, 1.0, 0.0),
+                                "blue" => (0.0, 0.0, 1.0),
+                                "yellow" => (1.0, 1.0, 0.0),
+                                "magenta" => (1.0, 0.0, 1.0),
+                                "cyan" => (0.0, 1.0, 1.0),
+                                "black" => (0.0, 0.0, 0.0),
+                                _ => (1.0, 1.0, 1.0),
+                            };
+                            self.colour_rgb.insert(
+                                *id,
+                                ColourRgbHolder {
+                                    label,
+                                    red,
+                                    green,
+                                    blue,
+                                },
+                            );
+                        }
+                    }
+                }
                 "DEFINITIONAL_REPRESENTATION" => {
                     if let Parameter::List(params) = &record.parameter {
                         if params.len() == 3 {
@@ -614,10 +699,72 @@ impl Table {
     pub fn from_data_section(data_section: &DataSection) -> Table {
         Table::from_iter(&data_section.entities)
     }
+    /// Merges all data sections in the exchange structure into one table.
+    ///
+    /// If an entity id appears in more than one section, the first entity is adopted
+    /// and a warning is output to stderr.
+    pub fn from_steps(exchange: &Exchange) -> Table {
+        let mut ids = HashSet::new();
+        let entities = exchange
+            .data
+            .iter()
+            .flat_map(|data_section| &data_section.entities)
+            .filter(|instance| {
+                let id = match instance {
+                    EntityInstance::Simple { id, .. } => *id,
+                    EntityInstance::Complex { id, .. } => *id,
+                };
+                let is_new = ids.insert(id);
+                if !is_new {
+                    eprintln!("warning: duplicated entity #{id} is ignored.");
+                }
+                is_new
+            });
+        Table::from_iter(entities)
+    }
     #[inline(always)]
     pub fn from_step(step_str: &str) -> Option<Table> {
         let exchange = ruststep::parser::parse(step_str).ok()?;
-        Some(Table::from_data_section(&exchange.data[0]))
+        Some(Table::from_steps(&exchange))
+    }
+
+    /// Returns the shells sorted by the entity ids in the STEP file.
+    ///
+    /// The entities are stored in the hash maps, which are iterated in the nondeterministic order.
+    /// Use the sorted entities to reconstruct the shapes reproducibly.
+    #[inline(always)]
+    pub fn sorted_shells(&self) -> Vec<(u64, &ShellHolder)> { sorted_entities(&self.shell) }
+    /// Returns the manifold solid breps sorted by the entity ids in the STEP file.
+    ///
+    /// cf. [`Table::sorted_shells`]
+    #[inline(always)]
+    pub fn sorted_manifold_solid_breps(&self) -> Vec<(u64, &ManifoldSolidBrepHolder)> {
+        sorted_entities(&self.manifold_solid_brep)
+    }
+    /// Returns the breps with voids sorted by the entity ids in the STEP file.
+    ///
+    /// cf. [`Table::sorted_shells`]
+    #[inline(always)]
+    pub fn sorted_brep_with_voids(&self) -> Vec<(u64, &BrepWithVoidsHolder)> {
+        sorted_entities(&self.brep_with_voids)
+    }
+}
+
+fn sorted_entities<T>(map: &HashMap<u64, T>) -> Vec<(u64, &T)> {
+    let mut entities = map.iter().map(|(idx, x)| (*idx, x)).collect::<Vec<_>>();
+    entities.sort_by_key(|(idx, _)| *idx);
+    entities
+}
+
+/// Collects all entity references in `parameter` recursively.
+fn collect_references(parameter: &Parameter, references: &mut Vec<u64>) {
+    match parameter {
+        Parameter::Ref(Name::Entity(idx)) => references.push(*idx),
+        Parameter::List(params) => params
+            .iter()
+            .for_each(|param| collect_references(param, references)),
+        Parameter::Typed { parameter, .. } => collect_references(parameter, references),
+        _ => {}
     }
 }
 
@@ -1508,6 +1655,18 @@ pub struct SurfaceCurve {
     master_representation: PreferredSurfaceCurveRepresentation,
 }
 
+impl SurfaceCurve {
+    /// Returns whether the curve is a seam curve, i.e. the curve on the seam of a closed surface,
+    /// whose two pcurves are on the same surface.
+    pub fn is_seam(&self) -> bool {
+        matches!(
+            self.associated_geometry.as_slice(),
+            [PcurveOrSurface::Pcurve(c0), PcurveOrSurface::Pcurve(c1)]
+                if c0.basis_surface == c1.basis_surface
+        )
+    }
+}
+
 impl TryFrom<&SurfaceCurve> for Curve3D {
     type Error = StepConvertingError;
     #[inline(always)]
@@ -1515,6 +1674,8 @@ impl TryFrom<&SurfaceCurve> for Curve3D {
         use PreferredSurfaceCurveRepresentation as PSCR;
         match &value.master_representation {
             PSCR::Curve3D => Ok((&value.curve_3d).try_into()?),
+            // The pcurves of a seam curve are ambiguous at the seam.
+            _ if value.is_seam() => Ok((&value.curve_3d).try_into()?),
             PSCR::PcurveS1 => {
                 if let Some(PcurveOrSurface::Pcurve(x)) = value.associated_geometry.first() {
                     Ok(Self::PCurve(x.as_ref().try_into()?))
@@ -2331,52 +2492,55 @@ impl EdgeCurve {
             },
             CurveAny::Pcurve(c) => {
                 let surface: Surface = (&c.basis_surface).try_into()?;
-                let u = surface
-                    .search_nearest_parameter(p, None, 100)
-                    .ok_or_else(|| "the point is not on surface".to_string())?;
-                let v = surface
-                    .search_nearest_parameter(q, None, 100)
-                    .ok_or_else(|| "the point is not on surface".to_string())?;
+                let search = |p: Point3| {
+                    surface
+                        .search_nearest_parameter(p, None, 100)
+                        .map(Point2::from)
+                        .ok_or_else(|| "the point is not on surface".to_string())
+                };
+                let (u, v) = (search(p)?, search(q)?);
                 let curve2d = c
                     .reference_to_curve
                     .representation_item
                     .first()
                     .ok_or("no representation item")?;
-                let curve2d = Self::sub_parse_2d(
-                    curve2d,
-                    Point2::new(u.0, u.1),
-                    Point2::new(v.0, v.1),
-                    true,
-                )?;
+                // On a closed surface, the parameters of the points on the seam are determined
+                // only up to the period. Take the ones on the side of the pcurve.
+                let rough = Self::sub_parse_2d(curve2d, u, v, true)?;
+                let u = nearest_periodic_parameter(&surface, u, rough.front());
+                let v = nearest_periodic_parameter(&surface, v, rough.back());
+                let curve2d = Self::sub_parse_2d(curve2d, u, v, true)?;
                 Curve3D::PCurve(truck::PCurve::new(Box::new(curve2d), Box::new(surface)))
             }
             CurveAny::SurfaceCurve(c) => {
                 if p.near(&q) {
                     return Self::sub_parse_curve3d(&c.curve_3d, p, q, same_sense);
                 }
-                use PreferredSurfaceCurveRepresentation::*;
-                match c.master_representation {
-                    Curve3D => Self::sub_parse_curve3d(&c.curve_3d, p, q, same_sense)?,
-                    PcurveS1 => {
-                        if let Some(PcurveOrSurface::Pcurve(c)) = c.associated_geometry.first() {
-                            Self::sub_parse_curve3d(&CurveAny::Pcurve(c.clone()), p, q, true)?
-                        } else {
-                            return Err(
-                                "The 0-indexed associated geometry is nothing or not PCURVE."
-                                    .into(),
-                            );
-                        }
+                let pcurve = |i: usize| match c.associated_geometry.get(i) {
+                    Some(PcurveOrSurface::Pcurve(c)) => {
+                        Self::sub_parse_curve3d(&CurveAny::Pcurve(c.clone()), p, q, true)
                     }
-                    PcurveS2 => {
-                        if let Some(PcurveOrSurface::Pcurve(c)) = c.associated_geometry.get(1) {
-                            Self::sub_parse_curve3d(&CurveAny::Pcurve(c.clone()), p, q, true)?
-                        } else {
-                            return Err(
-                                "The 1-indexed associated geometry is nothing or not PCURVE."
-                                    .into(),
-                            );
-                        }
+                    _ => Err(format!(
+                        "The {i}-indexed associated geometry is nothing or not PCURVE."
+                    )
+                    .into()),
+                };
+                use PreferredSurfaceCurveRepresentation::*;
+                match (c.master_representation, c.is_seam()) {
+                    // The two pcurves of a seam curve are on the both sides of the seam, and
+                    // are mapped to the same 3D curve.
+                    (master, true) => {
+                        let (i, j) = match master {
+                            PcurveS2 => (1, 0),
+                            _ => (0, 1),
+                        };
+                        Self::sub_parse_curve3d(&c.curve_3d, p, q, true)
+                            .or_else(|_| pcurve(i))
+                            .or_else(|_| pcurve(j))?
                     }
+                    (Curve3D, false) => Self::sub_parse_curve3d(&c.curve_3d, p, q, true)?,
+                    (PcurveS1, false) => pcurve(0)?,
+                    (PcurveS2, false) => pcurve(1)?,
                 }
             }
         };
@@ -2387,6 +2551,18 @@ impl EdgeCurve {
     }
 }
 
+/// Returns the parameter equivalent to `uv` modulo the periods of `surface` nearest to `reference`.
+fn nearest_periodic_parameter(surface: &Surface, uv: Point2, reference: Point2) -> Point2 {
+    let wrap = |x: f64, x0: f64, period: Option<f64>| match period {
+        Some(period) => x - f64::round((x - x0) / period) * period,
+        None => x,
+    };
+    Point2::new(
+        wrap(uv.x, reference.x, surface.u_period()),
+        wrap(uv.y, reference.y, surface.v_period()),
+    )
+}
+
 /// `oriented_edge`
 ///
 /// `oriented_edge` has duplicated information.
@@ -2572,6 +2748,117 @@ pub struct OrientedShell {
     pub orientation: bool,
 }
 
+impl OrientedShellHolder {
+    fn shell_element_holder(&self, table: &Table) -> Option<ShellHolder> {
+        match &self.shell_element {
+            PlaceHolder::Ref(Name::Entity(ref idx)) => table.shell.get(idx).cloned(),
+            PlaceHolder::Owned(x) => Some(x.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// `manifold_solid_brep`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Holder)]
+#[holder(table = Table)]
+#[holder(field = manifold_solid_brep)]
+#[holder(generate_deserialize)]
+pub struct ManifoldSolidBrep {
+    pub label: String,
+    #[holder(use_place_holder)]
+    pub outer: Shell,
+}
+
+impl ManifoldSolidBrepHolder {
+    fn outer_holder(&self, table: &Table) -> Option<ShellHolder> {
+        match &self.outer {
+            PlaceHolder::Ref(Name::Entity(ref idx)) => table.shell.get(idx).cloned(),
+            PlaceHolder::Owned(x) => Some(x.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// `brep_with_voids`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Holder)]
+#[holder(table = Table)]
+#[holder(field = brep_with_voids)]
+#[holder(generate_deserialize)]
+pub struct BrepWithVoids {
+    pub label: String,
+    #[holder(use_place_holder)]
+    pub outer: Shell,
+    #[holder(use_place_holder)]
+    pub voids: Vec<OrientedShell>,
+}
+
+impl BrepWithVoidsHolder {
+    fn outer_holder(&self, table: &Table) -> Option<ShellHolder> {
+        match &self.outer {
+            PlaceHolder::Ref(Name::Entity(ref idx)) => table.shell.get(idx).cloned(),
+            PlaceHolder::Owned(x) => Some(x.clone()),
+            _ => None,
+        }
+    }
+    fn voids_holder<'a>(
+        &'a self,
+        table: &'a Table,
+    ) -> impl Iterator<Item = Option<OrientedShellHolder>> + 'a {
+        self.voids.iter().map(|void| match void {
+            PlaceHolder::Ref(Name::Entity(ref idx)) => table.oriented_shell.get(idx).cloned(),
+            PlaceHolder::Owned(x) => Some(x.clone()),
+            _ => None,
+        })
+    }
+}
+
+/// `styled_item`
+///
+/// `over_riding_styled_item` is also parsed to this struct.
+/// The styles are resolved by [`Table::face_colors`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Holder)]
+#[holder(table = Table)]
+#[holder(field = styled_item)]
+#[holder(generate_deserialize)]
+pub struct StyledItem {
+    pub label: String,
+    #[holder(use_place_holder)]
+    pub styles: Vec<Dummy>,
+    #[holder(use_place_holder)]
+    pub item: Dummy,
+}
+
+/// Presentation styles between `styled_item` and colours,
+/// e.g. `presentation_style_assignment`, `surface_style_usage` and `fill_area_style_colour`.
+///
+/// Only the name of the entity and the references in its record are stored.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PresentationStyleHolder {
+    pub name: String,
+    pub references: Vec<u64>,
+}
+
+/// `colour_rgb`
+///
+/// `draughting_pre_defined_colour` is also parsed to this struct.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Holder)]
+#[holder(table = Table)]
+#[holder(field = colour_rgb)]
+#[holder(generate_deserialize)]
+pub struct ColourRgb {
+    pub label: String,
+    pub red: f64,
+    pub green: f64,
+    pub blue: f64,
+}
+
+fn place_holder_index<T>(place_holder: &PlaceHolder<T>) -> Option<u64> {
+    match place_holder {
+        PlaceHolder::Ref(Name::Entity(idx)) => Some(*idx),
+        _ => None,
+    }
+}
+
 impl Table {
     fn place_holder_edge_any_to_index_and_edge_curve(
         &self,
@@ -2784,4 +3071,154 @@ impl Table {
             faces: self.shell_faces(shell, &eidx_map),
         })
     }
+
+    /// construct `CompressedSolid` of `truck` from `ManifoldSolidBrep` in STEP file
+    pub fn to_compressed_solid(
+        &self,
+        brep: &ManifoldSolidBrepHolder,
+    ) -> Result<CompressedSolid<Point3, Curve3D, Surface>, StepConvertingError> {
+        let outer = brep.outer_holder(self).ok_or("no outer shell")?;
+        Ok(CompressedSolid {
+            boundaries: vec![self.to_compressed_shell(&outer)?],
+        })
+    }
+
+    /// construct `CompressedSolid` of `truck` from `BrepWithVoids` in STEP file
+    pub fn to_compressed_solid_with_voids(
+        &self,
+        brep: &BrepWithVoidsHolder,
+    ) -> Result<CompressedSolid<Point3, Curve3D, Surface>, StepConvertingError> {
+        let outer = brep.outer_holder(self).ok_or("no outer shell")?;
+        let mut boundaries = vec![self.to_compressed_shell(&outer)?];
+        brep.voids_holder(self).try_for_each(|void| {
+            let void = void.ok_or("void shell is not oriented shell")?;
+            let shell = void.shell_element_holder(self).ok_or("no void shell")?;
+            let mut cshell = self.to_compressed_shell(&shell)?;
+            if !void.orientation {
+                cshell
+                    .faces
+                    .iter_mut()
+                    .for_each(|face| face.orientation = !face.orientation);
+            }
+            boundaries.push(cshell);
+            Ok::<_, StepConvertingError>(())
+        })?;
+        Ok(CompressedSolid { boundaries })
+    }
+
+    /// construct all `CompressedSolid`s of `truck` from `ManifoldSolidBrep`s and `BrepWithVoids`s in STEP file.
+    ///
+    /// The solids are sorted by the entity ids in the STEP file.
+    /// Solids that fail to be converted are skipped, and the errors are output to stderr.
+    /// # Example
+    /// ```
+    /// use truck_stepio::r#in::*;
+    /// // read file
+    /// let step_string = include_str!(concat!(
+    ///     env!("CARGO_MANIFEST_DIR"),
+    ///     "/../resources/step/occt-cube.step",
+    /// ));
+    /// // parse step file and convert the parsing results to a Rust struct
+    /// let table = Table::from_step(&step_string).unwrap();
+    /// // take all solids
+    /// let csolids = table.to_compressed_solids();
+    /// // There is only one cube.
+    /// assert_eq!(csolids.len(), 1);
+    /// // The cube has 6 faces!
+    /// assert_eq!(csolids[0].boundaries[0].faces.len(), 6);
+    /// ```
+    pub fn to_compressed_solids(&self) -> Vec<CompressedSolid<Point3, Curve3D, Surface>> {
+        let manifold_solids = self
+            .sorted_manifold_solid_breps()
+            .into_iter()
+            .map(|(idx, brep)| (idx, self.to_compressed_solid(brep)));
+        let solids_with_voids = self
+            .sorted_brep_with_voids()
+            .into_iter()
+            .map(|(idx, brep)| (idx, self.to_compressed_solid_with_voids(brep)));
+        let mut solids: Vec<_> = manifold_solids
+            .chain(solids_with_voids)
+            .filter_map(|(idx, solid)| Some((idx, solid.map_err(|e| eprintln!("{e}")).ok()?)))
+            .collect();
+        solids.sort_by_key(|(idx, _)| *idx);
+        solids.into_iter().map(|(_, solid)| solid).collect()
+    }
+
+    /// Returns the colour of the first style in `styles` that has one.
+    fn style_colour(&self, styles: &[PlaceHolder<DummyHolder>]) -> Option<[f64; 3]> {
+        let mut stack: Vec<u64> = styles.iter().rev().filter_map(place_holder_index).collect();
+        let mut visited = HashSet::new();
+        while let Some(idx) = stack.pop() {
+            if !visited.insert(idx) {
+                continue;
+            }
+            if let Some(colour) = self.colour_rgb.get(&idx) {
+                return Some([colour.red, colour.green, colour.blue]);
+            }
+            if let Some(style) = self.presentation_style.get(&idx) {
+                stack.extend(style.references.iter().rev());
+            }
+        }
+        None
+    }
+
+    /// Returns the ids of the faces included in the representation item `idx`.
+    fn item_face_indices(&self, idx: u64) -> Vec<u64> {
+        if self.face_surface.contains_key(&idx) {
+            vec![idx]
+        } else if let Some(face) = self.oriented_face.get(&idx) {
+            place_holder_index(&face.face_element).into_iter().collect()
+        } else if let Some(shell) = self.shell.get(&idx) {
+            shell
+                .cfs_faces
+                .iter()
+                .filter_map(place_holder_index)
+                .flat_map(|idx| self.item_face_indices(idx))
+                .collect()
+        } else if let Some(shell) = self.oriented_shell.get(&idx) {
+            place_holder_index(&shell.shell_element)
+                .map(|idx| self.item_face_indices(idx))
+                .unwrap_or_default()
+        } else if let Some(brep) = self.manifold_solid_brep.get(&idx) {
+            place_holder_index(&brep.outer)
+                .map(|idx| self.item_face_indices(idx))
+                .unwrap_or_default()
+        } else if let Some(brep) = self.brep_with_voids.get(&idx) {
+            let voids = brep.voids.iter().filter_map(place_holder_index);
+            place_holder_index(&brep.outer)
+                .into_iter()
+                .chain(voids)
+                .flat_map(|idx| self.item_face_indices(idx))
+                .collect()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Returns the map from the ids of faces to their RGB colours.
+    ///
+    /// Colours assigned to solids or shells are applied to all their faces,
+    /// and are overwritten by the colours assigned to each face.
+    /// The keys are the ids of `face_surface`s, including `advanced_face`s.
+    pub fn face_colors(&self) -> HashMap<u64, [f64; 3]> {
+        let mut styled_items: Vec<_> = self
+            .styled_item
+            .iter()
+            .filter_map(|(idx, styled_item)| {
+                let item = place_holder_index(&styled_item.item)?;
+                let colour = self.style_colour(&styled_item.styles)?;
+                let is_face =
+                    self.face_surface.contains_key(&item) || self.oriented_face.contains_key(&item);
+                Some(((is_face, *idx), item, colour))
+            })
+            .collect();
+        styled_items.sort_by_key(|(key, _, _)| *key);
+        styled_items
+            .into_iter()
+            .flat_map(|(_, item, colour)| {
+                let faces = self.item_face_indices(item);
+                faces.into_iter().map(move |face| (face, colour))
+            })
+            .collect()
+    }
 }
@@ -2,8 +2,8 @@
 //!
 //! # Current Status
 //!
-//! It is possible to output data modeled by truck-modeling.
-//! Shapes created by set operations cannot be output yet.
+//! It is possible to output data modeled by truck-modeling,
+//! including shapes created by set operations in truck-shapeops.
 //! Input will come further down the road.
 
 #![cfg_attr(not(debug_assertions), deny(warnings))]
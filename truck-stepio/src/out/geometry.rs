@@ -365,7 +365,7 @@ where
         ))?;
         self.leader().fmt(curve_idx, f)?;
         self.surface0().fmt(surface0_idx, f)?;
-        self.surface1().fmt(surface0_idx, f)
+        self.surface1().fmt(surface1_idx, f)
     }
 }
 
@@ -456,7 +456,17 @@ impl StepLength for ModelingCurve {
     }
 }
 
-impl StepCurve for ModelingCurve {}
+impl StepCurve for ModelingCurve {
+    #[inline(always)]
+    fn same_sense(&self) -> bool {
+        match self {
+            ModelingCurve::Line(x) => x.same_sense(),
+            ModelingCurve::BSplineCurve(x) => x.same_sense(),
+            ModelingCurve::NurbsCurve(x) => x.same_sense(),
+            ModelingCurve::IntersectionCurve(x) => x.same_sense(),
+        }
+    }
+}
 
 impl DisplayByStep for Plane {
     fn fmt(&self, idx: usize, f: &mut Formatter<'_>) -> Result {
@@ -672,7 +682,7 @@ where C: StepLength + DisplayByStep
         let vector_idx = idx + 1 + curve.step_length();
         let vector = self.extruding_vector();
         f.write_fmt(format_args!(
-            "#{idx} = SURFACE_OF_LINEAR_EXTRUSION('', #{curve_idx}, #{vector_idx});{}{}",
+            "#{idx} = SURFACE_OF_LINEAR_EXTRUSION('', #{curve_idx}, #{vector_idx});\n{}{}",
             StepDisplay::new(curve, curve_idx),
             StepDisplay::new(vector, vector_idx),
         ))
@@ -686,6 +696,22 @@ impl<C: ConstStepLength> ConstStepLength for ExtrudedCurve<C, Vector3> {
 }
 impl<C> StepSurface for ExtrudedCurve<C, Vector3> {}
 
+impl<C> DisplayByStep for Processor<ExtrudedCurve<C, Vector3>, Matrix4>
+where C: StepLength + Transformed<Matrix4> + DisplayByStep
+{
+    fn fmt(&self, idx: usize, f: &mut Formatter<'_>) -> Result {
+        let surface = self.entity();
+        let transform = self.transform();
+        let curve = surface.entity_curve().transformed(*transform);
+        let vector = transform.transform_vector(surface.extruding_vector());
+        let surface = ExtrudedCurve::by_extrusion(curve, vector);
+        DisplayByStep::fmt(&surface, idx, f)
+    }
+}
+impl<C: StepLength> StepLength for Processor<ExtrudedCurve<C, Vector3>, Matrix4> {
+    fn step_length(&self) -> usize { self.entity().step_length() }
+}
+
 impl<C, T: One> StepSurface for Processor<ExtrudedCurve<C, Vector3>, T> {
     #[inline(always)]
     fn same_sense(&self) -> bool { self.orientation() }
@@ -779,4 +805,14 @@ impl StepLength for ModelingSurface {
     }
 }
 
-impl StepSurface for ModelingSurface {}
+impl StepSurface for ModelingSurface {
+    #[inline(always)]
+    fn same_sense(&self) -> bool {
+        match self {
+            ModelingSurface::Plane(x) => x.same_sense(),
+            ModelingSurface::BSplineSurface(x) => x.same_sense(),
+            ModelingSurface::NurbsSurface(x) => x.same_sense(),
+            ModelingSurface::RevolutedCurve(x) => StepSurface::same_sense(x),
+        }
+    }
+}
@@ -82,7 +82,7 @@ impl Display for SliceDisplay<'_, String> {
             if i != 0 {
                 f.write_str(", ")?;
             }
-            f.write_fmt(format_args!("'{x}'"))
+            Display::fmt(&StringDisplay(x), f)
         })?;
         f.write_str(")")
     }
@@ -268,7 +268,66 @@ impl<T: StepSurface> StepSurface for Box<T> {
     fn same_sense(&self) -> bool { self.as_ref().same_sense() }
 }
 
+/// Length unit of the output STEP file.
+///
+/// The coordinates of shapes are written as they are, i.e. they are interpreted in this unit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum StepLengthUnit {
+    /// millimetre, the default unit
+    #[default]
+    Millimetre,
+    /// metre
+    Metre,
+    /// inch
+    Inch,
+    /// foot
+    Foot,
+}
+
+impl StepLengthUnit {
+    /// the length of the unit in millimetres
+    /// # Examples
+    /// ```
+    /// use truck_stepio::out::StepLengthUnit;
+    /// assert_eq!(StepLengthUnit::Millimetre.in_millimetres(), 1.0);
+    /// assert_eq!(StepLengthUnit::Inch.in_millimetres(), 25.4);
+    /// ```
+    #[inline]
+    pub const fn in_millimetres(self) -> f64 {
+        match self {
+            Self::Millimetre => 1.0,
+            Self::Metre => 1000.0,
+            Self::Inch => 25.4,
+            Self::Foot => 304.8,
+        }
+    }
+}
+
+/// Data output to the data section of STEP file by [`CompleteStepDisplay`].
+pub trait StepDataSection {
+    /// Formats the data section with the length unit.
+    fn fmt_data_section(&self, unit: StepLengthUnit, f: &mut Formatter<'_>) -> Result;
+}
+
+impl<T: StepDataSection> StepDataSection for &T {
+    #[inline(always)]
+    fn fmt_data_section(&self, unit: StepLengthUnit, f: &mut Formatter<'_>) -> Result {
+        (*self).fmt_data_section(unit, f)
+    }
+}
+
+impl<T> StepDataSection for StepDisplay<T>
+where StepDisplay<T>: Display
+{
+    #[inline(always)]
+    fn fmt_data_section(&self, _: StepLengthUnit, f: &mut Formatter<'_>) -> Result {
+        Display::fmt(self, f)
+    }
+}
+
 /// Describe STEP file header
+///
+/// The optional fields are output as the default values if they are `None`.
 #[derive(Clone, Debug)]
 pub struct StepHeaderDescriptor {
     /// file name
@@ -279,10 +338,14 @@ pub struct StepHeaderDescriptor {
     pub authors: Vec<String>,
     /// organization
     pub organization: Vec<String>,
-    /// organization system
-    pub organization_system: String,
-    /// authorization
-    pub authorization: String,
+    /// the system which creates the file, `'truck'` by default
+    pub preprocessor_version: Option<String>,
+    /// the system from which the data is originated, empty by default
+    pub originating_system: Option<String>,
+    /// the person who authorizes the file, empty by default
+    pub authorization: Option<String>,
+    /// length unit
+    pub unit: StepLengthUnit,
 }
 
 #[derive(Clone, Debug)]
@@ -291,9 +354,11 @@ struct StepHeader {
     time_stamp: String,
     authors: Vec<String>,
     organization: Vec<String>,
-    origination_system: String,
+    preprocessor_version: String,
+    originating_system: String,
     authorization: String,
     schema: String,
+    unit: StepLengthUnit,
 }
 
 impl Default for StepHeaderDescriptor {
@@ -303,23 +368,41 @@ impl Default for StepHeaderDescriptor {
             time_stamp: chrono::Utc::now().naive_local().to_string(),
             authors: Default::default(),
             organization: Default::default(),
-            organization_system: Default::default(),
-            authorization: Default::default(),
+            preprocessor_version: None,
+            originating_system: None,
+            authorization: None,
+            unit: Default::default(),
         }
     }
 }
 
+/// display string to step file, with apostrophes and backslashes escaped.
+#[derive(Clone, Copy, Debug)]
+struct StringDisplay<'a>(&'a str);
+
+impl Display for StringDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        f.write_str("'")?;
+        self.0.chars().try_for_each(|c| match c {
+            '\'' => f.write_str("''"),
+            '\\' => f.write_str("\\\\"),
+            _ => f.write_fmt(format_args!("{c}")),
+        })?;
+        f.write_str("'")
+    }
+}
+
 impl Display for StepHeader {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         let empty_string = [String::new()];
         f.write_fmt(format_args!(
             "HEADER;
 FILE_DESCRIPTION(('Shape Data from Truck'), '2;1');
-FILE_NAME('{file_name}', '{time_stamp}', {authors}, {organization}, 'truck', '{origination_system}', '{authorization}');
+FILE_NAME({file_name}, {time_stamp}, {authors}, {organization}, {preprocessor_version}, {originating_system}, {authorization});
 FILE_SCHEMA(('{schema}'));
 ENDSEC;\n",
-            file_name = self.file_name,
-            time_stamp = self.time_stamp,
+            file_name = StringDisplay(&self.file_name),
+            time_stamp = StringDisplay(&self.time_stamp),
             authors = if self.authors.is_empty() {
                 SliceDisplay(&empty_string)
             } else {
@@ -330,8 +413,9 @@ ENDSEC;\n",
             } else {
                 SliceDisplay(&self.organization)
             },
-            origination_system = self.origination_system,
-            authorization = self.authorization,
+            preprocessor_version = StringDisplay(&self.preprocessor_version),
+            originating_system = StringDisplay(&self.originating_system),
+            authorization = StringDisplay(&self.authorization),
             schema = self.schema,
         ))
     }
@@ -355,12 +439,11 @@ pub struct CompleteStepDisplay<T> {
     header: StepHeader,
 }
 
-impl<T: Display> Display for CompleteStepDisplay<T> {
+impl<T: StepDataSection> Display for CompleteStepDisplay<T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        f.write_fmt(format_args!(
-            "ISO-10303-21;\n{}DATA;\n{}ENDSEC;\nEND-ISO-10303-21;\n",
-            self.header, self.display,
-        ))
+        f.write_fmt(format_args!("ISO-10303-21;\n{}DATA;\n", self.header))?;
+        self.display.fmt_data_section(self.header.unit, f)?;
+        f.write_str("ENDSEC;\nEND-ISO-10303-21;\n")
     }
 }
 
@@ -375,14 +458,62 @@ impl<T> CompleteStepDisplay<T> {
                 time_stamp: header.time_stamp,
                 authors: header.authors,
                 organization: header.organization,
-                origination_system: header.organization_system,
-                authorization: header.authorization,
+                preprocessor_version: header
+                    .preprocessor_version
+                    .unwrap_or_else(|| "truck".to_string()),
+                originating_system: header.originating_system.unwrap_or_default(),
+                authorization: header.authorization.unwrap_or_default(),
                 schema: "ISO-10303-042".to_string(),
+                unit: header.unit,
             },
         }
     }
 }
 
+/// Writes the STEP file of the model to `writer` without building the whole file in memory.
+///
+/// The output is the same as `CompleteStepDisplay::new(model, header.clone()).to_string()`, but
+/// the entities are passed to `writer` as soon as they are formatted. Since `writer` receives many
+/// small pieces, it is recommended to wrap a file by [`std::io::BufWriter`].
+///
+/// # Remarks
+/// STEP entities refer to the ones appearing later, so the output is made in two passes. The
+/// first pass is the construction of [`StepModel`], which counts the lines of all entities and
+/// assigns their indices without formatting them. The second pass is this function, which formats
+/// the entities one by one. Hence, the memory held during the output is only the model itself.
+///
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use truck_stepio::out::*;
+///
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+/// let cube = cube.compress();
+/// let model = StepModel::from(&cube);
+/// let header = StepHeaderDescriptor::default();
+///
+/// let mut buffer = Vec::new();
+/// write_step(&model, &header, &mut buffer).unwrap();
+/// let string = CompleteStepDisplay::new(&model, header).to_string();
+/// assert_eq!(String::from_utf8(buffer).unwrap(), string);
+/// ```
+pub fn write_step<P, C, S, W: std::io::Write>(
+    model: &StepModel<'_, P, C, S>,
+    header: &StepHeaderDescriptor,
+    writer: &mut W,
+) -> std::io::Result<()>
+where
+    P: DisplayByStep + Copy,
+    C: DisplayByStep + StepLength + StepCurve,
+    S: DisplayByStep + StepLength + StepSurface,
+{
+    let display = CompleteStepDisplay::new(model, header.clone());
+    writer.write_fmt(format_args!("{display}"))
+}
+
 mod geometry;
 mod topology;
 pub use geometry::VectorAsDirection;
@@ -1,10 +1,32 @@
 use super::{Result, *};
+use truck_geometry::prelude::{ParametricCurve, Tolerance};
+
+/// A boundary of a face in STEP output.
+#[derive(Clone, Debug)]
+enum StepBound {
+    /// `VERTEX_LOOP`, the loop collapsed to the vertex
+    Vertex(usize),
+    /// `EDGE_LOOP`, the oriented edges
+    Edges(Vec<CompressedEdgeIndex>),
+}
+
+impl StepBound {
+    fn step_length(&self) -> usize {
+        match self {
+            StepBound::Vertex(_) => 2,
+            StepBound::Edges(edges) => 2 + edges.len(),
+        }
+    }
+}
 
 #[derive(Clone, Debug)]
 pub(super) struct StepShell<'a, P, C, S> {
     entity: &'a CompressedShell<P, C, S>,
     idx: usize,
     face_indices: Vec<usize>,
+    face_bounds: Vec<Vec<StepBound>>,
+    edges: Vec<usize>,
+    vertex_reps: Vec<usize>,
     ep_edges: usize,
     ep_vertices: usize,
     surface_indices: Vec<usize>,
@@ -13,24 +35,105 @@ pub(super) struct StepShell<'a, P, C, S> {
     is_open: bool,
 }
 
+/// Returns the edges which are output and the boundaries of the faces.
+///
+/// The degenerate edges, whose ends are the distinct vertices at the same point and whose curves
+/// collapse into the point, e.g. at the poles of the swept spheres, are removed and their ends are
+/// identified. The loops consisting of the degenerate edges are collapsed into the vertices. The
+/// closed edges, e.g. full circles, are kept even if their ends are distinct vertices.
+fn step_bounds<P, C, S>(
+    shell: &CompressedShell<P, C, S>,
+) -> (Vec<usize>, Vec<usize>, Vec<Vec<StepBound>>)
+where
+    P: Copy + Tolerance,
+    C: ParametricCurve<Point = P>, {
+    let vertices = &shell.vertices;
+    let degenerate = |e: &CompressedEdge<C>| {
+        let (p, q) = (vertices[e.vertices.0], vertices[e.vertices.1]);
+        let collapsed = || match e.curve.try_range_tuple() {
+            Some((t0, t1)) => [0.25, 0.5, 0.75]
+                .into_iter()
+                .all(|s| e.curve.subs(t0 + (t1 - t0) * s).near(&p)),
+            None => false,
+        };
+        e.vertices.0 != e.vertices.1 && p.near(&q) && collapsed()
+    };
+    let mut vertex_reps = (0..vertices.len()).collect::<Vec<_>>();
+    let rep = |reps: &[usize], mut i: usize| {
+        while reps[i] != i {
+            i = reps[i];
+        }
+        i
+    };
+    shell.edges.iter().filter(|e| degenerate(e)).for_each(|e| {
+        let (i, j) = (
+            rep(&vertex_reps, e.vertices.0),
+            rep(&vertex_reps, e.vertices.1),
+        );
+        vertex_reps[usize::max(i, j)] = usize::min(i, j);
+    });
+    let vertex_reps = (0..vertices.len())
+        .map(|i| rep(&vertex_reps, i))
+        .collect::<Vec<_>>();
+    let mut edges = Vec::new();
+    let edge_indices = shell
+        .edges
+        .iter()
+        .enumerate()
+        .map(|(i, e)| match degenerate(e) {
+            true => None,
+            false => {
+                edges.push(i);
+                Some(edges.len() - 1)
+            }
+        })
+        .collect::<Vec<_>>();
+    let face_bounds = shell
+        .faces
+        .iter()
+        .map(|f| {
+            let bound = |b: &Vec<CompressedEdgeIndex>| {
+                let kept = b
+                    .iter()
+                    .filter_map(|ce| {
+                        Some(CompressedEdgeIndex {
+                            index: edge_indices[ce.index]?,
+                            orientation: ce.orientation,
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                match (kept.is_empty(), b.first()) {
+                    (true, Some(ce)) => {
+                        let vertex = shell.edges[ce.index].vertices.0;
+                        StepBound::Vertex(vertex_reps[vertex])
+                    }
+                    _ => StepBound::Edges(kept),
+                }
+            };
+            f.boundaries.iter().map(bound).collect()
+        })
+        .collect();
+    (edges, vertex_reps, face_bounds)
+}
+
 impl<'a, P, C, S> StepShell<'a, P, C, S>
 where
-    P: Copy,
-    C: StepLength,
+    P: Copy + Tolerance,
+    C: StepLength + ParametricCurve<Point = P>,
     S: StepLength,
 {
     fn new(shell: &'a CompressedShell<P, C, S>, idx: usize, is_open: bool) -> Self {
         let faces = &shell.faces;
-        let edges = &shell.edges;
         let vertices = &shell.vertices;
+        let (edges, vertex_reps, face_bounds) = step_bounds(shell);
         let mut cursor = idx + 1;
-        let face_indices = faces
+        let face_indices = face_bounds
             .iter()
-            .map(|f| {
+            .map(|bounds| {
                 let res = cursor;
-                cursor += match f.boundaries.is_empty() {
+                cursor += match bounds.is_empty() {
                     true => 5,
-                    false => 1 + f.boundaries.iter().map(|b| 2 + b.len()).sum::<usize>(),
+                    false => 1 + bounds.iter().map(StepBound::step_length).sum::<usize>(),
                 };
                 res
             })
@@ -48,9 +151,9 @@ where
             .collect::<Vec<_>>();
         let curve_indices = edges
             .iter()
-            .map(|e| {
+            .map(|i| {
                 let res = cursor;
-                cursor += e.curve.step_length();
+                cursor += shell.edges[*i].curve.step_length();
                 res
             })
             .collect::<Vec<_>>();
@@ -59,6 +162,9 @@ where
             entity: shell,
             idx,
             face_indices,
+            face_bounds,
+            edges,
+            vertex_reps,
             ep_edges,
             ep_vertices,
             surface_indices,
@@ -80,6 +186,9 @@ where
             entity,
             idx,
             face_indices,
+            face_bounds,
+            edges,
+            vertex_reps,
             ep_edges,
             ep_vertices,
             surface_indices,
@@ -88,7 +197,6 @@ where
             is_open,
         } = self;
         let faces = &entity.faces;
-        let edges = &entity.edges;
         let vertices = &entity.vertices;
         let shell_kind = match is_open {
             true => "OPEN_SHELL",
@@ -98,28 +206,28 @@ where
             "#{idx} = {shell_kind}('', {face_indices});\n",
             face_indices = IndexSliceDisplay(self.face_indices.clone()),
         ))?;
-        faces.iter().enumerate().try_for_each(|(i, f)| {
+        faces.iter().zip(face_bounds).enumerate().try_for_each(|(i, (f, bounds))| {
             let idx = face_indices[i];
             let mut cursor = idx + 1;
             let face_geometry = surface_indices[i];
-            let face_bounds = match f.boundaries.is_empty() {
+            let face_bound_indices = match bounds.is_empty() {
                 true => vec![cursor],
                 false => {
-                    let closure = |b: &Vec<CompressedEdgeIndex>| {
+                    let closure = |b: &StepBound| {
                         let res = cursor;
-                        cursor += 2 + b.len();
+                        cursor += b.step_length();
                         res
                     };
-                    f.boundaries.iter().map(closure).collect()
+                    bounds.iter().map(closure).collect()
                 }
             };
             formatter.write_fmt(format_args!(
                 "#{idx} = FACE_SURFACE('', {face_bound}, #{face_geometry}, {same_sense});\n",
                 same_sense = BooleanDisplay(f.orientation == f.surface.same_sense()),
-                face_bound = IndexSliceDisplay(face_bounds.clone()),
+                face_bound = IndexSliceDisplay(face_bound_indices.clone()),
             ))?;
             cursor = idx + 1;
-            if f.boundaries.is_empty() {
+            if bounds.is_empty() {
                 let face_bound_idx = cursor;
                 let vertex_loop_idx = cursor + 1;
                 let vertex_idx = cursor + 2;
@@ -131,35 +239,45 @@ where
 #{vertex_geometry} = POINT_ON_SURFACE('', #{face_geometry}, 0.0, 0.0);\n"
                 ))?;
             }
-            f.boundaries.iter().try_for_each(|b| {
+            bounds.iter().try_for_each(|b| {
                 let face_bound_idx = cursor;
-                let edge_loop_idx = cursor + 1;
-                let ep_oriented_edges = cursor + 2;
-                cursor += 2 + b.len();
-                formatter.write_fmt(format_args!(
-                    "#{face_bound_idx} = FACE_BOUND('', #{edge_loop_idx}, {orientation});
-#{edge_loop_idx} = EDGE_LOOP('', {oriented_edge_indices});\n",
-                    orientation = BooleanDisplay(f.orientation),
-                    oriented_edge_indices =
-                        IndexSliceDisplay(ep_oriented_edges..ep_oriented_edges + b.len()),
-                ))?;
-                b.iter().enumerate().try_for_each(|(j, ce)| {
-                    formatter.write_fmt(format_args!(
-                        "#{idx} = ORIENTED_EDGE('', *, *, #{edge_element}, {orientation});\n",
-                        idx = ep_oriented_edges + j,
-                        edge_element = ep_edges + ce.index,
-                        orientation = if ce.orientation { ".T." } else { ".F." },
-                    ))
-                })
+                let loop_idx = cursor + 1;
+                cursor += b.step_length();
+                match b {
+                    StepBound::Vertex(v) => formatter.write_fmt(format_args!(
+                        "#{face_bound_idx} = FACE_BOUND('', #{loop_idx}, .T.);
+#{loop_idx} = VERTEX_LOOP('', #{vertex_idx});\n",
+                        vertex_idx = ep_vertices + v,
+                    )),
+                    StepBound::Edges(b) => {
+                        let ep_oriented_edges = loop_idx + 1;
+                        formatter.write_fmt(format_args!(
+                            "#{face_bound_idx} = FACE_BOUND('', #{loop_idx}, {orientation});
+#{loop_idx} = EDGE_LOOP('', {oriented_edge_indices});\n",
+                            orientation = BooleanDisplay(f.orientation),
+                            oriented_edge_indices =
+                                IndexSliceDisplay(ep_oriented_edges..ep_oriented_edges + b.len()),
+                        ))?;
+                        b.iter().enumerate().try_for_each(|(j, ce)| {
+                            formatter.write_fmt(format_args!(
+                                "#{idx} = ORIENTED_EDGE('', *, *, #{edge_element}, {orientation});\n",
+                                idx = ep_oriented_edges + j,
+                                edge_element = ep_edges + ce.index,
+                                orientation = if ce.orientation { ".T." } else { ".F." },
+                            ))
+                        })
+                    }
+                }
             })
         })?;
         edges.iter().enumerate().try_for_each(|(i, e)| {
+            let e = &entity.edges[*e];
             let same_sense = if e.curve.same_sense() { ".T." } else { ".F." };
             formatter.write_fmt(format_args!(
                 "#{idx} = EDGE_CURVE('', #{edge_start}, #{edge_end}, #{edge_geometry}, {same_sense});\n",
                 idx = ep_edges + i,
-                edge_start = ep_vertices + e.vertices.0,
-                edge_end = ep_vertices + e.vertices.1,
+                edge_start = ep_vertices + vertex_reps[e.vertices.0],
+                edge_end = ep_vertices + vertex_reps[e.vertices.1],
                 edge_geometry = curve_indices[i],
             ))
         })?;
@@ -173,10 +291,9 @@ where
         faces.iter().zip(surface_indices).try_for_each(|(f, idx)| {
             Display::fmt(&StepDisplay::new(&f.surface, *idx), formatter)
         })?;
-        edges
-            .iter()
-            .zip(curve_indices)
-            .try_for_each(|(e, idx)| Display::fmt(&StepDisplay::new(&e.curve, *idx), formatter))?;
+        edges.iter().zip(curve_indices).try_for_each(|(e, idx)| {
+            Display::fmt(&StepDisplay::new(&entity.edges[*e].curve, *idx), formatter)
+        })?;
         vertices
             .iter()
             .enumerate()
@@ -185,9 +302,7 @@ where
 }
 
 impl<P, C, S> StepLength for StepShell<'_, P, C, S> {
-    fn step_length(&self) -> usize {
-        1 + self.ep_points + self.entity.vertices.len() - self.face_indices[0]
-    }
+    fn step_length(&self) -> usize { self.ep_points + self.entity.vertices.len() - self.idx }
 }
 
 #[derive(Clone, Debug)]
@@ -198,8 +313,8 @@ pub(super) struct StepSolid<'a, P, C, S> {
 
 impl<'a, P, C, S> StepSolid<'a, P, C, S>
 where
-    P: Copy,
-    C: StepLength,
+    P: Copy + Tolerance,
+    C: StepLength + ParametricCurve<Point = P>,
     S: StepLength,
 {
     fn new(solid: &'a CompressedSolid<P, C, S>, idx: usize) -> Self {
@@ -283,8 +398,8 @@ pub(super) enum PreStepModel<'a, P, C, S> {
 
 impl<'a, P, C, S> From<&'a CompressedShell<P, C, S>> for PreStepModel<'a, P, C, S>
 where
-    P: Copy,
-    C: StepLength,
+    P: Copy + Tolerance,
+    C: StepLength + ParametricCurve<Point = P>,
     S: StepLength,
 {
     fn from(shell: &'a CompressedShell<P, C, S>) -> Self {
@@ -294,8 +409,8 @@ where
 
 impl<'a, P, C, S> From<&'a CompressedSolid<P, C, S>> for PreStepModel<'a, P, C, S>
 where
-    P: Copy,
-    C: StepLength,
+    P: Copy + Tolerance,
+    C: StepLength + ParametricCurve<Point = P>,
     S: StepLength,
 {
     fn from(solid: &'a CompressedSolid<P, C, S>) -> Self { Self::Solid(StepSolid::new(solid, 16)) }
@@ -333,8 +448,8 @@ impl<P, C, S> StepLength for PreStepModel<'_, P, C, S> {
 
 impl<'a, P, C, S> From<&'a CompressedShell<P, C, S>> for StepModel<'a, P, C, S>
 where
-    P: Copy,
-    C: StepLength,
+    P: Copy + Tolerance,
+    C: StepLength + ParametricCurve<Point = P>,
     S: StepLength,
 {
     fn from(shell: &'a CompressedShell<P, C, S>) -> Self { Self(shell.into()) }
@@ -342,20 +457,20 @@ where
 
 impl<'a, P, C, S> From<&'a CompressedSolid<P, C, S>> for StepModel<'a, P, C, S>
 where
-    P: Copy,
-    C: StepLength,
+    P: Copy + Tolerance,
+    C: StepLength + ParametricCurve<Point = P>,
     S: StepLength,
 {
     fn from(solid: &'a CompressedSolid<P, C, S>) -> Self { Self(solid.into()) }
 }
 
-impl<P, C, S> Display for StepModel<'_, P, C, S>
+impl<P, C, S> StepDataSection for StepModel<'_, P, C, S>
 where
     P: DisplayByStep + Copy,
     C: DisplayByStep + StepLength + StepCurve,
     S: DisplayByStep + StepLength + StepSurface,
 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    fn fmt_data_section(&self, unit: StepLengthUnit, f: &mut Formatter<'_>) -> Result {
         f.pad(
 "#1 = APPLICATION_PROTOCOL_DEFINITION('international standard', 'automotive_design', 2000, #2);
 #2 = APPLICATION_CONTEXT('core data for automotive mechanical design processes');
@@ -366,22 +481,28 @@ where
 #7 = PRODUCT('','','', (#8));
 #8 = PRODUCT_CONTEXT('', #2, 'mechanical');
 #9 = PRODUCT_DEFINITION_CONTEXT('part definition', #2, 'design');
-#10 = ADVANCED_BREP_SHAPE_REPRESENTATION('', (#16), #11);
-#11 = (
-    GEOMETRIC_REPRESENTATION_CONTEXT(3)
-    GLOBAL_UNCERTAINTY_ASSIGNED_CONTEXT((#15))
-    GLOBAL_UNIT_ASSIGNED_CONTEXT((#12, #13, #14))
-    REPRESENTATION_CONTEXT('Context #1', '3D Context with UNIT and UNCERTAINTY')
-);
-#12 = ( LENGTH_UNIT() NAMED_UNIT(*) SI_UNIT(.MILLI.,.METRE.) );
-#13 = ( NAMED_UNIT(*) PLANE_ANGLE_UNIT() SI_UNIT($,.RADIAN.) );
-#14 = ( NAMED_UNIT(*) SI_UNIT($,.STERADIAN.) SOLID_ANGLE_UNIT() );
-#15 = UNCERTAINTY_MEASURE_WITH_UNIT(LENGTH_MEASURE(1.0E-6), #12, 'distance_accuracy_value','confusion accuracy');\n"
+#10 = ADVANCED_BREP_SHAPE_REPRESENTATION('', (#16), #11);\n"
         )?;
-        Display::fmt(&self.0, f)
+        let unit_idx = match unit {
+            StepLengthUnit::Millimetre | StepLengthUnit::Metre => 0,
+            _ => 16 + self.0.step_length(),
+        };
+        fmt_representation_context(unit, unit_idx, f)?;
+        Display::fmt(&self.0, f)?;
+        fmt_conversion_based_unit(unit, unit_idx, f)
     }
 }
 
+impl<P, C, S> Display for StepModel<'_, P, C, S>
+where
+    P: DisplayByStep + Copy,
+    C: DisplayByStep + StepLength + StepCurve,
+    S: DisplayByStep + StepLength + StepSurface,
+{
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result { self.fmt_data_section(Default::default(), f) }
+}
+
 impl<P, C, S> Default for StepModels<'_, P, C, S> {
     fn default() -> Self {
         Self {
@@ -393,8 +514,8 @@ impl<P, C, S> Default for StepModels<'_, P, C, S> {
 
 impl<'a, P, C, S> StepModels<'a, P, C, S>
 where
-    P: Copy,
-    C: StepLength,
+    P: Copy + Tolerance,
+    C: StepLength + ParametricCurve<Point = P>,
     S: StepLength,
 {
     /// push a shell to step models
@@ -413,8 +534,8 @@ where
 
 impl<'a, P, C, S> FromIterator<&'a CompressedShell<P, C, S>> for StepModels<'a, P, C, S>
 where
-    P: Copy,
-    C: StepLength,
+    P: Copy + Tolerance,
+    C: StepLength + ParametricCurve<Point = P>,
     S: StepLength,
 {
     fn from_iter<T: IntoIterator<Item = &'a CompressedShell<P, C, S>>>(iter: T) -> Self {
@@ -433,8 +554,8 @@ where
 
 impl<'a, P, C, S> FromIterator<&'a CompressedSolid<P, C, S>> for StepModels<'a, P, C, S>
 where
-    P: Copy,
-    C: StepLength,
+    P: Copy + Tolerance,
+    C: StepLength + ParametricCurve<Point = P>,
     S: StepLength,
 {
     fn from_iter<T: IntoIterator<Item = &'a CompressedSolid<P, C, S>>>(iter: T) -> Self {
@@ -451,13 +572,13 @@ where
     }
 }
 
-impl<P, C, S> Display for StepModels<'_, P, C, S>
+impl<P, C, S> StepDataSection for StepModels<'_, P, C, S>
 where
     P: DisplayByStep + Copy,
     C: DisplayByStep + StepLength + StepCurve,
     S: DisplayByStep + StepLength + StepSurface,
 {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    fn fmt_data_section(&self, unit: StepLengthUnit, f: &mut Formatter<'_>) -> Result {
         f.pad(
 "#1 = APPLICATION_PROTOCOL_DEFINITION('international standard', 'automotive_design', 2000, #2);
 #2 = APPLICATION_CONTEXT('core data for automotive mechanical design processes');
@@ -475,19 +596,79 @@ where
         f.write_fmt(format_args!(
             "#10 = ADVANCED_BREP_SHAPE_REPRESENTATION('', {models_slice}, #11);\n"
         ))?;
-        f.pad("#11 = (
-    GEOMETRIC_REPRESENTATION_CONTEXT(3) 
+        fmt_representation_context(unit, self.next_idx, f)?;
+        self.models
+            .iter()
+            .try_for_each(|model| Display::fmt(model, f))?;
+        fmt_conversion_based_unit(unit, self.next_idx, f)
+    }
+}
+
+impl<P, C, S> Display for StepModels<'_, P, C, S>
+where
+    P: DisplayByStep + Copy,
+    C: DisplayByStep + StepLength + StepCurve,
+    S: DisplayByStep + StepLength + StepSurface,
+{
+    #[inline(always)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result { self.fmt_data_section(Default::default(), f) }
+}
+
+/// Outputs the representation context and the units, `#11`-`#15`.
+/// `unit_idx` is the first index of the entities defining the conversion based unit.
+fn fmt_representation_context(
+    unit: StepLengthUnit,
+    unit_idx: usize,
+    f: &mut Formatter<'_>,
+) -> Result {
+    f.pad(
+        "#11 = (
+    GEOMETRIC_REPRESENTATION_CONTEXT(3)
     GLOBAL_UNCERTAINTY_ASSIGNED_CONTEXT((#15))
     GLOBAL_UNIT_ASSIGNED_CONTEXT((#12, #13, #14))
     REPRESENTATION_CONTEXT('Context #1', '3D Context with UNIT and UNCERTAINTY')
-);
-#12 = ( LENGTH_UNIT() NAMED_UNIT(*) SI_UNIT(.MILLI.,.METRE.) );
-#13 = ( NAMED_UNIT(*) PLANE_ANGLE_UNIT() SI_UNIT($,.RADIAN.) );
+);\n",
+    )?;
+    match unit {
+        StepLengthUnit::Millimetre => {
+            f.pad("#12 = ( LENGTH_UNIT() NAMED_UNIT(*) SI_UNIT(.MILLI.,.METRE.) );\n")?
+        }
+        StepLengthUnit::Metre => {
+            f.pad("#12 = ( LENGTH_UNIT() NAMED_UNIT(*) SI_UNIT($,.METRE.) );\n")?
+        }
+        StepLengthUnit::Inch | StepLengthUnit::Foot => {
+            let name = match unit {
+                StepLengthUnit::Inch => "INCH",
+                _ => "FOOT",
+            };
+            let dimension_idx = unit_idx + 1;
+            f.write_fmt(format_args!(
+                "#12 = ( CONVERSION_BASED_UNIT('{name}', #{unit_idx}) LENGTH_UNIT() NAMED_UNIT(#{dimension_idx}) );\n"
+            ))?
+        }
+    }
+    f.pad(
+        "#13 = ( NAMED_UNIT(*) PLANE_ANGLE_UNIT() SI_UNIT($,.RADIAN.) );
 #14 = ( NAMED_UNIT(*) SI_UNIT($,.STERADIAN.) SOLID_ANGLE_UNIT() );
-#15 = UNCERTAINTY_MEASURE_WITH_UNIT(LENGTH_MEASURE(1.0E-6), #12, 'distance_accuracy_value','confusion accuracy');\n"
-        )?;
-        self.models
-            .iter()
-            .try_for_each(|model| Display::fmt(model, f))
+#15 = UNCERTAINTY_MEASURE_WITH_UNIT(LENGTH_MEASURE(1.0E-6), #12, 'distance_accuracy_value','confusion accuracy');\n",
+    )
+}
+
+/// Outputs the entities defining the conversion based unit, if the unit is not SI unit.
+fn fmt_conversion_based_unit(
+    unit: StepLengthUnit,
+    unit_idx: usize,
+    f: &mut Formatter<'_>,
+) -> Result {
+    if matches!(unit, StepLengthUnit::Millimetre | StepLengthUnit::Metre) {
+        return Ok(());
     }
+    let dimension_idx = unit_idx + 1;
+    let si_unit_idx = unit_idx + 2;
+    f.write_fmt(format_args!(
+        "#{unit_idx} = LENGTH_MEASURE_WITH_UNIT(LENGTH_MEASURE({factor}), #{si_unit_idx});
+#{dimension_idx} = DIMENSIONAL_EXPONENTS(1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+#{si_unit_idx} = ( LENGTH_UNIT() NAMED_UNIT(*) SI_UNIT(.MILLI.,.METRE.) );\n",
+        factor = FloatDisplay(unit.in_millimetres()),
+    ))
 }
@@ -718,3 +718,114 @@ Record { name: \"REPRESENTATION_CONTEXT\", parameter: List([String(\"2D SPACE\")
     };
     assert_eq!(table, ans_table);
 }
+
+#[test]
+fn face_colors() {
+    let data_section = DataSection::from_str(
+        "DATA;
+#1 = CARTESIAN_POINT('Point', (0.0, 0.0, 0.0));
+#2 = AXIS2_PLACEMENT_3D('Axis2Placement3d', #1, $, $);
+#3 = PLANE('Plane', #2);
+#4 = ADVANCED_FACE('Face0', (), #3, .T.);
+#5 = ADVANCED_FACE('Face1', (), #3, .T.);
+#6 = ADVANCED_FACE('Face2', (), #3, .T.);
+#7 = ORIENTED_FACE('OrientedFace', *, #6, .F.);
+#8 = CLOSED_SHELL('Shell', (#4, #5, #7));
+#9 = MANIFOLD_SOLID_BREP('Solid', #8);
+
+#10 = COLOUR_RGB('', 0.1, 0.2, 0.3);
+#11 = FILL_AREA_STYLE_COLOUR('', #10);
+#12 = FILL_AREA_STYLE('', (#11));
+#13 = SURFACE_STYLE_FILL_AREA(#12);
+#14 = SURFACE_SIDE_STYLE('', (#13));
+#15 = SURFACE_STYLE_USAGE(.BOTH., #14);
+#16 = PRESENTATION_STYLE_ASSIGNMENT((#15));
+#17 = STYLED_ITEM('color', (#16), #9);
+
+#20 = DRAUGHTING_PRE_DEFINED_COLOUR('red');
+#21 = FILL_AREA_STYLE_COLOUR('', #20);
+#22 = FILL_AREA_STYLE('', (#21));
+#23 = SURFACE_STYLE_FILL_AREA(#22);
+#24 = SURFACE_SIDE_STYLE('', (#23));
+#25 = SURFACE_STYLE_USAGE(.BOTH., #24);
+#26 = PRESENTATION_STYLE_ASSIGNMENT((#25));
+#27 = OVER_RIDING_STYLED_ITEM('overriding color', (#26), #5, #17);
+#28 = STYLED_ITEM('color', (#26), #7);
+ENDSEC;",
+    )
+    .unwrap();
+    let table = Table::from_data_section(&data_section);
+    assert_eq!(table.styled_item.len(), 3);
+    assert_eq!(table.colour_rgb.len(), 2);
+
+    let colors = table.face_colors();
+    let ans = HashMap::from_iter(vec![
+        (4, [0.1, 0.2, 0.3]),
+        (5, [1.0, 0.0, 0.0]),
+        (6, [1.0, 0.0, 0.0]),
+    ]);
+    assert_eq!(colors, ans);
+}
+
+#[test]
+fn from_steps() {
+    let step_string = "ISO-10303-21;
+HEADER;
+FILE_DESCRIPTION(('two data sections'), '2;1');
+FILE_NAME('', '', (''), (''), '', '', '');
+FILE_SCHEMA(('AUTOMOTIVE_DESIGN'));
+ENDSEC;
+DATA;
+#1 = CARTESIAN_POINT('Point0', (0.0, 0.0, 0.0));
+#2 = VERTEX_POINT('Vertex', #1);
+ENDSEC;
+DATA;
+#1 = CARTESIAN_POINT('Duplicated', (1.0, 1.0, 1.0));
+#3 = DIRECTION('Dir', (1.0, 0.0, 0.0));
+#4 = VECTOR('Vector', #3, 1.0);
+#5 = LINE('Line', #1, #4);
+ENDSEC;
+END-ISO-10303-21;
+";
+    let exchange = ruststep::parser::parse(step_string).unwrap();
+    assert_eq!(exchange.data.len(), 2);
+    let table = Table::from_steps(&exchange);
+    assert_eq!(table.cartesian_point.len(), 1);
+    assert_eq!(table.cartesian_point[&1].label, "Point0");
+    assert_eq!(table.vertex_point.len(), 1);
+    assert_eq!(table.direction.len(), 1);
+    assert_eq!(table.vector.len(), 1);
+    assert_eq!(table.line.len(), 1);
+    assert_eq!(Table::from_step(step_string), Some(table));
+}
+
+#[test]
+fn sorted_reconstruction() {
+    use truck_modeling::*;
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let moved = builder::translated(&cube, Vector3::new(2.0, 0.0, 0.0));
+    let compressed = [cube.compress(), moved.compress()];
+    let step_string = truck_stepio::out::CompleteStepDisplay::new(
+        truck_stepio::out::StepModels::from_iter(&compressed),
+        Default::default(),
+    )
+    .to_string();
+
+    let reconstruct = || {
+        let table = Table::from_step(&step_string).unwrap();
+        let shells = table.sorted_shells();
+        assert!(shells.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        let shells = shells
+            .into_iter()
+            .map(|(_, shell)| table.to_compressed_shell(shell).unwrap())
+            .collect::<Vec<_>>();
+        (shells, table.to_compressed_solids())
+    };
+    let (shells, solids) = reconstruct();
+    assert_eq!(shells.len(), 2);
+    assert_eq!(solids.len(), 2);
+    (0..4).for_each(|_| assert_eq!(reconstruct(), (shells.clone(), solids.clone())));
+}
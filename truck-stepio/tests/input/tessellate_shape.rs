@@ -1,5 +1,7 @@
+use ruststep::ast::DataSection;
+use std::str::FromStr;
 use truck_meshalgo::prelude::*;
-use truck_stepio::r#in::*;
+use truck_stepio::r#in::{step_geometry::Curve3D, *};
 use truck_topology::shell::ShellCondition;
 
 const STEP_DIRECTORY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../resources/step/");
@@ -34,3 +36,80 @@ fn tessellate_shape() {
         });
     });
 }
+
+/// A cylinder whose side face is bounded by the seam curve with the pcurves at `u = 0` and `u = 2π`.
+const SEAM_CYLINDER: &str = "DATA;
+#1 = CARTESIAN_POINT('', (0.0, 0.0, 0.0));
+#2 = DIRECTION('', (0.0, 0.0, 1.0));
+#3 = DIRECTION('', (1.0, 0.0, 0.0));
+#4 = AXIS2_PLACEMENT_3D('', #1, #2, #3);
+#5 = CYLINDRICAL_SURFACE('', #4, 1.0);
+#6 = CARTESIAN_POINT('', (0.0, 0.0, 2.0));
+#7 = AXIS2_PLACEMENT_3D('', #6, #2, #3);
+#8 = PLANE('', #4);
+#9 = PLANE('', #7);
+#10 = CIRCLE('', #4, 1.0);
+#11 = CIRCLE('', #7, 1.0);
+#12 = CARTESIAN_POINT('', (1.0, 0.0, 0.0));
+#13 = CARTESIAN_POINT('', (1.0, 0.0, 2.0));
+#14 = VERTEX_POINT('', #12);
+#15 = VERTEX_POINT('', #13);
+#16 = VECTOR('', #2, 1.0);
+#17 = LINE('', #12, #16);
+#18 = CARTESIAN_POINT('', (0.0, 0.0));
+#19 = CARTESIAN_POINT('', (6.283185307179586, 0.0));
+#20 = DIRECTION('', (0.0, 1.0));
+#21 = VECTOR('', #20, 1.0);
+#22 = LINE('', #18, #21);
+#23 = LINE('', #19, #21);
+#24 = ( GEOMETRIC_REPRESENTATION_CONTEXT(2) PARAMETRIC_REPRESENTATION_CONTEXT() REPRESENTATION_CONTEXT('2D SPACE','') );
+#25 = DEFINITIONAL_REPRESENTATION('', (#22), #24);
+#26 = DEFINITIONAL_REPRESENTATION('', (#23), #24);
+#27 = PCURVE('', #5, #25);
+#28 = PCURVE('', #5, #26);
+#29 = SEAM_CURVE('', #17, (#27, #28), .PCURVE_S1.);
+#30 = EDGE_CURVE('', #14, #14, #10, .T.);
+#31 = EDGE_CURVE('', #15, #15, #11, .T.);
+#32 = EDGE_CURVE('', #14, #15, #29, .T.);
+#33 = ORIENTED_EDGE('', *, *, #30, .T.);
+#34 = ORIENTED_EDGE('', *, *, #32, .T.);
+#35 = ORIENTED_EDGE('', *, *, #31, .F.);
+#36 = ORIENTED_EDGE('', *, *, #32, .F.);
+#37 = EDGE_LOOP('', (#33, #34, #35, #36));
+#38 = FACE_BOUND('', #37, .T.);
+#39 = ADVANCED_FACE('', (#38), #5, .T.);
+#40 = ORIENTED_EDGE('', *, *, #30, .F.);
+#41 = EDGE_LOOP('', (#40));
+#42 = FACE_OUTER_BOUND('', #41, .T.);
+#43 = ADVANCED_FACE('', (#42), #8, .F.);
+#44 = ORIENTED_EDGE('', *, *, #31, .T.);
+#45 = EDGE_LOOP('', (#44));
+#46 = FACE_OUTER_BOUND('', #45, .T.);
+#47 = ADVANCED_FACE('', (#46), #9, .T.);
+#48 = CLOSED_SHELL('', (#39, #43, #47));
+ENDSEC;
+";
+
+#[test]
+fn seam_cylinder() {
+    let data_section = DataSection::from_str(SEAM_CYLINDER).unwrap();
+    let table = Table::from_data_section(&data_section);
+    let step_shell = table.shell.values().next().unwrap();
+    let cshell = table.to_compressed_shell(step_shell).unwrap();
+    assert_eq!(cshell.edges.len(), 3);
+
+    // The seam edge is the segment on the surface, not the pcurve on either side of the seam.
+    let seam = cshell
+        .edges
+        .iter()
+        .find(|edge| edge.vertices.0 != edge.vertices.1)
+        .unwrap();
+    assert!(matches!(seam.curve, Curve3D::Line(_)));
+    assert_near!(seam.curve.front(), Point3::new(1.0, 0.0, 0.0));
+    assert_near!(seam.curve.back(), Point3::new(1.0, 0.0, 2.0));
+
+    let mut poly = cshell.triangulation(0.01).to_polygon();
+    poly.put_together_same_attrs(TOLERANCE * 50.0)
+        .remove_degenerate_faces();
+    assert_eq!(poly.shell_condition(), ShellCondition::Closed);
+}
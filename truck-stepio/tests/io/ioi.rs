@@ -1,6 +1,9 @@
 use truck_meshalgo::prelude::*;
 use truck_stepio::{out::*, r#in::*};
-use truck_topology::shell::ShellCondition;
+use truck_topology::{
+    compress::{CompressedEdge, CompressedEdgeIndex, CompressedShell},
+    shell::ShellCondition,
+};
 
 const STEP_DIRECTORY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../resources/step/");
 
@@ -46,3 +49,214 @@ fn ioi() {
         });
     });
 }
+
+/// Returns the start and end points of the vertices and of the curves of the edges, which are
+/// listed along the boundaries oriented by the faces.
+fn oriented_edge_ends<C, S>(cshell: &CompressedShell<Point3, C, S>) -> Vec<[Point3; 4]>
+where C: BoundedCurve<Point = Point3> {
+    let mut ends = Vec::new();
+    for face in &cshell.faces {
+        for boundary in &face.boundaries {
+            let mut wire = boundary
+                .iter()
+                .map(|ce| (ce.index, ce.orientation == face.orientation))
+                .collect::<Vec<_>>();
+            if !face.orientation {
+                wire.reverse();
+            }
+            for (index, orientation) in wire {
+                let edge = &cshell.edges[index];
+                let (t0, t1) = edge.curve.range_tuple();
+                let (v0, v1) = edge.vertices;
+                let (p0, p1) = (cshell.vertices[v0], cshell.vertices[v1]);
+                let (q0, q1) = (edge.curve.subs(t0), edge.curve.subs(t1));
+                ends.push(match orientation {
+                    true => [p0, p1, q0, q1],
+                    false => [p1, p0, q1, q0],
+                });
+            }
+        }
+    }
+    ends
+}
+
+#[test]
+fn edge_orientations() {
+    STEP_FILES.iter().for_each(|file_name| {
+        let input = [STEP_DIRECTORY, file_name].concat();
+        let step_string = std::fs::read_to_string(input).unwrap();
+        let table = Table::from_step(&step_string).unwrap();
+        table.shell.values().cloned().for_each(|step_shell| {
+            let cshell = table.to_compressed_shell(&step_shell).unwrap();
+            let step_string =
+                CompleteStepDisplay::new(StepModel::from(&cshell), Default::default()).to_string();
+            let table = Table::from_step(&step_string).unwrap();
+            let step_shell = table.shell.values().next().unwrap();
+            let reimported = table.to_compressed_shell(step_shell).unwrap();
+
+            let ends0 = oriented_edge_ends(&cshell);
+            let ends1 = oriented_edge_ends(&reimported);
+            assert_eq!(ends0.len(), ends1.len(), "{file_name}");
+            ends0.iter().zip(&ends1).for_each(|(x, y)| {
+                x.iter()
+                    .zip(y)
+                    .for_each(|(p, q)| assert_near!(*p, *q, "{file_name}"));
+            });
+        })
+    });
+}
+
+#[test]
+fn oi_solids() {
+    use truck_modeling::*;
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x() * 3.0);
+    let f = builder::tsweep(&e, Vector3::unit_y() * 3.0);
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z() * 3.0);
+    let v = builder::vertex(Point3::new(1.0, 1.0, 1.0));
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let mut inner: Solid = builder::tsweep(&f, Vector3::unit_z());
+    inner.not();
+    let cube_in_cube = Solid::new(vec![
+        cube.boundaries()[0].clone(),
+        inner.boundaries()[0].clone(),
+    ]);
+
+    let compressed = [cube.compress(), cube_in_cube.compress()];
+    let step_string =
+        CompleteStepDisplay::new(StepModels::from_iter(&compressed), Default::default())
+            .to_string();
+    let table = Table::from_step(&step_string).unwrap();
+    let csolids = table.to_compressed_solids();
+    assert_eq!(csolids.len(), 2);
+    assert_eq!(csolids[0].boundaries.len(), 1);
+    assert_eq!(csolids[1].boundaries.len(), 2);
+    csolids.into_iter().for_each(|csolid| {
+        csolid.boundaries.iter().for_each(|cshell| {
+            let mut poly = cshell.triangulation(0.01).to_polygon();
+            poly.put_together_same_attrs(TOLERANCE * 50.0)
+                .remove_degenerate_faces();
+            assert_eq!(poly.shell_condition(), ShellCondition::Closed);
+        });
+        assert!(truck_topology::Solid::extract(csolid).is_ok());
+    });
+}
+
+#[test]
+fn oi_rsweep() {
+    use truck_modeling::*;
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_z());
+    let pipe: Solid = builder::rsweep(&f, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+
+    let compressed = pipe.compress();
+    let step_string =
+        CompleteStepDisplay::new(StepModel::from(&compressed), Default::default()).to_string();
+    assert!(step_string.contains("SURFACE_OF_REVOLUTION"));
+    assert!(!step_string.contains("B_SPLINE_SURFACE"));
+    let table = Table::from_step(&step_string).unwrap();
+    let csolids = table.to_compressed_solids();
+    assert_eq!(csolids.len(), 1);
+    let cshell = &csolids[0].boundaries[0];
+    assert_eq!(cshell.faces.len(), compressed.boundaries[0].faces.len());
+    let mut poly = cshell.triangulation(0.01).to_polygon();
+    poly.put_together_same_attrs(TOLERANCE * 50.0)
+        .remove_degenerate_faces();
+    assert_eq!(poly.shell_condition(), ShellCondition::Closed);
+}
+
+#[test]
+fn oi_swept_sphere() {
+    use truck_modeling::*;
+    let v0 = builder::vertex(Point3::new(0.0, 0.0, 1.0));
+    let v1 = builder::vertex(Point3::new(0.0, 0.0, -1.0));
+    let semicircle = builder::circle_arc(&v0, &v1, Point3::new(1.0, 0.0, 0.0));
+    // the arcs swept by the poles are degenerate.
+    let sphere: Shell = builder::rsweep(&semicircle, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    assert_eq!(sphere.shell_condition(), ShellCondition::Oriented);
+
+    let compressed = sphere.compress();
+    let step_string =
+        CompleteStepDisplay::new(StepModel::from(&compressed), Default::default()).to_string();
+    assert!(!step_string.contains("NaN"));
+    let table = Table::from_step(&step_string).unwrap();
+    let step_shell = table.shell.values().next().unwrap();
+    let cshell = table.to_compressed_shell(step_shell).unwrap();
+    // the degenerate arcs are collapsed into the poles.
+    assert_eq!(cshell.vertices.len(), 2);
+    assert_eq!(cshell.edges.len(), 3);
+    let mut poly = cshell.triangulation(0.01).to_polygon();
+    poly.put_together_same_attrs(TOLERANCE * 50.0)
+        .remove_degenerate_faces();
+    assert_eq!(poly.shell_condition(), ShellCondition::Closed);
+}
+
+#[test]
+fn oi_vertex_loop() {
+    use truck_modeling::*;
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let circle: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    let disk: Face = builder::try_attach_plane(vec![circle]).unwrap();
+    let mut compressed = Shell::from(vec![disk]).compress();
+    // a loop collapsed to the center of the disk
+    let len = compressed.vertices.len();
+    compressed.vertices.extend([Point3::origin(); 3]);
+    let degenerate = (0..3).map(|i| CompressedEdge {
+        vertices: (len + i, len + (i + 1) % 3),
+        curve: Curve::Line(Line(Point3::origin(), Point3::origin())),
+    });
+    let edge_len = compressed.edges.len();
+    compressed.edges.extend(degenerate);
+    let point_loop = (0..3)
+        .map(|i| CompressedEdgeIndex {
+            index: edge_len + i,
+            orientation: true,
+        })
+        .collect();
+    compressed.faces[0].boundaries.push(point_loop);
+
+    let step_string =
+        CompleteStepDisplay::new(StepModel::from(&compressed), Default::default()).to_string();
+    assert_eq!(step_string.matches("VERTEX_LOOP").count(), 1);
+    let table = Table::from_step(&step_string).unwrap();
+    let step_shell = table.shell.values().next().unwrap();
+    let cshell = table.to_compressed_shell(step_shell).unwrap();
+    // the vertex loop does not bound the face.
+    assert_eq!(cshell.faces[0].boundaries.len(), 1);
+    assert_eq!(cshell.edges.len(), 3);
+}
+
+#[test]
+fn oi_closed_edge() {
+    use truck_modeling::*;
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let circle: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    let disk: Face = builder::try_attach_plane(vec![circle]).unwrap();
+    let mut compressed = Shell::from(vec![disk]).compress();
+    // a closed edge whose ends are the distinct vertices at the same point
+    let curve = BSplineCurve::new(
+        KnotVec::bezier_knot(3),
+        vec![
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(0.0, 2.0, 0.0),
+            Point3::new(-1.0, -2.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+        ],
+    );
+    compressed.vertices = vec![Point3::new(1.0, 0.0, 0.0); 2];
+    compressed.edges = vec![CompressedEdge {
+        vertices: (0, 1),
+        curve: Curve::BSplineCurve(curve),
+    }];
+    compressed.faces[0].boundaries = vec![vec![CompressedEdgeIndex {
+        index: 0,
+        orientation: true,
+    }]];
+
+    let step_string =
+        CompleteStepDisplay::new(StepModel::from(&compressed), Default::default()).to_string();
+    assert_eq!(step_string.matches("EDGE_CURVE").count(), 1);
+    assert!(!step_string.contains("VERTEX_LOOP"));
+}
@@ -276,6 +276,37 @@ fn geometry() {
 #5 = DIRECTION('', (0.0, 1.0, 0.0));\n",
         5,
     );
+    step_test::<ExtrudedCurve<Line<Point3>, Vector3>>(
+        ExtrudedCurve::by_extrusion(
+            Line(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0)),
+            Vector3::new(0.0, 0.0, 2.0),
+        ),
+        "\
+#1 = SURFACE_OF_LINEAR_EXTRUSION('', #2, #6);
+#2 = LINE('', #3, #4);
+#3 = CARTESIAN_POINT('', (0.0, 0.0, 0.0));
+#4 = VECTOR('', #5, 1.0);
+#5 = DIRECTION('', (1.0, 0.0, 0.0));
+#6 = VECTOR('', #7, 2.0);
+#7 = DIRECTION('', (0.0, 0.0, 1.0));\n",
+        7,
+    );
+    step_test::<Processor<ExtrudedCurve<Line<Point3>, Vector3>, Matrix4>>(
+        Processor::new(ExtrudedCurve::by_extrusion(
+            Line(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0)),
+            Vector3::new(0.0, 0.0, 2.0),
+        ))
+        .transformed(Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0))),
+        "\
+#1 = SURFACE_OF_LINEAR_EXTRUSION('', #2, #6);
+#2 = LINE('', #3, #4);
+#3 = CARTESIAN_POINT('', (1.0, 2.0, 3.0));
+#4 = VECTOR('', #5, 1.0);
+#5 = DIRECTION('', (1.0, 0.0, 0.0));
+#6 = VECTOR('', #7, 2.0);
+#7 = DIRECTION('', (0.0, 0.0, 1.0));\n",
+        7,
+    );
     step_test::<BSplineSurface<Point2>>(
         BSplineSurface::new(
             (KnotVec::bezier_knot(2), KnotVec::uniform_knot(2, 2)),
@@ -116,8 +116,10 @@ fn custom_header_template() {
             "Univ. Clever Genius".to_string(),
             "Senkai".to_string(),
         ],
-        organization_system: "Kusanagi Sword".to_string(),
-        authorization: "IT'S ME!".to_string(),
+        preprocessor_version: None,
+        originating_system: Some("Kusanagi Sword".to_string()),
+        authorization: Some("IT'S ME!".to_string()),
+        unit: Default::default(),
     };
     let time_stamp = header.time_stamp.clone();
     let step_string = CompleteStepDisplay::new(StepModel::from(&cshell), header).to_string();
@@ -128,7 +130,7 @@ fn custom_header_template() {
 HEADER;
 FILE_DESCRIPTION(('Shape Data from Truck'), '2;1');
 FILE_NAME('AMAZING_PRODUCT.step', '{time_stamp}', ('Tensai', 'Genius', 'Bokusama'), \
-('Great Awesome Co. Ltd.', 'Univ. Clever Genius', 'Senkai'), 'truck', 'Kusanagi Sword', 'IT'S ME!');
+('Great Awesome Co. Ltd.', 'Univ. Clever Genius', 'Senkai'), 'truck', 'Kusanagi Sword', 'IT''S ME!');
 FILE_SCHEMA(('ISO-10303-042'));
 ENDSEC;
 DATA;
@@ -159,3 +161,46 @@ END-ISO-10303-21;\n"
         )
     );
 }
+
+#[test]
+fn custom_header_parse() {
+    let cshell = Shell::new().compress();
+    let header = StepHeaderDescriptor {
+        file_name: "bracket.step".to_string(),
+        authors: vec!["O'Brien".to_string()],
+        organization: vec!["PDM Pipeline Inc.".to_string()],
+        preprocessor_version: Some("truck 0.1".to_string()),
+        originating_system: Some("Bracket Designer".to_string()),
+        authorization: Some("Approved by QA".to_string()),
+        ..Default::default()
+    };
+    let step_string = CompleteStepDisplay::new(StepModel::from(&cshell), header).to_string();
+    ruststep::parser::parse(&step_string).unwrap_or_else(|e| panic!("{e}\n{step_string}"));
+    let file_name_line = step_string
+        .lines()
+        .find(|line| line.starts_with("FILE_NAME"))
+        .unwrap();
+    assert!(file_name_line.starts_with("FILE_NAME('bracket.step', "));
+    assert!(file_name_line.ends_with(
+        "('O''Brien'), ('PDM Pipeline Inc.'), 'truck 0.1', 'Bracket Designer', 'Approved by QA');"
+    ));
+}
+
+#[test]
+fn streamed_solid() {
+    let json = include_bytes!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../resources/shape/cube.json"
+    ));
+    let csolid: CompressedSolid = serde_json::from_reader(json.as_slice()).unwrap();
+    let model = StepModel::from(&csolid);
+    let header = StepHeaderDescriptor {
+        unit: StepLengthUnit::Inch,
+        ..Default::default()
+    };
+    let mut buffer = std::io::BufWriter::new(Vec::new());
+    write_step(&model, &header, &mut buffer).unwrap();
+    let streamed = String::from_utf8(buffer.into_inner().unwrap()).unwrap();
+    let step_string = CompleteStepDisplay::new(&model, header).to_string();
+    assert_eq!(streamed, step_string);
+}
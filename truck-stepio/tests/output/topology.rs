@@ -56,3 +56,62 @@ fn parse_solids() {
         panic!("failed to parse step\n[Error Message]\n{e}[STEP file]\n{step_string}")
     });
 }
+
+#[test]
+fn parse_shapeops_result() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+
+    let v = builder::vertex(Point3::new(0.5, 0.25, -0.5));
+    let w = builder::rsweep(&v, Point3::new(0.5, 0.5, 0.0), Vector3::unit_z(), Rad(7.0));
+    let f = builder::try_attach_plane(&[w]).unwrap();
+    let cylinder = builder::tsweep(&f, Vector3::unit_z() * 2.0);
+
+    for solid in [
+        truck_shapeops::or(&cube, &cylinder, 0.05).unwrap(),
+        truck_shapeops::and(&cube, &cylinder, 0.05).unwrap(),
+    ] {
+        let compressed = solid.compress();
+        let step_string =
+            CompleteStepDisplay::new(StepModel::from(&compressed), Default::default()).to_string();
+        assert!(step_string.contains("INTERSECTION_CURVE"));
+        let exchange = ruststep::parser::parse(&step_string).unwrap_or_else(|e| {
+            panic!("failed to parse step\n[Error Message]\n{e}[STEP file]\n{step_string}")
+        });
+        let table = truck_stepio::r#in::Table::from_data_section(&exchange.data[0]);
+        let step_shell = table.shell.values().next().unwrap();
+        let shell = table.to_compressed_shell(step_shell).unwrap();
+        assert_eq!(shell.faces.len(), compressed.boundaries[0].faces.len());
+    }
+}
+
+#[test]
+fn length_units() {
+    let json = std::fs::read(SOLID_JSONS[1]).unwrap();
+    let solid: CompressedSolid = serde_json::from_reader(json.as_slice()).unwrap();
+    let units = [
+        (StepLengthUnit::Millimetre, "SI_UNIT(.MILLI.,.METRE.)"),
+        (StepLengthUnit::Metre, "SI_UNIT($,.METRE.)"),
+        (StepLengthUnit::Inch, "CONVERSION_BASED_UNIT('INCH'"),
+        (StepLengthUnit::Foot, "CONVERSION_BASED_UNIT('FOOT'"),
+    ];
+    for (unit, entity) in units {
+        let header = StepHeaderDescriptor {
+            unit,
+            ..Default::default()
+        };
+        let step_string = CompleteStepDisplay::new(StepModel::from(&solid), header).to_string();
+        assert!(step_string.contains(entity), "{unit:?}");
+        let table = truck_stepio::r#in::Table::from_step(&step_string)
+            .unwrap_or_else(|| panic!("failed to parse step\n[STEP file]\n{step_string}"));
+        let step_shell = table.shell.values().next().unwrap();
+        let shell = table.to_compressed_shell(step_shell).unwrap();
+        let vertices = &solid.boundaries[0].vertices;
+        assert_eq!(vertices.len(), shell.vertices.len());
+        assert!(vertices
+            .iter()
+            .all(|p| shell.vertices.iter().any(|q| p.near(q))));
+    }
+}
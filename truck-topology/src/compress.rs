@@ -10,10 +10,12 @@
 
 use crate::*;
 use rustc_hash::FxHashMap as HashMap;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 /// Serialized compressed edge
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CompressedEdge<C> {
     /// vertices of the edge
     pub vertices: (usize, usize),
@@ -31,7 +33,8 @@ impl<C> CompressedEdge<C> {
 }
 
 /// The index of an edge in `CompressedShell`.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CompressedEdgeIndex {
     /// the index of the edge
     pub index: usize,
@@ -44,7 +47,8 @@ impl From<(usize, bool)> for CompressedEdgeIndex {
 }
 
 /// Serialized compressed face
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CompressedFace<S> {
     /// Boundaries of the face
     pub boundaries: Vec<Vec<CompressedEdgeIndex>>,
@@ -79,7 +83,8 @@ impl<S> CompressedFace<S> {
 }
 
 /// Serialized compressed shell
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CompressedShell<P, C, S> {
     /// all geometries of vertices
     pub vertices: Vec<P>,
@@ -90,7 +95,8 @@ pub struct CompressedShell<P, C, S> {
 }
 
 /// Serialized compressed solid
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CompressedSolid<P, C, S> {
     /// all boundaries of solid
     pub boundaries: Vec<CompressedShell<P, C, S>>,
@@ -290,6 +296,7 @@ fn same_topology<P, C, S, Q, D, T>(one: &Shell<P, C, S>, other: &Shell<Q, D, T>)
     true
 }
 
+#[cfg(feature = "serde")]
 impl<P, C, S> Serialize for Shell<P, C, S>
 where
     P: Clone + Serialize,
@@ -307,6 +314,7 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'de, P, C, S> Deserialize<'de> for Shell<P, C, S>
 where
     P: Clone + Deserialize<'de>,
@@ -321,6 +329,7 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
 impl<P, C, S> Serialize for Solid<P, C, S>
 where
     P: Clone + Serialize,
@@ -338,6 +347,7 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'de, P, C, S> Deserialize<'de> for Solid<P, C, S>
 where
     P: Clone + Deserialize<'de>,
@@ -352,6 +362,7 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
 impl<P, C, S> Serialize for Face<P, C, S>
 where
     P: Clone + Serialize,
@@ -369,6 +380,7 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
 impl<'de, P, C, S> Deserialize<'de> for Face<P, C, S>
 where
     P: Clone + Deserialize<'de>,
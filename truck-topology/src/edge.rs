@@ -42,7 +42,7 @@ impl<P, C> Edge<P, C> {
         Edge {
             vertices: (front.clone(), back.clone()),
             orientation: true,
-            curve: Arc::new(Mutex::new(curve)),
+            curve: new_entity(curve),
         }
     }
 
@@ -114,7 +114,7 @@ impl<P, C> Edge<P, C> {
         Edge {
             vertices: self.vertices.clone(),
             orientation: !self.orientation,
-            curve: Arc::clone(&self.curve),
+            curve: self.curve.clone(),
         }
     }
 
@@ -214,7 +214,7 @@ impl<P, C> Edge<P, C> {
     pub fn absolute_clone(&self) -> Self {
         Self {
             vertices: self.vertices.clone(),
-            curve: Arc::clone(&self.curve),
+            curve: self.curve.clone(),
             orientation: true,
         }
     }
@@ -288,7 +288,7 @@ impl<P, C> Edge<P, C> {
     /// assert_eq!(edge0.id(), edge1.id());
     /// ```
     #[inline(always)]
-    pub fn id(&self) -> EdgeID<C> { ID::new(Arc::as_ptr(&self.curve)) }
+    pub fn id(&self) -> EdgeID<C> { self.curve.id() }
 
     /// Returns how many same edges.
     ///
@@ -409,12 +409,12 @@ impl<P, C> Edge<P, C> {
         let edge0 = Edge {
             vertices: (self.absolute_front().clone(), vertex.clone()),
             orientation: self.orientation,
-            curve: Arc::new(Mutex::new(curve0)),
+            curve: new_entity(curve0),
         };
         let edge1 = Edge {
             vertices: (vertex.clone(), self.absolute_back().clone()),
             orientation: self.orientation,
-            curve: Arc::new(Mutex::new(curve1)),
+            curve: new_entity(curve1),
         };
         match self.orientation {
             true => (edge0, edge1),
@@ -551,7 +551,7 @@ impl<P, C> Clone for Edge<P, C> {
         Edge {
             vertices: self.vertices.clone(),
             orientation: self.orientation,
-            curve: Arc::clone(&self.curve),
+            curve: self.curve.clone(),
         }
     }
 }
@@ -579,7 +579,7 @@ impl<P: Debug, C: Debug> Debug for DebugDisplay<'_, Edge<P, C>, EdgeDisplayForma
         match self.format {
             EdgeDisplayFormat::Full { vertex_format } => f
                 .debug_struct("Edge")
-                .field("id", &Arc::as_ptr(&self.entity.curve))
+                .field("id", &self.entity.id())
                 .field(
                     "vertices",
                     &(
@@ -61,7 +61,7 @@ impl<P, C, S> Face<P, C, S> {
         Face {
             boundaries,
             orientation: true,
-            surface: Arc::new(Mutex::new(surface)),
+            surface: new_entity(surface),
         }
     }
 
@@ -176,7 +176,7 @@ impl<P, C, S> Face<P, C, S> {
     pub fn absolute_clone(&self) -> Self {
         Self {
             boundaries: self.boundaries.clone(),
-            surface: Arc::clone(&self.surface),
+            surface: self.surface.clone(),
             orientation: true,
         }
     }
@@ -215,7 +215,7 @@ impl<P, C, S> Face<P, C, S> {
     fn renew_pointer(&mut self)
     where S: Clone {
         let surface = self.surface();
-        self.surface = Arc::new(Mutex::new(surface));
+        self.surface = new_entity(surface);
     }
 
     /// Returns an iterator over the edges.
@@ -607,7 +607,7 @@ impl<P, C, S> Face<P, C, S> {
     /// assert_ne!(face0.id(), face2.id());
     /// ```
     #[inline(always)]
-    pub fn id(&self) -> FaceID<S> { ID::new(Arc::as_ptr(&self.surface)) }
+    pub fn id(&self) -> FaceID<S> { self.surface.id() }
 
     /// Returns how many same faces.
     ///
@@ -797,7 +797,7 @@ impl<P, C, S> Face<P, C, S> {
         let mut face0 = Face {
             boundaries: self.boundaries.clone(),
             orientation: self.orientation,
-            surface: Arc::new(Mutex::new(self.surface())),
+            surface: new_entity(self.surface()),
         };
         let boundary = &mut face0.boundaries[0];
         let i = boundary
@@ -820,7 +820,7 @@ impl<P, C, S> Face<P, C, S> {
         let face1 = Face {
             boundaries: vec![new_wire],
             orientation: self.orientation,
-            surface: Arc::new(Mutex::new(self.surface())),
+            surface: new_entity(self.surface()),
         };
         Some((face0, face1))
     }
@@ -921,7 +921,7 @@ impl<P, C, S> Face<P, C, S> {
         Some(Face {
             boundaries,
             orientation: self.orientation(),
-            surface: Arc::new(Mutex::new(surface)),
+            surface: new_entity(surface),
         })
     }
 
@@ -1002,6 +1002,19 @@ impl<P, C, S: Clone + Invertible> Face<P, C, S> {
     }
 }
 
+impl<P, C, S: ParametricSurface3D> Face<P, C, S> {
+    /// Returns the normal vector of the surface at `(u, v)`.
+    /// If face is inverted, then the returned normal is also inverted.
+    #[inline(always)]
+    pub fn oriented_normal(&self, u: f64, v: f64) -> S::Vector {
+        let normal = self.surface.lock().normal(u, v);
+        match self.orientation {
+            true => normal,
+            false => -normal,
+        }
+    }
+}
+
 impl<P, C, S> Face<P, C, S>
 where
     P: Tolerance,
@@ -1028,7 +1041,7 @@ impl<P, C, S> Clone for Face<P, C, S> {
         Face {
             boundaries: self.boundaries.clone(),
             orientation: self.orientation,
-            surface: Arc::clone(&self.surface),
+            surface: self.surface.clone(),
         }
     }
 }
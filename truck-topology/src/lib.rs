@@ -67,6 +67,8 @@
 //! * `nightly` – Use features available only in a `nightly` toolchain.
 //! * `rclite` – Use of `rclite::Arc` instead of `std::syn::Arc`. The latter
 //!   uses more memory and is potentially slower than the former. On by default.
+//! * `serde` – Implements `Serialize` and `Deserialize` for the compressed structures,
+//!   and for `Shell`, `Solid` and `Face` via them. On by default.
 
 #![cfg_attr(not(debug_assertions), deny(warnings))]
 #![deny(clippy::all, rust_2018_idioms)]
@@ -95,6 +97,58 @@ use std::sync::Arc;
 
 const SEARCH_PARAMETER_TRIALS: usize = 100;
 
+/// The shared entity of an element.
+///
+/// With the feature `deterministic-ids`, the entity also holds the ID given at its allocation.
+/// cf. [`ID::register`]
+struct Entity<T> {
+    arc: Arc<Mutex<T>>,
+    #[cfg(feature = "deterministic-ids")]
+    id: ID<Mutex<T>>,
+}
+
+impl<T> Entity<T> {
+    #[inline(always)]
+    fn id(&self) -> ID<Mutex<T>> {
+        #[cfg(not(feature = "deterministic-ids"))]
+        let id = ID::new(Arc::as_ptr(&self.arc));
+        #[cfg(feature = "deterministic-ids")]
+        let id = self.id;
+        id
+    }
+}
+
+impl<T> Clone for Entity<T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        Self {
+            arc: Arc::clone(&self.arc),
+            #[cfg(feature = "deterministic-ids")]
+            id: self.id,
+        }
+    }
+}
+
+impl<T> std::ops::Deref for Entity<T> {
+    type Target = Arc<Mutex<T>>;
+    #[inline(always)]
+    fn deref(&self) -> &Arc<Mutex<T>> { &self.arc }
+}
+
+impl<T: Debug> Debug for Entity<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result { self.arc.fmt(f) }
+}
+
+/// Allocates the shared entity of a new element.
+#[inline(always)]
+fn new_entity<T>(entity: T) -> Entity<T> {
+    Entity {
+        arc: Arc::new(Mutex::new(entity)),
+        #[cfg(feature = "deterministic-ids")]
+        id: ID::register(),
+    }
+}
+
 /// Vertex, the minimum topological unit.
 ///
 /// The constructor `Vertex::new()` creates a different vertex each time.
@@ -107,7 +161,7 @@ const SEARCH_PARAMETER_TRIALS: usize = 100;
 /// ```
 #[derive(Debug)]
 pub struct Vertex<P> {
-    point: Arc<Mutex<P>>,
+    point: Entity<P>,
 }
 
 /// Edge, which consists two vertices.
@@ -126,7 +180,7 @@ pub struct Vertex<P> {
 pub struct Edge<P, C> {
     vertices: (Vertex<P>, Vertex<P>),
     orientation: bool,
-    curve: Arc<Mutex<C>>,
+    curve: Entity<C>,
 }
 
 /// Wire, a path or cycle which consists some edges.
@@ -157,7 +211,7 @@ pub struct Wire<P, C> {
 pub struct Face<P, C, S> {
     boundaries: Vec<Wire<P, C>>,
     orientation: bool,
-    surface: Arc<Mutex<S>>,
+    surface: Entity<S>,
 }
 
 /// Shell, a connected compounded faces.
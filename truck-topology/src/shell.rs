@@ -1,6 +1,7 @@
 use crate::*;
 use rayon::prelude::*;
 use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use truck_base::cgmath64::{EuclideanSpace, InnerSpace, Point3};
 use truck_base::entry_map::FxEntryMap as EntryMap;
 
 /// A face adjacent to the specified face
@@ -131,6 +132,101 @@ impl<P, C, S> Shell<P, C, S> {
         self.edge_iter().collect::<Boundaries<C>>().condition()
     }
 
+    /// Returns the ids of the edges which are not shared by exactly two faces.
+    ///
+    /// The edges are listed in the order of appearance, and an edge appearing twice
+    /// in the boundaries of one face is counted as shared by two faces.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let v = Vertex::news(&[(); 4]);
+    /// let edge = [
+    ///     Edge::new(&v[0], &v[1], ()),
+    ///     Edge::new(&v[1], &v[2], ()),
+    ///     Edge::new(&v[2], &v[0], ()),
+    ///     Edge::new(&v[1], &v[3], ()),
+    ///     Edge::new(&v[3], &v[2], ()),
+    ///     Edge::new(&v[3], &v[0], ()),
+    /// ];
+    /// // three faces share edge[1].
+    /// let shell: Shell<_, _, _> = vec![
+    ///     Face::new(vec![wire![&edge[0], &edge[1], &edge[2]]], ()),
+    ///     Face::new(vec![wire![&edge[1].inverse(), &edge[3], &edge[4]]], ()),
+    ///     Face::new(vec![wire![&edge[1], &edge[4].inverse(), &edge[3].inverse()]], ()),
+    /// ]
+    /// .into();
+    /// assert_eq!(
+    ///     shell.non_manifold_edges(),
+    ///     vec![edge[0].id(), edge[1].id(), edge[2].id()],
+    /// );
+    /// ```
+    pub fn non_manifold_edges(&self) -> Vec<EdgeID<C>> {
+        self.edge_multiplicities()
+            .into_iter()
+            .filter_map(|(id, count)| Some(id).filter(|_| count != 2))
+            .collect()
+    }
+
+    /// Returns the ids of the edges which are included in only one face.
+    ///
+    /// The edges are listed in the order of appearance.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// // a square tube whose ends are not closed.
+    /// let v = Vertex::news(&[(); 8]);
+    /// let edge = [
+    ///     Edge::new(&v[0], &v[1], ()),
+    ///     Edge::new(&v[1], &v[2], ()),
+    ///     Edge::new(&v[2], &v[3], ()),
+    ///     Edge::new(&v[3], &v[0], ()),
+    ///     Edge::new(&v[0], &v[4], ()),
+    ///     Edge::new(&v[1], &v[5], ()),
+    ///     Edge::new(&v[2], &v[6], ()),
+    ///     Edge::new(&v[3], &v[7], ()),
+    ///     Edge::new(&v[4], &v[5], ()),
+    ///     Edge::new(&v[5], &v[6], ()),
+    ///     Edge::new(&v[6], &v[7], ()),
+    ///     Edge::new(&v[7], &v[4], ()),
+    /// ];
+    /// let shell: Shell<_, _, _> = (0..4)
+    ///     .map(|i| {
+    ///         let wire = wire![
+    ///             &edge[i],
+    ///             &edge[(i + 1) % 4 + 4],
+    ///             &edge[i + 8].inverse(),
+    ///             &edge[i + 4].inverse(),
+    ///         ];
+    ///         Face::new(vec![wire], ())
+    ///     })
+    ///     .collect();
+    /// let open_edges = shell.open_edges();
+    /// assert_eq!(open_edges.len(), 8);
+    /// assert!((0..4).all(|i| open_edges.contains(&edge[i].id())));
+    /// assert!((8..12).all(|i| open_edges.contains(&edge[i].id())));
+    /// assert_eq!(shell.non_manifold_edges(), open_edges);
+    /// ```
+    pub fn open_edges(&self) -> Vec<EdgeID<C>> {
+        self.edge_multiplicities()
+            .into_iter()
+            .filter_map(|(id, count)| Some(id).filter(|_| count == 1))
+            .collect()
+    }
+
+    /// the numbers of the appearances of edges in the boundaries, in the order of appearance
+    fn edge_multiplicities(&self) -> Vec<(EdgeID<C>, usize)> {
+        let mut indices = HashMap::<EdgeID<C>, usize>::default();
+        let mut counts = Vec::new();
+        self.edge_iter().for_each(|edge| {
+            let idx = *indices.entry(edge.id()).or_insert_with(|| {
+                counts.push((edge.id(), 0));
+                counts.len() - 1
+            });
+            counts[idx].1 += 1;
+        });
+        counts
+    }
+
     /// Returns a vector of all boundaries as wires.
     /// # Examples
     /// ```
@@ -453,6 +549,72 @@ impl<P, C, S> Shell<P, C, S> {
             .collect()
     }
 
+    /// Splits the shell into the shells of each connected components.
+    ///
+    /// The same as [`Shell::connected_components`], but consumes `self`.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// // the boundary of a cube
+    /// fn cube() -> Shell<(), (), ()> {
+    ///     let v = Vertex::news(&[(); 8]);
+    ///     let edge = [
+    ///         Edge::new(&v[0], &v[1], ()),
+    ///         Edge::new(&v[1], &v[2], ()),
+    ///         Edge::new(&v[2], &v[3], ()),
+    ///         Edge::new(&v[3], &v[0], ()),
+    ///         Edge::new(&v[0], &v[4], ()),
+    ///         Edge::new(&v[1], &v[5], ()),
+    ///         Edge::new(&v[2], &v[6], ()),
+    ///         Edge::new(&v[3], &v[7], ()),
+    ///         Edge::new(&v[4], &v[5], ()),
+    ///         Edge::new(&v[5], &v[6], ()),
+    ///         Edge::new(&v[6], &v[7], ()),
+    ///         Edge::new(&v[7], &v[4], ()),
+    ///     ];
+    ///     let wire = vec![
+    ///         wire![&edge[0], &edge[1], &edge[2], &edge[3]],
+    ///         wire![&edge[0].inverse(), &edge[4], &edge[8], &edge[5].inverse()],
+    ///         wire![&edge[1].inverse(), &edge[5], &edge[9], &edge[6].inverse()],
+    ///         wire![&edge[2].inverse(), &edge[6], &edge[10], &edge[7].inverse()],
+    ///         wire![&edge[3].inverse(), &edge[7], &edge[11], &edge[4].inverse()],
+    ///         wire![&edge[8], &edge[9], &edge[10], &edge[11]],
+    ///     ];
+    ///     wire.into_iter().map(|w| Face::new(vec![w], ())).collect()
+    /// }
+    ///
+    /// let mut shell = cube();
+    /// shell.append(&mut cube());
+    /// assert_eq!(shell.len(), 12);
+    ///
+    /// let components = shell.into_connected_components();
+    /// assert_eq!(components.len(), 2);
+    /// assert!(components.iter().all(|shell| shell.len() == 6));
+    /// ```
+    pub fn into_connected_components(self) -> Vec<Shell<P, C, S>> {
+        let index: HashMap<*const Face<P, C, S>, usize> = self
+            .face_iter()
+            .enumerate()
+            .map(|(i, face)| (std::ptr::from_ref(face), i))
+            .collect();
+        let components: Vec<Vec<usize>> = {
+            let mut adjacency = self.face_adjacency();
+            create_components(&mut adjacency)
+                .into_iter()
+                .map(|vec| {
+                    vec.into_iter()
+                        .map(|face| index[&std::ptr::from_ref(face)])
+                        .collect()
+                })
+                .collect()
+        };
+        let mut faces: Vec<Option<Face<P, C, S>>> = self.into_iter().map(Some).collect();
+        components
+            .into_iter()
+            .map(|vec| vec.into_iter().filter_map(|i| faces[i].take()).collect())
+            .collect()
+    }
+
     /// Returns the vector of all singular vertices.
     ///
     /// Here, we say that a vertex is singular if, for a sufficiently small neighborhood U of
@@ -669,6 +831,75 @@ impl<P, C, S> Shell<P, C, S> {
             .collect()
     }
 
+    /// Edits the surfaces of all faces in place. Each surface shared by several
+    /// faces is visited only once, and the orientations of faces are ignored.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let v = Vertex::news(&[(); 8]);
+    /// let edge = [
+    ///     Edge::new(&v[0], &v[1], 0),
+    ///     Edge::new(&v[1], &v[2], 0),
+    ///     Edge::new(&v[2], &v[3], 0),
+    ///     Edge::new(&v[3], &v[0], 0),
+    ///     Edge::new(&v[0], &v[4], 0),
+    ///     Edge::new(&v[1], &v[5], 0),
+    ///     Edge::new(&v[2], &v[6], 0),
+    ///     Edge::new(&v[3], &v[7], 0),
+    ///     Edge::new(&v[4], &v[5], 0),
+    ///     Edge::new(&v[5], &v[6], 0),
+    ///     Edge::new(&v[6], &v[7], 0),
+    ///     Edge::new(&v[7], &v[4], 0),
+    /// ];
+    /// let wire = vec![
+    ///     wire![&edge[0], &edge[1], &edge[2], &edge[3]],
+    ///     wire![&edge[0].inverse(), &edge[4], &edge[8], &edge[5].inverse()],
+    ///     wire![&edge[1].inverse(), &edge[5], &edge[9], &edge[6].inverse()],
+    ///     wire![&edge[2].inverse(), &edge[6], &edge[10], &edge[7].inverse()],
+    ///     wire![&edge[3].inverse(), &edge[7], &edge[11], &edge[4].inverse()],
+    ///     wire![&edge[8], &edge[9], &edge[10], &edge[11]],
+    /// ];
+    /// let mut shell: Shell<_, _, _> = wire.into_iter().map(|w| Face::new(vec![w], 0)).collect();
+    /// shell[5].invert();
+    ///
+    /// let mut counter = 0;
+    /// shell.map_surfaces_mut(|surface| {
+    ///     *surface += 1;
+    ///     counter += 1;
+    /// });
+    /// assert_eq!(counter, 6);
+    /// assert!(shell.face_iter().all(|face| face.surface() == 1));
+    ///
+    /// let mut counter = 0;
+    /// shell.map_curves_mut(|curve| {
+    ///     *curve += 1;
+    ///     counter += 1;
+    /// });
+    /// assert_eq!(counter, 12);
+    /// assert!(shell.edge_iter().all(|edge| edge.curve() == 1));
+    /// ```
+    /// # Remarks
+    /// Accessing geometry elements directly in the closure will result in a deadlock.
+    pub fn map_surfaces_mut<F: FnMut(&mut S)>(&mut self, mut f: F) {
+        let mut visited = HashSet::default();
+        self.face_iter()
+            .filter(|face| visited.insert(face.id()))
+            .for_each(|face| f(&mut *face.surface.lock()));
+    }
+
+    /// Edits the curves of all edges in place. Each curve shared by several
+    /// edges is visited only once, and the orientations of edges are ignored.
+    ///
+    /// Examples can be found on the page of [`Shell::map_surfaces_mut`].
+    /// # Remarks
+    /// Accessing geometry elements directly in the closure will result in a deadlock.
+    pub fn map_curves_mut<F: FnMut(&mut C)>(&mut self, mut f: F) {
+        let mut visited = HashSet::default();
+        self.edge_iter()
+            .filter(|edge| visited.insert(edge.id()))
+            .for_each(|edge| f(&mut *edge.curve.lock()));
+    }
+
     /// Returns the consistence of the geometry of end vertices
     /// and the geometry of edge.
     #[inline(always)]
@@ -680,6 +911,179 @@ impl<P, C, S> Shell<P, C, S> {
         self.iter().all(|face| face.is_geometric_consistent())
     }
 
+    /// Determines whether the normals of the faces are directed outward or not.
+    ///
+    /// The signed volume surrounded by the shell is computed by the divergence theorem
+    /// on the coarse tessellation: each boundary is divided into polylines, and they are
+    /// connected to the point on the surface nearest to their centroid.
+    ///
+    /// # Returns
+    /// Returns `None` if the shell is not closed or the computed volume is degenerate.
+    pub fn is_outward_oriented(&self) -> Option<bool>
+    where
+        C: BoundedCurve<Point = Point3>,
+        S: ParametricSurface3D + SearchNearestParameter<D2, Point = Point3>, {
+        if self.shell_condition() != ShellCondition::Closed {
+            return None;
+        }
+        let volume = self
+            .face_iter()
+            .map(|face| {
+                let polylines = face
+                    .boundaries()
+                    .iter()
+                    .map(|wire| wire.edge_iter().flat_map(edge_polyline).collect::<Vec<_>>())
+                    .collect::<Vec<_>>();
+                let points = polylines.iter().flatten().copied().collect::<Vec<_>>();
+                let center = Point3::centroid(&points);
+                let surface = face.surface();
+                let apex = surface
+                    .search_nearest_parameter(center, None, SEARCH_PARAMETER_TRIALS)
+                    .map(|(u, v)| surface.subs(u, v))
+                    .unwrap_or(center);
+                polylines
+                    .iter()
+                    .flat_map(|polyline| {
+                        let next = polyline.iter().cycle().skip(1);
+                        polyline
+                            .iter()
+                            .zip(next)
+                            .map(|(p, q)| apex.to_vec().dot(p.to_vec().cross(q.to_vec())) / 6.0)
+                    })
+                    .sum::<f64>()
+            })
+            .sum::<f64>();
+        match volume.so_small() {
+            true => None,
+            false => Some(volume > 0.0),
+        }
+    }
+
+    /// Makes the orientations of the faces consistent, i.e. each edge shared by two faces is
+    /// traversed in the opposite directions by them.
+    ///
+    /// The orientation is propagated from the first face of each connected component across the
+    /// shared edges, and the faces disagreeing with their neighbors are inverted. Hence, a whole
+    /// component may be inside out, which can be checked by [`Shell::is_outward_oriented`].
+    /// The edges shared by more than two faces are ignored.
+    ///
+    /// # Returns
+    /// Returns `false` and does not change `self` if the shell is not orientable, e.g. it contains
+    /// a Möbius band.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// use truck_topology::shell::ShellCondition;
+    /// let v = Vertex::news(&[(); 8]);
+    /// let edge = [
+    ///     Edge::new(&v[0], &v[1], ()),
+    ///     Edge::new(&v[1], &v[2], ()),
+    ///     Edge::new(&v[2], &v[3], ()),
+    ///     Edge::new(&v[3], &v[0], ()),
+    ///     Edge::new(&v[0], &v[4], ()),
+    ///     Edge::new(&v[1], &v[5], ()),
+    ///     Edge::new(&v[2], &v[6], ()),
+    ///     Edge::new(&v[3], &v[7], ()),
+    ///     Edge::new(&v[4], &v[5], ()),
+    ///     Edge::new(&v[5], &v[6], ()),
+    ///     Edge::new(&v[6], &v[7], ()),
+    ///     Edge::new(&v[7], &v[4], ()),
+    /// ];
+    /// let wire = vec![
+    ///     wire![&edge[0], &edge[1], &edge[2], &edge[3]],
+    ///     wire![&edge[0].inverse(), &edge[4], &edge[8], &edge[5].inverse()],
+    ///     wire![&edge[1].inverse(), &edge[5], &edge[9], &edge[6].inverse()],
+    ///     wire![&edge[2].inverse(), &edge[6], &edge[10], &edge[7].inverse()],
+    ///     wire![&edge[3].inverse(), &edge[7], &edge[11], &edge[4].inverse()],
+    ///     wire![&edge[11].inverse(), &edge[10].inverse(), &edge[9].inverse(), &edge[8].inverse()],
+    /// ];
+    /// let mut shell: Shell<_, _, _> = wire.into_iter().map(|w| Face::new(vec![w], ())).collect();
+    /// shell[0].invert();
+    /// shell[2].invert();
+    /// assert_eq!(shell.shell_condition(), ShellCondition::Regular);
+    ///
+    /// assert!(shell.make_consistent_orientation());
+    /// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+    /// // The first face keeps its orientation, so that the other faces are inverted.
+    /// assert!(shell.face_iter().all(|face| !face.orientation()));
+    /// ```
+    /// ```
+    /// // a Möbius band
+    /// use truck_topology::*;
+    /// let v = Vertex::news(&[(); 6]);
+    /// let edge = [
+    ///     Edge::new(&v[0], &v[1], ()),
+    ///     Edge::new(&v[1], &v[2], ()),
+    ///     Edge::new(&v[1], &v[4], ()),
+    ///     Edge::new(&v[3], &v[4], ()),
+    ///     Edge::new(&v[4], &v[5], ()),
+    ///     Edge::new(&v[0], &v[3], ()),
+    ///     Edge::new(&v[2], &v[5], ()),
+    ///     Edge::new(&v[2], &v[3], ()),
+    ///     Edge::new(&v[0], &v[5], ()),
+    /// ];
+    /// let wire = vec![
+    ///     wire![&edge[0], &edge[2], &edge[3].inverse(), &edge[5].inverse()],
+    ///     wire![&edge[1], &edge[6], &edge[4].inverse(), &edge[2].inverse()],
+    ///     wire![&edge[7], &edge[5].inverse(), &edge[8], &edge[6].inverse()],
+    /// ];
+    /// let mut shell: Shell<_, _, _> = wire.into_iter().map(|w| Face::new(vec![w], ())).collect();
+    /// let org_shell = shell.clone();
+    /// assert!(!shell.make_consistent_orientation());
+    /// assert!(shell.iter().zip(&org_shell).all(|(f, g)| f.orientation() == g.orientation()));
+    /// ```
+    pub fn make_consistent_orientation(&mut self) -> bool {
+        let mut edge_faces = HashMap::<EdgeID<C>, Vec<(usize, bool)>>::default();
+        self.face_iter().enumerate().for_each(|(i, face)| {
+            face.absolute_boundaries()
+                .iter()
+                .flatten()
+                .for_each(|edge| {
+                    let orientation = edge.orientation() == face.orientation();
+                    edge_faces
+                        .entry(edge.id())
+                        .or_default()
+                        .push((i, orientation));
+                })
+        });
+        // The pair `(j, toggle)` in `adjacency[i]` means that the face `j` has to be inverted
+        // relative to the face `i` if `toggle` is `true`.
+        let mut adjacency = vec![Vec::new(); self.len()];
+        edge_faces.values().for_each(|faces| {
+            if let [(i, oi), (j, oj)] = faces[..] {
+                adjacency[i].push((j, oi == oj));
+                adjacency[j].push((i, oi == oj));
+            }
+        });
+        let mut inverts = vec![None; self.len()];
+        for seed in 0..self.len() {
+            if inverts[seed].is_some() {
+                continue;
+            }
+            inverts[seed] = Some(false);
+            let mut stack = vec![(seed, false)];
+            while let Some((i, invert)) = stack.pop() {
+                for &(j, toggle) in &adjacency[i] {
+                    match inverts[j] {
+                        None => {
+                            inverts[j] = Some(invert ^ toggle);
+                            stack.push((j, invert ^ toggle));
+                        }
+                        Some(got) if got != invert ^ toggle => return false,
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+        self.face_iter_mut()
+            .zip(inverts)
+            .filter(|(_, invert)| *invert == Some(true))
+            .for_each(|(face, _)| {
+                face.invert();
+            });
+        true
+    }
+
     /// Cuts one edge into two edges at vertex.
     ///
     /// # Returns
@@ -1126,6 +1530,20 @@ impl<P, C> FromIterator<Edge<P, C>> for Boundaries<C> {
     }
 }
 
+/// the sample points on the oriented edge, without the back vertex
+fn edge_polyline<P, C: BoundedCurve<Point = Point3>>(edge: &Edge<P, C>) -> Vec<Point3> {
+    const DIVISION: usize = 8;
+    let curve = edge.curve();
+    let (t0, t1) = curve.range_tuple();
+    (0..DIVISION)
+        .map(|i| {
+            let s = i as f64 / DIVISION as f64;
+            let s = if edge.orientation() { s } else { 1.0 - s };
+            curve.subs(t0 + (t1 - t0) * s)
+        })
+        .collect()
+}
+
 fn check_connectivity<T>(adjacency: &mut HashMap<T, Vec<T>>) -> bool
 where T: Eq + Clone + Hash {
     create_one_component(adjacency);
@@ -1,7 +1,10 @@
+use crate::compress::*;
 use crate::errors::Error;
 use crate::shell::ShellCondition;
 use crate::*;
+use rustc_hash::FxHashMap as HashMap;
 use std::vec::Vec;
+use truck_base::cgmath64::{Array, MetricSpace};
 
 impl<P, C, S> Solid<P, C, S> {
     /// create the shell whose boundaries is boundary.
@@ -29,6 +32,29 @@ impl<P, C, S> Solid<P, C, S> {
         }
         Ok(Solid::new_unchecked(boundaries))
     }
+    /// create the solid whose boundaries are `boundaries`, sewing the faces whose vertices and
+    /// edges coincide up to `tol`.
+    ///
+    /// The shells which are not closed, e.g. imported from files whose faces have their own
+    /// vertices, are rebuilt by identifying the vertices closer than `tol` and the edges with the
+    /// same ends and the nearby middle points. The closed shells are kept as they are.
+    /// # Failure
+    /// All rebuilt boundary must be non-empty, connected, and closed manifold. If `tol` is so
+    /// large that the ends of an edge are identified, returns [`Error::SameVertex`].
+    pub fn try_new_with_tolerance(boundaries: Vec<Shell<P, C, S>>, tol: f64) -> Result<Self>
+    where
+        P: Clone + MetricSpace<Metric = f64> + Array<Element = f64>,
+        C: Clone + BoundedCurve<Point = P>,
+        S: Clone, {
+        let boundaries = boundaries
+            .into_iter()
+            .map(|shell| match shell.shell_condition() {
+                ShellCondition::Closed => Ok(shell),
+                _ => sew_shell(&shell, tol),
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Solid::try_new(boundaries)
+    }
     /// create the shell whose boundaries is boundary.
     /// # Remarks
     /// This method is prepared only for performance-critical development and is not recommended.
@@ -215,6 +241,107 @@ impl<P, C, S> Solid<P, C, S> {
     }
 }
 
+/// Rebuilds `shell` by identifying the vertices and the edges which coincide up to `tol`.
+fn sew_shell<P, C, S>(shell: &Shell<P, C, S>, tol: f64) -> Result<Shell<P, C, S>>
+where
+    P: Clone + MetricSpace<Metric = f64> + Array<Element = f64>,
+    C: Clone + BoundedCurve<Point = P>,
+    S: Clone, {
+    let CompressedShell {
+        vertices,
+        edges,
+        mut faces,
+    } = shell.compress();
+    // The representative vertices are bucketed by the cells of the grid with the width `tol`,
+    // so that the vertices closer than `tol` are in the same or the adjacent cells.
+    let cell = |p: &P| {
+        (0..P::len())
+            .map(|k| f64::floor(p[k] / tol) as i64)
+            .collect::<Vec<_>>()
+    };
+    let adjacent_cells = |cell: &[i64]| {
+        let cell = cell.to_vec();
+        (0..3_usize.pow(cell.len() as u32)).map(move |mut n| {
+            cell.iter()
+                .map(|x| {
+                    let d = (n % 3) as i64 - 1;
+                    n /= 3;
+                    x + d
+                })
+                .collect::<Vec<_>>()
+        })
+    };
+    // Each vertex is represented by the first vertex closer than `tol`.
+    let mut buckets = HashMap::<Vec<i64>, Vec<usize>>::default();
+    let vrep = (0..vertices.len())
+        .map(|i| {
+            let key = cell(&vertices[i]);
+            let rep = adjacent_cells(&key)
+                .filter_map(|key| buckets.get(&key))
+                .flatten()
+                .copied()
+                .filter(|j| vertices[*j].clone().distance(vertices[i].clone()) < tol)
+                .min()
+                .unwrap_or(i);
+            if rep == i {
+                buckets.entry(key).or_default().push(i);
+            }
+            rep
+        })
+        .collect::<Vec<_>>();
+    let middle = |curve: &C| {
+        let (t0, t1) = curve.range_tuple();
+        curve.subs((t0 + t1) / 2.0)
+    };
+    // Each edge is represented by the first edge with the same ends and the nearby middle point.
+    let mut new_edges = Vec::<CompressedEdge<C>>::new();
+    let mut edge_buckets = HashMap::<(usize, usize), Vec<usize>>::default();
+    let erep = edges
+        .into_iter()
+        .map(|CompressedEdge { vertices, curve }| {
+            let ends = (vrep[vertices.0], vrep[vertices.1]);
+            let key = (usize::min(ends.0, ends.1), usize::max(ends.0, ends.1));
+            let bucket = edge_buckets.entry(key).or_default();
+            let found = bucket
+                .iter()
+                .copied()
+                .find(|index| middle(&new_edges[*index].curve).distance(middle(&curve)) < tol);
+            match found {
+                Some(index) => CompressedEdgeIndex {
+                    index,
+                    orientation: new_edges[index].vertices == ends,
+                },
+                None => {
+                    bucket.push(new_edges.len());
+                    new_edges.push(CompressedEdge {
+                        vertices: ends,
+                        curve,
+                    });
+                    CompressedEdgeIndex {
+                        index: new_edges.len() - 1,
+                        orientation: true,
+                    }
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+    faces
+        .iter_mut()
+        .flat_map(|face| face.boundaries.iter_mut().flatten())
+        .for_each(|idx| {
+            let rep = erep[idx.index];
+            *idx = CompressedEdgeIndex {
+                index: rep.index,
+                orientation: idx.orientation == rep.orientation,
+            };
+        });
+    Shell::extract(CompressedShell {
+        vertices,
+        edges: new_edges,
+        faces,
+    })
+}
+
 impl<P: Clone, C: Clone, S: Clone> Solid<P, C, Option<S>> {
     /// Returns the value with the Option removed if there is no `None` in the surfaces of the faces.
     #[inline(always)]
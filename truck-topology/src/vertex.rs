@@ -14,7 +14,7 @@ impl<P> Vertex<P> {
     #[inline(always)]
     pub fn new(point: P) -> Vertex<P> {
         Vertex {
-            point: Arc::new(Mutex::new(point)),
+            point: new_entity(point),
         }
     }
 
@@ -92,7 +92,7 @@ impl<P> Vertex<P> {
 
     /// Returns the id of the vertex.
     #[inline(always)]
-    pub fn id(&self) -> VertexID<P> { ID::new(Arc::as_ptr(&self.point)) }
+    pub fn id(&self) -> VertexID<P> { self.point.id() }
 
     /// Returns how many same vertices.
     ///
@@ -155,14 +155,16 @@ impl<P> Clone for Vertex<P> {
     #[inline(always)]
     fn clone(&self) -> Vertex<P> {
         Vertex {
-            point: Arc::clone(&self.point),
+            point: self.point.clone(),
         }
     }
 }
 
 impl<P> PartialEq for Vertex<P> {
     #[inline(always)]
-    fn eq(&self, other: &Self) -> bool { self.id() == other.id() }
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(Arc::as_ptr(&self.point), Arc::as_ptr(&other.point))
+    }
 }
 
 impl<P> Eq for Vertex<P> {}
@@ -177,7 +179,7 @@ impl<P: Debug> Debug for DebugDisplay<'_, Vertex<P>, VertexDisplayFormat> {
         match self.format {
             VertexDisplayFormat::Full => f
                 .debug_struct("Vertex")
-                .field("id", &Arc::as_ptr(&self.entity.point))
+                .field("id", &self.entity.id())
                 .field("entity", &MutexFmt(&self.entity.point))
                 .finish(),
             VertexDisplayFormat::IDTuple => {
@@ -3,6 +3,7 @@ use rayon::prelude::*;
 use rustc_hash::FxHashSet as HashSet;
 use std::collections::{vec_deque, VecDeque};
 use std::iter::Peekable;
+use truck_base::cgmath64::{InnerSpace, MetricSpace, Point2, Vector2};
 use truck_base::entry_map::FxEntryMap as EntryMap;
 
 impl<P, C> Wire<P, C> {
@@ -504,6 +505,103 @@ impl<P, C> Wire<P, C> {
         self.iter().all(|edge| edge.is_geometric_consistent())
     }
 
+    /// Returns whether each edge and the next edge meet tangentially, i.e. G1-continuously.
+    ///
+    /// The `i`th entry is for the `i`th and the `(i + 1)`th edges. If the wire is cyclic, the last
+    /// entry is for the last and the first edges. The tangent vectors are compared according to
+    /// the orientations of edges, and are aligned if the angle between them is less than `tol`
+    /// radians. The edges whose tangent vectors vanish at the shared vertex are not tangent.
+    pub fn tangent_continuity(&self, tol: f64) -> Vec<bool>
+    where
+        C: BoundedCurve,
+        C::Vector: InnerSpace<Scalar = f64>, {
+        let tangents = self
+            .iter()
+            .map(|edge| {
+                let curve = edge.curve();
+                let (t0, t1) = curve.range_tuple();
+                let (front, back) = (curve.der(t0), curve.der(t1));
+                match edge.orientation() {
+                    true => (front, back),
+                    false => (back * -1.0, front * -1.0),
+                }
+            })
+            .collect::<Vec<_>>();
+        let len = match self.is_cyclic() {
+            true => tangents.len(),
+            false => tangents.len().saturating_sub(1),
+        };
+        (0..len)
+            .map(|i| {
+                let (back, front) = (tangents[i].1, tangents[(i + 1) % tangents.len()].0);
+                let cos = back.dot(front) / (back.magnitude() * front.magnitude());
+                cos >= f64::cos(tol)
+            })
+            .collect()
+    }
+
+    /// Returns the pairs of the indices of edges crossing each other in the parameter space of
+    /// `surface`, with the crossing points.
+    ///
+    /// # Details
+    /// This is a debugging aid for the boundaries which fail to build faces. Each edge is divided
+    /// into a polyline with tolerance `tol`, and the points are projected to the parameter space by
+    /// [`SearchParameter`]. The edges some of whose points cannot be projected are skipped. Each
+    /// pair `(i, j)` with `i < j` is reported at most once, with one of its crossing points.
+    ///
+    /// The edges meeting only at their shared vertices do not intersect each other, i.e. the
+    /// crossing points within `tol` from the shared vertices are ignored.
+    pub fn find_self_intersections<S>(&self, surface: &S, tol: f64) -> Vec<(usize, usize, P)>
+    where
+        P: Clone + MetricSpace<Metric = f64>,
+        C: BoundedCurve<Point = P> + ParameterDivision1D<Point = P>,
+        S: ParametricSurface<Point = P> + SearchParameter<D2, Point = P>, {
+        let polylines = self
+            .iter()
+            .map(|edge| {
+                let curve = edge.curve();
+                let (_, points) = curve.parameter_division(curve.range_tuple(), tol);
+                let mut hint = None;
+                points
+                    .into_iter()
+                    .map(|point| {
+                        let uv = surface.search_parameter(point, hint, 100)?;
+                        hint = Some(uv);
+                        Some(Point2::from(uv))
+                    })
+                    .collect::<Option<Vec<_>>>()
+            })
+            .collect::<Vec<_>>();
+        let edges = self.iter().collect::<Vec<_>>();
+        let mut intersections = Vec::new();
+        for i in 0..edges.len() {
+            for j in i + 1..edges.len() {
+                let (Some(poly0), Some(poly1)) = (&polylines[i], &polylines[j]) else {
+                    continue;
+                };
+                let ends = |k: usize| [edges[k].front(), edges[k].back()];
+                let shared = ends(i)
+                    .into_iter()
+                    .filter(|v| ends(j).contains(v))
+                    .map(|v| v.point())
+                    .collect::<Vec<_>>();
+                let crossing = poly0
+                    .windows(2)
+                    .flat_map(move |seg0| {
+                        poly1
+                            .windows(2)
+                            .filter_map(move |seg1| segment_crossing(seg0, seg1))
+                    })
+                    .map(|uv| surface.subs(uv.x, uv.y))
+                    .find(|p| shared.iter().all(|q| q.clone().distance(p.clone()) >= tol));
+                if let Some(p) = crossing {
+                    intersections.push((i, j, p));
+                }
+            }
+        }
+        intersections
+    }
+
     /// Creates display struct for debugging the wire.
     /// # Examples
     /// ```
@@ -582,6 +680,23 @@ where
     }
 }
 
+/// Returns the intersection of two segments in the parameter space, including their ends.
+fn segment_crossing(seg0: &[Point2], seg1: &[Point2]) -> Option<Point2> {
+    let cross = |a: Vector2, b: Vector2| a.x * b.y - a.y * b.x;
+    let (d0, d1, r) = (seg0[1] - seg0[0], seg1[1] - seg1[0], seg1[0] - seg0[0]);
+    let det = cross(d0, d1);
+    // Parallel segments have no single crossing point. The overlapping ones are detected at their
+    // ends by the neighbor segments.
+    if det == 0.0 {
+        return None;
+    }
+    let (s, t) = (cross(r, d1) / det, cross(r, d0) / det);
+    match (0.0..=1.0).contains(&s) && (0.0..=1.0).contains(&t) {
+        true => Some(seg0[0] + d0 * s),
+        false => None,
+    }
+}
+
 impl<P, C, T> From<T> for Wire<P, C>
 where VecDeque<Edge<P, C>>: From<T>
 {
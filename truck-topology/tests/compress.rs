@@ -0,0 +1,47 @@
+use truck_topology::{compress::*, shell::ShellCondition, *};
+
+fn tetrahedron() -> Solid<usize, usize, usize> {
+    let v = Vertex::news([0, 1, 2, 3]);
+    let edge = [
+        Edge::new(&v[0], &v[1], 10),
+        Edge::new(&v[0], &v[2], 20),
+        Edge::new(&v[0], &v[3], 30),
+        Edge::new(&v[1], &v[2], 40),
+        Edge::new(&v[1], &v[3], 50),
+        Edge::new(&v[2], &v[3], 60),
+    ];
+    let wire = vec![
+        wire![&edge[0], &edge[3], &edge[1].inverse()],
+        wire![&edge[1], &edge[5], &edge[2].inverse()],
+        wire![&edge[2], &edge[4].inverse(), &edge[0].inverse()],
+        wire![&edge[3], &edge[5], &edge[4].inverse()],
+    ];
+    let mut shell: Shell<_, _, _> = wire
+        .into_iter()
+        .enumerate()
+        .map(|(i, wire)| Face::new(vec![wire], 100 * i))
+        .collect();
+    shell[3].invert();
+    Solid::new(vec![shell])
+}
+
+#[test]
+fn compressed_solid_round_trip() {
+    let compressed = tetrahedron().compress();
+    let json = serde_json::to_vec(&compressed).unwrap();
+    let deserialized: CompressedSolid<usize, usize, usize> = serde_json::from_slice(&json).unwrap();
+    assert_eq!(compressed, deserialized);
+
+    let solid = Solid::extract(deserialized).unwrap();
+    let shell = &solid.boundaries()[0];
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+    assert_eq!(solid.compress(), compressed);
+}
+
+#[test]
+fn solid_round_trip() {
+    let solid = tetrahedron();
+    let json = serde_json::to_string(&solid).unwrap();
+    let deserialized: Solid<usize, usize, usize> = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized.compress(), solid.compress());
+}
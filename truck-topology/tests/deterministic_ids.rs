@@ -0,0 +1,56 @@
+#![cfg(feature = "deterministic-ids")]
+
+use std::collections::HashSet;
+use truck_base::id::reset_id_counter;
+use truck_topology::*;
+
+fn tetrahedron() -> Shell<usize, usize, usize> {
+    let v = Vertex::news([0, 1, 2, 3]);
+    let edge = [
+        Edge::new(&v[0], &v[1], 10),
+        Edge::new(&v[0], &v[2], 20),
+        Edge::new(&v[0], &v[3], 30),
+        Edge::new(&v[1], &v[2], 40),
+        Edge::new(&v[1], &v[3], 50),
+        Edge::new(&v[2], &v[3], 60),
+    ];
+    let wire = vec![
+        wire![&edge[0], &edge[3], &edge[1].inverse()],
+        wire![&edge[1], &edge[5], &edge[2].inverse()],
+        wire![&edge[2], &edge[4].inverse(), &edge[0].inverse()],
+        wire![&edge[3], &edge[5], &edge[4].inverse()],
+    ];
+    wire.into_iter()
+        .zip([100, 200, 300, 400])
+        .map(|(wire, surface)| Face::new(vec![wire], surface))
+        .collect()
+}
+
+fn ids(shell: &Shell<usize, usize, usize>) -> (Vec<VertexID<usize>>, Vec<EdgeID<usize>>) {
+    let vertices = shell.vertex_iter().map(|v| v.id()).collect();
+    let edges = shell.edge_iter().map(|e| e.id()).collect();
+    (vertices, edges)
+}
+
+#[test]
+fn reproducible_ids() {
+    reset_id_counter();
+    let shell0 = tetrahedron();
+    let ids0 = ids(&shell0);
+
+    // the ids do not depend on the addresses.
+    let _garbage = vec![Vertex::new(0); 10];
+    reset_id_counter();
+    let shell1 = tetrahedron();
+    let ids1 = ids(&shell1);
+    assert_eq!(ids0, ids1);
+    assert_eq!(format!("{:?}", ids0.0[0]), "0x0");
+
+    // unique within a run
+    let vertex_ids = ids1.0.iter().collect::<HashSet<_>>();
+    assert_eq!(vertex_ids.len(), 4);
+
+    // The counter continues without reset.
+    let shell2 = tetrahedron();
+    assert!(ids(&shell2).0.iter().all(|id| !ids1.0.contains(id)));
+}
@@ -0,0 +1,84 @@
+use std::ops::Bound;
+use truck_base::{assert_near, cgmath64::*, tolerance::*};
+use truck_geotrait::*;
+use truck_topology::*;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Segment(Point3, Point3);
+
+impl ParametricCurve for Segment {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, t: f64) -> Point3 { self.0 + (self.1 - self.0) * t }
+    fn der(&self, _: f64) -> Vector3 { self.1 - self.0 }
+    fn der2(&self, _: f64) -> Vector3 { Vector3::zero() }
+    fn parameter_range(&self) -> ParameterRange { (Bound::Included(0.0), Bound::Included(1.0)) }
+}
+
+impl BoundedCurve for Segment {}
+
+impl ParameterDivision1D for Segment {
+    type Point = Point3;
+    fn parameter_division(&self, (t0, t1): (f64, f64), _: f64) -> (Vec<f64>, Vec<Point3>) {
+        (vec![t0, t1], vec![self.subs(t0), self.subs(t1)])
+    }
+}
+
+/// The plane `z = 0` parametrized by `(x, y)`.
+#[derive(Clone, Copy, Debug)]
+struct XYPlane;
+
+impl ParametricSurface for XYPlane {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, u: f64, v: f64) -> Point3 { Point3::new(u, v, 0.0) }
+    fn uder(&self, _: f64, _: f64) -> Vector3 { Vector3::unit_x() }
+    fn vder(&self, _: f64, _: f64) -> Vector3 { Vector3::unit_y() }
+    fn uuder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn uvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+    fn vvder(&self, _: f64, _: f64) -> Vector3 { Vector3::zero() }
+}
+
+impl SearchParameter<D2> for XYPlane {
+    type Point = Point3;
+    fn search_parameter<H: Into<SPHint2D>>(
+        &self,
+        point: Point3,
+        _: H,
+        _: usize,
+    ) -> Option<(f64, f64)> {
+        match point.z.so_small() {
+            true => Some((point.x, point.y)),
+            false => None,
+        }
+    }
+}
+
+fn polygon(points: &[(f64, f64)]) -> Wire<Point3, Segment> {
+    let v = points
+        .iter()
+        .map(|(x, y)| Vertex::new(Point3::new(*x, *y, 0.0)))
+        .collect::<Vec<_>>();
+    (0..v.len())
+        .map(|i| {
+            let (p, q) = (&v[i], &v[(i + 1) % v.len()]);
+            Edge::new(p, q, Segment(p.point(), q.point()))
+        })
+        .collect()
+}
+
+#[test]
+fn figure_eight() {
+    let wire = polygon(&[(0.0, 0.0), (1.0, 1.0), (1.0, 0.0), (0.0, 1.0)]);
+    let intersections = wire.find_self_intersections(&XYPlane, 1.0e-3);
+    assert_eq!(intersections.len(), 1);
+    let (i, j, p) = intersections[0];
+    assert_eq!((i, j), (0, 2));
+    assert_near!(p, Point3::new(0.5, 0.5, 0.0));
+}
+
+#[test]
+fn simple_square() {
+    let wire = polygon(&[(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)]);
+    assert!(wire.find_self_intersections(&XYPlane, 1.0e-3).is_empty());
+}
@@ -0,0 +1,87 @@
+use std::{collections::HashSet, ops::Bound};
+use truck_base::cgmath64::*;
+use truck_geotrait::*;
+use truck_topology::{errors::Error, *};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Segment(Point3, Point3);
+
+impl ParametricCurve for Segment {
+    type Point = Point3;
+    type Vector = Vector3;
+    fn subs(&self, t: f64) -> Point3 { self.0 + (self.1 - self.0) * t }
+    fn der(&self, _: f64) -> Vector3 { self.1 - self.0 }
+    fn der2(&self, _: f64) -> Vector3 { Vector3::zero() }
+    fn parameter_range(&self) -> ParameterRange { (Bound::Included(0.0), Bound::Included(1.0)) }
+}
+
+impl BoundedCurve for Segment {}
+
+/// The faces of the unit cube, each of which has its own vertices and edges, as in the files
+/// exported face by face. The vertices are perturbed by `1.0e-5` at most.
+fn scattered_cube(missing_face: bool) -> Shell<Point3, Segment, ()> {
+    let corner = |i: usize| Point3::new((i & 1) as f64, ((i >> 1) & 1) as f64, (i >> 2) as f64);
+    let faces = [
+        [0, 2, 3, 1],
+        [0, 1, 5, 4],
+        [1, 3, 7, 5],
+        [3, 2, 6, 7],
+        [2, 0, 4, 6],
+        [4, 5, 7, 6],
+    ];
+    let len = faces.len() - missing_face as usize;
+    faces[..len]
+        .iter()
+        .enumerate()
+        .map(|(k, face)| {
+            let v = face
+                .iter()
+                .enumerate()
+                .map(|(l, i)| {
+                    let t = (4 * k + l) as f64;
+                    let noise = Vector3::new(f64::sin(t), f64::cos(t), f64::sin(2.0 * t));
+                    Vertex::new(corner(*i) + noise * 0.5e-5)
+                })
+                .collect::<Vec<_>>();
+            let wire = (0..4)
+                .map(|l| {
+                    let (p, q) = (&v[l], &v[(l + 1) % 4]);
+                    Edge::new(p, q, Segment(p.point(), q.point()))
+                })
+                .collect::<Wire<_, _>>();
+            Face::new(vec![wire], ())
+        })
+        .collect()
+}
+
+#[test]
+fn sew_scattered_cube() {
+    let shell = scattered_cube(false);
+    assert_eq!(
+        Solid::try_new(vec![shell.clone()]).unwrap_err(),
+        Error::NotConnected,
+    );
+    assert!(Solid::try_new_with_tolerance(vec![shell.clone()], 1.0e-6).is_err());
+
+    let solid = Solid::try_new_with_tolerance(vec![shell], 1.0e-4).unwrap();
+    let shell = &solid.boundaries()[0];
+    assert_eq!(shell.len(), 6);
+    let vertex_ids = shell.vertex_iter().map(|v| v.id()).collect::<HashSet<_>>();
+    assert_eq!(vertex_ids.len(), 8);
+    let edge_ids = shell.edge_iter().map(|e| e.id()).collect::<HashSet<_>>();
+    assert_eq!(edge_ids.len(), 12);
+}
+
+#[test]
+fn reject_open_shell() {
+    let shell = scattered_cube(true);
+    assert_eq!(
+        Solid::try_new_with_tolerance(vec![shell.clone()], 1.0e-4).unwrap_err(),
+        Error::NotClosedShell,
+    );
+    // All the vertices are identified with the excessive tolerance.
+    assert_eq!(
+        Solid::try_new_with_tolerance(vec![shell], 10.0).unwrap_err(),
+        Error::SameVertex,
+    );
+}